@@ -1,6 +1,7 @@
 use std::path::Path;
 
 use imxrt_dcd as dcd;
+#[allow(unused_imports)]
 use imxrt_ral as ral;
 
 fn main() -> std::io::Result<()> {
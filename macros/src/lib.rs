@@ -0,0 +1,492 @@
+//! Procedural macros for [`imxrt-dcd`](https://docs.rs/imxrt-dcd). Use them through the `"derive"`
+//! feature of `imxrt-dcd`, which re-exports them: [`DcdFragment`] as
+//! `imxrt_dcd::board::DcdFragment`, and [`macro@dcd`] as `imxrt_dcd::dcd`.
+
+use proc_macro::TokenStream;
+use proc_macro2::Span;
+use quote::quote;
+use syn::punctuated::Punctuated;
+use syn::{
+    parse_macro_input, Attribute, Data, DeriveInput, Error, Expr, ExprIf, ExprLit, Fields, ItemFn,
+    Lit, LitStr, Macro, Member, ReturnType, Stmt, StmtMacro, Token,
+};
+
+/// Implements `DcdFragment` for a configuration struct whose fields are register fields (or whole
+/// registers), named by `#[dcd(...)]` attributes:
+///
+/// ```
+/// use imxrt_dcd::board::DcdFragment;
+/// use imxrt_dcd::Command;
+///
+/// #[derive(DcdFragment)]
+/// #[dcd(name = "clocks")]
+/// struct Clocks {
+///     #[dcd(peripheral = "CCM", register = "CBCDR", field = "SEMC_PODF")]
+///     semc_podf: u8,
+///     #[dcd(peripheral = "CCM", register = "CBCDR", field = "SEMC_CLK_SEL")]
+///     semc_alt_clock: bool,
+///     #[dcd(peripheral = "CCM", register = "CCGR3")]
+///     gates: u32,
+///     /// Fields without an attribute are ignored.
+///     comment: &'static str,
+/// }
+///
+/// let clocks = Clocks {
+///     semc_podf: 2,
+///     semc_alt_clock: true,
+///     gates: 0xFFFF_FFFF,
+///     comment: "SEMC at 198 MHz",
+/// };
+/// assert_eq!(clocks.name(), "clocks");
+/// assert_eq!(
+///     clocks.commands(),
+///     [
+///         Command::clear32(0x400F_C014, 0b111 << 16 | 1 << 6),
+///         Command::set32(0x400F_C014, 2 << 16 | 1 << 6),
+///         Command::write32(0x400F_C074, 0xFFFF_FFFF),
+///     ],
+/// );
+/// ```
+///
+/// Each field becomes a [`FieldWrites::field`] (or [`FieldWrites::register`] without `field`)
+/// with the field value converted by `Into<u32>`, so the types may be integers, `bool`s or enums
+/// implementing `Into<u32>`. Fields of the same register are combined into one `[clear, set]`
+/// pair, in order of first use. The fragment name defaults to the name of the struct.
+///
+/// Names are resolved through `imxrt_dcd::registers` at compile time, so a misspelled peripheral,
+/// register or field name, or a read-only register, fails the build (naming the struct field).
+/// Values that do not fit their field panic when the commands are generated.
+///
+/// [`FieldWrites::field`]: https://docs.rs/imxrt-dcd/latest/imxrt_dcd/registers/struct.FieldWrites.html#method.field
+/// [`FieldWrites::register`]: https://docs.rs/imxrt-dcd/latest/imxrt_dcd/registers/struct.FieldWrites.html#method.register
+///
+/// ```compile_fail
+/// # use imxrt_dcd::board::DcdFragment;
+/// #[derive(DcdFragment)]
+/// struct Clocks {
+///     #[dcd(peripheral = "CCM", register = "CBCDR", field = "SEMC_PDOF")]
+///     semc_podf: u8,
+/// }
+/// ```
+///
+/// Every mapped field needs a peripheral and a register:
+///
+/// ```compile_fail
+/// # use imxrt_dcd::board::DcdFragment;
+/// #[derive(DcdFragment)]
+/// struct Clocks {
+///     #[dcd(peripheral = "CCM", field = "SEMC_PODF")]
+///     semc_podf: u8,
+/// }
+/// ```
+#[proc_macro_derive(DcdFragment, attributes(dcd))]
+pub fn derive_dcd_fragment(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    dcd_fragment(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn dcd_fragment(input: DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let ident = &input.ident;
+    let mut name = LitStr::new(&ident.to_string(), ident.span());
+    for attr in input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("dcd"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("name") {
+                name = meta.value()?.parse()?;
+                Ok(())
+            } else {
+                Err(meta.error("expected `name = \"...\"`"))
+            }
+        })?;
+    }
+
+    let fields = match &input.data {
+        Data::Struct(data) => &data.fields,
+        _ => {
+            return Err(Error::new(
+                Span::call_site(),
+                "`DcdFragment` can only be derived for structs",
+            ))
+        }
+    };
+    let (mut checks, mut writes) = (vec![], vec![]);
+    for (index, field) in fields.iter().enumerate() {
+        let member = match (&field.ident, fields) {
+            (Some(ident), Fields::Named(_)) => Member::Named(ident.clone()),
+            _ => Member::Unnamed(index.into()),
+        };
+        for attr in field
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("dcd"))
+        {
+            let (mut peripheral, mut register, mut reg_field) = (None, None, None);
+            attr.parse_nested_meta(|meta| {
+                let slot = if meta.path.is_ident("peripheral") {
+                    &mut peripheral
+                } else if meta.path.is_ident("register") {
+                    &mut register
+                } else if meta.path.is_ident("field") {
+                    &mut reg_field
+                } else {
+                    return Err(meta.error("expected `peripheral`, `register` or `field`"));
+                };
+                *slot = Some(meta.value()?.parse::<LitStr>()?);
+                Ok(())
+            })?;
+            let (Some(peripheral), Some(register)) = (peripheral, register) else {
+                return Err(Error::new_spanned(
+                    attr,
+                    "expected `#[dcd(peripheral = \"...\", register = \"...\")]`, \
+                     with an optional `field = \"...\"`",
+                ));
+            };
+            let value = quote! {
+                ::core::convert::Into::<u32>::into(::core::clone::Clone::clone(&self.#member))
+            };
+            let write = match &reg_field {
+                Some(reg_field) => quote! { field(#peripheral, #register, #reg_field, #value) },
+                None => quote! { register(#peripheral, #register, #value) },
+            };
+            let context = format!("`{}::{}`", ident, quote!(#member));
+            let name = format!("{}_{}", peripheral.value(), register.value());
+            let unknown_register = format!("{}: unknown register `{}`", context, name);
+            let read_only = format!("{}: register `{}` is read-only", context, name);
+            let check_field = reg_field.as_ref().map(|reg_field| {
+                let unknown_field = format!(
+                    "{}: unknown field `{}` of register `{}`",
+                    context,
+                    reg_field.value(),
+                    name
+                );
+                quote! {
+                    if register.field(#reg_field).is_none() {
+                        ::core::panic!(#unknown_field);
+                    }
+                }
+            });
+            checks.push(quote! {
+                const _: () = match ::imxrt_dcd::registers::by_name(#peripheral, #register) {
+                    ::core::option::Option::Some(register) => {
+                        if ::core::matches!(
+                            register.access,
+                            ::imxrt_dcd::registers::Access::ReadOnly
+                        ) {
+                            ::core::panic!(#read_only);
+                        }
+                        #check_field
+                    }
+                    ::core::option::Option::None => ::core::panic!(#unknown_register),
+                };
+            });
+            writes.push(quote! {
+                if let ::core::result::Result::Err(err) = writes.#write {
+                    ::core::panic!("{}: {}", #context, err);
+                }
+            });
+        }
+    }
+
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        #(#checks)*
+
+        impl #impl_generics ::imxrt_dcd::board::DcdFragment for #ident #ty_generics #where_clause {
+            fn name(&self) -> &str {
+                #name
+            }
+
+            fn commands(&self) -> ::std::vec::Vec<::imxrt_dcd::Command> {
+                let mut writes = ::imxrt_dcd::registers::FieldWrites::new();
+                #(#writes)*
+                writes.commands()
+            }
+        }
+    })
+}
+
+/// Turns a function whose body lists commands in an imperative style into a function returning a
+/// `static` command table, built at compile time:
+///
+/// ```
+/// use imxrt_dcd::{dcd, CheckCond, Command};
+///
+/// const SEMC: u32 = 0x402F_0000;
+///
+/// /// SDRAM setup.
+/// #[dcd]
+/// pub fn sdram() {
+///     write!(32, 0x400F_C068, 0xFFFF_FFFF);
+///     set!(32, SEMC + 0x08, 1 << 31);
+///     if cfg!(feature = "sdram-32mb") {
+///         write!(32, SEMC + 0x10, 0x8000_001B);
+///     } else {
+///         write!(32, SEMC + 0x10, 0x8000_0019);
+///     }
+///     check!(32, all_set, SEMC + 0x3C, 1, 100);
+///     nop!();
+/// }
+///
+/// assert_eq!(
+///     sdram(),
+///     [
+///         Command::write32(0x400F_C068, 0xFFFF_FFFF),
+///         Command::set32(0x402F_0008, 1 << 31),
+///         Command::write32(0x402F_0010, 0x8000_0019),
+///         Command::check32(CheckCond::AllSet, 0x402F_003C, 1).with_count(100),
+///         Command::Nop,
+///     ],
+/// );
+/// ```
+///
+/// The function becomes `fn sdram() -> &'static [Command]`, keeping its attributes and visibility.
+/// The body consists of these statements, named like in the [text format]:
+///
+/// | statement                                  | command                                    |
+/// |--------------------------------------------|--------------------------------------------|
+/// | `write!(bits, address, value)`             | `Write` with `WriteOp::Write`              |
+/// | `set!(bits, address, value)`               | `Write` with `WriteOp::Set`                |
+/// | `clear!(bits, address, value)`             | `Write` with `WriteOp::Clear`              |
+/// | `check!(bits, cond, address, mask)`        | `Check`, polling indefinitely              |
+/// | `check!(bits, cond, address, mask, count)` | `Check` with a poll count                  |
+/// | `nop!()`                                   | `Command::Nop`                             |
+/// | `command!(expr)`                           | any constant `Command` expression          |
+///
+/// `bits` is one of `8`, `16` or `32`, and `cond` one of `all_clear`, `any_clear`, `all_set` and
+/// `any_set`; the other arguments are constant expressions. Statements can be made conditional
+/// with `if cfg!(...) { ... } else { ... }` (also `else if`) or a `#[cfg(...)]` attribute, which
+/// become `#[cfg]` attributes on the table entries. Other conditions and statements are
+/// compile errors, since there is no code left to run them:
+///
+/// ```compile_fail
+/// # use imxrt_dcd::dcd;
+/// #[dcd]
+/// fn sdram() {
+///     for cs in 0..2 {
+///         write!(32, 0x402F_0010 + 4 * cs, 0x8000_001B);
+///     }
+/// }
+/// ```
+///
+/// [text format]: https://docs.rs/imxrt-dcd/latest/imxrt_dcd/text/index.html
+#[proc_macro_attribute]
+pub fn dcd(attr: TokenStream, item: TokenStream) -> TokenStream {
+    if let Some(token) = proc_macro2::TokenStream::from(attr).into_iter().next() {
+        return Error::new_spanned(token, "`#[dcd]` takes no arguments")
+            .into_compile_error()
+            .into();
+    }
+    let item = parse_macro_input!(item as ItemFn);
+    command_table(item)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn command_table(item: ItemFn) -> syn::Result<proc_macro2::TokenStream> {
+    let sig = &item.sig;
+    if !sig.inputs.is_empty()
+        || !sig.generics.params.is_empty()
+        || !matches!(sig.output, ReturnType::Default)
+        || sig.asyncness.is_some()
+    {
+        return Err(Error::new_spanned(
+            sig,
+            "expected `fn name()` without parameters, generics or return type",
+        ));
+    }
+    let mut entries = vec![];
+    statements(&item.block.stmts, &[], &mut entries)?;
+    let (attrs, vis, ident) = (&item.attrs, &item.vis, &sig.ident);
+    Ok(quote! {
+        #(#attrs)*
+        #vis fn #ident() -> &'static [::imxrt_dcd::Command] {
+            static COMMANDS: &[::imxrt_dcd::Command] = &[#(#entries),*];
+            COMMANDS
+        }
+    })
+}
+
+/// Appends the table entries of the statements, under the `cfg` predicates of the enclosing
+/// conditions.
+fn statements(
+    stmts: &[Stmt],
+    cfgs: &[proc_macro2::TokenStream],
+    entries: &mut Vec<proc_macro2::TokenStream>,
+) -> syn::Result<()> {
+    for stmt in stmts {
+        match stmt {
+            Stmt::Macro(StmtMacro { attrs, mac, .. }) => {
+                entries.push(entry(&with_cfgs(cfgs, attrs)?, command(mac)?));
+            }
+            Stmt::Expr(Expr::Macro(expr), _) => {
+                entries.push(entry(&with_cfgs(cfgs, &expr.attrs)?, command(&expr.mac)?));
+            }
+            Stmt::Expr(Expr::If(expr), _) => {
+                conditional(expr, &with_cfgs(cfgs, &expr.attrs)?, entries)?;
+            }
+            _ => {
+                return Err(Error::new_spanned(
+                    stmt,
+                    "expected `write!`, `set!`, `clear!`, `check!`, `nop!`, `command!` or \
+                     `if cfg!(...) { ... }`",
+                ))
+            }
+        }
+    }
+    Ok(())
+}
+
+fn conditional(
+    expr: &ExprIf,
+    cfgs: &[proc_macro2::TokenStream],
+    entries: &mut Vec<proc_macro2::TokenStream>,
+) -> syn::Result<()> {
+    let predicate =
+        match &*expr.cond {
+            Expr::Macro(cond) if cond.mac.path.is_ident("cfg") => cond.mac.tokens.clone(),
+            cond => return Err(Error::new_spanned(
+                cond,
+                "only `cfg!(...)` conditions are supported, since the table is built at compile \
+                 time",
+            )),
+        };
+    let mut then = cfgs.to_vec();
+    then.push(predicate.clone());
+    statements(&expr.then_branch.stmts, &then, entries)?;
+    if let Some((_, otherwise)) = &expr.else_branch {
+        let mut cfgs = cfgs.to_vec();
+        cfgs.push(quote!(not(#predicate)));
+        match &**otherwise {
+            Expr::If(expr) => conditional(expr, &cfgs, entries)?,
+            Expr::Block(block) => statements(&block.block.stmts, &cfgs, entries)?,
+            otherwise => return Err(Error::new_spanned(otherwise, "expected a block")),
+        }
+    }
+    Ok(())
+}
+
+/// Adds the predicates of `#[cfg(...)]` attributes, the only attributes allowed on statements.
+fn with_cfgs(
+    cfgs: &[proc_macro2::TokenStream],
+    attrs: &[Attribute],
+) -> syn::Result<Vec<proc_macro2::TokenStream>> {
+    let mut cfgs = cfgs.to_vec();
+    for attr in attrs {
+        if !attr.path().is_ident("cfg") {
+            return Err(Error::new_spanned(attr, "expected `#[cfg(...)]`"));
+        }
+        cfgs.push(attr.parse_args()?);
+    }
+    Ok(cfgs)
+}
+
+fn entry(
+    cfgs: &[proc_macro2::TokenStream],
+    command: proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    if cfgs.is_empty() {
+        command
+    } else {
+        quote!(#[cfg(all(#(#cfgs),*))] #command)
+    }
+}
+
+/// Returns the `Command` expression of a statement such as `write!(32, address, value)`.
+fn command(mac: &Macro) -> syn::Result<proc_macro2::TokenStream> {
+    let name = match mac.path.get_ident() {
+        Some(ident) => ident.to_string(),
+        None => String::new(),
+    };
+    let args: Vec<Expr> = mac
+        .parse_body_with(Punctuated::<Expr, Token![,]>::parse_terminated)?
+        .into_iter()
+        .collect();
+    let arity = |counts: &[usize], usage: &str| {
+        if counts.contains(&args.len()) {
+            Ok(())
+        } else {
+            Err(Error::new_spanned(mac, format!("expected `{}`", usage)))
+        }
+    };
+    let op = match name.as_str() {
+        "write" => quote!(Write),
+        "set" => quote!(Set),
+        "clear" => quote!(Clear),
+        "check" => {
+            arity(&[4, 5], "check!(bits, cond, address, mask[, count])")?;
+            let width = width(&args[0])?;
+            let cond = cond(&args[1])?;
+            let (address, mask) = (&args[2], &args[3]);
+            let check = quote! {
+                ::imxrt_dcd::Check::new(::imxrt_dcd::Width::#width, ::imxrt_dcd::CheckCond::#cond, #address, #mask)
+            };
+            return Ok(match args.get(4) {
+                Some(count) => quote!(::imxrt_dcd::Command::Check(#check.with_count(#count))),
+                None => quote!(::imxrt_dcd::Command::Check(#check)),
+            });
+        }
+        "nop" => {
+            arity(&[0], "nop!()")?;
+            return Ok(quote!(::imxrt_dcd::Command::Nop));
+        }
+        "command" => {
+            arity(&[1], "command!(expr)")?;
+            let command = &args[0];
+            return Ok(quote!(#command));
+        }
+        _ => {
+            return Err(Error::new_spanned(
+                &mac.path,
+                "expected `write!`, `set!`, `clear!`, `check!`, `nop!` or `command!`",
+            ))
+        }
+    };
+    arity(&[3], &format!("{}!(bits, address, value)", name))?;
+    let width = width(&args[0])?;
+    let (address, value) = (&args[1], &args[2]);
+    Ok(quote! {
+        ::imxrt_dcd::Command::Write(::imxrt_dcd::Write::new(
+            ::imxrt_dcd::Width::#width, ::imxrt_dcd::WriteOp::#op, #address, #value))
+    })
+}
+
+fn width(bits: &Expr) -> syn::Result<proc_macro2::TokenStream> {
+    if let Expr::Lit(ExprLit {
+        lit: Lit::Int(lit), ..
+    }) = bits
+    {
+        match lit.base10_digits() {
+            "8" => return Ok(quote!(B1)),
+            "16" => return Ok(quote!(B2)),
+            "32" => return Ok(quote!(B4)),
+            _ => {}
+        }
+    }
+    Err(Error::new_spanned(
+        bits,
+        "expected a width of `8`, `16` or `32` bits",
+    ))
+}
+
+fn cond(name: &Expr) -> syn::Result<proc_macro2::TokenStream> {
+    if let Expr::Path(path) = name {
+        if let Some(ident) = path.path.get_ident() {
+            match ident.to_string().as_str() {
+                "all_clear" => return Ok(quote!(AllClear)),
+                "any_clear" => return Ok(quote!(AnyClear)),
+                "all_set" => return Ok(quote!(AllSet)),
+                "any_set" => return Ok(quote!(AnySet)),
+                _ => {}
+            }
+        }
+    }
+    Err(Error::new_spanned(
+        name,
+        "expected `all_clear`, `any_clear`, `all_set` or `any_set`",
+    ))
+}
@@ -0,0 +1,100 @@
+#!/usr/bin/env python3
+"""Generates the allowed DCD Write address ranges of each chip family.
+
+Usage: scripts/gen-allowed-ranges.py
+
+Reads `src/allowed_ranges.toml` and writes `src/allowed_ranges.rs` (see `Target::allowed_ranges`).
+Targets without any range (e.g. the RT1180, which has no DCD) get an empty table. The table of
+each chip family is gated by the cargo feature of its imxrt-ral chip; disabled families fall back
+to an empty table.
+"""
+
+import os
+import tomllib
+
+ROOT = os.path.join(os.path.dirname(os.path.abspath(__file__)), "..")
+INPUT = os.path.join(ROOT, "src", "allowed_ranges.toml")
+OUTPUT = os.path.join(ROOT, "src", "allowed_ranges.rs")
+# `Target` variants, in declaration order
+TARGETS = [
+    "Imxrt1010",
+    "Imxrt1015",
+    "Imxrt1020",
+    "Imxrt1050",
+    "Imxrt1060",
+    "Imxrt1064",
+    "Imxrt1180",
+]
+# `Target` variant => cargo feature (imxrt-ral chip) gating its table
+FEATURES = {
+    "Imxrt1010": "imxrt1011",
+    "Imxrt1015": "imxrt1015",
+    "Imxrt1020": "imxrt1021",
+    "Imxrt1050": "imxrt1052",
+    "Imxrt1060": "imxrt1062",
+    "Imxrt1064": "imxrt1064",
+}
+
+
+def rust_str(s):
+    return '"%s"' % s.replace("\\", "\\\\").replace('"', '\\"').strip()
+
+
+def hex32(n):
+    return "0x%04X_%04X" % (n >> 16, n & 0xFFFF)
+
+
+def arms(out, ranges, entry):
+    for target in TARGETS:
+        if target in FEATURES:
+            out.append('        #[cfg(feature = "%s")]' % FEATURES[target])
+        out.append("        Target::%s => &[" % target)
+        for r in ranges:
+            if target in r["targets"]:
+                out.extend(entry(r))
+        out.append("        ],")
+    out.append("        #[allow(unreachable_patterns)]")
+    out.append("        _ => &[],")
+
+
+def main():
+    with open(INPUT, "rb") as f:
+        ranges = tomllib.load(f)["range"]
+    for r in ranges:
+        if not 0 <= r["begin"] <= r["end"] <= 0xFFFF_FFFF:
+            raise ValueError("invalid range %r" % r)
+        for target in r["targets"]:
+            if target not in TARGETS:
+                raise ValueError("unknown target %r in range %r" % (target, r["name"]))
+
+    out = []
+    out.append("//! Generated by `scripts/gen-allowed-ranges.py` from `src/allowed_ranges.toml`; do not edit.")
+    out.append("")
+    out.append("use crate::{AllowedRange, Target};")
+    out.append("")
+    out.append("pub(crate) const fn allowed_ranges(target: Target) -> &'static [AllowedRange] {")
+    out.append("    match target {")
+    arms(out, ranges, lambda r: [
+        "            AllowedRange {",
+        "                begin: %s," % hex32(r["begin"]),
+        "                end: %s," % hex32(r["end"]),
+        "                name: %s," % rust_str(r["name"]),
+        "            },",
+    ])
+    out.append("    }")
+    out.append("}")
+    out.append("")
+    out.append("pub(crate) const fn write_ranges(target: Target) -> &'static [(u32, u32, &'static str)] {")
+    out.append("    match target {")
+    arms(out, ranges, lambda r: [
+        "            (%s, %s, %s)," % (hex32(r["begin"]), hex32(r["end"]), rust_str(r["name"])),
+    ])
+    out.append("    }")
+    out.append("}")
+    out.append("")
+    with open(OUTPUT, "w") as f:
+        f.write("\n".join(out))
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,262 @@
+#!/usr/bin/env python3
+"""Generates register tables from the imxrt-ral sources.
+
+Usage: scripts/gen-registers.py [path/to/imxrt-ral-0.5.x]
+
+If no path is given, the imxrt-ral version in `Cargo.lock` is looked up in the cargo registry.
+
+- `src/registers/imxrt1062.rs`: registers and fields of the peripherals within the valid DCD
+  Write command address ranges (see `Target::write_ranges`).
+- `src/detect/ranges.rs`: address ranges of the registers of each chip family; registers of the
+  above peripherals individually, other peripherals as whole instances. Each family is gated by
+  the cargo feature named after its imxrt-ral chip.
+"""
+
+import glob
+import os
+import re
+import sys
+
+CHIP = "imxrt1062"
+PERIPHERALS = [
+    "iomuxc_snvs_gpr",
+    "iomuxc_snvs",
+    "iomuxc_gpr",
+    "iomuxc",
+    "ccm_analog",
+    "pmu",
+    "tempmon",
+    "usb_analog",
+    "xtalosc24m",
+    "usbphy",
+    "ccm",
+    "semc",
+]
+# read-only fields of read-write registers (module => field names), from the reference manual;
+# imxrt-ral does not tell fields apart by access
+READ_ONLY_FIELDS = {
+    "ccm_analog": [
+        "LOCK", "PFD0_STABLE", "PFD1_STABLE", "PFD2_STABLE", "PFD3_STABLE", "REFTOP_VBGUP",
+        "OSC_XTALOK", "IRQ_TEMPPANIC", "IRQ_TEMPLOW", "IRQ_TEMPHIGH", "IRQ_ANA_BO", "IRQ_DIG_BO",
+        "REG0_BO_STATUS", "REG0_OK", "REG1_BO_STATUS", "REG1_OK", "REG2_BO_STATUS", "REG2_OK",
+    ],
+    "pmu": [
+        "BO_VDD1P1", "OK_VDD1P1", "BO_VDD3P0", "OK_VDD3P0", "BO_VDD2P5", "OK_VDD2P5",
+        "REFTOP_VBGUP", "OSC_XTALOK", "IRQ_TEMPPANIC", "IRQ_TEMPLOW", "IRQ_TEMPHIGH",
+        "IRQ_ANA_BO", "IRQ_DIG_BO", "REG0_BO_STATUS", "REG1_BO_STATUS", "REG2_BO_STATUS",
+        "REG2_OK",
+    ],
+    "tempmon": ["FINISHED", "TEMP_CNT"],
+    "xtalosc24m": [
+        "REFTOP_VBGUP", "OSC_XTALOK", "XTALOSC_PWRUP_STAT", "COUNT_RC_CUR", "CLK_1M_ERR_FL",
+    ],
+    "usbphy": ["HOSTDISCONDETECT_STATUS", "DEVPLUGIN_STATUS", "OTGID_STATUS", "RESUME_STATUS"],
+}
+ACCESS = {"RW": "ReadWrite", "RO": "ReadOnly", "WO": "WriteOnly"}
+# chip family (`Target` variant) => imxrt-ral chip
+FAMILIES = [
+    ("Imxrt1010", "imxrt1011"),
+    ("Imxrt1015", "imxrt1015"),
+    ("Imxrt1020", "imxrt1021"),
+    ("Imxrt1050", "imxrt1052"),
+    ("Imxrt1060", "imxrt1062"),
+    ("Imxrt1064", "imxrt1064"),
+]
+# address space of a peripheral instance
+PERIPHERAL_SIZE = 0x4000
+ROOT = os.path.join(os.path.dirname(__file__), "..")
+OUTPUT = os.path.join(ROOT, "src", "registers", CHIP + ".rs")
+RANGES_OUTPUT = os.path.join(ROOT, "src", "detect", "ranges.rs")
+
+REG_RE = re.compile(
+    r"^    pub (\w+): (?:\[)?crate::(RW|RO|WO)Register<u(8|16|32)>(?:; (\d+)usize\])?,$"
+)
+RESERVED_RE = re.compile(r"^    _reserved\d+: \[u8; (0x[0-9a-fA-F]+|\d+)\],$")
+DOC_RE = re.compile(r'^\s*#\[doc = "(.*)"\]$')
+MOD_RE = re.compile(r"^pub mod (\w+) \{$")
+FIELD_RE = re.compile(r"^    pub mod (\w+) \{$")
+OFFSET_RE = re.compile(r"^        pub const offset: u32 = (\d+);$")
+MASK_RE = re.compile(r"^        pub const mask: u32 = (0x[0-9a-fA-F]+|\d+) << offset;$")
+
+
+def find_ral():
+    if len(sys.argv) > 1:
+        return sys.argv[1]
+    lock = open(os.path.join(ROOT, "Cargo.lock")).read()
+    version = re.search(r'name = "imxrt-ral"\nversion = "([^"]+)"', lock).group(1)
+    home = os.environ.get("CARGO_HOME", os.path.expanduser("~/.cargo"))
+    candidates = sorted(glob.glob(os.path.join(home, "registry/src/*/imxrt-ral-" + version)))
+    if not candidates:
+        sys.exit("imxrt-ral not found; run `cargo fetch` or pass its path")
+    return candidates[-1]
+
+
+def parse_instances(ral, chip):
+    """Returns {module: ([(instance, base address)], block file)} of all peripherals."""
+    text = open(os.path.join(ral, "src", chip + ".rs")).read()
+    result = {}
+    for module, body in re.findall(r"^pub mod (\w+) \{\n(.*?)^}", text, re.M | re.S):
+        instances = [
+            (name, int(address.replace("_", ""), 16))
+            for name, address in re.findall(
+                r"pub const (\w+): \*const RegisterBlock = (0x[0-9a-f_]+)", body
+            )
+        ]
+        path = re.search(r'#\[path = "(blocks/[^"]+)"\]', body)
+        if instances and path:
+            result[module] = (instances, os.path.join(ral, "src", path.group(1)))
+    return result
+
+
+def parse_block(path):
+    """Returns [(name, offset, bits, access, description, fields)]."""
+    lines = open(path).read().split("\n")
+    registers = []
+    offset = 0
+    doc = ""
+    i = lines.index("pub struct RegisterBlock {") + 1
+    while lines[i] != "}":
+        line = lines[i]
+        i += 1
+        if m := DOC_RE.match(line):
+            doc = m.group(1)
+        elif m := RESERVED_RE.match(line):
+            offset += int(m.group(1), 0)
+        elif m := REG_RE.match(line):
+            name, access, bits, count = m.groups()
+            bits = int(bits)
+            if count is None:
+                registers.append((name, name, offset, bits, access, doc))
+                offset += bits // 8
+            else:
+                for n in range(int(count)):
+                    registers.append(("%s[%d]" % (name, n), name, offset, bits, access, doc))
+                    offset += bits // 8
+            doc = ""
+        else:
+            raise ValueError("unexpected line in %s: %r" % (path, line))
+
+    # fields of each register module
+    fields = {}
+    module = None
+    doc = ""
+    field = None
+    deprecated = False
+    for line in lines:
+        if m := MOD_RE.match(line):
+            module = m.group(1)
+            fields[module] = []
+        elif m := DOC_RE.match(line):
+            doc = m.group(1)
+        elif line.startswith("    #[deprecated"):
+            # aliases of renamed fields
+            deprecated = True
+        elif module and (m := FIELD_RE.match(line)):
+            field = None if deprecated else [m.group(1), None, None, doc]
+            deprecated = False
+        elif field and (m := OFFSET_RE.match(line)):
+            field[1] = int(m.group(1))
+        elif field and (m := MASK_RE.match(line)):
+            field[2] = bin(int(m.group(1), 0)).count("1")
+            fields[module].append(tuple(field))
+            field = None
+    return [
+        (name, offset, bits, access, doc, tuple(sorted(fields.get(module, []), key=lambda f: f[1])))
+        for name, module, offset, bits, access, doc in registers
+    ]
+
+
+def rust_str(s):
+    return '"%s"' % s.replace("\\", "\\\\").replace('"', '\\"').strip()
+
+
+def family_ranges(ral, chip):
+    """Returns the merged [begin, end) address ranges of the registers of a chip."""
+    ranges = []
+    for module, (instances, path) in parse_instances(ral, chip).items():
+        if module in PERIPHERALS:
+            block = parse_block(path)
+            for _, base in instances:
+                for _, offset, bits, _, _, _ in block:
+                    ranges.append((base + offset, base + offset + bits // 8))
+        else:
+            ranges.extend((base, base + PERIPHERAL_SIZE) for _, base in instances)
+    merged = []
+    for begin, end in sorted(ranges):
+        if merged and begin <= merged[-1][1]:
+            merged[-1][1] = max(merged[-1][1], end)
+        else:
+            merged.append([begin, end])
+    return merged
+
+
+def write_ranges(ral):
+    out = []
+    out.append("//! Generated by `scripts/gen-registers.py` from imxrt-ral (%s); do not edit." % os.path.basename(os.path.normpath(ral)))
+    out.append("")
+    out.append("use crate::Target;")
+    out.append("")
+    out.append("pub(super) const RANGES: &[(Target, &[(u32, u32)])] = &[")
+    for target, chip in FAMILIES:
+        out.append('    #[cfg(feature = "%s")]' % chip)
+        out.append("    (Target::%s, &[" % target)
+        for begin, end in family_ranges(ral, chip):
+            out.append("        (0x%04X_%04X, 0x%04X_%04X)," % (begin >> 16, begin & 0xFFFF, (end - 1) >> 16, (end - 1) & 0xFFFF))
+        out.append("    ]),")
+    out.append("];")
+    out.append("")
+    with open(RANGES_OUTPUT, "w") as f:
+        f.write("\n".join(out))
+
+
+def main():
+    ral = find_ral()
+    write_ranges(ral)
+    registers = {}
+    peripherals = parse_instances(ral, CHIP)
+    for module in PERIPHERALS:
+        instances, path = peripherals[module]
+        block = parse_block(path)
+        for instance, base in instances:
+            for name, offset, bits, access, doc, fields in block:
+                # fields inherit the access of the register, except for known read-only fields
+                fields = tuple(
+                    (field, offset, width, "RO" if field in READ_ONLY_FIELDS.get(module, []) else access, doc)
+                    for field, offset, width, doc in fields
+                )
+                # the first peripheral wins where register blocks overlap (e.g. `MISC0`)
+                registers.setdefault(base + offset, (module, instance, name, bits, access, doc, fields))
+
+    field_lists = {}
+    for _, _, _, _, _, _, fields in registers.values():
+        field_lists.setdefault(fields, len(field_lists))
+
+    out = []
+    out.append("//! Generated by `scripts/gen-registers.py` from imxrt-ral (%s); do not edit." % os.path.basename(os.path.normpath(ral)))
+    out.append("")
+    out.append("use super::{Access, Field, Register};")
+    out.append("use crate::Width;")
+    out.append("")
+    for fields, index in field_lists.items():
+        out.append("const F%d: &[Field] = &[" % index)
+        for name, offset, width, access, doc in fields:
+            out.append("    Field { name: %s, offset: %d, width: %d, access: Access::%s, description: %s }," % (rust_str(name), offset, width, ACCESS[access], rust_str(doc)))
+        out.append("];")
+    out.append("")
+    out.append("pub(super) const REGISTERS: &[Register] = &[")
+    for address in sorted(registers):
+        module, instance, name, bits, access, doc, fields = registers[address]
+        out.append(
+            "    Register { module: %s, peripheral: %s, name: %s, address: 0x%04X_%04X, width: Width::B%d, access: Access::%s, description: %s, fields: F%d },"
+            % (rust_str(module), rust_str(instance), rust_str(name), address >> 16, address & 0xFFFF, bits // 8,
+               ACCESS[access], rust_str(doc), field_lists[fields])
+        )
+    out.append("];")
+    out.append("")
+    with open(OUTPUT, "w") as f:
+        f.write("\n".join(out))
+    print("%d registers, %d distinct field lists" % (len(registers), len(field_lists)))
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,297 @@
+//! Generated by `scripts/gen-allowed-ranges.py` from `src/allowed_ranges.toml`; do not edit.
+
+use crate::{AllowedRange, Target};
+
+pub(crate) const fn allowed_ranges(target: Target) -> &'static [AllowedRange] {
+    match target {
+        #[cfg(feature = "imxrt1011")]
+        Target::Imxrt1010 => &[
+            AllowedRange {
+                begin: 0x400A_4000,
+                end: 0x400A_7FFF,
+                name: "IOMUX Control SNVS GPR",
+            },
+            AllowedRange {
+                begin: 0x400A_8000,
+                end: 0x400A_BFFF,
+                name: "IOMUX Control SNVS",
+            },
+            AllowedRange {
+                begin: 0x400A_C000,
+                end: 0x400A_FFFF,
+                name: "IOMUX Control GPR",
+            },
+            AllowedRange {
+                begin: 0x401F_8000,
+                end: 0x401F_BFFF,
+                name: "IOMUX Control",
+            },
+            AllowedRange {
+                begin: 0x400D_8000,
+                end: 0x400D_BFFF,
+                name: "CCM Analog",
+            },
+            AllowedRange {
+                begin: 0x400F_C000,
+                end: 0x400F_FFFF,
+                name: "CCM",
+            },
+        ],
+        #[cfg(feature = "imxrt1015")]
+        Target::Imxrt1015 => &[
+            AllowedRange {
+                begin: 0x400A_4000,
+                end: 0x400A_7FFF,
+                name: "IOMUX Control SNVS GPR",
+            },
+            AllowedRange {
+                begin: 0x400A_8000,
+                end: 0x400A_BFFF,
+                name: "IOMUX Control SNVS",
+            },
+            AllowedRange {
+                begin: 0x400A_C000,
+                end: 0x400A_FFFF,
+                name: "IOMUX Control GPR",
+            },
+            AllowedRange {
+                begin: 0x401F_8000,
+                end: 0x401F_BFFF,
+                name: "IOMUX Control",
+            },
+            AllowedRange {
+                begin: 0x400D_8000,
+                end: 0x400D_BFFF,
+                name: "CCM Analog",
+            },
+            AllowedRange {
+                begin: 0x400F_C000,
+                end: 0x400F_FFFF,
+                name: "CCM",
+            },
+        ],
+        #[cfg(feature = "imxrt1021")]
+        Target::Imxrt1020 => &[
+            AllowedRange {
+                begin: 0x400A_4000,
+                end: 0x400A_7FFF,
+                name: "IOMUX Control SNVS GPR",
+            },
+            AllowedRange {
+                begin: 0x400A_8000,
+                end: 0x400A_BFFF,
+                name: "IOMUX Control SNVS",
+            },
+            AllowedRange {
+                begin: 0x400A_C000,
+                end: 0x400A_FFFF,
+                name: "IOMUX Control GPR",
+            },
+            AllowedRange {
+                begin: 0x401F_8000,
+                end: 0x401F_BFFF,
+                name: "IOMUX Control",
+            },
+            AllowedRange {
+                begin: 0x400D_8000,
+                end: 0x400D_BFFF,
+                name: "CCM Analog",
+            },
+            AllowedRange {
+                begin: 0x400F_C000,
+                end: 0x400F_FFFF,
+                name: "CCM",
+            },
+            AllowedRange {
+                begin: 0x402F_0000,
+                end: 0x402F_3FFF,
+                name: "SEMC",
+            },
+        ],
+        #[cfg(feature = "imxrt1052")]
+        Target::Imxrt1050 => &[
+            AllowedRange {
+                begin: 0x400A_4000,
+                end: 0x400A_7FFF,
+                name: "IOMUX Control SNVS GPR",
+            },
+            AllowedRange {
+                begin: 0x400A_8000,
+                end: 0x400A_BFFF,
+                name: "IOMUX Control SNVS",
+            },
+            AllowedRange {
+                begin: 0x400A_C000,
+                end: 0x400A_FFFF,
+                name: "IOMUX Control GPR",
+            },
+            AllowedRange {
+                begin: 0x401F_8000,
+                end: 0x401F_BFFF,
+                name: "IOMUX Control",
+            },
+            AllowedRange {
+                begin: 0x400D_8000,
+                end: 0x400D_BFFF,
+                name: "CCM Analog",
+            },
+            AllowedRange {
+                begin: 0x400F_C000,
+                end: 0x400F_FFFF,
+                name: "CCM",
+            },
+            AllowedRange {
+                begin: 0x402F_0000,
+                end: 0x402F_3FFF,
+                name: "SEMC",
+            },
+        ],
+        #[cfg(feature = "imxrt1062")]
+        Target::Imxrt1060 => &[
+            AllowedRange {
+                begin: 0x400A_4000,
+                end: 0x400A_7FFF,
+                name: "IOMUX Control SNVS GPR",
+            },
+            AllowedRange {
+                begin: 0x400A_8000,
+                end: 0x400A_BFFF,
+                name: "IOMUX Control SNVS",
+            },
+            AllowedRange {
+                begin: 0x400A_C000,
+                end: 0x400A_FFFF,
+                name: "IOMUX Control GPR",
+            },
+            AllowedRange {
+                begin: 0x401F_8000,
+                end: 0x401F_BFFF,
+                name: "IOMUX Control",
+            },
+            AllowedRange {
+                begin: 0x400D_8000,
+                end: 0x400D_BFFF,
+                name: "CCM Analog",
+            },
+            AllowedRange {
+                begin: 0x400F_C000,
+                end: 0x400F_FFFF,
+                name: "CCM",
+            },
+            AllowedRange {
+                begin: 0x402F_0000,
+                end: 0x402F_3FFF,
+                name: "SEMC",
+            },
+        ],
+        #[cfg(feature = "imxrt1064")]
+        Target::Imxrt1064 => &[
+            AllowedRange {
+                begin: 0x400A_4000,
+                end: 0x400A_7FFF,
+                name: "IOMUX Control SNVS GPR",
+            },
+            AllowedRange {
+                begin: 0x400A_8000,
+                end: 0x400A_BFFF,
+                name: "IOMUX Control SNVS",
+            },
+            AllowedRange {
+                begin: 0x400A_C000,
+                end: 0x400A_FFFF,
+                name: "IOMUX Control GPR",
+            },
+            AllowedRange {
+                begin: 0x401F_8000,
+                end: 0x401F_BFFF,
+                name: "IOMUX Control",
+            },
+            AllowedRange {
+                begin: 0x400D_8000,
+                end: 0x400D_BFFF,
+                name: "CCM Analog",
+            },
+            AllowedRange {
+                begin: 0x400F_C000,
+                end: 0x400F_FFFF,
+                name: "CCM",
+            },
+            AllowedRange {
+                begin: 0x402F_0000,
+                end: 0x402F_3FFF,
+                name: "SEMC",
+            },
+        ],
+        Target::Imxrt1180 => &[
+        ],
+        #[allow(unreachable_patterns)]
+        _ => &[],
+    }
+}
+
+pub(crate) const fn write_ranges(target: Target) -> &'static [(u32, u32, &'static str)] {
+    match target {
+        #[cfg(feature = "imxrt1011")]
+        Target::Imxrt1010 => &[
+            (0x400A_4000, 0x400A_7FFF, "IOMUX Control SNVS GPR"),
+            (0x400A_8000, 0x400A_BFFF, "IOMUX Control SNVS"),
+            (0x400A_C000, 0x400A_FFFF, "IOMUX Control GPR"),
+            (0x401F_8000, 0x401F_BFFF, "IOMUX Control"),
+            (0x400D_8000, 0x400D_BFFF, "CCM Analog"),
+            (0x400F_C000, 0x400F_FFFF, "CCM"),
+        ],
+        #[cfg(feature = "imxrt1015")]
+        Target::Imxrt1015 => &[
+            (0x400A_4000, 0x400A_7FFF, "IOMUX Control SNVS GPR"),
+            (0x400A_8000, 0x400A_BFFF, "IOMUX Control SNVS"),
+            (0x400A_C000, 0x400A_FFFF, "IOMUX Control GPR"),
+            (0x401F_8000, 0x401F_BFFF, "IOMUX Control"),
+            (0x400D_8000, 0x400D_BFFF, "CCM Analog"),
+            (0x400F_C000, 0x400F_FFFF, "CCM"),
+        ],
+        #[cfg(feature = "imxrt1021")]
+        Target::Imxrt1020 => &[
+            (0x400A_4000, 0x400A_7FFF, "IOMUX Control SNVS GPR"),
+            (0x400A_8000, 0x400A_BFFF, "IOMUX Control SNVS"),
+            (0x400A_C000, 0x400A_FFFF, "IOMUX Control GPR"),
+            (0x401F_8000, 0x401F_BFFF, "IOMUX Control"),
+            (0x400D_8000, 0x400D_BFFF, "CCM Analog"),
+            (0x400F_C000, 0x400F_FFFF, "CCM"),
+            (0x402F_0000, 0x402F_3FFF, "SEMC"),
+        ],
+        #[cfg(feature = "imxrt1052")]
+        Target::Imxrt1050 => &[
+            (0x400A_4000, 0x400A_7FFF, "IOMUX Control SNVS GPR"),
+            (0x400A_8000, 0x400A_BFFF, "IOMUX Control SNVS"),
+            (0x400A_C000, 0x400A_FFFF, "IOMUX Control GPR"),
+            (0x401F_8000, 0x401F_BFFF, "IOMUX Control"),
+            (0x400D_8000, 0x400D_BFFF, "CCM Analog"),
+            (0x400F_C000, 0x400F_FFFF, "CCM"),
+            (0x402F_0000, 0x402F_3FFF, "SEMC"),
+        ],
+        #[cfg(feature = "imxrt1062")]
+        Target::Imxrt1060 => &[
+            (0x400A_4000, 0x400A_7FFF, "IOMUX Control SNVS GPR"),
+            (0x400A_8000, 0x400A_BFFF, "IOMUX Control SNVS"),
+            (0x400A_C000, 0x400A_FFFF, "IOMUX Control GPR"),
+            (0x401F_8000, 0x401F_BFFF, "IOMUX Control"),
+            (0x400D_8000, 0x400D_BFFF, "CCM Analog"),
+            (0x400F_C000, 0x400F_FFFF, "CCM"),
+            (0x402F_0000, 0x402F_3FFF, "SEMC"),
+        ],
+        #[cfg(feature = "imxrt1064")]
+        Target::Imxrt1064 => &[
+            (0x400A_4000, 0x400A_7FFF, "IOMUX Control SNVS GPR"),
+            (0x400A_8000, 0x400A_BFFF, "IOMUX Control SNVS"),
+            (0x400A_C000, 0x400A_FFFF, "IOMUX Control GPR"),
+            (0x401F_8000, 0x401F_BFFF, "IOMUX Control"),
+            (0x400D_8000, 0x400D_BFFF, "CCM Analog"),
+            (0x400F_C000, 0x400F_FFFF, "CCM"),
+            (0x402F_0000, 0x402F_3FFF, "SEMC"),
+        ],
+        Target::Imxrt1180 => &[
+        ],
+        #[allow(unreachable_patterns)]
+        _ => &[],
+    }
+}
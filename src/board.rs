@@ -0,0 +1,225 @@
+//! Board descriptions made of named DCD fragments, and a registry to pick a board by name.
+//!
+//! A board support crate implements [`Board`] for each board it supports, splitting its DCD into
+//! [`DcdFragment`]s such as clock, pin and external memory setup, so that applications can reuse
+//! or replace parts of it. An application (typically its build script) collects the boards it
+//! knows about in a [`Registry`] and picks one by name, e.g. from a cargo feature or an
+//! environment variable:
+//!
+//! ```
+//! use imxrt_dcd::board::{Board, DcdFragment, Fragment, Registry};
+//! use imxrt_dcd::Command;
+//!
+//! struct Evk;
+//!
+//! impl Board for Evk {
+//!     fn name(&self) -> &str {
+//!         "imxrt1060evk"
+//!     }
+//!     fn fragments(&self) -> Vec<Box<dyn DcdFragment + '_>> {
+//!         vec![
+//!             Box::new(Fragment::new("clocks", vec![Command::write32(0x400F_C068, 0xFFFF_FFFF)])),
+//!             Box::new(Fragment::new("sdram", vec![Command::write32(0x402F_0000, 0x1000_0004)])),
+//!         ]
+//!     }
+//! }
+//!
+//! let mut registry = Registry::new();
+//! registry.register(Box::new(Evk)).unwrap();
+//! let board = std::env::var("BOARD").unwrap_or("imxrt1060evk".into());
+//! let board = registry.get(&board).expect("unknown board");
+//! assert_eq!(board.commands().len(), 2);
+//! assert_eq!(
+//!     board.fragment("sdram").unwrap(),
+//!     [Command::write32(0x402F_0000, 0x1000_0004)],
+//! );
+//! ```
+//!
+//! With the `"derive"` feature, `#[derive(DcdFragment)]` turns a configuration struct into a
+//! fragment, mapping its fields to register fields by name (see
+//! [`FieldWrites`](crate::registers::FieldWrites)):
+//!
+//! ```
+//! # #[cfg(feature = "derive")]
+//! # {
+//! use imxrt_dcd::board::DcdFragment;
+//!
+//! #[derive(DcdFragment)]
+//! #[dcd(name = "clocks")]
+//! struct Clocks {
+//!     #[dcd(peripheral = "CCM", register = "CBCDR", field = "SEMC_PODF")]
+//!     semc_podf: u8,
+//! }
+//!
+//! assert_eq!(Clocks { semc_podf: 2 }.commands().len(), 2);
+//! # }
+//! ```
+
+use std::io;
+
+#[cfg(feature = "derive")]
+pub use imxrt_dcd_macros::DcdFragment;
+
+use crate::{concat, Command};
+
+/// A named part of a DCD, e.g. the clock, pin or external memory setup of a board.
+pub trait DcdFragment {
+    /// Name of the fragment, unique within a [`Board`].
+    fn name(&self) -> &str;
+
+    /// Commands of the fragment.
+    fn commands(&self) -> Vec<Command>;
+}
+
+/// A [`DcdFragment`] given by a name and a fixed list of commands.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Fragment<C> {
+    /// See [`DcdFragment::name`].
+    pub name: &'static str,
+    /// See [`DcdFragment::commands`].
+    pub commands: C,
+}
+
+impl<C> Fragment<C> {
+    /// Creates a fragment; `commands` may be e.g. a `Vec<Command>` or a `&'static [Command]`.
+    pub const fn new(name: &'static str, commands: C) -> Self {
+        Self { name, commands }
+    }
+}
+
+impl<C: AsRef<[Command]>> DcdFragment for Fragment<C> {
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn commands(&self) -> Vec<Command> {
+        self.commands.as_ref().to_vec()
+    }
+}
+
+/// A board whose DCD consists of [`DcdFragment`]s.
+pub trait Board {
+    /// Name of the board, unique within a [`Registry`].
+    fn name(&self) -> &str;
+
+    /// Fragments of the DCD, in the order their commands are to be executed.
+    fn fragments(&self) -> Vec<Box<dyn DcdFragment + '_>>;
+
+    /// Commands of the whole DCD, i.e. of all fragments joined with [`concat()`].
+    fn commands(&self) -> Vec<Command> {
+        let fragments: Vec<_> = self
+            .fragments()
+            .iter()
+            .map(|fragment| fragment.commands())
+            .collect();
+        concat(&fragments)
+    }
+
+    /// Commands of the fragment with the given name, if any.
+    fn fragment(&self, name: &str) -> Option<Vec<Command>> {
+        self.fragments()
+            .iter()
+            .find(|fragment| fragment.name() == name)
+            .map(|fragment| fragment.commands())
+    }
+}
+
+/// Collection of [`Board`]s to pick from by name.
+#[derive(Default)]
+pub struct Registry {
+    boards: Vec<Box<dyn Board>>,
+}
+
+impl Registry {
+    /// Creates an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a board.
+    ///
+    /// Returns [`io::ErrorKind::AlreadyExists`] if a board with the same name is registered
+    /// already.
+    pub fn register(&mut self, board: Box<dyn Board>) -> io::Result<()> {
+        if self.get(board.name()).is_some() {
+            return Err(io::Error::new(
+                io::ErrorKind::AlreadyExists,
+                format!("board `{}` is already registered", board.name()),
+            ));
+        }
+        self.boards.push(board);
+        Ok(())
+    }
+
+    /// Returns the board with the given name, if any.
+    pub fn get(&self, name: &str) -> Option<&dyn Board> {
+        self.boards
+            .iter()
+            .find(|board| board.name() == name)
+            .map(|board| &**board)
+    }
+
+    /// Returns the names of all boards, in order of registration.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.boards.iter().map(|board| board.name())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static CLOCKS: [Command; 2] = [
+        Command::write32(0x400F_C068, 0xFFFF_FFFF),
+        Command::write32(0x400F_C06C, 0xFFFF_FFFF),
+    ];
+
+    struct Board1060 {
+        sdram: bool,
+    }
+
+    impl Board for Board1060 {
+        fn name(&self) -> &str {
+            if self.sdram {
+                "with-sdram"
+            } else {
+                "without-sdram"
+            }
+        }
+        fn fragments(&self) -> Vec<Box<dyn DcdFragment + '_>> {
+            let mut fragments: Vec<Box<dyn DcdFragment>> =
+                vec![Box::new(Fragment::new("clocks", &CLOCKS[..]))];
+            if self.sdram {
+                let sdram = vec![Command::write32(0x402F_0000, 0x1000_0004)];
+                fragments.push(Box::new(Fragment::new("sdram", sdram)));
+            }
+            fragments
+        }
+    }
+
+    #[test]
+    fn registry() {
+        let mut registry = Registry::new();
+        registry
+            .register(Box::new(Board1060 { sdram: true }))
+            .unwrap();
+        registry
+            .register(Box::new(Board1060 { sdram: false }))
+            .unwrap();
+        let err = registry
+            .register(Box::new(Board1060 { sdram: true }))
+            .unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::AlreadyExists);
+        assert_eq!(err.to_string(), "board `with-sdram` is already registered");
+        assert!(registry.names().eq(["with-sdram", "without-sdram"]));
+        assert!(registry.get("evk").is_none());
+
+        let board = registry.get("with-sdram").unwrap();
+        assert_eq!(board.commands()[..2], CLOCKS);
+        assert_eq!(board.commands().len(), 3);
+        assert_eq!(board.fragment("clocks").unwrap(), CLOCKS);
+        let board = registry.get("without-sdram").unwrap();
+        assert_eq!(board.commands(), CLOCKS);
+        assert_eq!(board.fragment("sdram"), None);
+    }
+}
@@ -0,0 +1,218 @@
+//! Helpers for the Clock Controller Module (CCM).
+
+use crate::{Command, Target};
+
+/// Address of `CCM_CCGR0`; `CCM_CCGRn` follow at 4-byte strides.
+const CCGR0_ADDRESS: u32 = 0x400F_C068;
+
+/// A named peripheral clock gate, i.e. a 2-bit `CGn` field in one of the `CCM_CCGRx` registers.
+///
+/// Use [`ClockGate::locate`] to find the field on a given [`Target`], or [`clock_gates`] to emit
+/// the DCD commands that configure a set of gates.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum ClockGate {
+    Semc,
+    SemcExsc,
+    FlexSpi,
+    FlexSpi2,
+    Iomuxc,
+    IomuxcGpr,
+    IomuxcSnvs,
+    IomuxcSnvsGpr,
+    Flexram,
+    Ocram,
+    OcramExsc,
+    OcotpCtrl,
+    Dma,
+    Pit,
+    Trng,
+    Usb,
+    Usdhc1,
+    Usdhc2,
+    Enet,
+    Enet2,
+    Gpio1,
+    Gpio2,
+    Gpio3,
+    Gpio4,
+    Gpio5,
+    Lpuart1,
+    Lpuart2,
+    Lpuart3,
+    Lpuart4,
+    Lpuart5,
+    Lpuart6,
+    Lpuart7,
+    Lpuart8,
+    Lpspi1,
+    Lpspi2,
+    Lpspi3,
+    Lpspi4,
+    Lpi2c1,
+    Lpi2c2,
+    Lpi2c3,
+    Lpi2c4,
+}
+
+/// Value of a clock gate field.
+#[derive(Default, Debug, Copy, Clone, Eq, PartialEq)]
+#[repr(u32)]
+pub enum GateSetting {
+    /// Clock is off in all modes.
+    Off = 0b00,
+    /// Clock is on in run mode, but off in WAIT and STOP modes.
+    OnlyRun = 0b01,
+    /// Clock is on in all modes, except STOP mode.
+    #[default]
+    On = 0b11,
+}
+
+impl ClockGate {
+    /// Returns `(x, n)` such that this gate is the `CGn` field of `CCM_CCGRx` on the given target,
+    /// or `None` if the target is not supported or does not have this gate.
+    ///
+    /// Currently only the RT1050/1060/1064 CCGR layout is known.
+    ///
+    /// ```
+    /// # use imxrt_dcd::{ccm::ClockGate, Target};
+    /// assert_eq!(ClockGate::Semc.locate(Target::Imxrt1060), Some((3, 2)));
+    /// assert_eq!(ClockGate::FlexSpi2.locate(Target::Imxrt1050), None);
+    /// ```
+    pub const fn locate(self, target: Target) -> Option<(u8, u8)> {
+        use ClockGate::*;
+        let has_ccgr7 = match target {
+            Target::Imxrt1060 | Target::Imxrt1064 => true,
+            Target::Imxrt1050 => false,
+            _ => return None,
+        };
+        let (ccgr, cg) = match self {
+            Semc => (3, 2),
+            SemcExsc => (1, 9),
+            FlexSpi => (6, 5),
+            FlexSpi2 => (7, 1),
+            Iomuxc => (4, 1),
+            IomuxcGpr => (4, 2),
+            IomuxcSnvs => (2, 2),
+            IomuxcSnvsGpr => (3, 15),
+            Flexram => (3, 9),
+            Ocram => (3, 14),
+            OcramExsc => (2, 0),
+            OcotpCtrl => (2, 6),
+            Dma => (5, 3),
+            Pit => (1, 6),
+            Trng => (6, 6),
+            Usb => (6, 0),
+            Usdhc1 => (6, 1),
+            Usdhc2 => (6, 2),
+            Enet => (1, 5),
+            Enet2 => (7, 0),
+            Gpio1 => (1, 13),
+            Gpio2 => (0, 15),
+            Gpio3 => (2, 13),
+            Gpio4 => (3, 6),
+            Gpio5 => (1, 15),
+            Lpuart1 => (5, 12),
+            Lpuart2 => (0, 14),
+            Lpuart3 => (0, 6),
+            Lpuart4 => (1, 12),
+            Lpuart5 => (3, 1),
+            Lpuart6 => (3, 3),
+            Lpuart7 => (5, 13),
+            Lpuart8 => (6, 7),
+            Lpspi1 => (1, 0),
+            Lpspi2 => (1, 1),
+            Lpspi3 => (1, 2),
+            Lpspi4 => (1, 3),
+            Lpi2c1 => (2, 3),
+            Lpi2c2 => (2, 4),
+            Lpi2c3 => (2, 5),
+            Lpi2c4 => (6, 12),
+        };
+        if ccgr == 7 && !has_ccgr7 {
+            return None;
+        }
+        Some((ccgr, cg))
+    }
+}
+
+/// Creates DCD commands that configure the given clock gates to `setting`, leaving all other
+/// gates unchanged.
+///
+/// Gates in the same `CCM_CCGRx` register share commands; consecutive commands of the same kind are
+/// then merged by [`crate::serialize`]:
+/// - [`GateSetting::On`] => one [`crate::WriteOp::Set`] per register
+/// - [`GateSetting::Off`] => one [`crate::WriteOp::Clear`] per register
+/// - [`GateSetting::OnlyRun`] => both of the above (clear first)
+///
+/// # Panics
+///
+/// Panics if any gate cannot be located on the target --- see [`ClockGate::locate`].
+///
+/// # Example
+///
+/// ```
+/// # use imxrt_dcd::{ccm::{clock_gates, ClockGate, GateSetting}, Command, Target};
+/// assert_eq!(
+///     clock_gates(Target::Imxrt1060, GateSetting::On, &[ClockGate::Semc, ClockGate::Flexram]),
+///     [Command::set32(0x400F_C074, (0b11 << 4) | (0b11 << 18))],
+/// );
+/// ```
+pub fn clock_gates(target: Target, setting: GateSetting, gates: &[ClockGate]) -> Vec<Command> {
+    let mut masks = [0u32; 8];
+    for &gate in gates {
+        let Some((ccgr, cg)) = gate.locate(target) else {
+            panic!("clock gate {:?} is not known for {:?}", gate, target);
+        };
+        masks[ccgr as usize] |= 0b11 << (cg * 2);
+    }
+    let touched = || {
+        masks
+            .iter()
+            .enumerate()
+            .filter(|&(_, &mask)| mask != 0)
+            .map(|(ccgr, &mask)| (CCGR0_ADDRESS + 4 * ccgr as u32, mask))
+    };
+    let mut commands = vec![];
+    if setting != GateSetting::On {
+        commands.extend(touched().map(|(address, mask)| Command::clear32(address, mask)));
+    }
+    if setting != GateSetting::Off {
+        // replicate the 2-bit setting into every touched field
+        let value = setting as u32 * 0x5555_5555;
+        commands.extend(touched().map(|(address, mask)| Command::set32(address, mask & value)));
+    }
+    commands
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gates_grouped_by_register() {
+        assert_eq!(
+            clock_gates(
+                Target::Imxrt1060,
+                GateSetting::OnlyRun,
+                &[ClockGate::Iomuxc, ClockGate::Semc, ClockGate::IomuxcGpr],
+            ),
+            [
+                Command::clear32(0x400F_C074, 0b11 << 4),
+                Command::clear32(0x400F_C078, (0b11 << 2) | (0b11 << 4)),
+                Command::set32(0x400F_C074, 0b01 << 4),
+                Command::set32(0x400F_C078, (0b01 << 2) | (0b01 << 4)),
+            ],
+        );
+        assert_eq!(
+            clock_gates(Target::Imxrt1060, GateSetting::Off, &[ClockGate::Lpuart8]),
+            [Command::clear32(0x400F_C080, 0b11 << 14)],
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn unknown_gate() {
+        clock_gates(Target::Imxrt1050, GateSetting::On, &[ClockGate::FlexSpi2]);
+    }
+}
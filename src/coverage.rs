@@ -0,0 +1,203 @@
+//! Register coverage of a DCD against a reference configuration, e.g. as a checklist for board
+//! bring-up reviews: which registers of a template or SDK DCD the DCD configures, which it misses,
+//! and which other registers it writes.
+//!
+//! Registers are identified by address; a register counts as configured if any write (of any op
+//! or width) goes to its address. Checks do not configure anything.
+//!
+//! ```
+//! # use imxrt_dcd::{coverage::Coverage, Command};
+//! let template = [
+//!     Command::write32(0x400F_C068, 0xFFFF_FFFF),
+//!     Command::write32(0x402F_0000, 0x1000_0004),
+//!     Command::write32(0x402F_0008, 0x0000_0081),
+//! ];
+//! let board = [
+//!     Command::write32(0x400F_C068, 0xFFFF_FFFF),
+//!     Command::set32(0x402F_0000, 0x1000_0000),
+//!     Command::write32(0x401F_8014, 0x0000_0000),
+//! ];
+//! let coverage = Coverage::against(&board, &template);
+//! assert_eq!(coverage.configured, [0x400F_C068, 0x402F_0000]);
+//! assert_eq!(coverage.missing, [0x402F_0008]);
+//! assert_eq!(coverage.extra, [0x401F_8014]);
+//! ```
+//!
+//! The [`fmt::Display`] implementation lists the result as a Markdown checklist, with the `"ral"`
+//! feature naming the registers known to [`registers`](crate::registers) (otherwise e.g.
+//! `CCM+0x068` instead of `CCM_CCGR0`):
+//!
+//! ```
+//! # use imxrt_dcd::{coverage::Coverage, Command};
+//! # let template = [
+//! #     Command::write32(0x400F_C068, 0xFFFF_FFFF),
+//! #     Command::write32(0x402F_0000, 0x1000_0004),
+//! #     Command::write32(0x402F_0008, 0x0000_0081),
+//! # ];
+//! # let board = [
+//! #     Command::write32(0x400F_C068, 0xFFFF_FFFF),
+//! #     Command::set32(0x402F_0000, 0x1000_0000),
+//! #     Command::write32(0x401F_8014, 0x0000_0000),
+//! # ];
+//! # #[cfg(feature = "ral")]
+//! assert_eq!(
+//!     Coverage::against(&board, &template).to_string(),
+//!     "\
+//! 2 of 3 reference registers configured, 1 extra
+//! - [x] CCM_CCGR0
+//! - [x] SEMC_MCR
+//! - [ ] SEMC_BMCR0
+//! extra:
+//! - IOMUXC_SW_MUX_CTL_PAD_GPIO_EMC_00
+//! ",
+//! );
+//! ```
+
+use std::collections::BTreeSet;
+use std::fmt;
+
+use crate::{symbols, Command};
+
+/// Registers of a reference configuration that a DCD writes or misses, and the other registers it
+/// writes; see the [module-level docs](self).
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Coverage {
+    /// Reference registers the DCD writes, by address in ascending order.
+    pub configured: Vec<u32>,
+    /// Reference registers the DCD does not write, by address in ascending order.
+    pub missing: Vec<u32>,
+    /// Registers the DCD writes that are not in the reference, by address in ascending order.
+    pub extra: Vec<u32>,
+}
+
+impl Coverage {
+    /// Compares the registers the commands write against the addresses of the `reference`
+    /// registers, e.g. a list of registers that must be configured.
+    pub fn of(commands: &[Command], reference: impl IntoIterator<Item = u32>) -> Self {
+        let written = written(commands);
+        let reference: BTreeSet<u32> = reference.into_iter().collect();
+        let (configured, missing) = reference
+            .iter()
+            .partition(|address| written.contains(address));
+        Coverage {
+            configured,
+            missing,
+            extra: written.difference(&reference).copied().collect(),
+        }
+    }
+
+    /// Compares the registers the commands write against the registers the `reference` commands
+    /// write, e.g. of a template or SDK DCD (see [`crate::sdk`]).
+    pub fn against(commands: &[Command], reference: &[Command]) -> Self {
+        Self::of(commands, written(reference))
+    }
+}
+
+impl fmt::Display for Coverage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "{} of {} reference registers configured, {} extra",
+            self.configured.len(),
+            self.configured.len() + self.missing.len(),
+            self.extra.len()
+        )?;
+        let mut reference: Vec<(u32, bool)> = self
+            .configured
+            .iter()
+            .map(|&address| (address, true))
+            .chain(self.missing.iter().map(|&address| (address, false)))
+            .collect();
+        reference.sort_unstable();
+        for (address, configured) in reference {
+            let mark = if configured { 'x' } else { ' ' };
+            writeln!(f, "- [{}] {}", mark, register_name(address))?;
+        }
+        if !self.extra.is_empty() {
+            writeln!(f, "extra:")?;
+            for &address in &self.extra {
+                writeln!(f, "- {}", register_name(address))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Returns the addresses of all writes.
+fn written(commands: &[Command]) -> BTreeSet<u32> {
+    commands
+        .iter()
+        .flat_map(|command| command.writes())
+        .map(|write| write.address)
+        .collect()
+}
+
+fn register_name(address: u32) -> String {
+    #[cfg(feature = "ral")]
+    if let Some(register) = crate::registers::lookup(address) {
+        return format!("{}_{}", register.peripheral, register.name);
+    }
+    symbols::symbolize(address).replace("0X", "0x")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CheckCond, Width, WriteGroup, WriteOp};
+
+    #[test]
+    fn coverage() {
+        let commands = [
+            Command::check32(CheckCond::AllSet, 0x2000_0000, 1),
+            Command::WriteGroup(WriteGroup::new(
+                Width::B1,
+                WriteOp::Clear,
+                vec![(0x2000_0004, 1), (0x2000_0008, 1)],
+            )),
+            Command::write32(0x2000_0004, 0),
+            Command::Nop,
+        ];
+        let coverage = Coverage::of(&commands, [0x2000_0008, 0x2000_0000, 0x2000_0008]);
+        assert_eq!(
+            coverage,
+            Coverage {
+                configured: vec![0x2000_0008],
+                missing: vec![0x2000_0000],
+                extra: vec![0x2000_0004],
+            }
+        );
+        assert_eq!(
+            coverage.to_string(),
+            "\
+1 of 2 reference registers configured, 1 extra
+- [ ] 0x20000000
+- [x] 0x20000008
+extra:
+- 0x20000004
+"
+        );
+
+        let coverage = Coverage::against(&commands, &commands);
+        assert_eq!(coverage.configured, [0x2000_0004, 0x2000_0008]);
+        assert!(coverage.missing.is_empty() && coverage.extra.is_empty());
+        assert_eq!(
+            Coverage::of(&[], []).to_string(),
+            "0 of 0 reference registers configured, 0 extra\n"
+        );
+    }
+
+    #[cfg(feature = "ral")]
+    #[test]
+    fn register_names() {
+        let coverage = Coverage::of(&[Command::write32(0x400F_C068, 0xFFFF_FFFF)], [0x402F_0000]);
+        assert_eq!(
+            coverage.to_string(),
+            "\
+0 of 1 reference registers configured, 1 extra
+- [ ] SEMC_MCR
+extra:
+- CCM_CCGR0
+"
+        );
+    }
+}
@@ -0,0 +1,532 @@
+//! Turning commands (e.g. from a legacy DCD binary) back into Rust source code.
+//!
+//! [`to_rust`] emits plain constructors, e.g. to paste into a build script:
+//!
+//! ```
+//! # use imxrt_dcd::{decompile, CheckCond, Command};
+//! let commands = [
+//!     Command::write32(0x400D_8000, 0x0001_4000),
+//!     Command::check32(CheckCond::AllClear, 0x400F_C048, (1 << 3) | (1 << 5)),
+//! ];
+//! assert_eq!(
+//!     decompile::to_rust(&commands),
+//!     "\
+//! vec![
+//!     dcd::Command::write32(0x400D_8000, 0x0001_4000), // CCM_ANALOG+0x000
+//!     dcd::Command::check32(dcd::CheckCond::AllClear, 0x400F_C048, 0x28), // CCM+0x048
+//! ]
+//! ",
+//! );
+//! ```
+//!
+//! With the `"ral"` feature, `to_ral_macros` emits the
+//! [convenience macros](crate#convenience-macros), with addresses symbolized as `imxrt-ral`
+//! registers and values decomposed into fields:
+//!
+//! ```
+//! # #[cfg(feature = "ral")] {
+//! # use imxrt_dcd::{decompile, deserialize, serialize, CheckCond, Command};
+//! # let mut bytes = vec![];
+//! # serialize(&mut bytes, &[
+//! #     Command::write32(0x400D_8000, 0x0001_4000),
+//! #     Command::check32(CheckCond::AllClear, 0x400F_C048, (1 << 3) | (1 << 5)),
+//! # ]).unwrap();
+//! let commands = deserialize(&bytes).unwrap();
+//! assert_eq!(
+//!     decompile::to_ral_macros(&commands),
+//!     "\
+//! vec![
+//!     dcd::write_reg!(ral::ccm_analog, CCM_ANALOG, PLL_ARM, BYPASS_CLK_SRC: 1, BYPASS: 1),
+//!     dcd::check_all_clear!(ral::ccm, CCM, CDHIPR, @PERIPH2_CLK_SEL_BUSY, @PERIPH_CLK_SEL_BUSY),
+//! ]
+//! ",
+//! );
+//! # }
+//! ```
+//!
+//! The output is a `Vec<Command>` expression that expects `use imxrt_dcd as dcd;` (and for
+//! `to_ral_macros`, `use imxrt_ral as ral;` with the `imxrt1062` feature) in scope. Commands on
+//! addresses without a known register fall back to plain constructors.
+
+use std::fmt::Write as _;
+
+#[cfg(feature = "ral")]
+use crate::registers::{self, Register};
+use crate::{symbols, Check, CheckCond, Command, Width, Write, WriteOp};
+
+/// Formats a number as hex with `_` separating groups of 4 digits, e.g. `0x400F_C068`.
+fn hex(value: u32) -> String {
+    if value > 0xFFFF {
+        format!("{:#06X}_{:04X}", value >> 16, value & 0xFFFF)
+    } else {
+        format!("{:#X}", value)
+    }
+    .replace("0X", "0x")
+}
+
+/// Formats a field value; small numbers in decimal, larger in hex.
+#[cfg(feature = "ral")]
+fn field_value(value: u32) -> String {
+    if value < 10 {
+        value.to_string()
+    } else {
+        hex(value)
+    }
+}
+
+/// Emits a plain constructor expression for a command.
+fn constructor(command: &Command) -> String {
+    match command {
+        Command::Nop => "dcd::Command::Nop".to_owned(),
+        Command::Unlock(unlock) => {
+            let mut out = format!(
+                "dcd::Command::Unlock(dcd::Unlock::new({:#04X}, {})",
+                unlock.engine,
+                hex(unlock.features)
+            );
+            if let Some(uid) = unlock.uid {
+                write!(out, ".with_uid({:#X})", uid).unwrap();
+            }
+            out + ")"
+        }
+        Command::Write(Write {
+            width: Width::B4,
+            op,
+            address,
+            value,
+        }) => {
+            format!("dcd::Command::{}32({}, {})", op, hex(*address), hex(*value))
+        }
+        Command::Write(write) => format!(
+            "dcd::Command::Write(dcd::Write::new(dcd::Width::{:?}, dcd::WriteOp::{:?}, {}, {}))",
+            write.width,
+            write.op,
+            hex(write.address),
+            hex(write.value)
+        ),
+        Command::WriteGroup(group) => format!(
+            "dcd::Command::WriteGroup(dcd::WriteGroup::new(\
+             dcd::Width::{:?}, dcd::WriteOp::{:?}, vec![{}]))",
+            group.width,
+            group.op,
+            group
+                .pairs
+                .iter()
+                .map(|&(address, value)| format!("({}, {})", hex(address), hex(value)))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        Command::Check(check) => {
+            let mut out = if check.width == Width::B4 {
+                format!(
+                    "dcd::Command::check32(dcd::CheckCond::{:?}, {}, {})",
+                    check.cond,
+                    hex(check.address),
+                    hex(check.mask)
+                )
+            } else {
+                format!(
+                    "dcd::Command::Check(dcd::Check::new(\
+                     dcd::Width::{:?}, dcd::CheckCond::{:?}, {}, {}))",
+                    check.width,
+                    check.cond,
+                    hex(check.address),
+                    hex(check.mask)
+                )
+            };
+            if let Some(count) = check.count {
+                write!(out, ".with_count({})", count).unwrap();
+            }
+            out
+        }
+    }
+}
+
+/// Decomposes a value into macro args: `FIELD: value` (or `@FIELD` for fully set fields with
+/// `whole_fields`), followed by any bits outside of the fields.
+#[cfg(feature = "ral")]
+fn field_args(register: &Register, value: u32, whole_fields: bool) -> Vec<String> {
+    let mut args = vec![];
+    for field in register.fields {
+        let bits = value & field.mask();
+        if bits == 0 {
+            continue;
+        }
+        if whole_fields && bits == field.mask() {
+            args.push(format!("@{}", field.name));
+        } else {
+            args.push(format!(
+                "{}: {}",
+                field.name,
+                field_value(bits >> field.offset)
+            ));
+        }
+    }
+    let rest = value & !register.fields_mask();
+    if rest != 0 || args.is_empty() {
+        args.push(hex(rest));
+    }
+    args
+}
+
+/// Emits a macro invocation for a command on a known register.
+#[cfg(feature = "ral")]
+fn macro_call(command: &Command) -> Option<String> {
+    let (address, width) = match command {
+        Command::Write(write) => (write.address, write.width),
+        Command::Check(check) => (check.address, check.width),
+        Command::Nop | Command::WriteGroup(_) | Command::Unlock(_) => return None,
+    };
+    let register = registers::lookup(address).filter(|register| register.width == width)?;
+    let (name, args, count) = match command {
+        Command::Write(Write { op, value, .. }) => {
+            let name = match op {
+                WriteOp::Write => "write_reg",
+                WriteOp::Clear => "clear_reg",
+                WriteOp::Set => "set_reg",
+            };
+            (
+                name,
+                field_args(register, *value, *op != WriteOp::Write),
+                None,
+            )
+        }
+        Command::Check(Check {
+            cond, mask, count, ..
+        }) => {
+            let name = match cond {
+                CheckCond::AllClear => "check_all_clear",
+                CheckCond::AnyClear => "check_any_clear",
+                CheckCond::AllSet => "check_all_set",
+                CheckCond::AnySet => "check_any_set",
+            };
+            (name, field_args(register, *mask, true), *count)
+        }
+        Command::Nop | Command::WriteGroup(_) | Command::Unlock(_) => unreachable!(),
+    };
+    let mut out = format!(
+        "dcd::{}!(ral::{}, {}, {}, {})",
+        name,
+        register.module,
+        register.peripheral,
+        register.name,
+        args.join(", ")
+    );
+    if let Some(count) = count {
+        write!(out, ".with_count({})", count).unwrap();
+    }
+    Some(out)
+}
+
+/// Emits a `vec![..]` of the commands, with `expression` returning a specialized expression for
+/// a command, and plain constructors otherwise.
+fn emit(commands: &[Command], expression: impl Fn(&Command) -> Option<String>) -> String {
+    let mut out = "vec![\n".to_owned();
+    for command in commands {
+        match expression(command) {
+            Some(expression) => writeln!(out, "    {},", expression).unwrap(),
+            None => {
+                write!(out, "    {},", constructor(command)).unwrap();
+                match command {
+                    Command::Write(Write { address, .. })
+                    | Command::Check(Check { address, .. })
+                        if symbols::peripheral(*address).is_some() =>
+                    {
+                        writeln!(out, " // {}", symbols::symbolize(*address)).unwrap()
+                    }
+                    _ => out.push('\n'),
+                }
+            }
+        }
+    }
+    out.push_str("]\n");
+    out
+}
+
+/// Emits the commands as a `vec![..]` of plain constructors (with symbolized addresses as
+/// comments); see [module-level docs](self).
+pub fn to_rust(commands: &[Command]) -> String {
+    emit(commands, |_| None)
+}
+
+/// Emits the commands as a `vec![..]` of convenience macro invocations; see
+/// [module-level docs](self).
+#[cfg(feature = "ral")]
+pub fn to_ral_macros(commands: &[Command]) -> String {
+    emit(commands, macro_call)
+}
+
+/// Emits a Rust function `name` performing the commands at runtime with volatile reads and
+/// writes, e.g. to configure the hardware from the same commands when the firmware is loaded by a
+/// debugger, and the boot ROM does not apply the DCD.
+///
+/// Like the boot ROM, the function skips the remaining commands if a check with a poll count is
+/// not satisfied in time; it returns the index of that check as error then.
+///
+/// ```
+/// # use imxrt_dcd::{decompile, CheckCond, Command};
+/// let commands = [
+///     Command::set32(0x400F_C074, 0b11 << 4),
+///     Command::check32(CheckCond::AllSet, 0x400D_8000, 1 << 31).with_count(100),
+/// ];
+/// assert_eq!(
+///     decompile::to_apply_fn(&commands, "apply_dcd"),
+///     "\
+/// /// Applies the DCD at runtime.
+/// ///
+/// /// Returns the index of the first check not satisfied within its poll count, if any.
+/// ///
+/// /// # Safety
+/// ///
+/// /// Writes to hardware registers; see the commands of the DCD.
+/// pub unsafe fn apply_dcd() -> Result<(), usize> {
+///     use core::ptr::{read_volatile, write_volatile};
+///     unsafe {
+///         write_volatile(0x400F_C074 as *mut u32, read_volatile(0x400F_C074 as *const u32) | 0x30); // CCM+0x074
+///         if (0..100).all(|_| read_volatile(0x400D_8000 as *const u32) & 0x8000_0000 != 0x8000_0000) { return Err(1); } // CCM_ANALOG+0x000
+///     }
+///     Ok(())
+/// }
+/// ",
+/// );
+/// ```
+pub fn to_apply_fn(commands: &[Command], name: &str) -> String {
+    let mut out = "\
+/// Applies the DCD at runtime.
+///
+/// Returns the index of the first check not satisfied within its poll count, if any.
+///
+/// # Safety
+///
+/// Writes to hardware registers; see the commands of the DCD.
+"
+    .to_owned();
+    writeln!(out, "pub unsafe fn {}() -> Result<(), usize> {{", name).unwrap();
+    out += "    use core::ptr::{read_volatile, write_volatile};\n    unsafe {\n";
+    for (index, command) in commands.iter().enumerate() {
+        let (statement, address) = match command {
+            Command::Nop => ("// nop".to_owned(), None),
+            Command::Unlock(_) => ("// HAB unlock, no effect on memory".to_owned(), None),
+            Command::Write(write) => (write_statement(write), Some(write.address)),
+            Command::WriteGroup(group) => {
+                let statements: Vec<_> = group
+                    .writes()
+                    .map(|write| write_statement(&write))
+                    .collect();
+                (
+                    statements.join("\n        "),
+                    group.pairs.first().map(|&(address, _)| address),
+                )
+            }
+            Command::Check(check) if check.count == Some(0) => {
+                ("// check with count=0, i.e. nop".to_owned(), None)
+            }
+            Command::Check(check) => {
+                let mask = hex(check.mask & check.width.mask());
+                let masked = format!(
+                    "read_volatile({} as *const {}) & {}",
+                    hex(check.address),
+                    volatile_type(check.width),
+                    mask
+                );
+                // condition of polling again
+                let unsatisfied = match check.cond {
+                    CheckCond::AllClear => format!("{} != 0", masked),
+                    CheckCond::AnyClear => format!("{} == {}", masked, mask),
+                    CheckCond::AllSet => format!("{} != {}", masked, mask),
+                    CheckCond::AnySet => format!("{} == 0", masked),
+                };
+                let statement = match check.count {
+                    Some(count) => format!(
+                        "if (0..{}).all(|_| {}) {{ return Err({}); }}",
+                        count, unsatisfied, index
+                    ),
+                    None => format!("while {} {{}}", unsatisfied),
+                };
+                (statement, Some(check.address))
+            }
+        };
+        write!(out, "        {}", statement).unwrap();
+        match address {
+            Some(address) if symbols::peripheral(address).is_some() => {
+                writeln!(out, " // {}", symbols::symbolize(address)).unwrap()
+            }
+            _ => out.push('\n'),
+        }
+    }
+    out += "    }\n    Ok(())\n}\n";
+    out
+}
+
+/// Returns the volatile write statement of a Write command.
+fn write_statement(write: &Write) -> String {
+    let ty = volatile_type(write.width);
+    let value = hex(write.value & write.width.mask());
+    let read = format!("read_volatile({} as *const {})", hex(write.address), ty);
+    let value = match write.op {
+        WriteOp::Write => value,
+        WriteOp::Set => format!("{} | {}", read, value),
+        WriteOp::Clear => format!("{} & !{}", read, value),
+    };
+    format!(
+        "write_volatile({} as *mut {}, {});",
+        hex(write.address),
+        ty,
+        value
+    )
+}
+
+/// Returns the integer type of a volatile access of the width.
+fn volatile_type(width: Width) -> &'static str {
+    match width {
+        Width::B1 => "u8",
+        Width::B2 => "u16",
+        Width::B4 => "u32",
+    }
+}
+
+#[cfg(all(test, feature = "imxrt1062"))]
+mod tests {
+    use super::*;
+
+    use crate as dcd;
+
+    #[test]
+    fn rust() {
+        let commands = [
+            Command::set32(0x400F_C074, 0b11 << 4),
+            Command::Write(Write::new(Width::B2, WriteOp::Clear, 0x400A_C000, 0x8000)),
+            Command::check32(CheckCond::AnySet, 0x400D_8000, 1 << 31).with_count(100),
+            Command::Check(Check::new(Width::B1, CheckCond::AllClear, 0x2020_0000, 1)),
+            Command::Nop,
+        ];
+        assert_eq!(
+            to_rust(&commands),
+            "\
+vec![
+    dcd::Command::set32(0x400F_C074, 0x30), // CCM+0x074
+    dcd::Command::Write(dcd::Write::new(dcd::Width::B2, dcd::WriteOp::Clear, 0x400A_C000, 0x8000)), // IOMUXC_GPR+0x000
+    dcd::Command::check32(dcd::CheckCond::AnySet, 0x400D_8000, 0x8000_0000).with_count(100), // CCM_ANALOG+0x000
+    dcd::Command::Check(dcd::Check::new(dcd::Width::B1, dcd::CheckCond::AllClear, 0x2020_0000, 0x1)),
+    dcd::Command::Nop,
+]
+"
+        );
+        // the output compiles to the same commands
+        let decompiled = vec![
+            dcd::Command::set32(0x400F_C074, 0x30),
+            dcd::Command::Write(dcd::Write::new(
+                dcd::Width::B2,
+                dcd::WriteOp::Clear,
+                0x400A_C000,
+                0x8000,
+            )),
+            dcd::Command::check32(dcd::CheckCond::AnySet, 0x400D_8000, 0x8000_0000).with_count(100),
+            dcd::Command::Check(dcd::Check::new(
+                dcd::Width::B1,
+                dcd::CheckCond::AllClear,
+                0x2020_0000,
+                0x1,
+            )),
+            dcd::Command::Nop,
+        ];
+        assert_eq!(decompiled, commands);
+    }
+
+    #[cfg(feature = "ral")]
+    #[test]
+    fn ral_macros() {
+        use imxrt_ral as ral;
+
+        let commands = [
+            Command::write32(0x400F_C014, (1 << 16) | (1 << 15) | (1 << 6)),
+            Command::set32(0x400F_C074, 0b11 << 4),
+            Command::clear32(0x400A_C040, 1 << 2),
+            Command::write32(0x400F_C068, 0),
+            Command::check32(CheckCond::AnySet, 0x400D_8000, 1 << 31).with_count(100),
+            Command::Nop,
+            Command::Write(Write::new(Width::B1, WriteOp::Set, 0x402A_4000, 0x80)),
+            Command::write32(0x2020_0000, 0x1234_5678),
+        ];
+        assert_eq!(
+            to_ral_macros(&commands),
+            "\
+vec![
+    dcd::write_reg!(ral::ccm, CCM, CBCDR, SEMC_CLK_SEL: 1, SEMC_PODF: 1, 0x8000),
+    dcd::set_reg!(ral::ccm, CCM, CCGR3, @CG2),
+    dcd::clear_reg!(ral::iomuxc_gpr, IOMUXC_GPR, GPR16, @FLEXRAM_BANK_CFG_SEL),
+    dcd::write_reg!(ral::ccm, CCM, CCGR0, 0x0),
+    dcd::check_any_set!(ral::ccm_analog, CCM_ANALOG, PLL_ARM, @LOCK).with_count(100),
+    dcd::Command::Nop,
+    dcd::Command::Write(dcd::Write::new(dcd::Width::B1, dcd::WriteOp::Set, 0x402A_4000, 0x80)), // FLEXSPI2+0x000
+    dcd::Command::write32(0x2020_0000, 0x1234_5678),
+]
+"
+        );
+        // the output compiles to the same commands
+        let decompiled = vec![
+            dcd::write_reg!(ral::ccm, CCM, CBCDR, SEMC_CLK_SEL: 1, SEMC_PODF: 1, 0x8000),
+            dcd::set_reg!(ral::ccm, CCM, CCGR3, @CG2),
+            dcd::clear_reg!(ral::iomuxc_gpr, IOMUXC_GPR, GPR16, @FLEXRAM_BANK_CFG_SEL),
+            dcd::write_reg!(ral::ccm, CCM, CCGR0, 0x0),
+            dcd::check_any_set!(ral::ccm_analog, CCM_ANALOG, PLL_ARM, @LOCK).with_count(100),
+            dcd::Command::Nop,
+            dcd::Command::Write(dcd::Write::new(
+                dcd::Width::B1,
+                dcd::WriteOp::Set,
+                0x402A_4000,
+                0x80,
+            )),
+            dcd::Command::write32(0x2020_0000, 0x1234_5678),
+        ];
+        assert_eq!(decompiled, commands);
+    }
+
+    #[test]
+    fn apply_fn() {
+        let commands = [
+            Command::write32(0x400F_C068, 0xFFFF_FFFF),
+            Command::Write(Write::new(Width::B2, WriteOp::Clear, 0x400A_C000, 0x8000)),
+            Command::Write(Write::new(Width::B1, WriteOp::Set, 0x2020_0000, 0x180)),
+            Command::Nop,
+            Command::check32(CheckCond::AllClear, 0x400F_C048, 0x28),
+            Command::Check(Check::new(Width::B1, CheckCond::AnyClear, 0x2020_0000, 3))
+                .with_count(5),
+            Command::check32(CheckCond::AnySet, 0x400D_8000, 1 << 31).with_count(0),
+        ];
+        assert_eq!(
+            to_apply_fn(&commands, "init").lines().skip(7).collect::<Vec<_>>().join("\n"),
+            "\
+pub unsafe fn init() -> Result<(), usize> {
+    use core::ptr::{read_volatile, write_volatile};
+    unsafe {
+        write_volatile(0x400F_C068 as *mut u32, 0xFFFF_FFFF); // CCM+0x068
+        write_volatile(0x400A_C000 as *mut u16, read_volatile(0x400A_C000 as *const u16) & !0x8000); // IOMUXC_GPR+0x000
+        write_volatile(0x2020_0000 as *mut u8, read_volatile(0x2020_0000 as *const u8) | 0x80);
+        // nop
+        while read_volatile(0x400F_C048 as *const u32) & 0x28 != 0 {} // CCM+0x048
+        if (0..5).all(|_| read_volatile(0x2020_0000 as *const u8) & 0x3 == 0x3) { return Err(5); }
+        // check with count=0, i.e. nop
+    }
+    Ok(())
+}"
+        );
+    }
+
+    /// The output of [`apply_fn`] above; it is not run, but must compile.
+    #[allow(dead_code)]
+    #[rustfmt::skip]
+    unsafe fn init() -> Result<(), usize> {
+        use core::ptr::{read_volatile, write_volatile};
+        unsafe {
+            write_volatile(0x400F_C068 as *mut u32, 0xFFFF_FFFF); // CCM+0x068
+            write_volatile(0x400A_C000 as *mut u16, read_volatile(0x400A_C000 as *const u16) & !0x8000); // IOMUXC_GPR+0x000
+            write_volatile(0x2020_0000 as *mut u8, read_volatile(0x2020_0000 as *const u8) | 0x80);
+            // nop
+            while read_volatile(0x400F_C048 as *const u32) & 0x28 != 0 {} // CCM+0x048
+            if (0..5).all(|_| read_volatile(0x2020_0000 as *const u8) & 0x3 == 0x3) { return Err(5); }
+            // check with count=0, i.e. nop
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,152 @@
+//! Guessing the chip family of a DCD from the addresses it touches, e.g. when auditing DCDs
+//! extracted from devices.
+//!
+//! Each family is scored by the number of commands accessing an address that is not a register of
+//! that family. The register tables are generated from `imxrt-ral` (see
+//! `scripts/gen-registers.py`); registers of the peripherals within the valid Write command address
+//! ranges are known individually, other peripherals only as a whole.
+//!
+//! Only families whose chip feature (e.g. `imxrt1062`, see the crate features) is enabled are
+//! considered.
+//!
+//! ```
+//! # #[cfg(all(feature = "imxrt1021", feature = "imxrt1052", feature = "imxrt1062", feature = "imxrt1064"))] {
+//! # use imxrt_dcd::{detect, Command, Target};
+//! let commands = [
+//!     Command::write32(0x400F_C068, 0xFFFF_FFFF), // CCM_CCGR0: all families
+//!     Command::write32(0x402F_0000, 0x1000_0004), // SEMC_MCR: no SEMC on RT1010 / RT1015
+//! ];
+//! assert_eq!(
+//!     detect::guess(&commands),
+//!     [Target::Imxrt1020, Target::Imxrt1050, Target::Imxrt1060, Target::Imxrt1064],
+//! );
+//! # }
+//! ```
+
+use crate::{Command, Target};
+
+#[rustfmt::skip]
+mod ranges;
+
+/// Returns the address and width of the accesses of a command: one, or one per write of a
+/// [`WriteGroup`](crate::WriteGroup), or none for a NOP.
+fn accesses(command: &Command) -> Vec<(u32, u32)> {
+    match command {
+        Command::Write(write) => vec![(write.address, write.width as u32)],
+        Command::Check(check) => vec![(check.address, check.width as u32)],
+        Command::WriteGroup(group) => group
+            .pairs
+            .iter()
+            .map(|&(address, _)| (address, group.width as u32))
+            .collect(),
+        Command::Nop | Command::Unlock(_) => vec![],
+    }
+}
+
+/// Returns whether `[address, address + len)` lies within one of the (inclusive) ranges.
+fn contains(ranges: &[(u32, u32)], address: u32, len: u32) -> bool {
+    let index = ranges.partition_point(|&(_, end)| end < address);
+    ranges
+        .get(index)
+        .is_some_and(|&(begin, end)| begin <= address && address.saturating_add(len - 1) <= end)
+}
+
+/// Returns, for each chip family, the number of accesses (of commands, or of the writes of a
+/// group) to an address that is not a register of the family, ordered from the fewest (most
+/// likely family) to the most.
+///
+/// Families with the same count keep the order of [`Target`]. Families of disabled chip features
+/// are left out.
+pub fn scores(commands: &[Command]) -> Vec<(Target, usize)> {
+    let mut scores: Vec<(Target, usize)> = ranges::RANGES
+        .iter()
+        .map(|&(target, ranges)| {
+            let unknown = commands
+                .iter()
+                .flat_map(accesses)
+                .filter(|&(address, len)| !contains(ranges, address, len))
+                .count();
+            (target, unknown)
+        })
+        .collect();
+    scores.sort_by_key(|&(_, unknown)| unknown);
+    scores
+}
+
+/// Returns the chip families most consistent with the addresses accessed by the commands, i.e.
+/// those with the lowest [`scores`].
+///
+/// Several families are returned if the commands do not tell them apart, e.g. RT1060 and RT1064
+/// share most of their register layout. Nothing is returned if no command accesses a register of
+/// any family.
+pub fn guess(commands: &[Command]) -> Vec<Target> {
+    let scores = scores(commands);
+    let accesses = commands.iter().flat_map(accesses).count();
+    let Some(&(_, best)) = scores.first() else {
+        return vec![];
+    };
+    if best == accesses {
+        return vec![];
+    }
+    scores
+        .into_iter()
+        .take_while(|&(_, unknown)| unknown == best)
+        .map(|(target, _)| target)
+        .collect()
+}
+
+#[cfg(all(
+    test,
+    feature = "imxrt1011",
+    feature = "imxrt1015",
+    feature = "imxrt1021",
+    feature = "imxrt1052",
+    feature = "imxrt1062",
+    feature = "imxrt1064"
+))]
+mod tests {
+    use super::*;
+    use crate::{Width, Write, WriteOp};
+
+    #[test]
+    fn table() {
+        for &(_, ranges) in ranges::RANGES {
+            assert!(ranges.iter().all(|&(begin, end)| begin <= end));
+            assert!(ranges.windows(2).all(|w| w[0].1 < w[1].0));
+        }
+        let ranges = ranges::RANGES[0].1;
+        let (begin, end) = ranges[1];
+        assert!(contains(ranges, begin, 4));
+        assert!(contains(ranges, end - 3, 4));
+        assert!(!contains(ranges, end - 1, 4));
+        assert!(!contains(ranges, end + 1, 1));
+        assert!(!contains(ranges, u32::MAX, 4));
+    }
+
+    #[test]
+    fn guess_family() {
+        assert_eq!(guess(&[]), []);
+        // not a register of any family
+        assert_eq!(guess(&[Command::write32(0x2020_0000, 0), Command::Nop]), []);
+
+        let all = ranges::RANGES.iter().map(|&(target, _)| target);
+        assert!(guess(&[Command::write32(0x400F_C068, 0)])
+            .into_iter()
+            .eq(all));
+
+        // IOMUXC_GPR_GPR26 (GPIO6..9 select), and FLEXSPI2 on RT1060 / RT1064
+        let commands = [
+            Command::write32(0x400A_C068, 0xFFFF_FFFF),
+            Command::check32(crate::CheckCond::AllSet, 0x402A_4000, 1),
+        ];
+        assert_eq!(guess(&commands), [Target::Imxrt1060, Target::Imxrt1064]);
+        // a stray command does not rule out the best guess
+        let stray = Command::Write(Write::new(Width::B1, WriteOp::Write, 0x400A_C0FF, 0));
+        let commands = [&commands[..], &[stray]].concat();
+        assert_eq!(
+            scores(&commands)[..2],
+            [(Target::Imxrt1060, 1), (Target::Imxrt1064, 1)]
+        );
+        assert_eq!(guess(&commands), [Target::Imxrt1060, Target::Imxrt1064]);
+    }
+}
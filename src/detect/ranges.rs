@@ -0,0 +1,352 @@
+//! Generated by `scripts/gen-registers.py` from imxrt-ral (imxrt-ral-0.5.3); do not edit.
+
+use crate::Target;
+
+pub(super) const RANGES: &[(Target, &[(u32, u32)])] = &[
+    #[cfg(feature = "imxrt1011")]
+    (Target::Imxrt1010, &[
+        (0x4007_C000, 0x4008_BFFF),
+        (0x4009_4000, 0x4009_BFFF),
+        (0x400A_0000, 0x400A_400F),
+        (0x400A_8000, 0x400A_8013),
+        (0x400A_C000, 0x400A_C077),
+        (0x400B_0000, 0x400C_7FFF),
+        (0x400C_C000, 0x400D_7FFF),
+        (0x400D_8010, 0x400D_801F),
+        (0x400D_8030, 0x400D_8043),
+        (0x400D_8050, 0x400D_8053),
+        (0x400D_8060, 0x400D_8063),
+        (0x400D_8070, 0x400D_8083),
+        (0x400D_8090, 0x400D_8093),
+        (0x400D_80E0, 0x400D_81C3),
+        (0x400D_81D0, 0x400D_81D3),
+        (0x400D_81E0, 0x400D_81FF),
+        (0x400D_8260, 0x400D_8263),
+        (0x400D_8270, 0x400D_827F),
+        (0x400D_8290, 0x400D_82CF),
+        (0x400D_9000, 0x400D_9043),
+        (0x400D_9050, 0x400D_9063),
+        (0x400D_9070, 0x400D_9083),
+        (0x400D_C000, 0x400D_FFFF),
+        (0x400E_4000, 0x400F_C003),
+        (0x400F_C008, 0x400F_C00F),
+        (0x400F_C014, 0x400F_C02B),
+        (0x400F_C030, 0x400F_C033),
+        (0x400F_C038, 0x400F_C03B),
+        (0x400F_C048, 0x400F_C04B),
+        (0x400F_C054, 0x400F_C083),
+        (0x400F_C088, 0x400F_C08B),
+        (0x4017_C000, 0x4019_BFFF),
+        (0x401A_4000, 0x401A_FFFF),
+        (0x401B_8000, 0x401B_BFFF),
+        (0x401C_C000, 0x401C_FFFF),
+        (0x401D_C000, 0x401E_3FFF),
+        (0x401E_8000, 0x401F_7FFF),
+        (0x401F_8010, 0x401F_8223),
+        (0x401F_C000, 0x401F_FFFF),
+        (0x4200_0000, 0x4200_3FFF),
+    ]),
+    #[cfg(feature = "imxrt1015")]
+    (Target::Imxrt1015, &[
+        (0x4007_C000, 0x4008_7FFF),
+        (0x400A_4000, 0x400A_400F),
+        (0x400A_8004, 0x400A_8007),
+        (0x400A_800C, 0x400A_8017),
+        (0x400A_801C, 0x400A_801F),
+        (0x400A_C000, 0x400A_C067),
+        (0x400B_0000, 0x400C_7FFF),
+        (0x400C_C000, 0x400D_7FFF),
+        (0x400D_8010, 0x400D_801F),
+        (0x400D_8030, 0x400D_8043),
+        (0x400D_8050, 0x400D_8053),
+        (0x400D_8060, 0x400D_8063),
+        (0x400D_8070, 0x400D_8083),
+        (0x400D_8090, 0x400D_8093),
+        (0x400D_80E0, 0x400D_81C3),
+        (0x400D_81D0, 0x400D_81D3),
+        (0x400D_81E0, 0x400D_81FF),
+        (0x400D_8260, 0x400D_8263),
+        (0x400D_8270, 0x400D_827F),
+        (0x400D_8290, 0x400D_82CF),
+        (0x400D_9000, 0x400D_9043),
+        (0x400D_9050, 0x400D_9063),
+        (0x400D_9070, 0x400D_9083),
+        (0x400D_C000, 0x400D_FFFF),
+        (0x400E_8000, 0x400E_FFFF),
+        (0x400F_4000, 0x400F_C003),
+        (0x400F_C008, 0x400F_C033),
+        (0x400F_C038, 0x400F_C03F),
+        (0x400F_C048, 0x400F_C04B),
+        (0x400F_C054, 0x400F_C083),
+        (0x400F_C088, 0x400F_C08B),
+        (0x4017_C000, 0x4019_3FFF),
+        (0x401A_C000, 0x401A_FFFF),
+        (0x401B_8000, 0x401C_3FFF),
+        (0x401D_C000, 0x401D_FFFF),
+        (0x401E_C000, 0x401F_7FFF),
+        (0x401F_8024, 0x401F_803B),
+        (0x401F_8054, 0x401F_8083),
+        (0x401F_8094, 0x401F_80A3),
+        (0x401F_80BC, 0x401F_80FB),
+        (0x401F_8124, 0x401F_813B),
+        (0x401F_8158, 0x401F_8187),
+        (0x401F_8198, 0x401F_81AF),
+        (0x401F_81C8, 0x401F_81F7),
+        (0x401F_8208, 0x401F_8217),
+        (0x401F_8230, 0x401F_826F),
+        (0x401F_8298, 0x401F_82AF),
+        (0x401F_82CC, 0x401F_8303),
+        (0x401F_8328, 0x401F_8347),
+        (0x401F_8368, 0x401F_838B),
+        (0x401F_839C, 0x401F_83BB),
+        (0x401F_83CC, 0x401F_83EB),
+        (0x401F_840C, 0x401F_841F),
+        (0x401F_8430, 0x401F_8483),
+        (0x401F_8488, 0x401F_848F),
+        (0x401F_84A0, 0x401F_84C3),
+        (0x401F_C000, 0x401F_FFFF),
+        (0x4027_C000, 0x4027_FFFF),
+        (0x402A_8000, 0x402A_BFFF),
+        (0x402E_0000, 0x402E_3FFF),
+        (0x402F_C000, 0x402F_FFFF),
+        (0x4037_C000, 0x4038_FFFF),
+        (0x4039_4000, 0x4039_BFFF),
+        (0x403B_0000, 0x403B_7FFF),
+        (0x403B_C000, 0x403C_3FFF),
+        (0x403C_8000, 0x403C_BFFF),
+        (0x403D_C000, 0x403D_FFFF),
+        (0x403E_C000, 0x403F_7FFF),
+    ]),
+    #[cfg(feature = "imxrt1021")]
+    (Target::Imxrt1020, &[
+        (0x4007_C000, 0x4008_7FFF),
+        (0x4009_4000, 0x4009_8017),
+        (0x400A_4000, 0x400A_400F),
+        (0x400A_8000, 0x400A_8023),
+        (0x400A_C000, 0x400A_C067),
+        (0x400B_0000, 0x400D_7FFF),
+        (0x400D_8010, 0x400D_801F),
+        (0x400D_8030, 0x400D_8043),
+        (0x400D_8050, 0x400D_8053),
+        (0x400D_8060, 0x400D_8063),
+        (0x400D_8070, 0x400D_8083),
+        (0x400D_8090, 0x400D_8093),
+        (0x400D_80E0, 0x400D_81C3),
+        (0x400D_81D0, 0x400D_81D3),
+        (0x400D_81E0, 0x400D_81FF),
+        (0x400D_8260, 0x400D_8263),
+        (0x400D_8270, 0x400D_827F),
+        (0x400D_8290, 0x400D_82CF),
+        (0x400D_9000, 0x400D_9043),
+        (0x400D_9050, 0x400D_9063),
+        (0x400D_9070, 0x400D_9083),
+        (0x400D_C000, 0x400D_FFFF),
+        (0x400E_8000, 0x400E_FFFF),
+        (0x400F_4000, 0x400F_C003),
+        (0x400F_C008, 0x400F_C033),
+        (0x400F_C038, 0x400F_C03F),
+        (0x400F_C048, 0x400F_C04B),
+        (0x400F_C054, 0x400F_C083),
+        (0x400F_C088, 0x400F_C08B),
+        (0x4017_C000, 0x401A_3FFF),
+        (0x401A_C000, 0x401A_FFFF),
+        (0x401B_8000, 0x401C_3FFF),
+        (0x401D_0000, 0x401D_7FFF),
+        (0x401D_C000, 0x401E_3FFF),
+        (0x401E_C000, 0x401F_7FFF),
+        (0x401F_8014, 0x401F_84C3),
+        (0x401F_C000, 0x401F_FFFF),
+        (0x4027_C000, 0x4027_FFFF),
+        (0x402A_8000, 0x402A_BFFF),
+        (0x402C_0000, 0x402C_7FFF),
+        (0x402D_8000, 0x402D_BFFF),
+        (0x402E_0000, 0x402E_3FFF),
+        (0x402F_0000, 0x402F_0033),
+        (0x402F_0038, 0x402F_0087),
+        (0x402F_0090, 0x402F_00A3),
+        (0x402F_00B0, 0x402F_00B3),
+        (0x402F_00C0, 0x402F_00FF),
+        (0x402F_C000, 0x402F_FFFF),
+        (0x4037_C000, 0x4038_FFFF),
+        (0x4039_4000, 0x403A_3FFF),
+        (0x403B_0000, 0x403B_7FFF),
+        (0x403B_C000, 0x403C_3FFF),
+        (0x403C_8000, 0x403C_FFFF),
+        (0x403D_C000, 0x403E_3FFF),
+        (0x403E_C000, 0x403F_FFFF),
+    ]),
+    #[cfg(feature = "imxrt1052")]
+    (Target::Imxrt1050, &[
+        (0x4007_C000, 0x4008_7FFF),
+        (0x4009_4000, 0x4009_8017),
+        (0x400A_4000, 0x400A_400F),
+        (0x400A_8000, 0x400A_8023),
+        (0x400A_C000, 0x400A_C067),
+        (0x400B_0000, 0x400D_8043),
+        (0x400D_8050, 0x400D_8053),
+        (0x400D_8060, 0x400D_8063),
+        (0x400D_8070, 0x400D_8083),
+        (0x400D_8090, 0x400D_8093),
+        (0x400D_80A0, 0x400D_80B3),
+        (0x400D_80C0, 0x400D_80C3),
+        (0x400D_80E0, 0x400D_81C3),
+        (0x400D_81D0, 0x400D_81D3),
+        (0x400D_81E0, 0x400D_8223),
+        (0x400D_8230, 0x400D_8233),
+        (0x400D_8240, 0x400D_8263),
+        (0x400D_8270, 0x400D_827F),
+        (0x400D_8290, 0x400D_82CF),
+        (0x400D_9000, 0x400D_9043),
+        (0x400D_9050, 0x400D_9063),
+        (0x400D_9070, 0x400D_9083),
+        (0x400D_A000, 0x400D_A043),
+        (0x400D_A050, 0x400D_A063),
+        (0x400D_A070, 0x400D_A083),
+        (0x400D_C000, 0x400E_3FFF),
+        (0x400E_8000, 0x400E_FFFF),
+        (0x400F_4000, 0x400F_C003),
+        (0x400F_C008, 0x400F_C033),
+        (0x400F_C038, 0x400F_C03F),
+        (0x400F_C048, 0x400F_C04B),
+        (0x400F_C054, 0x400F_C083),
+        (0x400F_C088, 0x400F_C08B),
+        (0x4017_C000, 0x401A_3FFF),
+        (0x401A_C000, 0x401B_3FFF),
+        (0x401B_8000, 0x401C_7FFF),
+        (0x401D_0000, 0x401D_7FFF),
+        (0x401D_C000, 0x401F_7FFF),
+        (0x401F_8014, 0x401F_865B),
+        (0x401F_C000, 0x401F_FFFF),
+        (0x4027_C000, 0x4027_FFFF),
+        (0x402A_8000, 0x402A_BFFF),
+        (0x402B_4000, 0x402C_7FFF),
+        (0x402D_8000, 0x402D_BFFF),
+        (0x402E_0000, 0x402E_41FF),
+        (0x402F_0000, 0x402F_0033),
+        (0x402F_0038, 0x402F_0087),
+        (0x402F_0090, 0x402F_00A3),
+        (0x402F_00B0, 0x402F_00B3),
+        (0x402F_00C0, 0x402F_00FF),
+        (0x402F_C000, 0x402F_FFFF),
+        (0x4037_C000, 0x4038_FFFF),
+        (0x4039_4000, 0x403A_3FFF),
+        (0x403B_0000, 0x403D_7FFF),
+        (0x403D_C000, 0x403F_FFFF),
+    ]),
+    #[cfg(feature = "imxrt1062")]
+    (Target::Imxrt1060, &[
+        (0x4007_C000, 0x4008_7FFF),
+        (0x4009_4000, 0x4009_8017),
+        (0x400A_4000, 0x400A_400F),
+        (0x400A_8000, 0x400A_8023),
+        (0x400A_C000, 0x400A_C08B),
+        (0x400B_0000, 0x400D_8043),
+        (0x400D_8050, 0x400D_8053),
+        (0x400D_8060, 0x400D_8063),
+        (0x400D_8070, 0x400D_8083),
+        (0x400D_8090, 0x400D_8093),
+        (0x400D_80A0, 0x400D_80B3),
+        (0x400D_80C0, 0x400D_80C3),
+        (0x400D_80E0, 0x400D_81C3),
+        (0x400D_81D0, 0x400D_81D3),
+        (0x400D_81E0, 0x400D_8223),
+        (0x400D_8230, 0x400D_8233),
+        (0x400D_8240, 0x400D_8263),
+        (0x400D_8270, 0x400D_827F),
+        (0x400D_8290, 0x400D_82CF),
+        (0x400D_9000, 0x400D_9043),
+        (0x400D_9050, 0x400D_9063),
+        (0x400D_9070, 0x400D_9083),
+        (0x400D_A000, 0x400D_A043),
+        (0x400D_A050, 0x400D_A063),
+        (0x400D_A070, 0x400D_A083),
+        (0x400D_C000, 0x400E_3FFF),
+        (0x400E_8000, 0x400E_FFFF),
+        (0x400F_4000, 0x400F_C003),
+        (0x400F_C008, 0x400F_C033),
+        (0x400F_C038, 0x400F_C03F),
+        (0x400F_C048, 0x400F_C04B),
+        (0x400F_C054, 0x400F_C08B),
+        (0x4017_C000, 0x401A_3FFF),
+        (0x401A_C000, 0x401B_3FFF),
+        (0x401B_8000, 0x401C_7FFF),
+        (0x401D_0000, 0x401F_7FFF),
+        (0x401F_8014, 0x401F_878F),
+        (0x401F_C000, 0x401F_FFFF),
+        (0x4027_C000, 0x4027_FFFF),
+        (0x402A_4000, 0x402A_BFFF),
+        (0x402B_4000, 0x402C_7FFF),
+        (0x402D_4000, 0x402D_BFFF),
+        (0x402E_0000, 0x402E_41FF),
+        (0x402F_0000, 0x402F_0087),
+        (0x402F_0090, 0x402F_00A3),
+        (0x402F_00B0, 0x402F_00B3),
+        (0x402F_00C0, 0x402F_00FF),
+        (0x402F_C000, 0x402F_FFFF),
+        (0x4037_C000, 0x4038_FFFF),
+        (0x4039_4000, 0x403A_3FFF),
+        (0x403B_0000, 0x403D_7FFF),
+        (0x403D_C000, 0x403F_FFFF),
+        (0x4200_0000, 0x4200_FFFF),
+        (0x4202_0000, 0x4202_3FFF),
+    ]),
+    #[cfg(feature = "imxrt1064")]
+    (Target::Imxrt1064, &[
+        (0x4007_C000, 0x4008_7FFF),
+        (0x4009_4000, 0x4009_8017),
+        (0x400A_4000, 0x400A_400F),
+        (0x400A_8000, 0x400A_8023),
+        (0x400A_C000, 0x400A_C08B),
+        (0x400B_0000, 0x400D_8043),
+        (0x400D_8050, 0x400D_8053),
+        (0x400D_8060, 0x400D_8063),
+        (0x400D_8070, 0x400D_8083),
+        (0x400D_8090, 0x400D_8093),
+        (0x400D_80A0, 0x400D_80B3),
+        (0x400D_80C0, 0x400D_80C3),
+        (0x400D_80E0, 0x400D_81C3),
+        (0x400D_81D0, 0x400D_81D3),
+        (0x400D_81E0, 0x400D_8223),
+        (0x400D_8230, 0x400D_8233),
+        (0x400D_8240, 0x400D_8263),
+        (0x400D_8270, 0x400D_827F),
+        (0x400D_8290, 0x400D_82CF),
+        (0x400D_9000, 0x400D_9043),
+        (0x400D_9050, 0x400D_9063),
+        (0x400D_9070, 0x400D_9083),
+        (0x400D_A000, 0x400D_A043),
+        (0x400D_A050, 0x400D_A063),
+        (0x400D_A070, 0x400D_A083),
+        (0x400D_C000, 0x400E_3FFF),
+        (0x400E_8000, 0x400E_FFFF),
+        (0x400F_4000, 0x400F_C003),
+        (0x400F_C008, 0x400F_C033),
+        (0x400F_C038, 0x400F_C03F),
+        (0x400F_C048, 0x400F_C04B),
+        (0x400F_C054, 0x400F_C08B),
+        (0x4017_C000, 0x401A_3FFF),
+        (0x401A_C000, 0x401B_3FFF),
+        (0x401B_8000, 0x401C_7FFF),
+        (0x401D_0000, 0x401F_7FFF),
+        (0x401F_8014, 0x401F_865B),
+        (0x401F_86B4, 0x401F_872B),
+        (0x401F_8758, 0x401F_878F),
+        (0x401F_C000, 0x401F_FFFF),
+        (0x4027_C000, 0x4027_FFFF),
+        (0x402A_4000, 0x402A_BFFF),
+        (0x402B_4000, 0x402C_7FFF),
+        (0x402D_4000, 0x402D_BFFF),
+        (0x402E_0000, 0x402E_41FF),
+        (0x402F_0000, 0x402F_0087),
+        (0x402F_0090, 0x402F_00A3),
+        (0x402F_00B0, 0x402F_00B3),
+        (0x402F_00C0, 0x402F_00FF),
+        (0x402F_C000, 0x402F_FFFF),
+        (0x4037_C000, 0x4038_FFFF),
+        (0x4039_4000, 0x403A_3FFF),
+        (0x403B_0000, 0x403D_7FFF),
+        (0x403D_C000, 0x403F_FFFF),
+        (0x4200_0000, 0x4200_FFFF),
+        (0x4202_0000, 0x4202_3FFF),
+    ]),
+];
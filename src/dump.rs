@@ -0,0 +1,253 @@
+//! Synthesizing a DCD from register dumps taken before and after an initialization, e.g. to
+//! reverse-engineer the configuration a board's firmware applies at runtime.
+//!
+//! [`parse`] reads a dump of 32-bit registers, and [`diff`] returns the writes turning the
+//! registers of one dump into those of the other:
+//!
+//! ```
+//! # use imxrt_dcd::{dump::{diff, parse}, Command};
+//! let before = parse("0x400fc068: c0c00fff ffffffff\n0x401f8014: 00000005").unwrap();
+//! let after = parse("0x400fc068: ffffffff ffffffff\n0x401f8014: 00000000").unwrap();
+//! let synthesis = diff(&before, &after, &["CCM"]);
+//! assert_eq!(synthesis.commands, [Command::write32(0x400F_C068, 0xFFFF_FFFF)]);
+//! assert!(synthesis.warnings.is_empty());
+//! ```
+//!
+//! The writes are ordered by address, which may differ from the order the registers need to be
+//! written in (e.g. a clock gate before the peripheral it clocks); run the [lints](crate::lint)
+//! and reorder as needed.
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+use crate::text::ParseError;
+use crate::{lint, symbols, Command};
+
+/// Registers (peripheral instance without its number, offset, name, reason) where writing the
+/// value of a dump does not reproduce it, e.g. status bits cleared by writing 1.
+#[rustfmt::skip]
+const WRITE_SENSITIVE: &[(&str, u32, &str, &str)] = &[
+    ("CCM", 0x048, "CDHIPR", "read-only handshake status"),
+    ("CCM", 0x058, "CISR", "interrupt status, cleared by writing 1"),
+    ("FLEXSPI", 0x014, "INTR", "interrupt status, cleared by writing 1"),
+    ("FLEXSPI", 0x0B0, "IPCMD", "writing triggers an IP command"),
+    ("GPIO", 0x008, "PSR", "read-only pad status"),
+    ("GPIO", 0x018, "ISR", "interrupt status, cleared by writing 1"),
+    ("GPIO", 0x084, "DR_SET", "write-only"),
+    ("GPIO", 0x088, "DR_CLEAR", "write-only"),
+    ("GPIO", 0x08C, "DR_TOGGLE", "write-only"),
+    ("LPUART", 0x014, "STAT", "status, partly cleared by writing 1"),
+    ("LPUART", 0x01C, "DATA", "FIFO access"),
+    ("SEMC", 0x03C, "INTR", "interrupt status, cleared by writing 1"),
+    ("SEMC", 0x09C, "IPCMD", "writing triggers an IP command"),
+    ("SEMC", 0x0B0, "IPRXDAT", "read-only IP command data"),
+    ("SRC", 0x008, "SRSR", "reset status, cleared by writing 1"),
+];
+
+/// Result of [`diff`].
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct Synthesis {
+    /// Writes of the registers that changed, ordered by address.
+    pub commands: Vec<Command>,
+    /// Changed registers that were left out, or written but need a closer look.
+    pub warnings: Vec<Warning>,
+}
+
+/// A changed register that [`diff`] could not simply write.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Warning {
+    pub address: u32,
+    /// Whether the register was left out of the commands.
+    pub skipped: bool,
+    /// Human-readable description.
+    pub message: String,
+}
+
+impl fmt::Display for Warning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let action = if self.skipped { "skipped" } else { "written" };
+        write!(
+            f,
+            "{} ({}): {}",
+            symbols::symbolize(self.address).replace("0X", "0x"),
+            action,
+            self.message
+        )
+    }
+}
+
+/// Parses a dump of 32-bit registers into values by address.
+///
+/// Each line holds an address and one or more values of consecutive registers, all hexadecimal
+/// with an optional `0x` prefix, separated by whitespace, `:` or `=`; this matches the output of
+/// OpenOCD's `mdw`. Empty lines and comments starting with `#` or `//` are ignored.
+///
+/// ```
+/// # use imxrt_dcd::dump::parse;
+/// let dump = parse("# CCM\n0x400FC068: 0xFFFFFFFF c0c00fff\n400fc070 = 0").unwrap();
+/// assert_eq!(dump.len(), 3);
+/// assert_eq!(dump[&0x400F_C06C], 0xC0C0_0FFF);
+/// ```
+pub fn parse(dump: &str) -> Result<BTreeMap<u32, u32>, ParseError> {
+    let mut values = BTreeMap::new();
+    for (index, line) in dump.lines().enumerate() {
+        let error = |message: String| ParseError {
+            line: index + 1,
+            message,
+        };
+        let line = line.split("//").next().unwrap_or_default();
+        let line = line.split('#').next().unwrap_or_default();
+        let mut words = line
+            .split(|c: char| c.is_whitespace() || c == ':' || c == '=')
+            .filter(|word| !word.is_empty())
+            .map(|word| {
+                let hex = word.strip_prefix("0x").or_else(|| word.strip_prefix("0X"));
+                u32::from_str_radix(&hex.unwrap_or(word).replace('_', ""), 16)
+                    .map_err(|_| error(format!("invalid hexadecimal number `{}`", word)))
+            });
+        let Some(address) = words.next() else {
+            continue;
+        };
+        let address = address?;
+        let mut count = 0;
+        for value in words {
+            let register = address
+                .checked_add(4 * count)
+                .ok_or_else(|| error("dump beyond the end of the address space".into()))?;
+            values.insert(register, value?);
+            count += 1;
+        }
+        if count == 0 {
+            return Err(error(format!("no value for address {:#010X}", address)));
+        }
+    }
+    Ok(values)
+}
+
+/// Returns the writes turning the registers of `before` into those of `after`.
+///
+/// Only registers of the peripheral instances named in `peripherals` (e.g. `"CCM"`, `"SEMC"`)
+/// are considered, or all registers if it is empty. Registers missing from `before` are written
+/// if they are in `after`; registers missing from `after` are ignored.
+///
+/// Registers whose value cannot be reproduced by writing it --- status bits cleared by writing 1,
+/// registers triggering an action, read-only registers (with the `"ral"` feature, all registers
+/// defined as such) --- are skipped with a warning. Registers where a wrong value may damage or
+/// lock the device (see [`Lint::DangerousWrite`](crate::lint::Lint::DangerousWrite)) are written
+/// with a warning.
+///
+/// ```
+/// # use imxrt_dcd::dump::{diff, parse};
+/// let before = parse("402f003c: 0\n402f0040: 0").unwrap();
+/// let after = parse("402f003c: 3\n402f0040: 00000100").unwrap();
+/// let synthesis = diff(&before, &after, &[]);
+/// assert_eq!(synthesis.commands.len(), 1);
+/// assert_eq!(
+///     synthesis.warnings[0].to_string(),
+///     "SEMC+0x03C (skipped): INTR: interrupt status, cleared by writing 1",
+/// );
+/// ```
+pub fn diff(
+    before: &BTreeMap<u32, u32>,
+    after: &BTreeMap<u32, u32>,
+    peripherals: &[&str],
+) -> Synthesis {
+    let mut synthesis = Synthesis::default();
+    for (&address, &value) in after {
+        if before.get(&address) == Some(&value) {
+            continue;
+        }
+        let instance = symbols::peripheral(address);
+        let selected =
+            peripherals.is_empty() || instance.is_some_and(|(name, _)| peripherals.contains(&name));
+        if !selected {
+            continue;
+        }
+        let mut warn = |skipped, message| {
+            synthesis.warnings.push(Warning {
+                address,
+                skipped,
+                message,
+            })
+        };
+        if let Some((name, reason)) =
+            instance.and_then(|(name, offset)| write_sensitive(name, offset))
+        {
+            warn(true, format!("{}: {}", name, reason));
+            continue;
+        }
+        #[cfg(feature = "ral")]
+        if let Some(register) = crate::registers::lookup(address) {
+            if register.access == crate::registers::Access::ReadOnly {
+                warn(true, format!("{}: read-only", register.name));
+                continue;
+            }
+        }
+        if let Some(region) = lint::dangerous_region(address) {
+            warn(false, format!("dangerous region, {}", region));
+        }
+        synthesis.commands.push(Command::write32(address, value));
+    }
+    synthesis
+}
+
+/// Returns the name of a write-sensitive register, and why it is.
+pub(crate) fn write_sensitive(
+    peripheral: &str,
+    offset: u32,
+) -> Option<(&'static str, &'static str)> {
+    let kind = peripheral.trim_end_matches(|c: char| c.is_ascii_digit());
+    WRITE_SENSITIVE
+        .iter()
+        .find(|&&(p, o, _, _)| p == kind && o == offset)
+        .map(|&(_, _, name, reason)| (name, reason))
+}
+
+#[cfg(all(test, feature = "imxrt1062"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn synthesis() {
+        let before = parse(
+            "
+            // GPIO1: DR, GDIR, PSR
+            0x401B8000: 00000000 00000000 00000000
+            0x401F4000: 00000000 # OCOTP_CTRL
+            ",
+        )
+        .unwrap();
+        let after = parse(
+            "
+            0x401B8000: 00000008 00000008 00000008
+            0x401F4000: 00001000
+            0x400FC014: 00098000 # missing from before
+            ",
+        )
+        .unwrap();
+        let synthesis = diff(&before, &after, &[]);
+        assert_eq!(
+            synthesis.commands,
+            [
+                Command::write32(0x400F_C014, 0x0009_8000),
+                Command::write32(0x401B_8000, 8),
+                Command::write32(0x401B_8004, 8),
+                Command::write32(0x401F_4000, 0x1000),
+            ]
+        );
+        let warnings: Vec<_> = synthesis.warnings.iter().map(|w| w.to_string()).collect();
+        assert_eq!(
+            warnings,
+            [
+                "GPIO1+0x008 (skipped): PSR: read-only pad status",
+                "OCOTP+0x000 (written): dangerous region, OCOTP: fuse programming",
+            ]
+        );
+        assert_eq!(diff(&before, &after, &["GPIO1"]).commands.len(), 2);
+
+        let err = parse("0x401B8000:\n").unwrap_err();
+        assert_eq!(err.to_string(), "line 1: no value for address 0x401B8000");
+        let err = parse("0x401B8000: 0\nCCM: 0").unwrap_err();
+        assert_eq!(err.to_string(), "line 2: invalid hexadecimal number `CCM`");
+    }
+}
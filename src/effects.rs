@@ -0,0 +1,263 @@
+//! Static analysis of the values a DCD leaves in the registers it writes, e.g. for audit reports
+//! of what a vendor DCD configures, or to spot registers written several times.
+//!
+//! [`effects`] follows the writes in order. Without knowledge of the values before the DCD, a
+//! Write command makes all bits of the register known, whereas Set / Clear commands only make the
+//! bits they touch known:
+//!
+//! ```
+//! # use imxrt_dcd::{effects::effects, Command};
+//! let commands = [
+//!     Command::write32(0x400F_C068, 0x0000_00FF),
+//!     Command::clear32(0x400F_C068, 0x0000_000F),
+//!     Command::set32(0x400F_C06C, 0b11 << 4),
+//! ];
+//! let effects = effects(&commands);
+//! assert_eq!(effects[&0x400F_C068].value(), Some(0x0000_00F0));
+//! assert_eq!(effects[&0x400F_C068].writes, [0, 1]);
+//! assert_eq!(effects[&0x400F_C06C].value(), None);
+//! assert_eq!(effects[&0x400F_C06C].known_set(), 0b11 << 4);
+//! ```
+//!
+//! [`render`] lists the result, with the `"ral"` feature decoded into the fields of known
+//! registers:
+//!
+//! ```
+//! # use imxrt_dcd::{effects::{effects, render}, Command};
+//! let commands = [
+//!     Command::write32(0x400D_8000, 0x0000_2064), // PLL_ARM
+//!     Command::set32(0x2000_0000, 0x8000_0000),
+//! ];
+//! # #[cfg(feature = "ral")]
+//! assert_eq!(
+//!     render(&effects(&commands)),
+//!     "\
+//! 0x20000000: bits 0x80000000 = 0x80000000
+//! CCM_ANALOG_PLL_ARM: DIV_SELECT = 100, POWERDOWN = 0, ENABLE = 1, BYPASS_CLK_SRC = 0, \
+//!     BYPASS = 0, PLL_SEL = 0, LOCK = 0
+//! ",
+//! );
+//! ```
+
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+
+use crate::{Command, Width, WriteOp};
+
+/// What is known about the value of a register after a DCD; see [`effects`].
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ValueKnowledge {
+    /// Access width of the writes to the register.
+    pub width: Width,
+    /// Bits whose value is known.
+    pub known: u32,
+    /// Value of the known bits; unknown bits are 0.
+    pub value: u32,
+    /// Indices of the commands writing the register, in order.
+    pub writes: Vec<usize>,
+}
+
+impl ValueKnowledge {
+    /// Returns the final value, if all bits of the register are known.
+    pub fn value(&self) -> Option<u32> {
+        (self.known == self.width.mask()).then_some(self.value)
+    }
+
+    /// Returns the bits known to be 1.
+    pub fn known_set(&self) -> u32 {
+        self.known & self.value
+    }
+
+    /// Returns the bits known to be 0.
+    pub fn known_clear(&self) -> u32 {
+        self.known & !self.value
+    }
+
+    /// Decodes the value into the fields of `register`: the value of each field whose bits are
+    /// all known, or `None` if only some of them are. Fields without known bits are left out.
+    #[cfg(feature = "ral")]
+    pub fn fields(
+        &self,
+        register: &crate::registers::Register,
+    ) -> Vec<(&'static crate::registers::Field, Option<u32>)> {
+        register
+            .fields
+            .iter()
+            .filter(|field| self.known & field.mask() != 0)
+            .map(|field| {
+                let value = (self.known & field.mask() == field.mask())
+                    .then(|| (self.value & field.mask()) >> field.offset);
+                (field, value)
+            })
+            .collect()
+    }
+}
+
+/// Computes the knowledge about the value of each register written by the commands, by address.
+///
+/// Registers are identified by address and width. A write overlapping a register of another
+/// address or width (e.g. a byte write into a 32-bit register) makes the overlapping bytes of that
+/// register unknown. Checks and NOPs do not change any value.
+pub fn effects(commands: &[Command]) -> BTreeMap<u32, ValueKnowledge> {
+    let mut effects = BTreeMap::new();
+    for (index, command) in commands.iter().enumerate() {
+        apply(&mut effects, index, command);
+    }
+    effects
+}
+
+/// Updates `effects` with the writes of the command at `index`, for analyses that need the state
+/// in between commands.
+pub(crate) fn apply(effects: &mut BTreeMap<u32, ValueKnowledge>, index: usize, command: &Command) {
+    for write in command.writes() {
+        let (begin, end) = (
+            write.address as u64,
+            write.address as u64 + write.width as u64,
+        );
+        for (&address, knowledge) in effects.iter_mut() {
+            if (address, knowledge.width) == (write.address, write.width) {
+                continue;
+            }
+            // little endian: byte `i` of the register holds bits `8 * i ..`
+            for i in 0..knowledge.width as u64 {
+                if (begin..end).contains(&(address as u64 + i)) {
+                    knowledge.known &= !(0xFF << (8 * i));
+                    knowledge.value &= knowledge.known;
+                }
+            }
+        }
+        let knowledge = effects
+            .entry(write.address)
+            .or_insert_with(|| ValueKnowledge {
+                width: write.width,
+                known: 0,
+                value: 0,
+                writes: vec![],
+            });
+        if knowledge.width != write.width {
+            // a register of another width at the same address
+            *knowledge = ValueKnowledge {
+                width: write.width,
+                known: 0,
+                value: 0,
+                writes: vec![],
+            };
+        }
+        let mask = write.width.mask();
+        let value = write.value & mask;
+        match write.op {
+            WriteOp::Write => {
+                knowledge.known = mask;
+                knowledge.value = value;
+            }
+            WriteOp::Set => {
+                knowledge.known |= value;
+                knowledge.value |= value;
+            }
+            WriteOp::Clear => {
+                knowledge.known |= value;
+                knowledge.value &= !value;
+            }
+        }
+        knowledge.writes.push(index);
+    }
+}
+
+/// Renders the result of [`effects`] as text, one line per register.
+///
+/// With the `"ral"` feature, registers known to [`registers`](crate::registers) are listed by
+/// name with their fields in decimal (`?` for partially known fields); other registers as the
+/// known bits and their value in hexadecimal.
+pub fn render(effects: &BTreeMap<u32, ValueKnowledge>) -> String {
+    let mut out = String::new();
+    for (&address, knowledge) in effects {
+        #[cfg(feature = "ral")]
+        if let Some(register) = crate::registers::lookup(address)
+            .filter(|register| register.width == knowledge.width && !register.fields.is_empty())
+        {
+            write!(out, "{}_{}:", register.peripheral, register.name).unwrap();
+            for (i, (field, value)) in knowledge.fields(register).into_iter().enumerate() {
+                let separator = if i == 0 { "" } else { "," };
+                match value {
+                    Some(value) => write!(out, "{} {} = {}", separator, field.name, value),
+                    None => write!(out, "{} {} = ?", separator, field.name),
+                }
+                .unwrap();
+            }
+            out.push('\n');
+            continue;
+        }
+        match knowledge.value() {
+            Some(value) => writeln!(out, "{:#010X} = {:#010X}", address, value),
+            None => writeln!(
+                out,
+                "{:#010X}: bits {:#010X} = {:#010X}",
+                address, knowledge.known, knowledge.value
+            ),
+        }
+        .unwrap();
+    }
+    out.replace("0X", "0x")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CheckCond, Write};
+
+    #[test]
+    fn overlapping_writes() {
+        let commands = [
+            Command::write32(0x1000, 0x1234_5678),
+            Command::check32(CheckCond::AllSet, 0x1000, 0xFF),
+            Command::Write(Write::new(Width::B1, WriteOp::Set, 0x1001, 0x80)),
+            Command::Write(Write::new(Width::B2, WriteOp::Write, 0x1004, 0xABCD_EF01)),
+            Command::Write(Write::new(Width::B4, WriteOp::Clear, 0x1004, 0x1)),
+        ];
+        let effects = effects(&commands);
+        assert_eq!(
+            effects[&0x1000],
+            ValueKnowledge {
+                width: Width::B4,
+                known: 0xFFFF_00FF,
+                value: 0x1234_0078,
+                writes: vec![0],
+            }
+        );
+        assert_eq!(effects[&0x1001].value(), None);
+        assert_eq!(effects[&0x1001].known_set(), 0x80);
+        // the 32-bit clear replaces the 16-bit register at the same address
+        assert_eq!(effects[&0x1004].width, Width::B4);
+        assert_eq!(effects[&0x1004].known_clear(), 1);
+        assert_eq!(effects[&0x1004].writes, [4]);
+        assert_eq!(effects.len(), 3);
+        assert_eq!(
+            render(&effects),
+            "0x00001000: bits 0xFFFF00FF = 0x12340078\n\
+             0x00001001: bits 0x00000080 = 0x00000080\n\
+             0x00001004: bits 0x00000001 = 0x00000000\n"
+        );
+    }
+
+    #[cfg(feature = "ral")]
+    #[test]
+    fn fields() {
+        let commands = [
+            Command::set32(0x400F_C014, 0b001 << 16), // CBCDR: SEMC_PODF
+            Command::clear32(0x400F_C014, 0b110 << 16),
+            Command::set32(0x400F_C014, 1 << 6), // SEMC_CLK_SEL
+            Command::write32(0x400F_C068, 0xFFFF_FFFF),
+        ];
+        assert_eq!(
+            render(&effects(&commands)),
+            "CCM_CBCDR: SEMC_CLK_SEL = 1, SEMC_PODF = 1\n\
+             CCM_CCGR0: CG0 = 3, CG1 = 3, CG2 = 3, CG3 = 3, CG4 = 3, CG5 = 3, CG6 = 3, CG7 = 3, \
+             CG8 = 3, CG9 = 3, CG10 = 3, CG11 = 3, CG12 = 3, CG13 = 3, CG14 = 3, CG15 = 3\n"
+        );
+        let partial = effects(&[Command::set32(0x400F_C014, 0b001 << 16)]);
+        let register = crate::registers::lookup(0x400F_C014).unwrap();
+        let fields = partial[&0x400F_C014].fields(register);
+        assert_eq!(fields.len(), 1);
+        assert_eq!((fields[0].0.name, fields[0].1), ("SEMC_PODF", None));
+    }
+}
@@ -0,0 +1,192 @@
+//! Applying a DCD from the host, e.g. through a debug probe to initialize external RAM before
+//! loading a test image into it, without going through the boot ROM.
+//!
+//! [`apply`] performs the commands in order like the boot ROM: writes go to memory through
+//! [`WriteMemory`] (Set / Clear as read-modify-write), and checks poll memory up to their poll
+//! count. With the `"probe"` feature, `WriteMemory` is implemented for a halted
+//! `probe_rs::Core`:
+//!
+//! ```no_run
+//! # #[cfg(feature = "probe")]
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! # let commands = vec![];
+//! use imxrt_dcd::{exec::apply, sim::Outcome};
+//! use probe_rs::{probe::list::Lister, Permissions};
+//! use std::time::Duration;
+//!
+//! let probe = Lister::new().list_all()[0].open()?;
+//! let mut session = probe.attach("MIMXRT1062xxxxA", Permissions::default())?;
+//! let mut core = session.core(0)?;
+//! core.reset_and_halt(Duration::from_millis(100))?;
+//! assert_eq!(apply(&commands, &mut core, Duration::from_secs(1))?, Outcome::Completed);
+//! // load the test image into the external RAM ...
+//! # Ok(())
+//! # }
+//! # #[cfg(not(feature = "probe"))]
+//! # fn main() {}
+//! ```
+//!
+//! Unlike the boot ROM, writes are not restricted to the allowed address ranges; validate the
+//! commands first (see [`crate::lint`]) if they come from an untrusted source.
+
+use std::io;
+use std::time::{Duration, Instant};
+
+use crate::sim::{self, Outcome, Simulator};
+use crate::verify::ReadMemory;
+use crate::{Command, Width, Write, WriteOp};
+
+/// Memory of a target that can be read and written, e.g. through a debug probe.
+pub trait WriteMemory: ReadMemory {
+    /// Writes the low `width` bytes of `value` at `address`.
+    fn write(&mut self, address: u32, width: Width, value: u32) -> io::Result<()>;
+}
+
+impl WriteMemory for Simulator {
+    fn write(&mut self, address: u32, width: Width, value: u32) -> io::Result<()> {
+        Simulator::write(self, address, width, value);
+        Ok(())
+    }
+}
+
+#[cfg(feature = "probe")]
+impl WriteMemory for probe_rs::Core<'_> {
+    fn write(&mut self, address: u32, width: Width, value: u32) -> io::Result<()> {
+        use probe_rs::MemoryInterface;
+        let address = address as u64;
+        match width {
+            Width::B1 => self.write_word_8(address, value as u8),
+            Width::B2 => self.write_word_16(address, value as u16),
+            Width::B4 => self.write_word_32(address, value),
+        }
+        .map_err(io::Error::other)
+    }
+}
+
+/// Applies commands in order to `memory`, like the boot ROM.
+///
+/// A check with a poll count reads its address up to that many times, and ends the DCD with
+/// [`Outcome::Aborted`] if it is not satisfied by then. A check polling indefinitely is given up
+/// after `timeout` instead, with [`Outcome::Hung`]. Errors of `memory` are returned as is.
+///
+/// ```
+/// # use imxrt_dcd::{exec::apply, sim::{Outcome, Simulator}, CheckCond, Command, Width};
+/// # use std::time::Duration;
+/// let commands = [
+///     Command::write32(0x2020_0000, 0x0000_00FF),
+///     Command::clear32(0x2020_0000, 0x0000_000F),
+///     Command::check32(CheckCond::AllSet, 0x2020_0000, 0x0000_0001).with_count(10),
+///     Command::write32(0x2020_0004, 1),
+/// ];
+/// let mut target = Simulator::new();
+/// let outcome = apply(&commands, &mut target, Duration::from_secs(1)).unwrap();
+/// assert_eq!(outcome, Outcome::Aborted { index: 2 });
+/// assert_eq!(target.read(0x2020_0000, Width::B4), 0x0000_00F0);
+/// ```
+pub fn apply(
+    commands: &[Command],
+    memory: &mut impl WriteMemory,
+    timeout: Duration,
+) -> io::Result<Outcome> {
+    for (index, command) in commands.iter().enumerate() {
+        match command {
+            Command::Nop | Command::Unlock(_) => {}
+            Command::Write(write) => apply_write(write, memory)?,
+            Command::WriteGroup(group) => {
+                for write in group.writes() {
+                    apply_write(&write, memory)?;
+                }
+            }
+            Command::Check(check) if check.count == Some(0) => {}
+            Command::Check(check) => {
+                let start = Instant::now();
+                let mut polls = 0;
+                loop {
+                    match check.count {
+                        Some(count) if polls >= count => return Ok(Outcome::Aborted { index }),
+                        None if polls > 0 && start.elapsed() >= timeout => {
+                            return Ok(Outcome::Hung { index })
+                        }
+                        _ => {}
+                    }
+                    if sim::satisfied(check, memory.read(check.address, check.width)?) {
+                        break;
+                    }
+                    polls += 1;
+                }
+            }
+        }
+    }
+    Ok(Outcome::Completed)
+}
+
+fn apply_write(write: &Write, memory: &mut impl WriteMemory) -> io::Result<()> {
+    let value = match write.op {
+        WriteOp::Write => write.value,
+        WriteOp::Set => memory.read(write.address, write.width)? | write.value,
+        WriteOp::Clear => memory.read(write.address, write.width)? & !write.value,
+    };
+    memory.write(write.address, write.width, value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::CheckCond;
+
+    /// Memory where a status bit is set after a number of reads.
+    struct Delayed {
+        memory: Simulator,
+        reads: u32,
+        ready_after: u32,
+    }
+
+    impl ReadMemory for Delayed {
+        fn read(&mut self, address: u32, width: Width) -> io::Result<u32> {
+            self.reads += 1;
+            if self.reads >= self.ready_after {
+                self.memory.write(0x400D_8000, Width::B4, 1 << 31);
+            }
+            Ok(self.memory.read(address, width))
+        }
+    }
+
+    impl WriteMemory for Delayed {
+        fn write(&mut self, address: u32, width: Width, value: u32) -> io::Result<()> {
+            WriteMemory::write(&mut self.memory, address, width, value)
+        }
+    }
+
+    #[test]
+    fn polls() {
+        let lock = Command::check32(CheckCond::AllSet, 0x400D_8000, 1 << 31);
+        let commands = [
+            Command::set32(0x400D_8004, 0x2000),
+            lock.clone().with_count(5),
+            Command::write32(0x2020_0000, 1),
+        ];
+        let run = |ready_after, commands: &[Command]| {
+            let mut memory = Delayed {
+                memory: Simulator::new(),
+                reads: 0,
+                ready_after,
+            };
+            let outcome = apply(commands, &mut memory, Duration::from_millis(10)).unwrap();
+            (
+                outcome,
+                memory.reads,
+                memory.memory.read(0x2020_0000, Width::B4),
+            )
+        };
+        // one read for the Set, then up to 5 polls
+        assert_eq!(run(6, &commands), (Outcome::Completed, 6, 1));
+        assert_eq!(run(7, &commands), (Outcome::Aborted { index: 1 }, 6, 0));
+        let lock = std::slice::from_ref(&lock);
+        assert_eq!(run(u32::MAX, lock).0, Outcome::Hung { index: 0 });
+        assert_eq!(run(100, lock), (Outcome::Completed, 100, 0));
+        assert_eq!(
+            run(u32::MAX, &[lock[0].clone().with_count(0)]),
+            (Outcome::Completed, 0, 0)
+        );
+    }
+}
@@ -0,0 +1,382 @@
+//! Human-readable explanations of DCD commands, e.g. for hover texts in an IDE plugin or for
+//! generated documentation of a board's DCD.
+//!
+//! [`explain`] describes each command as structured data: the register it accesses, what the
+//! command does in words, and with the `"ral"` feature the register fields it writes or checks:
+//!
+//! ```
+//! # use imxrt_dcd::{explain::explain, CheckCond, Command};
+//! let commands = [
+//!     Command::set32(0x400F_C068, 0b11 << 4),
+//!     Command::check32(CheckCond::AllSet, 0x400D_8000, 1 << 31).with_count(100),
+//! ];
+//! let explanations = explain(&commands);
+//! assert_eq!(explanations[1].summary, "poll until all of bits 0x80000000 are 1, at most 100 times");
+//! # #[cfg(feature = "ral")]
+//! assert_eq!(
+//!     explanations[1].to_string(),
+//!     "\
+//! CCM_ANALOG_PLL_ARM (Analog ARM PLL control Register): poll until all of bits 0x80000000 are 1, \
+//!     at most 100 times
+//!     LOCK = 1: 1 - PLL is currently locked. 0 - PLL is not currently locked.
+//! ",
+//! );
+//! ```
+
+use std::fmt;
+
+use crate::{symbols, CheckCond, Command, WriteOp};
+
+/// Explanation of a single command; see [`explain`].
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Explanation {
+    /// Index of the command in the explained list.
+    pub index: usize,
+    /// Accessed address (of the first write of a group), or `None` for a NOP or an Unlock
+    /// command.
+    pub address: Option<u32>,
+    /// Name of the accessed register, e.g. `CCM_CBCDR` (with the `"ral"` feature), or the
+    /// address relative to its peripheral, e.g. `CCM+0x014`, or the plain address.
+    pub register: String,
+    /// Description of the register, with the `"ral"` feature.
+    pub description: Option<&'static str>,
+    /// What the command does, e.g. `set bits 0x00000030`.
+    pub summary: String,
+    /// Register fields touched by the command, ordered by offset; empty without the `"ral"`
+    /// feature.
+    pub fields: Vec<FieldExplanation>,
+}
+
+/// A register field touched by a command; see [`Explanation::fields`].
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct FieldExplanation {
+    pub name: &'static str,
+    pub description: &'static str,
+    /// Bits of the register within the field that the command writes or checks.
+    pub bits: u32,
+    /// Value of the field written, or expected by a check; `None` if only some of its bits are
+    /// known, e.g. for a set of part of the field or an `any_set` check.
+    pub value: Option<u32>,
+}
+
+impl fmt::Display for Explanation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.register)?;
+        if let Some(description) = self.description {
+            write!(f, " ({})", description)?;
+        }
+        writeln!(f, ": {}", self.summary)?;
+        for field in &self.fields {
+            match field.value {
+                Some(value) => write!(f, "    {} = {}", field.name, value)?,
+                None => write!(
+                    f,
+                    "    {} (bits {})",
+                    field.name,
+                    format!("{:#010X}", field.bits).replace("0X", "0x")
+                )?,
+            }
+            if field.description.is_empty() {
+                writeln!(f)?;
+            } else {
+                writeln!(f, ": {}", field.description)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Explanation {
+    /// Returns a one-line annotation of the command for comments, e.g. in
+    /// [`text::render_annotated`](crate::text::render_annotated): the register name, followed by
+    /// the fields, e.g. `CCM_CBCDR: AHB_PODF = 0, IPG_PODF = 1`. Returns `None` for a NOP or an
+    /// Unlock command, and for an address outside of known peripherals.
+    pub fn annotation(&self) -> Option<String> {
+        let address = self.address?;
+        symbols::peripheral(address)?;
+        let mut annotation = self.register.clone();
+        for (i, field) in self.fields.iter().enumerate() {
+            annotation += if i == 0 { ": " } else { ", " };
+            match field.value {
+                Some(value) => annotation += &format!("{} = {}", field.name, value),
+                None => annotation += &format!("{} (bits {:#010X})", field.name, field.bits),
+            }
+        }
+        Some(annotation.replace("0X", "0x"))
+    }
+}
+
+/// A command with its [annotation](Explanation::annotation); see [`annotate`].
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Annotated {
+    pub command: Command,
+    /// See [`Explanation::annotation`].
+    pub annotation: Option<String>,
+}
+
+/// Attaches an [annotation](Explanation::annotation) to each command, e.g. of a DCD loaded with
+/// [`deserialize`](crate::deserialize) or [`text::parse`](crate::text::parse), so that exports of
+/// it name the registers and fields it sets; see also
+/// [`text::render_annotated`](crate::text::render_annotated).
+///
+/// ```
+/// # use imxrt_dcd::{explain, Command};
+/// let annotated = explain::annotate(&[Command::write32(0x400D_8000, 0x0000_2064), Command::Nop]);
+/// assert_eq!(annotated[0].command, Command::write32(0x400D_8000, 0x0000_2064));
+/// # #[cfg(feature = "ral")]
+/// assert!(annotated[0]
+///     .annotation
+///     .as_ref()
+///     .unwrap()
+///     .starts_with("CCM_ANALOG_PLL_ARM: DIV_SELECT = 100, POWERDOWN = 0, ENABLE = 1"));
+/// assert_eq!(annotated[1].annotation, None);
+/// ```
+pub fn annotate(commands: &[Command]) -> Vec<Annotated> {
+    commands
+        .iter()
+        .zip(explain(commands))
+        .map(|(command, explanation)| Annotated {
+            command: command.clone(),
+            annotation: explanation.annotation(),
+        })
+        .collect()
+}
+
+/// Explains each command of the list; see the [module documentation](self).
+///
+/// Check conditions are phrased in terms of the masked bits, e.g. `all_clear` as "until all of
+/// bits ... are 0". Fields are decoded only if the access width matches the register.
+pub fn explain(commands: &[Command]) -> Vec<Explanation> {
+    commands
+        .iter()
+        .enumerate()
+        .map(|(index, command)| explain_command(index, command))
+        .collect()
+}
+
+fn explain_command(index: usize, command: &Command) -> Explanation {
+    let (address, width, summary) = match command {
+        Command::Nop => {
+            return Explanation {
+                index,
+                address: None,
+                register: "(nop)".into(),
+                description: None,
+                summary: "no operation, may behave as a small delay".into(),
+                fields: vec![],
+            }
+        }
+        Command::Unlock(unlock) => {
+            let mut summary = format!(
+                "keep features {:#010X} of HAB engine {:#04X} unlocked",
+                unlock.features, unlock.engine
+            );
+            if let Some(uid) = unlock.uid {
+                summary += &format!(" on the device with UID {:#018X}", uid);
+            }
+            return Explanation {
+                index,
+                address: None,
+                register: "(unlock)".into(),
+                description: None,
+                summary: summary.replace("0X", "0x"),
+                fields: vec![],
+            };
+        }
+        Command::Write(write) => (
+            write.address,
+            write.width,
+            write_summary(write.op, write.value),
+        ),
+        Command::WriteGroup(group) => {
+            let writes: Vec<_> = group
+                .pairs
+                .iter()
+                .map(|&(address, value)| {
+                    format!(
+                        "{} at {}",
+                        write_summary(group.op, value),
+                        symbols::symbolize(address)
+                    )
+                })
+                .collect();
+            let address = group.pairs.first().map(|&(address, _)| address);
+            return Explanation {
+                index,
+                address,
+                register: address
+                    .map_or("(empty group)".into(), symbols::symbolize)
+                    .replace("0X", "0x"),
+                description: None,
+                summary: format!("group of {} writes: {}", writes.len(), writes.join(", "))
+                    .replace("0X", "0x"),
+                fields: vec![],
+            };
+        }
+        Command::Check(check) => {
+            let condition = match check.cond {
+                CheckCond::AllClear => "all of bits {} are 0",
+                CheckCond::AnyClear => "any of bits {} is 0",
+                CheckCond::AllSet => "all of bits {} are 1",
+                CheckCond::AnySet => "any of bits {} is 1",
+            }
+            .replace("{}", &format!("{:#010X}", check.mask));
+            let summary = match check.count {
+                None => format!("poll until {}, indefinitely", condition),
+                Some(0) => "no operation (poll count 0)".into(),
+                Some(1) => format!("check that {}, once", condition),
+                Some(count) => format!("poll until {}, at most {} times", condition, count),
+            };
+            (check.address, check.width, summary)
+        }
+    };
+    let mut explanation = Explanation {
+        index,
+        address: Some(address),
+        register: symbols::symbolize(address),
+        description: None,
+        summary,
+        fields: vec![],
+    };
+    #[cfg(feature = "ral")]
+    if let Some(register) =
+        crate::registers::lookup(address).filter(|register| register.width == width)
+    {
+        explanation.register = format!("{}_{}", register.peripheral, register.name);
+        explanation.description =
+            Some(register.description).filter(|description| !description.is_empty());
+        explanation.fields = fields(command, register);
+    }
+    #[cfg(not(feature = "ral"))]
+    let _ = width;
+    explanation.register = explanation.register.replace("0X", "0x");
+    explanation.summary = explanation.summary.replace("0X", "0x");
+    explanation
+}
+
+fn write_summary(op: WriteOp, value: u32) -> String {
+    match op {
+        WriteOp::Write => format!("write {:#010X}", value),
+        WriteOp::Set => format!("set bits {:#010X}", value),
+        WriteOp::Clear => format!("clear bits {:#010X}", value),
+    }
+}
+
+/// Returns the fields of `register` touched by `command`.
+#[cfg(feature = "ral")]
+fn fields(command: &Command, register: &crate::registers::Register) -> Vec<FieldExplanation> {
+    // bits touched, and their value if known
+    let (bits, value) = match command {
+        Command::Nop | Command::WriteGroup(_) | Command::Unlock(_) => return vec![],
+        Command::Write(write) => match write.op {
+            WriteOp::Write => (write.width.mask(), Some(write.value)),
+            WriteOp::Set => (write.value, Some(write.value)),
+            WriteOp::Clear => (write.value, Some(0)),
+        },
+        Command::Check(check) => match check.cond {
+            CheckCond::AllClear => (check.mask, Some(0)),
+            CheckCond::AllSet => (check.mask, Some(check.mask)),
+            CheckCond::AnyClear | CheckCond::AnySet => (check.mask, None),
+        },
+    };
+    register
+        .fields
+        .iter()
+        .filter(|field| bits & field.mask() != 0)
+        .map(|field| FieldExplanation {
+            name: field.name,
+            description: field.description,
+            bits: bits & field.mask(),
+            value: value
+                .filter(|_| bits & field.mask() == field.mask())
+                .map(|value| (value & field.mask()) >> field.offset),
+        })
+        .collect()
+}
+
+#[cfg(all(test, feature = "imxrt1062"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn summaries() {
+        let commands = [
+            Command::Nop,
+            Command::write32(0x2000_0000, 0xAB),
+            Command::clear32(0x401B_8000, 7),
+            Command::check32(CheckCond::AnyClear, 0x2000_0000, 1).with_count(1),
+            Command::check32(CheckCond::AllClear, 0x2000_0000, 1),
+        ];
+        let explanations = explain(&commands);
+        let lines: Vec<_> = explanations
+            .iter()
+            .map(|e| format!("{}: {}", e.register, e.summary))
+            .collect();
+        assert_eq!(
+            lines,
+            [
+                "(nop): no operation, may behave as a small delay",
+                "0x20000000: write 0x000000AB",
+                "GPIO1+0x000: clear bits 0x00000007",
+                "0x20000000: check that any of bits 0x00000001 is 0, once",
+                "0x20000000: poll until all of bits 0x00000001 are 0, indefinitely",
+            ]
+        );
+        assert_eq!(explanations[2].index, 2);
+        assert_eq!(explanations[2].address, Some(0x401B_8000));
+    }
+
+    #[test]
+    fn annotations() {
+        let commands = [
+            Command::Nop,
+            Command::write32(0x2000_0000, 0xAB),
+            Command::clear32(0x400D_8000, 1 << 16),
+        ];
+        let annotated = annotate(&commands);
+        let (annotated_commands, annotations): (Vec<_>, Vec<_>) = annotated
+            .into_iter()
+            .map(|annotated| (annotated.command, annotated.annotation))
+            .unzip();
+        assert_eq!(annotated_commands, commands);
+        assert_eq!(annotations[..2], [None, None]);
+        #[cfg(not(feature = "ral"))]
+        assert_eq!(annotations[2].as_deref(), Some("CCM_ANALOG+0x000"));
+        #[cfg(feature = "ral")]
+        assert_eq!(
+            annotations[2].as_deref(),
+            Some("CCM_ANALOG_PLL_ARM: BYPASS = 0")
+        );
+    }
+
+    #[cfg(feature = "ral")]
+    #[test]
+    fn fields() {
+        use crate::{Width, Write};
+
+        let explanations = explain(&[
+            Command::write32(0x400F_C014, 0x000A_8300), // CBCDR
+            Command::set32(0x400F_C014, 0b001 << 16),   // part of SEMC_PODF
+            Command::Write(Write::new(Width::B1, WriteOp::Write, 0x400F_C014, 0)),
+        ]);
+        assert_eq!(explanations[0].register, "CCM_CBCDR");
+        let podf = explanations[0]
+            .fields
+            .iter()
+            .find(|field| field.name == "SEMC_PODF")
+            .unwrap();
+        assert_eq!(podf.value, Some(0b010));
+        assert_eq!(
+            explanations[1].fields,
+            [FieldExplanation {
+                name: "SEMC_PODF",
+                description: podf.description,
+                bits: 1 << 16,
+                value: None,
+            }]
+        );
+        // width mismatch
+        assert_eq!(explanations[2].register, "CCM+0x014");
+        assert!(explanations[2].fields.is_empty());
+    }
+}
@@ -0,0 +1,372 @@
+//! C API for encoding, decoding and validating DCDs.
+//!
+//! All types are `#[repr(C)]` and all functions are `#[no_mangle] extern "C"`, so a C header can
+//! be generated with `cbindgen`. To link from C, build a static library, e.g.:
+//!
+//! ```text
+//! cargo rustc --release --features ffi --crate-type staticlib
+//! ```
+
+use std::ffi::{c_char, c_void, CString};
+
+use crate::{deserialize, lint, serialize, Check, CheckCond, Command, Width, Write, WriteOp};
+
+/// Result of a C API call.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[repr(C)]
+pub enum DcdStatus {
+    Ok = 0,
+    /// A pointer argument is null, or a [`DcdCommand`] has an invalid field.
+    InvalidArgument = -1,
+    /// The output buffer is too small; the required size has been stored.
+    BufferTooSmall = -2,
+    /// The input is not a well-formed DCD block.
+    InvalidData = -3,
+    /// The serialized DCD would exceed the maximum DCD byte length.
+    TooLarge = -4,
+}
+
+/// [`DcdCommand::kind`] of a [`Command::Nop`].
+pub const DCD_KIND_NOP: u8 = 0;
+/// [`DcdCommand::kind`] of a [`Command::Write`].
+pub const DCD_KIND_WRITE: u8 = 1;
+/// [`DcdCommand::kind`] of a [`Command::Check`].
+pub const DCD_KIND_CHECK: u8 = 2;
+
+/// Flat representation of a [`Command`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[repr(C)]
+pub struct DcdCommand {
+    /// `DCD_KIND_*`, i.e. which of the other fields are meaningful.
+    pub kind: u8,
+    /// Width in bytes (1, 2 or 4); ignored for nop.
+    pub width: u8,
+    /// [`WriteOp`] for writes, [`CheckCond`] for checks (numeric value as in the DCD binary);
+    /// ignored for nop.
+    pub op: u8,
+    /// Whether `count` is used; checks only.
+    pub has_count: bool,
+    pub address: u32,
+    /// Value for writes, mask for checks.
+    pub value: u32,
+    /// Poll count for checks with `has_count`.
+    pub count: u32,
+}
+
+/// Fails with [`DcdStatus::InvalidArgument`] for a [`Command::WriteGroup`] or
+/// [`Command::Unlock`], which have no flat representation; [`deserialize`] never returns a group.
+impl TryFrom<&Command> for DcdCommand {
+    type Error = DcdStatus;
+
+    fn try_from(command: &Command) -> Result<Self, Self::Error> {
+        let mut flat = DcdCommand {
+            kind: DCD_KIND_NOP,
+            width: 0,
+            op: 0,
+            has_count: false,
+            address: 0,
+            value: 0,
+            count: 0,
+        };
+        match command {
+            Command::Nop => {}
+            Command::Write(write) => {
+                flat.kind = DCD_KIND_WRITE;
+                flat.width = write.width as u8;
+                flat.op = write.op as u8;
+                flat.address = write.address;
+                flat.value = write.value;
+            }
+            Command::Check(check) => {
+                flat.kind = DCD_KIND_CHECK;
+                flat.width = check.width as u8;
+                flat.op = check.cond as u8;
+                flat.has_count = check.count.is_some();
+                flat.address = check.address;
+                flat.value = check.mask;
+                flat.count = check.count.unwrap_or(0);
+            }
+            Command::WriteGroup(_) | Command::Unlock(_) => return Err(DcdStatus::InvalidArgument),
+        }
+        Ok(flat)
+    }
+}
+
+impl TryFrom<&DcdCommand> for Command {
+    type Error = DcdStatus;
+
+    fn try_from(flat: &DcdCommand) -> Result<Self, Self::Error> {
+        match flat.kind {
+            DCD_KIND_NOP => return Ok(Command::Nop),
+            DCD_KIND_WRITE | DCD_KIND_CHECK => {}
+            _ => return Err(DcdStatus::InvalidArgument),
+        }
+        let width = match flat.width {
+            1 => Width::B1,
+            2 => Width::B2,
+            4 => Width::B4,
+            _ => return Err(DcdStatus::InvalidArgument),
+        };
+        Ok(match flat.kind {
+            DCD_KIND_WRITE => {
+                let op = match flat.op {
+                    op if op == WriteOp::Write as u8 => WriteOp::Write,
+                    op if op == WriteOp::Clear as u8 => WriteOp::Clear,
+                    op if op == WriteOp::Set as u8 => WriteOp::Set,
+                    _ => return Err(DcdStatus::InvalidArgument),
+                };
+                Command::Write(Write::new(width, op, flat.address, flat.value))
+            }
+            _ => {
+                let cond = match flat.op {
+                    op if op == CheckCond::AllClear as u8 => CheckCond::AllClear,
+                    op if op == CheckCond::AnyClear as u8 => CheckCond::AnyClear,
+                    op if op == CheckCond::AllSet as u8 => CheckCond::AllSet,
+                    op if op == CheckCond::AnySet as u8 => CheckCond::AnySet,
+                    _ => return Err(DcdStatus::InvalidArgument),
+                };
+                let check = Check::new(width, cond, flat.address, flat.value);
+                Command::Check(if flat.has_count {
+                    check.with_count(flat.count)
+                } else {
+                    check
+                })
+            }
+        })
+    }
+}
+
+/// Converts a C array of commands.
+///
+/// # Safety
+///
+/// `commands` must point to `len` valid commands (or may be null if `len` is 0).
+unsafe fn commands_from_raw(
+    commands: *const DcdCommand,
+    len: usize,
+) -> Result<Vec<Command>, DcdStatus> {
+    if len == 0 {
+        return Ok(vec![]);
+    }
+    if commands.is_null() {
+        return Err(DcdStatus::InvalidArgument);
+    }
+    std::slice::from_raw_parts(commands, len)
+        .iter()
+        .map(Command::try_from)
+        .collect()
+}
+
+/// Serializes `len` commands into `out`, which has room for `capacity` bytes.
+///
+/// The byte length of the DCD is stored in `*out_len`, also on [`DcdStatus::BufferTooSmall`] so
+/// that the caller can retry with a large enough buffer.
+///
+/// # Safety
+///
+/// `commands` must point to `len` valid commands (or may be null if `len` is 0); `out` must point
+/// to `capacity` writable bytes (or may be null if `capacity` is 0); `out_len` must be valid for
+/// writes.
+#[no_mangle]
+pub unsafe extern "C" fn imxrt_dcd_encode(
+    commands: *const DcdCommand,
+    len: usize,
+    out: *mut u8,
+    capacity: usize,
+    out_len: *mut usize,
+) -> DcdStatus {
+    if out_len.is_null() || (out.is_null() && capacity > 0) {
+        return DcdStatus::InvalidArgument;
+    }
+    let commands = match commands_from_raw(commands, len) {
+        Ok(commands) => commands,
+        Err(status) => return status,
+    };
+    let mut bytes = vec![];
+    if serialize(&mut bytes, &commands).is_err() {
+        return DcdStatus::TooLarge;
+    }
+    *out_len = bytes.len();
+    if bytes.len() > capacity {
+        return DcdStatus::BufferTooSmall;
+    }
+    if !bytes.is_empty() {
+        std::ptr::copy_nonoverlapping(bytes.as_ptr(), out, bytes.len());
+    }
+    DcdStatus::Ok
+}
+
+/// Callback receiving decoded commands one by one, along with the user data pointer.
+pub type DcdCommandCallback = extern "C" fn(command: *const DcdCommand, user_data: *mut c_void);
+
+/// Parses the DCD block in `bytes` (`len` bytes), calling `callback` for each command in order.
+///
+/// Nothing is reported through `callback` if the DCD block is invalid. Returns
+/// [`DcdStatus::InvalidArgument`] at the first HAB Unlock command, which has no flat
+/// representation.
+///
+/// # Safety
+///
+/// `bytes` must point to `len` readable bytes (or may be null if `len` is 0).
+#[no_mangle]
+pub unsafe extern "C" fn imxrt_dcd_decode(
+    bytes: *const u8,
+    len: usize,
+    callback: DcdCommandCallback,
+    user_data: *mut c_void,
+) -> DcdStatus {
+    if bytes.is_null() && len > 0 {
+        return DcdStatus::InvalidArgument;
+    }
+    let bytes = if len == 0 {
+        &[]
+    } else {
+        std::slice::from_raw_parts(bytes, len)
+    };
+    let Ok(commands) = deserialize(bytes) else {
+        return DcdStatus::InvalidData;
+    };
+    for command in &commands {
+        match DcdCommand::try_from(command) {
+            Ok(flat) => callback(&flat, user_data),
+            Err(status) => return status,
+        }
+    }
+    DcdStatus::Ok
+}
+
+/// Callback receiving diagnostics one by one: the index of the offending command, the lint name,
+/// whether the lint is deny-level (as opposed to warn-level), the message, and the user data
+/// pointer. The strings are only valid for the duration of the call.
+pub type DcdDiagnosticCallback = extern "C" fn(
+    index: usize,
+    lint: *const c_char,
+    deny: bool,
+    message: *const c_char,
+    user_data: *mut c_void,
+);
+
+/// Runs all lints at their default levels over `len` commands, calling `callback` for each
+/// diagnostic, ordered by command index.
+///
+/// # Safety
+///
+/// `commands` must point to `len` valid commands (or may be null if `len` is 0).
+#[no_mangle]
+pub unsafe extern "C" fn imxrt_dcd_validate(
+    commands: *const DcdCommand,
+    len: usize,
+    callback: DcdDiagnosticCallback,
+    user_data: *mut c_void,
+) -> DcdStatus {
+    let commands = match commands_from_raw(commands, len) {
+        Ok(commands) => commands,
+        Err(status) => return status,
+    };
+    for diagnostic in lint::validate(&commands) {
+        let lint = CString::new(diagnostic.lint.name()).unwrap();
+        let message = CString::new(diagnostic.message.replace('\0', "")).unwrap();
+        callback(
+            diagnostic.index,
+            lint.as_ptr(),
+            diagnostic.level == lint::Level::Deny,
+            message.as_ptr(),
+            user_data,
+        );
+    }
+    DcdStatus::Ok
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ffi::CStr;
+
+    #[test]
+    fn encode_decode() {
+        let commands = [
+            Command::Nop,
+            Command::Write(Write::new(Width::B2, WriteOp::Set, 0x400F_C068, 0x00C0)),
+            Command::check32(CheckCond::AnySet, 0x400D_8000, 1 << 31).with_count(10),
+        ];
+        let flat: Vec<DcdCommand> = commands
+            .iter()
+            .map(|command| DcdCommand::try_from(command).unwrap())
+            .collect();
+
+        let mut len = 0;
+        let status = unsafe {
+            imxrt_dcd_encode(flat.as_ptr(), flat.len(), std::ptr::null_mut(), 0, &mut len)
+        };
+        assert_eq!((status, len), (DcdStatus::BufferTooSmall, 36));
+        let mut buf = vec![0u8; len];
+        let status = unsafe {
+            imxrt_dcd_encode(
+                flat.as_ptr(),
+                flat.len(),
+                buf.as_mut_ptr(),
+                buf.len(),
+                &mut len,
+            )
+        };
+        assert_eq!(status, DcdStatus::Ok);
+
+        extern "C" fn collect(command: *const DcdCommand, user_data: *mut c_void) {
+            let decoded = unsafe { &mut *(user_data as *mut Vec<Command>) };
+            decoded.push(Command::try_from(unsafe { &*command }).unwrap());
+        }
+        let mut decoded: Vec<Command> = vec![];
+        let status = unsafe {
+            imxrt_dcd_decode(
+                buf.as_ptr(),
+                buf.len(),
+                collect,
+                &mut decoded as *mut _ as *mut c_void,
+            )
+        };
+        assert_eq!(status, DcdStatus::Ok);
+        assert_eq!(decoded, commands);
+
+        let status = unsafe { imxrt_dcd_decode(buf.as_ptr(), 3, collect, std::ptr::null_mut()) };
+        assert_eq!(status, DcdStatus::InvalidData);
+    }
+
+    #[test]
+    fn invalid_command() {
+        let mut flat = DcdCommand::try_from(&Command::write32(0, 0)).unwrap();
+        flat.width = 3;
+        let mut len = 0;
+        let status = unsafe { imxrt_dcd_encode(&flat, 1, std::ptr::null_mut(), 0, &mut len) };
+        assert_eq!(status, DcdStatus::InvalidArgument);
+    }
+
+    #[test]
+    fn validate() {
+        extern "C" fn collect(
+            index: usize,
+            lint: *const c_char,
+            deny: bool,
+            _message: *const c_char,
+            user_data: *mut c_void,
+        ) {
+            let diagnostics = unsafe { &mut *(user_data as *mut Vec<(usize, String, bool)>) };
+            let lint = unsafe { CStr::from_ptr(lint) }.to_str().unwrap().to_owned();
+            diagnostics.push((index, lint, deny));
+        }
+        let flat = [
+            DcdCommand::try_from(&Command::write32(0x400F_C068, 0)).unwrap(),
+            DcdCommand::try_from(&Command::write32(0x401F_4000, 0)).unwrap(),
+        ];
+        let mut diagnostics: Vec<(usize, String, bool)> = vec![];
+        let status = unsafe {
+            imxrt_dcd_validate(
+                flat.as_ptr(),
+                flat.len(),
+                collect,
+                &mut diagnostics as *mut _ as *mut c_void,
+            )
+        };
+        assert_eq!(status, DcdStatus::Ok);
+        assert_eq!(diagnostics, [(1, "dangerous_write".to_owned(), true)]);
+    }
+}
@@ -0,0 +1,201 @@
+//! Golden-file assertions for tests and build scripts.
+//!
+//! [`assert_matches_golden`] serializes commands and compares the result against a checked-in DCD
+//! binary. On mismatch, both sides are decoded and a command-level diff is printed in the
+//! [`text`] format, instead of two opaque hexdumps.
+//!
+//! Set the environment variable `DCD_BLESS=1` to (re)write the golden files instead of comparing.
+
+use std::fmt::Write as _;
+use std::path::Path;
+
+use crate::{deserialize, serialize, text, Command};
+
+/// Name of the environment variable that turns [`assert_matches_golden`] into an update.
+pub const BLESS_ENV: &str = "DCD_BLESS";
+
+/// Asserts that `commands` serialize to exactly the content of the golden file at `path`.
+///
+/// If the [`BLESS_ENV`] environment variable is set to a non-empty value, writes the golden file
+/// instead.
+///
+/// # Panics
+///
+/// Panics with a structural diff if the serialized commands do not match the golden file, or if
+/// the golden file cannot be read or written.
+///
+/// # Example
+///
+/// ```no_run
+/// # use imxrt_dcd::{golden::assert_matches_golden, Command};
+/// let commands = [Command::write32(0x400F_C068, 0xFFFF_FFFF)];
+/// assert_matches_golden("tests/golden/clocks.bin", &commands);
+/// ```
+pub fn assert_matches_golden(path: impl AsRef<Path>, commands: &[Command]) {
+    let path = path.as_ref();
+    let mut actual = vec![];
+    serialize(&mut actual, commands).expect("failed to serialize DCD");
+
+    if std::env::var_os(BLESS_ENV).is_some_and(|value| !value.is_empty()) {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)
+                .unwrap_or_else(|err| panic!("failed to create `{}`: {}", parent.display(), err));
+        }
+        std::fs::write(path, &actual)
+            .unwrap_or_else(|err| panic!("failed to write `{}`: {}", path.display(), err));
+        return;
+    }
+
+    let golden = std::fs::read(path).unwrap_or_else(|err| {
+        panic!(
+            "failed to read golden file `{}`: {} (run with {}=1 to create it)",
+            path.display(),
+            err,
+            BLESS_ENV
+        )
+    });
+    if let Some(report) = compare(&golden, &actual) {
+        panic!(
+            "DCD does not match golden file `{}` (run with {}=1 to update it)\n{}",
+            path.display(),
+            BLESS_ENV,
+            report
+        );
+    }
+}
+
+/// Returns a human-readable report of the differences between two DCD binaries, or `None` if
+/// they are equal.
+fn compare(golden: &[u8], actual: &[u8]) -> Option<String> {
+    if golden == actual {
+        return None;
+    }
+    let mut report = format!(
+        "golden: {} bytes, actual: {} bytes\n",
+        golden.len(),
+        actual.len()
+    );
+    let golden = match deserialize(golden) {
+        Ok(commands) => commands,
+        Err(err) => {
+            writeln!(report, "golden file is not a valid DCD: {}", err).unwrap();
+            return Some(report);
+        }
+    };
+    let actual = deserialize(actual).expect("serialized DCD is invalid");
+    if golden == actual {
+        report.push_str("commands are equal, but the encoding differs (e.g. merging of writes)\n");
+        return Some(report);
+    }
+    render_diff(&mut report, ("golden", &golden), ("actual", &actual));
+    Some(report)
+}
+
+/// Appends a command-level diff of two command lists, each labeled, in the [`text`] format.
+pub(crate) fn render_diff(report: &mut String, a: (&str, &[Command]), b: (&str, &[Command])) {
+    writeln!(report, "--- {}\n+++ {}", a.0, b.0).unwrap();
+    for line in diff(a.1, b.1) {
+        let (marker, command) = match line {
+            Line::Same(command) => (' ', command),
+            Line::Removed(command) => ('-', command),
+            Line::Added(command) => ('+', command),
+        };
+        writeln!(report, "{} {}", marker, text::render_command(command)).unwrap();
+    }
+}
+
+enum Line<'a> {
+    Same(&'a Command),
+    Removed(&'a Command),
+    Added(&'a Command),
+}
+
+/// Computes a minimal line diff via the longest common subsequence.
+fn diff<'a>(a: &'a [Command], b: &'a [Command]) -> Vec<Line<'a>> {
+    // lcs[i][j] = length of the LCS of a[i..] and b[j..]
+    let mut lcs = vec![vec![0usize; b.len() + 1]; a.len() + 1];
+    for i in (0..a.len()).rev() {
+        for j in (0..b.len()).rev() {
+            lcs[i][j] = if a[i] == b[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+    let (mut i, mut j) = (0, 0);
+    let mut lines = vec![];
+    while i < a.len() || j < b.len() {
+        if i < a.len() && j < b.len() && a[i] == b[j] {
+            lines.push(Line::Same(&a[i]));
+            i += 1;
+            j += 1;
+        } else if j == b.len() || (i < a.len() && lcs[i + 1][j] >= lcs[i][j + 1]) {
+            lines.push(Line::Removed(&a[i]));
+            i += 1;
+        } else {
+            lines.push(Line::Added(&b[j]));
+            j += 1;
+        }
+    }
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn to_bytes(commands: &[Command]) -> Vec<u8> {
+        let mut buf = vec![];
+        serialize(&mut buf, commands).unwrap();
+        buf
+    }
+
+    #[cfg(feature = "imxrt1062")]
+    #[test]
+    fn structural_diff() {
+        use crate::CheckCond;
+
+        let golden = to_bytes(&[
+            Command::write32(0x400F_C068, 0xFFFF_FFFF),
+            Command::write32(0x400F_C06C, 0xFFFF_FFFF),
+            Command::check32(CheckCond::AllSet, 0x400D_8000, 1 << 31).with_count(100),
+        ]);
+        let actual = to_bytes(&[
+            Command::write32(0x400F_C068, 0xFFFF_FFFF),
+            Command::set32(0x400F_C06C, 0x0000_00C0),
+            Command::check32(CheckCond::AllSet, 0x400D_8000, 1 << 31).with_count(100),
+            Command::Nop,
+        ]);
+        assert_eq!(compare(&golden, &golden), None);
+        assert_eq!(
+            compare(&golden, &actual).unwrap(),
+            "golden: 40 bytes, actual: 48 bytes\n\
+             --- golden\n\
+             +++ actual\n  \
+             write.32 0x400FC068 0xFFFFFFFF  # CCM+0x068\n\
+             - write.32 0x400FC06C 0xFFFFFFFF  # CCM+0x06C\n\
+             + set.32 0x400FC06C 0x000000C0  # CCM+0x06C\n  \
+             check.32 all_set 0x400D8000 0x80000000 count=100  # CCM_ANALOG+0x000\n\
+             + nop\n",
+        );
+    }
+
+    #[test]
+    fn invalid_golden() {
+        let actual = to_bytes(&[Command::Nop]);
+        assert!(compare(b"not a DCD", &actual)
+            .unwrap()
+            .contains("golden file is not a valid DCD"));
+    }
+
+    #[test]
+    fn roundtrip_file() {
+        let path =
+            std::env::temp_dir().join(format!("imxrt-dcd-golden-{}.bin", std::process::id()));
+        let commands = [Command::write32(0x400F_C068, 0xFFFF_FFFF)];
+        std::fs::write(&path, to_bytes(&commands)).unwrap();
+        assert_matches_golden(&path, &commands);
+        std::fs::remove_file(&path).unwrap();
+    }
+}
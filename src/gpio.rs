@@ -0,0 +1,207 @@
+//! Helpers for driving GPIO pins from the DCD.
+//!
+//! **CAUTION**: The boot ROM of RT1050/1060/1064 does not list GPIO among the valid Write command
+//! address ranges (see [crate-level docs](crate)), and will abandon the rest of the DCD upon the
+//! first GPIO write. Only use [`gpio_pin`] where the ROM is known to accept these writes, or place
+//! the commands at the very end of the DCD. The IOMUXC writes alone (e.g. with a pull-up /
+//! pull-down selected in `pad_ctl`) are always valid, and may already hold a high-impedance enable
+//! input at the intended level until the application takes over.
+
+use crate::{Command, Target};
+
+/// Base address of `IOMUXC`.
+const IOMUXC_ADDRESS: u32 = 0x401F_8000;
+/// Offset of the first `SW_MUX_CTL_PAD_*` register in `IOMUXC`.
+const SW_MUX_CTL_OFFSET: u32 = 0x014;
+/// Offset of the first `SW_PAD_CTL_PAD_*` register in `IOMUXC`.
+const SW_PAD_CTL_OFFSET: u32 = 0x204;
+/// Base addresses of `GPIO1` to `GPIO4`.
+const GPIO_ADDRESSES: [u32; 4] = [0x401B_8000, 0x401B_C000, 0x401C_0000, 0x401C_4000];
+/// Offset of the GPIO data register.
+const DR_OFFSET: u32 = 0x00;
+/// Offset of the GPIO direction register.
+const GDIR_OFFSET: u32 = 0x04;
+/// `MUX_MODE` selecting GPIO on all supported pads.
+const MUX_MODE_GPIO: u32 = 5;
+
+/// An IOMUXC pad (`GPIO_<group>_<index>`), e.g. `Pad::AdB0(9)` for `GPIO_AD_B0_09`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Pad {
+    /// `GPIO_EMC_00` to `GPIO_EMC_41`
+    Emc(u8),
+    /// `GPIO_AD_B0_00` to `GPIO_AD_B0_15`
+    AdB0(u8),
+    /// `GPIO_AD_B1_00` to `GPIO_AD_B1_15`
+    AdB1(u8),
+    /// `GPIO_B0_00` to `GPIO_B0_15`
+    B0(u8),
+    /// `GPIO_B1_00` to `GPIO_B1_15`
+    B1(u8),
+    /// `GPIO_SD_B0_00` to `GPIO_SD_B0_05`
+    SdB0(u8),
+    /// `GPIO_SD_B1_00` to `GPIO_SD_B1_11`
+    SdB1(u8),
+}
+
+/// Output level of a GPIO pin.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Level {
+    Low,
+    High,
+}
+
+/// Direction (and initial output level) of a GPIO pin.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Direction {
+    Input,
+    Output(Level),
+}
+
+/// Location of a pad in the IOMUXC / GPIO register maps.
+struct PadInfo {
+    /// Index of the pad's `SW_MUX_CTL_PAD_*` / `SW_PAD_CTL_PAD_*` registers.
+    iomuxc_index: u32,
+    /// `n` in `GPIOn`, starting from 1.
+    gpio: u8,
+    /// Bit of the pin in `GPIOn` registers.
+    bit: u8,
+}
+
+impl Pad {
+    fn info(self, target: Target) -> PadInfo {
+        if !matches!(
+            target,
+            Target::Imxrt1050 | Target::Imxrt1060 | Target::Imxrt1064
+        ) {
+            panic!("pad layout is not known for {:?}", target);
+        }
+        // (index within group, IOMUXC index of `_00`, group length, GPIO port, bit of `_00`)
+        let (index, base, len, gpio, first_bit) = match self {
+            Pad::Emc(i @ 32..) => (i - 32, 32, 10, 3, 18),
+            Pad::Emc(i) => (i, 0, 32, 4, 0),
+            Pad::AdB0(i) => (i, 42, 16, 1, 0),
+            Pad::AdB1(i) => (i, 58, 16, 1, 16),
+            Pad::B0(i) => (i, 74, 16, 2, 0),
+            Pad::B1(i) => (i, 90, 16, 2, 16),
+            Pad::SdB0(i) => (i, 106, 6, 3, 12),
+            Pad::SdB1(i) => (i, 112, 12, 3, 0),
+        };
+        if index >= len {
+            panic!("invalid pad {:?}", self);
+        }
+        PadInfo {
+            iomuxc_index: (base + index) as u32,
+            gpio,
+            bit: first_bit + index,
+        }
+    }
+}
+
+/// Creates DCD commands that configure a pad as GPIO with the given direction.
+///
+/// For outputs, the level is written to `GPIOn_DR` before the direction is changed in
+/// `GPIOn_GDIR` and the pad is muxed to GPIO, so that the pin never glitches to the wrong level.
+/// If `pad_ctl` is given, it is written to the pad's `SW_PAD_CTL_PAD_*` register as-is.
+///
+/// See [module-level docs](self) for caveats.
+///
+/// # Panics
+///
+/// Panics if the pad does not exist or the pad layout of the target is not known (currently only
+/// RT1050/1060/1064 are supported).
+///
+/// # Example
+///
+/// ```
+/// # use imxrt_dcd::{gpio::*, Command, Target};
+/// // drive GPIO_AD_B0_09 (GPIO1_IO09) high
+/// assert_eq!(
+///     gpio_pin(Target::Imxrt1060, Pad::AdB0(9), Direction::Output(Level::High), None),
+///     [
+///         Command::set32(0x401B_8000, 1 << 9),
+///         Command::set32(0x401B_8004, 1 << 9),
+///         Command::write32(0x401F_80E0, 5),
+///     ],
+/// );
+/// ```
+pub fn gpio_pin(
+    target: Target,
+    pad: Pad,
+    direction: Direction,
+    pad_ctl: Option<u32>,
+) -> Vec<Command> {
+    let info = pad.info(target);
+    let gpio_address = GPIO_ADDRESSES[info.gpio as usize - 1];
+    let bit = 1 << info.bit;
+
+    let mut commands = vec![];
+    match direction {
+        Direction::Input => {
+            commands.push(Command::clear32(gpio_address + GDIR_OFFSET, bit));
+        }
+        Direction::Output(level) => {
+            commands.push(match level {
+                Level::Low => Command::clear32(gpio_address + DR_OFFSET, bit),
+                Level::High => Command::set32(gpio_address + DR_OFFSET, bit),
+            });
+            commands.push(Command::set32(gpio_address + GDIR_OFFSET, bit));
+        }
+    }
+    commands.push(Command::write32(
+        IOMUXC_ADDRESS + SW_MUX_CTL_OFFSET + 4 * info.iomuxc_index,
+        MUX_MODE_GPIO,
+    ));
+    if let Some(pad_ctl) = pad_ctl {
+        commands.push(Command::write32(
+            IOMUXC_ADDRESS + SW_PAD_CTL_OFFSET + 4 * info.iomuxc_index,
+            pad_ctl,
+        ));
+    }
+    commands
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pad_locations() {
+        let locate = |pad: Pad| {
+            let info = pad.info(Target::Imxrt1060);
+            (info.iomuxc_index, info.gpio, info.bit)
+        };
+        assert_eq!(locate(Pad::Emc(0)), (0, 4, 0));
+        assert_eq!(locate(Pad::Emc(31)), (31, 4, 31));
+        assert_eq!(locate(Pad::Emc(32)), (32, 3, 18));
+        assert_eq!(locate(Pad::Emc(41)), (41, 3, 27));
+        assert_eq!(locate(Pad::AdB0(0)), (42, 1, 0));
+        assert_eq!(locate(Pad::AdB1(15)), (73, 1, 31));
+        assert_eq!(locate(Pad::B0(3)), (77, 2, 3));
+        assert_eq!(locate(Pad::B1(0)), (90, 2, 16));
+        assert_eq!(locate(Pad::SdB0(5)), (111, 3, 17));
+        assert_eq!(locate(Pad::SdB1(11)), (123, 3, 11));
+    }
+
+    #[test]
+    fn input_with_pad_ctl() {
+        assert_eq!(
+            gpio_pin(
+                Target::Imxrt1060,
+                Pad::SdB1(0),
+                Direction::Input,
+                Some(0x10B0)
+            ),
+            [
+                Command::clear32(0x401C_0004, 1),
+                Command::write32(0x401F_81D4, 5),
+                Command::write32(0x401F_83C4, 0x10B0),
+            ],
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn invalid_pad() {
+        gpio_pin(Target::Imxrt1060, Pad::SdB0(6), Direction::Input, None);
+    }
+}
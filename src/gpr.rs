@@ -0,0 +1,115 @@
+//! Setting fields of the general purpose registers of the IOMUXC (`IOMUXC_GPR_GPRn`) by name,
+//! e.g. the TCM / OCRAM split of the FlexRAM, or the clock muxing options of ENET and SAI.
+//!
+//! Field names are those of `imxrt-ral` (see [`crate::registers`]); a misspelled field, or a
+//! value wider than its field, is an error rather than a wrong magic constant:
+//!
+//! ```
+//! # use imxrt_dcd::{gpr::GprConfig, Command, Target};
+//! let commands = GprConfig::new(Target::Imxrt1060)
+//!     .field("GPR1", "ENET1_TX_CLK_DIR", 1)
+//!     .field("GPR1", "ENET1_CLK_SEL", 0)
+//!     .field("GPR16", "FLEXRAM_BANK_CFG_SEL", 1)
+//!     .commands()
+//!     .unwrap();
+//! assert_eq!(
+//!     commands,
+//!     [
+//!         Command::clear32(0x400A_C004, 1 << 17 | 1 << 13),
+//!         Command::set32(0x400A_C004, 1 << 17),
+//!         Command::clear32(0x400A_C040, 1 << 2),
+//!         Command::set32(0x400A_C040, 1 << 2),
+//!     ],
+//! );
+//!
+//! let err = GprConfig::new(Target::Imxrt1060).field("GPR1", "ENET_CLK_SEL", 1).commands();
+//! assert_eq!(
+//!     err.unwrap_err().to_string(),
+//!     "unknown field `ENET_CLK_SEL` of register `IOMUXC_GPR_GPR1`",
+//! );
+//! ```
+//!
+//! Fields of the same register are combined into a Clear of all their bits, then a Set of the new
+//! values, in the order the registers are first mentioned (see [`registers::FieldWrites`]). Only
+//! the register layout of the RT1060 / RT1064 is known.
+
+use std::io;
+
+use crate::{registers, Command, Target};
+
+/// Field values to write to the `IOMUXC_GPR` registers of a target; see the
+/// [module-level docs](self).
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct GprConfig<'a> {
+    target: Target,
+    fields: Vec<(&'a str, &'a str, u32)>,
+}
+
+impl<'a> GprConfig<'a> {
+    /// Creates a configuration changing nothing.
+    pub fn new(target: Target) -> Self {
+        Self {
+            target,
+            fields: vec![],
+        }
+    }
+
+    /// Sets field `field` of register `register` (e.g. `"GPR16"`) to `value`, given unshifted.
+    pub fn field(mut self, register: &'a str, field: &'a str, value: u32) -> Self {
+        self.fields.push((register, field, value));
+        self
+    }
+
+    /// Returns the commands setting the fields.
+    ///
+    /// Returns [`io::ErrorKind::Unsupported`] if the register layout of the target is not known,
+    /// and [`io::ErrorKind::InvalidInput`] for unknown or read-only registers, unknown fields, or
+    /// values that do not fit their field.
+    pub fn commands(&self) -> io::Result<Vec<Command>> {
+        if !matches!(self.target, Target::Imxrt1060 | Target::Imxrt1064) {
+            return Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                format!("the IOMUXC_GPR layout of {:?} is not known", self.target),
+            ));
+        }
+        let mut writes = registers::FieldWrites::new();
+        for &(register, field, value) in &self.fields {
+            writes.field("IOMUXC_GPR", register, field, value)?;
+        }
+        Ok(writes.commands())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fields() {
+        // the last value of a field wins
+        let commands = GprConfig::new(Target::Imxrt1064)
+            .field("GPR1", "SAI1_MCLK1_SEL", 0b111)
+            .field("GPR1", "SAI1_MCLK1_SEL", 0b010)
+            .commands()
+            .unwrap();
+        assert_eq!(
+            commands,
+            [
+                Command::clear32(0x400A_C004, 0b111),
+                Command::set32(0x400A_C004, 0b010),
+            ]
+        );
+
+        let message = |config: GprConfig| config.commands().unwrap_err().to_string();
+        assert_eq!(
+            message(GprConfig::new(Target::Imxrt1060).field("GPR1", "SAI1_MCLK1_SEL", 8)),
+            "value 0x8 does not fit in field `IOMUXC_GPR_GPR1.SAI1_MCLK1_SEL`"
+        );
+        assert_eq!(
+            message(GprConfig::new(Target::Imxrt1060).field("GPR99", "X", 0)),
+            "unknown register `IOMUXC_GPR_GPR99`"
+        );
+        let err = GprConfig::new(Target::Imxrt1010).commands().unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::Unsupported);
+    }
+}
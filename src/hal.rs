@@ -0,0 +1,162 @@
+//! Emitting the clock configuration of `imxrt-hal` (RT1060) as DCD commands, e.g. to move early
+//! clock setup from the firmware to the boot ROM without duplicating the numbers.
+//!
+//! [`ClockConfig`] takes the same selections and dividers as the `imxrt_hal::ccm` setters, and
+//! emits the commands that have the same effect on the CCM registers:
+//!
+//! ```
+//! use imxrt_dcd::{board::DcdFragment, hal::ClockConfig, Command};
+//! use imxrt_hal::ccm::{perclk_clk, uart_clk};
+//!
+//! const PERCLK_DIVIDER: u32 = 3;
+//!
+//! let config = ClockConfig {
+//!     perclk_selection: Some(perclk_clk::Selection::Oscillator),
+//!     perclk_divider: Some(PERCLK_DIVIDER),
+//!     uart_selection: Some(uart_clk::Selection::Oscillator),
+//!     ..ClockConfig::default()
+//! };
+//! assert_eq!(
+//!     config.commands(),
+//!     [
+//!         Command::clear32(0x400F_C01C, 1 << 6), // CSCMR1: PERCLK_CLK_SEL
+//!         Command::set32(0x400F_C01C, 1 << 6),
+//!         Command::clear32(0x400F_C01C, 0x3F),   // CSCMR1: PERCLK_PODF
+//!         Command::set32(0x400F_C01C, 2),
+//!         Command::clear32(0x400F_C024, 1 << 6), // CSCDR1: UART_CLK_SEL
+//!         Command::set32(0x400F_C024, 1 << 6),
+//!     ],
+//! );
+//! ```
+//!
+//! Since the DCD cannot modify a field in a single command, each field is first cleared, then set
+//! to the new value (see [`registers::modify`]), so that a clock mux never selects a mix of the
+//! old and the new source. In between, the field reads 0, i.e. the first source of a mux, or a
+//! divider of 1.
+
+use imxrt_hal::ccm::{ahb_clk, ipg_clk, lpi2c_clk, lpspi_clk, perclk_clk, uart_clk};
+
+use crate::board::DcdFragment;
+use crate::{registers, CheckCond, Command};
+
+/// Clock root selections and dividers, as passed to the setters in `imxrt_hal::ccm`. Fields left
+/// at `None` are not changed.
+///
+/// Dividers are clamped to the valid range, like `imxrt-hal` does.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
+pub struct ClockConfig {
+    /// `imxrt_hal::ccm::arm_divider::set_divider`.
+    pub arm_divider: Option<u32>,
+    /// `imxrt_hal::ccm::ahb_clk::set_divider`.
+    pub ahb_divider: Option<u32>,
+    /// `imxrt_hal::ccm::ahb_clk::set_selection`.
+    pub ahb_selection: Option<ahb_clk::Selection>,
+    /// `imxrt_hal::ccm::ipg_clk::set_divider`.
+    pub ipg_divider: Option<u32>,
+    /// `imxrt_hal::ccm::perclk_clk::set_selection`.
+    pub perclk_selection: Option<perclk_clk::Selection>,
+    /// `imxrt_hal::ccm::perclk_clk::set_divider`.
+    pub perclk_divider: Option<u32>,
+    /// `imxrt_hal::ccm::uart_clk::set_selection`.
+    pub uart_selection: Option<uart_clk::Selection>,
+    /// `imxrt_hal::ccm::uart_clk::set_divider`.
+    pub uart_divider: Option<u32>,
+    /// `imxrt_hal::ccm::lpi2c_clk::set_selection`.
+    pub lpi2c_selection: Option<lpi2c_clk::Selection>,
+    /// `imxrt_hal::ccm::lpi2c_clk::set_divider`.
+    pub lpi2c_divider: Option<u32>,
+    /// `imxrt_hal::ccm::lpspi_clk::set_selection`.
+    pub lpspi_selection: Option<lpspi_clk::Selection>,
+    /// `imxrt_hal::ccm::lpspi_clk::set_divider`.
+    pub lpspi_divider: Option<u32>,
+}
+
+impl ClockConfig {
+    /// Returns the commands applying the configuration, in the order of the fields.
+    ///
+    /// Like `imxrt-hal`, each change of the ARM / AHB clock is followed by waiting for the CCM
+    /// handshake (`CCM_CDHIPR`) to complete.
+    pub fn commands(&self) -> Vec<Command> {
+        let divider = |divider: Option<u32>, max: u32| divider.map(|d| d.clamp(1, max) - 1);
+        #[rustfmt::skip]
+        let fields = [
+            ("CACRR", "ARM_PODF", divider(self.arm_divider, 8), true),
+            ("CBCDR", "AHB_PODF", divider(self.ahb_divider, 8), true),
+            ("CBCDR", "PERIPH_CLK_SEL", self.ahb_selection.map(|s| s as u32), true),
+            ("CBCDR", "IPG_PODF", divider(self.ipg_divider, ipg_clk::MAX_DIVIDER), false),
+            ("CSCMR1", "PERCLK_CLK_SEL", self.perclk_selection.map(|s| s as u32), false),
+            ("CSCMR1", "PERCLK_PODF", divider(self.perclk_divider, perclk_clk::MAX_DIVIDER), false),
+            ("CSCDR1", "UART_CLK_SEL", self.uart_selection.map(|s| s as u32), false),
+            ("CSCDR1", "UART_CLK_PODF", divider(self.uart_divider, uart_clk::MAX_DIVIDER), false),
+            ("CSCDR2", "LPI2C_CLK_SEL", self.lpi2c_selection.map(|s| s as u32), false),
+            ("CSCDR2", "LPI2C_CLK_PODF", divider(self.lpi2c_divider, lpi2c_clk::MAX_DIVIDER), false),
+            ("CBCMR", "LPSPI_CLK_SEL", self.lpspi_selection.map(|s| s as u32), false),
+            ("CBCMR", "LPSPI_PODF", divider(self.lpspi_divider, lpspi_clk::MAX_DIVIDER), false),
+        ];
+        let mut commands = vec![];
+        for (register, field, value, handshake) in fields {
+            let Some(value) = value else { continue };
+            let register = registers::by_name("CCM", register).unwrap();
+            let field = register.field(field).unwrap();
+            commands.extend(registers::modify(
+                register.width,
+                register.address,
+                field.mask(),
+                value << field.offset,
+            ));
+            if handshake {
+                let cdhipr = registers::by_name("CCM", "CDHIPR").unwrap();
+                commands.push(Command::check32(
+                    CheckCond::AllClear,
+                    cdhipr.address,
+                    cdhipr.fields_mask(),
+                ));
+            }
+        }
+        commands
+    }
+}
+
+impl DcdFragment for ClockConfig {
+    fn name(&self) -> &str {
+        "clocks"
+    }
+
+    fn commands(&self) -> Vec<Command> {
+        ClockConfig::commands(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ahb() {
+        let config = ClockConfig {
+            arm_divider: Some(2),
+            ahb_divider: Some(100),
+            ahb_selection: Some(ahb_clk::Selection::PrePeriphClkSel),
+            ipg_divider: Some(4),
+            ..ClockConfig::default()
+        };
+        let handshake = Command::check32(CheckCond::AllClear, 0x400F_C048, 0x0001_002B);
+        assert_eq!(
+            config.commands(),
+            [
+                Command::clear32(0x400F_C010, 0b111), // CACRR: ARM_PODF
+                Command::set32(0x400F_C010, 1),
+                handshake.clone(),
+                Command::clear32(0x400F_C014, 0b111 << 10), // CBCDR: AHB_PODF, clamped
+                Command::set32(0x400F_C014, 0b111 << 10),
+                handshake.clone(),
+                Command::clear32(0x400F_C014, 1 << 25), // CBCDR: PERIPH_CLK_SEL
+                handshake,
+                Command::clear32(0x400F_C014, 0b11 << 8), // CBCDR: IPG_PODF
+                Command::set32(0x400F_C014, 0b11 << 8),
+            ]
+        );
+        assert_eq!(DcdFragment::name(&config), "clocks");
+        assert!(ClockConfig::default().commands().is_empty());
+    }
+}
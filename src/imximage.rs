@@ -0,0 +1,286 @@
+//! Importing DCDs from the `imximage` configuration files of U-Boot (`imximage.cfg`), the input of
+//! `mkimage -T imximage`.
+//!
+//! U-Boot boards of other i.MX families describe their DCD in this format, one command per line:
+//!
+//! ```text
+//! # DDR clock gates
+//! IMAGE_VERSION 2
+//! BOOT_FROM     sd
+//! DATA 4 0x020c4068 0xffffffff
+//! SET_BIT 4 0x020c4018 0x00000020
+//! CHECK_BITS_CLR 4 0x020c4048 0x00000020
+//! ```
+//!
+//! [`parse`] turns such a file into commands, e.g. to seed an i.MX RT DCD from a board with the
+//! same external memory. The registers of other families are at other addresses, so the result
+//! usually needs porting; [`crate::lint::validate`] points out addresses an i.MX RT boot ROM
+//! rejects.
+//!
+//! Conversely, [`render`] emits commands in this format, e.g. to build the image of an SPL-based
+//! project sharing the hardware with `mkimage`.
+
+use std::io;
+
+use crate::text::ParseError;
+use crate::{symbols, Check, CheckCond, Command, Width, Write, WriteOp};
+
+/// Parses the DCD commands of an `imximage` configuration file.
+///
+/// Keywords are case-insensitive, and numbers may be given in decimal, hexadecimal (`0x`) or octal
+/// (leading `0`), like U-Boot accepts them. `#` starts a comment. The keywords describing the
+/// image rather than the DCD (`IMAGE_VERSION`, `BOOT_FROM`, `BOOT_OFFSET`, `CSF`) are ignored.
+///
+/// `imximage` checks have no poll count, so checks poll indefinitely. Returns an error for
+/// `PLUGIN` images, which replace the DCD with code.
+///
+/// ```
+/// # use imxrt_dcd::{imximage, CheckCond, Command};
+/// let cfg = "
+///     IMAGE_VERSION 2
+///     DATA 4 0x400fc068 0xffffffff   # CCGR0
+///     CHECK_BITS_SET 4 0x400d8000 0x80000000
+/// ";
+/// assert_eq!(
+///     imximage::parse(cfg).unwrap(),
+///     [
+///         Command::write32(0x400F_C068, 0xFFFF_FFFF),
+///         Command::check32(CheckCond::AllSet, 0x400D_8000, 0x8000_0000),
+///     ],
+/// );
+/// ```
+pub fn parse(text: &str) -> Result<Vec<Command>, ParseError> {
+    let mut commands = vec![];
+    for (index, line) in text.lines().enumerate() {
+        let error = |message: String| ParseError {
+            line: index + 1,
+            message,
+        };
+        let line = line.split('#').next().unwrap();
+        let mut tokens = line.split_whitespace();
+        let Some(keyword) = tokens.next() else {
+            continue;
+        };
+        let keyword = keyword.to_ascii_uppercase();
+        let kind = match keyword.as_str() {
+            "IMAGE_VERSION" | "BOOT_FROM" | "BOOT_OFFSET" | "CSF" => continue,
+            "PLUGIN" => {
+                return Err(error(
+                    "`PLUGIN` images run code instead of a DCD, which cannot be imported".into(),
+                ))
+            }
+            "NOP" => None,
+            "DATA" => Some(Ok(WriteOp::Write)),
+            "SET_BIT" => Some(Ok(WriteOp::Set)),
+            "CLR_BIT" => Some(Ok(WriteOp::Clear)),
+            "CHECK_BITS_SET" => Some(Err(CheckCond::AllSet)),
+            "CHECK_BITS_CLR" => Some(Err(CheckCond::AllClear)),
+            "CHECK_ANY_BIT_SET" => Some(Err(CheckCond::AnySet)),
+            "CHECK_ANY_BIT_CLR" => Some(Err(CheckCond::AnyClear)),
+            _ => return Err(error(format!("unknown keyword `{}`", keyword))),
+        };
+        let command = match kind {
+            None => Command::Nop,
+            Some(kind) => {
+                let mut next = |what: &str| {
+                    let token = tokens
+                        .next()
+                        .ok_or_else(|| error(format!("missing {}", what)))?;
+                    parse_number(token)
+                        .ok_or_else(|| error(format!("invalid {} `{}`", what, token)))
+                };
+                let width = match next("width")? {
+                    width @ (1 | 2 | 4) => Width::from_num_bytes(width as usize),
+                    width => return Err(error(format!("invalid width {}", width))),
+                };
+                let address = next("address")?;
+                let value = next("value")?;
+                match kind {
+                    Ok(op) => Command::Write(Write::new(width, op, address, value)),
+                    Err(cond) => Command::Check(Check::new(width, cond, address, value)),
+                }
+            }
+        };
+        if let Some(token) = tokens.next() {
+            return Err(error(format!("unexpected `{}`", token)));
+        }
+        commands.push(command);
+    }
+    Ok(commands)
+}
+
+/// Renders commands as the DCD lines of an `imximage` configuration file, one line per command.
+///
+/// The lines describing the image (`IMAGE_VERSION`, `BOOT_FROM` / `BOOT_OFFSET`) are up to the
+/// project and not included. Returns [`io::ErrorKind::InvalidInput`] for checks with a poll count
+/// and for Unlock commands, which `imximage` cannot express.
+///
+/// ```
+/// # use imxrt_dcd::{imximage, CheckCond, Command};
+/// let commands = [
+///     Command::write32(0x400F_C068, 0xFFFF_FFFF),
+///     Command::check32(CheckCond::AllSet, 0x400D_8000, 0x8000_0000),
+/// ];
+/// let cfg = imximage::render(&commands).unwrap();
+/// assert_eq!(
+///     cfg,
+///     "\
+/// ## Generated by imxrt-dcd; do not edit.
+/// DATA 4 0x400fc068 0xffffffff  # CCM+0x068
+/// CHECK_BITS_SET 4 0x400d8000 0x80000000  # CCM_ANALOG+0x000
+/// ",
+/// );
+/// assert_eq!(imximage::parse(&cfg).unwrap(), commands);
+/// ```
+pub fn render(commands: &[Command]) -> io::Result<String> {
+    let mut out = "# Generated by imxrt-dcd; do not edit.\n".to_owned();
+    for (index, command) in commands.iter().enumerate() {
+        let (keyword, width, address, value) = match command {
+            Command::Nop => {
+                out += "NOP\n";
+                continue;
+            }
+            Command::Unlock(_) => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!(
+                        "command #{} is an Unlock command, which imximage cannot express",
+                        index
+                    ),
+                ))
+            }
+            Command::Write(write) => (
+                write_keyword(write.op),
+                write.width,
+                write.address,
+                write.value,
+            ),
+            Command::WriteGroup(group) => {
+                // imximage groups writes itself
+                for &(address, value) in &group.pairs {
+                    push_line(
+                        &mut out,
+                        write_keyword(group.op),
+                        group.width,
+                        address,
+                        value,
+                    );
+                }
+                continue;
+            }
+            Command::Check(check) => {
+                if check.count.is_some() {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidInput,
+                        format!(
+                            "check #{} has a poll count, which imximage cannot express",
+                            index
+                        ),
+                    ));
+                }
+                let keyword = match check.cond {
+                    CheckCond::AllSet => "CHECK_BITS_SET",
+                    CheckCond::AllClear => "CHECK_BITS_CLR",
+                    CheckCond::AnySet => "CHECK_ANY_BIT_SET",
+                    CheckCond::AnyClear => "CHECK_ANY_BIT_CLR",
+                };
+                (keyword, check.width, check.address, check.mask)
+            }
+        };
+        push_line(&mut out, keyword, width, address, value);
+    }
+    Ok(out)
+}
+
+fn write_keyword(op: WriteOp) -> &'static str {
+    match op {
+        WriteOp::Write => "DATA",
+        WriteOp::Set => "SET_BIT",
+        WriteOp::Clear => "CLR_BIT",
+    }
+}
+
+/// Appends a command line, with the symbolized address as comment.
+fn push_line(out: &mut String, keyword: &str, width: Width, address: u32, value: u32) {
+    *out += &format!(
+        "{} {} {:#010x} {:#010x}",
+        keyword, width as u8, address, value
+    );
+    if symbols::peripheral(address).is_some() {
+        *out += &format!("  # {}", symbols::symbolize(address));
+    }
+    out.push('\n');
+}
+
+/// Parses an integer like C's `strtoul` with base 0.
+fn parse_number(token: &str) -> Option<u32> {
+    if let Some(hex) = token.strip_prefix("0x").or(token.strip_prefix("0X")) {
+        u32::from_str_radix(hex, 16).ok()
+    } else if token.len() > 1 && token.starts_with('0') {
+        u32::from_str_radix(&token[1..], 8).ok()
+    } else {
+        token.parse().ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keywords() {
+        let cfg = "
+# comment
+image_version 2
+BOOT_FROM sd
+NOP
+SET_BIT 1 0x401f8014 07
+CLR_BIT 2 0x401f8016 16
+CHECK_BITS_CLR 4 0x400fc048 0x20
+CHECK_ANY_BIT_SET 4 0x400fc048 0x20
+CHECK_ANY_BIT_CLR 4 0x400fc048 0x20
+";
+        assert_eq!(
+            parse(cfg).unwrap(),
+            [
+                Command::Nop,
+                Command::Write(Write::new(Width::B1, WriteOp::Set, 0x401F_8014, 7)),
+                Command::Write(Write::new(Width::B2, WriteOp::Clear, 0x401F_8016, 16)),
+                Command::check32(CheckCond::AllClear, 0x400F_C048, 0x20),
+                Command::check32(CheckCond::AnySet, 0x400F_C048, 0x20),
+                Command::check32(CheckCond::AnyClear, 0x400F_C048, 0x20),
+            ]
+        );
+    }
+
+    #[test]
+    fn errors() {
+        let message = |cfg: &str| parse(cfg).unwrap_err().to_string();
+        assert_eq!(message("\nDATA 3 0 0"), "line 2: invalid width 3");
+        assert_eq!(message("DATA 4 0x10"), "line 1: missing value");
+        assert_eq!(message("DATA 4 0x10 0x"), "line 1: invalid value `0x`");
+        assert_eq!(message("DATA 4 0x10 1 2"), "line 1: unexpected `2`");
+        assert_eq!(message("WRITE 4 0x10 1"), "line 1: unknown keyword `WRITE`");
+        assert!(message("PLUGIN plugin.bin 0x00907000").starts_with("line 1: `PLUGIN`"));
+    }
+
+    #[test]
+    fn roundtrip() {
+        let commands = [
+            Command::Nop,
+            Command::Write(Write::new(Width::B1, WriteOp::Set, 0x401F_8014, 7)),
+            Command::Write(Write::new(Width::B2, WriteOp::Clear, 0x2000_0000, 16)),
+            Command::check32(CheckCond::AllClear, 0x400F_C048, 0x20),
+            Command::check32(CheckCond::AnySet, 0x400F_C048, 0x20),
+            Command::check32(CheckCond::AnyClear, 0x400F_C048, 0x20),
+        ];
+        assert_eq!(parse(&render(&commands).unwrap()).unwrap(), commands);
+
+        let err = render(&[Command::Nop, commands[3].clone().with_count(10)]).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+        assert_eq!(
+            err.to_string(),
+            "check #1 has a poll count, which imximage cannot express"
+        );
+    }
+}
@@ -1,11 +1,88 @@
 #![doc = include_str!("../README.md")]
+use std::fmt;
+
 use itertools::Itertools;
 
+/// Emits a `log::debug!` event if the `log` feature is enabled; otherwise only type-checks the
+/// arguments.
+macro_rules! debug {
+    ($($arg:tt)*) => {
+        #[cfg(feature = "log")]
+        ::log::debug!($($arg)*);
+        #[cfg(not(feature = "log"))]
+        if false {
+            let _ = format_args!($($arg)*);
+        }
+    };
+}
+
 #[cfg(feature = "ral")]
 mod macros;
 
+#[rustfmt::skip]
+mod allowed_ranges;
+
+pub mod board;
+pub mod ccm;
+pub mod coverage;
+pub mod decompile;
+pub mod detect;
+pub mod dump;
+pub mod effects;
+pub mod exec;
+pub mod explain;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+pub mod golden;
+pub mod gpio;
+#[cfg(feature = "ral")]
+pub mod gpr;
+#[cfg(feature = "hal")]
+pub mod hal;
+pub mod imximage;
+pub mod lint;
+#[cfg(feature = "mboot")]
+pub mod mboot;
+pub mod output;
+pub mod packed;
+#[cfg(feature = "ral")]
+pub mod pmu;
+pub mod readback;
+#[cfg(feature = "ral")]
+pub mod registers;
+pub mod rt;
+#[cfg(feature = "schema")]
+pub mod schema;
+pub mod script;
+#[cfg(feature = "rhai")]
+pub mod scripting;
+pub mod sdk;
+#[cfg(feature = "sdp")]
+pub mod sdp;
+pub mod semc;
+pub mod sim;
+pub mod stats;
+pub mod stream;
+mod symbols;
+pub mod text;
+pub mod trace;
+pub mod verify;
+#[cfg(feature = "serde")]
+pub mod versioned;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+#[cfg(feature = "ral")]
+pub mod xtalosc;
+pub mod zephyr;
+
+#[cfg(feature = "derive")]
+pub use imxrt_dcd_macros::dcd;
+
 /// A DCD command.
 #[derive(Default, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(tag = "type", rename_all = "snake_case"))]
 pub enum Command {
     /// Dummy command --- may behave as a small delay.
     #[default]
@@ -14,36 +91,230 @@ pub enum Command {
     Write(Write),
     /// DCD command for polling an address until the value matches a given bitmask condition; [`Check`].
     Check(Check),
+    /// DCD command for writing several values with exactly the given grouping; [`WriteGroup`].
+    WriteGroup(WriteGroup),
+    /// HAB command keeping features of a security engine unlocked; [`Unlock`].
+    Unlock(Unlock),
 }
 
-/*
-// TODO(summivox): not ready
 impl Command {
-    pub fn with_count(self, count: u32) -> Self {
-        match self {
-            Self::Check(check) => Self::Check(check.with_count(count)),
+    /// Shorthand for a 32-bit [`WriteOp::Write`] command.
+    ///
+    /// ```
+    /// # use imxrt_dcd::*;
+    /// const CMD: Command = Command::write32(0x400F_C068, 0xFFFF_FFFF);
+    /// assert_eq!(CMD, Command::Write(Write::new(Width::B4, WriteOp::Write, 0x400F_C068, 0xFFFF_FFFF)));
+    /// ```
+    pub const fn write32(address: u32, value: u32) -> Self {
+        Self::Write(Write::new(Width::B4, WriteOp::Write, address, value))
+    }
+
+    /// Shorthand for a 32-bit [`WriteOp::Set`] command.
+    pub const fn set32(address: u32, value: u32) -> Self {
+        Self::Write(Write::new(Width::B4, WriteOp::Set, address, value))
+    }
+
+    /// Shorthand for a 32-bit [`WriteOp::Clear`] command.
+    pub const fn clear32(address: u32, value: u32) -> Self {
+        Self::Write(Write::new(Width::B4, WriteOp::Clear, address, value))
+    }
+
+    /// Shorthand for a 32-bit [`WriteOp::Write`] command followed by bounded checks verifying that
+    /// the bits in `mask` read back as written, each polling at most `count` times --- see
+    /// [`readback::write_then_check`].
+    ///
+    /// ```
+    /// # use imxrt_dcd::*;
+    /// let commands = [
+    ///     &Command::write_then_check32(0x400F_C014, 0x000A_8300, 0x0000_FF00, 100)[..],
+    ///     &[Command::set32(0x400F_C068, 0b11 << 4)],
+    /// ]
+    /// .concat();
+    /// assert_eq!(commands.len(), 4);
+    /// ```
+    pub fn write_then_check32(address: u32, value: u32, mask: u32, count: u32) -> Vec<Self> {
+        readback::write_then_check(
+            Write::new(Width::B4, WriteOp::Write, address, value),
+            mask,
+            count,
+        )
+    }
+
+    /// Shorthand for a 32-bit [`Check`] command that polls indefinitely.
+    pub const fn check32(cond: CheckCond, address: u32, mask: u32) -> Self {
+        Self::Check(Check::new(Width::B4, cond, address, mask))
+    }
+
+    /// Sets the poll count of a [`Command::Check`] --- see [`Check::count`].
+    ///
+    /// Panics if `self` is not a [`Command::Check`].
+    pub const fn with_count(self, count: u32) -> Self {
+        let check = match &self {
+            Self::Check(check) => Check { ..*check },
             _ => panic!("`with_count` can only be called on a `Check` command."),
+        };
+        // `self` holds no heap data here; forgetting it keeps this a `const fn`
+        core::mem::forget(self);
+        Self::Check(check.with_count(count))
+    }
+
+    /// Number of bytes a [`Command::Nop`] takes in the serialized DCD.
+    pub const NOP_BYTES: usize = 4;
+
+    /// Returns whether the command accesses any byte in `addresses`; see [`Dcd::find`].
+    ///
+    /// ```
+    /// # use imxrt_dcd::*;
+    /// let semc = 0x402F_0000..=0x402F_3FFF;
+    /// assert!(Command::write32(0x402F_0000, 0x1000_0004).accesses(semc.clone()));
+    /// assert!(!Command::Nop.accesses(semc));
+    /// ```
+    pub fn accesses(&self, addresses: impl std::ops::RangeBounds<u32>) -> bool {
+        let (begin, end) = address_bounds(&addresses);
+        self.overlaps(begin, end)
+    }
+
+    /// Returns whether the command accesses the peripheral instance `name`, as named in the text
+    /// format (e.g. `SEMC`, `CCM_ANALOG` or `IOMUXC`). Commands to addresses of no known
+    /// peripheral never match.
+    ///
+    /// ```
+    /// # use imxrt_dcd::*;
+    /// assert!(Command::write32(0x402F_0000, 0x1000_0004).accesses_peripheral("SEMC"));
+    /// assert!(!Command::write32(0x400F_C068, 0xFFFF_FFFF).accesses_peripheral("SEMC"));
+    /// ```
+    pub fn accesses_peripheral(&self, name: &str) -> bool {
+        self.accessed()
+            .into_iter()
+            .any(|(address, _)| symbols::peripheral(address).is_some_and(|(n, _)| n == name))
+    }
+
+    /// Returns whether the command accesses any byte in `[begin, end)`.
+    fn overlaps(&self, begin: u64, end: u64) -> bool {
+        self.accessed()
+            .into_iter()
+            .any(|(address, width)| (address as u64) < end && begin < address as u64 + width as u64)
+    }
+
+    /// Returns the addresses the command reads or writes, with the width of the access.
+    fn accessed(&self) -> Vec<(u32, Width)> {
+        match self {
+            Self::Nop | Self::Unlock(_) => vec![],
+            Self::Write(write) => vec![(write.address, write.width)],
+            Self::Check(check) => vec![(check.address, check.width)],
+            Self::WriteGroup(group) => group
+                .pairs
+                .iter()
+                .map(|&(address, _)| (address, group.width))
+                .collect(),
+        }
+    }
+
+    /// Returns the writes of a [`Command::Write`] or [`Command::WriteGroup`], nothing otherwise.
+    pub(crate) fn writes(&self) -> Vec<Write> {
+        match self {
+            Self::Write(write) => vec![write.clone()],
+            Self::WriteGroup(group) => group.writes().collect(),
+            Self::Nop | Self::Check(_) | Self::Unlock(_) => vec![],
         }
     }
 }
- */
+
+/// Creates an array of `n` [`Command::Nop`]s, e.g. as a crude settle delay between writes.
+///
+/// The boot ROM does not specify how long a NOP takes, so this is no substitute for polling with a
+/// Check command where possible. Each NOP costs [`Command::NOP_BYTES`] bytes of the DCD size
+/// limit, i.e. `nops!(n)` costs `n * Command::NOP_BYTES` bytes.
+///
+/// `n` must be a constant; the result can be used in `static` / `const` command tables.
+///
+/// ```
+/// # use imxrt_dcd::{nops, Command};
+/// const DELAY: [Command; 8] = nops!(8);
+/// assert!(DELAY.iter().all(|command| *command == Command::Nop));
+///
+/// let mut commands = vec![Command::write32(0x402F_0000, 1)];
+/// commands.extend(nops!(8));
+/// commands.push(Command::write32(0x402F_0000, 0));
+/// let mut bytes = vec![];
+/// assert_eq!(imxrt_dcd::serialize(&mut bytes, &commands).unwrap(), 4 + 12 + 8 * 4 + 12);
+/// ```
+#[macro_export]
+macro_rules! nops {
+    ($n:expr) => {{
+        const NOP: $crate::Command = $crate::Command::Nop;
+        [NOP; $n]
+    }};
+}
 
 /// DCD command for writing a value to an address.
 #[derive(Default, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Write {
     /// Width of the bus write.
+    #[cfg_attr(feature = "serde", serde(default))]
     pub width: Width,
     /// Writing operation --- see [`WriteOp`].
+    #[cfg_attr(feature = "serde", serde(default))]
     pub op: WriteOp,
     /// Address to be written to. Note that the ROM may enforce valid address ranges.
     pub address: u32,
     pub value: u32,
 }
 
+/// DCD command for writing several address / value pairs under one command header, exactly as
+/// given.
+///
+/// [`serialize`] merges consecutive [`Write`]s of the same width and op into one command; a
+/// `WriteGroup` instead is emitted as a command of its own, with its pairs in order, and is never
+/// merged with neighboring writes. This gives exact control over the command structure, e.g. to
+/// reproduce a reference DCD byte for byte. Other than that, it behaves like the sequence of
+/// [`Write`]s returned by [`WriteGroup::writes`].
+///
+/// ```
+/// # use imxrt_dcd::*;
+/// let group = WriteGroup::new(Width::B4, WriteOp::Write, vec![(0x400F_C068, !0), (0x400F_C06C, !0)]);
+/// let commands = [Command::write32(0x400F_C064, !0), Command::WriteGroup(group)];
+/// let mut bytes = vec![];
+/// // two write commands instead of one with three pairs
+/// assert_eq!(serialize(&mut bytes, &commands).unwrap(), 4 + (4 + 8) + (4 + 2 * 8));
+/// ```
+#[derive(Default, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct WriteGroup {
+    /// Width of the bus writes.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub width: Width,
+    /// Writing operation of all pairs --- see [`WriteOp`].
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub op: WriteOp,
+    /// Address / value pairs, in order of writing.
+    pub pairs: Vec<(u32, u32)>,
+}
+
+impl WriteGroup {
+    /// Creates a group of writes.
+    pub const fn new(width: Width, op: WriteOp, pairs: Vec<(u32, u32)>) -> Self {
+        Self { width, op, pairs }
+    }
+
+    /// Returns the writes of the group, in order.
+    pub fn writes(&self) -> impl Iterator<Item = Write> + '_ {
+        self.pairs
+            .iter()
+            .map(|&(address, value)| Write::new(self.width, self.op, address, value))
+    }
+}
+
 /// DCD command for polling an address until the value matches a given bitmask condition.
 #[derive(Default, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Check {
     /// Width of the bus read.
+    #[cfg_attr(feature = "serde", serde(default))]
     pub width: Width,
     /// Condition to check --- see [`CheckCond`].
     pub cond: CheckCond,
@@ -56,20 +327,133 @@ pub struct Check {
     /// - `Some(0)` => equivalent to [`Command::Nop`]
     /// - `Some(x) if x > 0` => poll at most `x` times; if the condition still is not satisfied,
     ///   the boot ROM will abandon interpreting the rest of the DCD.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub count: Option<u32>,
 }
 
-/*
-// TODO(summivox): not ready
+/// HABv4 Unlock command, keeping features of a security engine unlocked after boot, e.g. for the
+/// field return of a closed device.
+///
+/// Unlock commands belong to the HAB command sequence file (CSF) rather than the DCD: the boot ROM
+/// abandons a DCD containing one (see [`lint::Lint::UnlockCommand`]). They are supported here so
+/// that tools handling complete HAB command streams can build on the same types; they serialize
+/// as a command of their own, and have no effect on memory in [`sim`] or [`exec`].
+///
+/// ```
+/// # use imxrt_dcd::*;
+/// let unlock = Unlock::new(Unlock::ENGINE_OCOTP, Unlock::OCOTP_FIELD_RETURN)
+///     .with_uid(0x0123_4567_89AB_CDEF);
+/// let bytes = [
+///     0xB2, 0x00, 0x10, 0x21,
+///     0x00, 0x00, 0x00, 0x01,
+///     0x01, 0x23, 0x45, 0x67, 0x89, 0xAB, 0xCD, 0xEF,
+/// ];
+/// assert_eq!(Command::try_from(&bytes[..]).unwrap(), Command::Unlock(unlock));
+/// ```
+#[derive(Default, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct Unlock {
+    /// HAB engine whose features stay unlocked, e.g. [`Unlock::ENGINE_OCOTP`].
+    pub engine: u8,
+    /// Engine-specific bits of the features to keep unlocked, e.g.
+    /// [`Unlock::OCOTP_FIELD_RETURN`].
+    pub features: u32,
+    /// Unique ID of the device the command is restricted to; required by some features, e.g. the
+    /// OCOTP field return.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub uid: Option<u64>,
+}
+
+impl Unlock {
+    /// Cryptographic Acceleration and Assurance Module.
+    pub const ENGINE_CAAM: u8 = 0x1D;
+    /// Secure Non-Volatile Storage.
+    pub const ENGINE_SNVS: u8 = 0x1E;
+    /// On-Chip OTP controller, i.e. the fuses.
+    pub const ENGINE_OCOTP: u8 = 0x21;
+
+    /// CAAM: leave the job ring and DECO master ID registers unlocked.
+    pub const CAAM_MID: u32 = 1 << 0;
+    /// CAAM: do not instantiate the RNG state handle.
+    pub const CAAM_RNG: u32 = 1 << 1;
+    /// CAAM: keep the manufacturing protection private key in CAAM.
+    pub const CAAM_MFG: u32 = 1 << 2;
+    /// SNVS: leave the LP software reset unlocked.
+    pub const SNVS_LP_SWR: u32 = 1 << 0;
+    /// SNVS: leave the zeroizable master key write unlocked.
+    pub const SNVS_ZMK_WRITE: u32 = 1 << 1;
+    /// OCOTP: leave the field return activation unlocked; requires [`Unlock::uid`].
+    pub const OCOTP_FIELD_RETURN: u32 = 1 << 0;
+    /// OCOTP: leave the SRK revocation fuses unlocked.
+    pub const OCOTP_SRK_REVOKE: u32 = 1 << 1;
+    /// OCOTP: leave the SCS register unlocked.
+    pub const OCOTP_SCS: u32 = 1 << 2;
+    /// OCOTP: unlock JTAG through the `HAB_JDE` bit of the SCS register.
+    pub const OCOTP_JTAG: u32 = 1 << 3;
+
+    /// Creates an unlock command without UID.
+    pub const fn new(engine: u8, features: u32) -> Self {
+        Self {
+            engine,
+            features,
+            uid: None,
+        }
+    }
+
+    /// Restricts the command to the device with the unique ID --- see [`Unlock::uid`].
+    pub const fn with_uid(self, uid: u64) -> Self {
+        Self {
+            uid: Some(uid),
+            ..self
+        }
+    }
+}
+
+impl Write {
+    /// Creates a write command; see also [`Command::write32`] and friends.
+    pub const fn new(width: Width, op: WriteOp, address: u32, value: u32) -> Self {
+        Self {
+            width,
+            op,
+            address,
+            value,
+        }
+    }
+}
+
 impl Check {
-    pub fn with_count(self, count: u32) -> Self {
-        Self { count: Some(count), ..self }
+    /// Creates a check that polls indefinitely; see [`Check::with_count`].
+    pub const fn new(width: Width, cond: CheckCond, address: u32, mask: u32) -> Self {
+        Self {
+            width,
+            cond,
+            address,
+            mask,
+            count: None,
+        }
+    }
+
+    /// Sets the poll count --- see [`Check::count`].
+    pub const fn with_count(self, count: u32) -> Self {
+        Self {
+            count: Some(count),
+            ..self
+        }
     }
 }
- */
 
 /// Byte width of the bus read/write.
 #[derive(Default, Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[repr(u8)]
 pub enum Width {
     /// 1 byte / 8 bit
@@ -112,10 +496,56 @@ impl Width {
     pub const fn from_reg<T>(_: &T) -> Self {
         Self::from_num_bytes(core::mem::size_of::<T>())
     }
+
+    /// Same as [`Width::from_reg`], but takes a raw pointer to the register, which does not need
+    /// to point to valid memory.
+    /// ```
+    /// # use imxrt_dcd::Width;
+    /// assert_eq!(Width::from_ptr(0x400F_C000 as *const u16), Width::B2);
+    /// ```
+    pub const fn from_ptr<T>(_: *const T) -> Self {
+        Self::from_num_bytes(core::mem::size_of::<T>())
+    }
+
+    /// Returns the mask of the bits a register of this width holds.
+    /// ```
+    /// # use imxrt_dcd::Width;
+    /// assert_eq!(Width::B1.mask(), 0xFF);
+    /// assert_eq!(Width::B2.mask(), 0xFFFF);
+    /// assert_eq!(Width::B4.mask(), 0xFFFF_FFFF);
+    /// ```
+    pub const fn mask(self) -> u32 {
+        u32::MAX >> (32 - 8 * self as u32)
+    }
+
+    /// Returns the canonical name of the width, as used by the `serde` representation: `b1`,
+    /// `b2` or `b4`. [`Display`](fmt::Display) and [`FromStr`](std::str::FromStr) use it, too.
+    ///
+    /// ```
+    /// # use imxrt_dcd::Width;
+    /// assert_eq!(Width::B2.to_string(), "b2");
+    /// assert_eq!("b4".parse(), Ok(Width::B4));
+    /// assert_eq!(
+    ///     "b3".parse::<Width>().unwrap_err().to_string(),
+    ///     "invalid width `b3`, expected one of `b1`, `b2`, `b4`",
+    /// );
+    /// ```
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::B1 => "b1",
+            Self::B2 => "b2",
+            Self::B4 => "b4",
+        }
+    }
+
+    const ALL: &'static [Self] = &[Self::B1, Self::B2, Self::B4];
 }
 
 /// [`Write`] operation variants.
 #[derive(Default, Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[repr(u8)]
 pub enum WriteOp {
     /// `*address = value` --- direct write
@@ -127,8 +557,25 @@ pub enum WriteOp {
     Set = 0b11_000u8,
 }
 
+impl WriteOp {
+    /// Returns the canonical name of the operation: `write`, `clear` or `set`; see
+    /// [`Width::name`].
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Write => "write",
+            Self::Clear => "clear",
+            Self::Set => "set",
+        }
+    }
+
+    const ALL: &'static [Self] = &[Self::Write, Self::Clear, Self::Set];
+}
+
 /// [`Check`] condition variants.
 #[derive(Default, Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[repr(u8)]
 pub enum CheckCond {
     #[default]
@@ -142,8 +589,326 @@ pub enum CheckCond {
     AnySet = 0b11_000u8,
 }
 
+impl CheckCond {
+    /// Returns the canonical name of the condition: `all_clear`, `any_clear`, `all_set` or
+    /// `any_set`; see [`Width::name`].
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::AllClear => "all_clear",
+            Self::AnyClear => "any_clear",
+            Self::AllSet => "all_set",
+            Self::AnySet => "any_set",
+        }
+    }
+
+    const ALL: &'static [Self] = &[Self::AllClear, Self::AnyClear, Self::AllSet, Self::AnySet];
+}
+
+/// Implements `Display` and `FromStr` with the `name` of a field-less enum.
+macro_rules! impl_name_conversions {
+    ($($ty:ident: $what:literal),*) => {$(
+        impl fmt::Display for $ty {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(self.name())
+            }
+        }
+
+        impl std::str::FromStr for $ty {
+            type Err = ParseNameError;
+
+            fn from_str(name: &str) -> Result<Self, Self::Err> {
+                Self::ALL
+                    .iter()
+                    .copied()
+                    .find(|value| value.name() == name)
+                    .ok_or_else(|| ParseNameError {
+                        what: $what,
+                        name: name.to_owned(),
+                        expected: Self::ALL.iter().map(|value| value.name()).collect(),
+                    })
+            }
+        }
+    )*};
+}
+
+impl_name_conversions!(Width: "width", WriteOp: "write operation", CheckCond: "check condition");
+
+/// Error returned when parsing a [`Width`], [`WriteOp`] or [`CheckCond`] from a string that is
+/// not one of their [names](Width::name).
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ParseNameError {
+    what: &'static str,
+    name: String,
+    expected: Vec<&'static str>,
+}
+
+impl fmt::Display for ParseNameError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid {} `{}`, expected one of ", self.what, self.name)?;
+        for (i, name) in self.expected.iter().enumerate() {
+            let separator = if i == 0 { "" } else { ", " };
+            write!(f, "{}`{}`", separator, name)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ParseNameError {}
+
+/// i.MX RT chip family targeted by a DCD.
+///
+/// The DCD format is shared by all families listed here, but the boot ROM of each family differs
+/// in details such as register layouts and size limits.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum Target {
+    /// i.MX RT1010
+    Imxrt1010,
+    /// i.MX RT1015
+    Imxrt1015,
+    /// i.MX RT1020 / RT1024
+    Imxrt1020,
+    /// i.MX RT1050
+    Imxrt1050,
+    /// i.MX RT1060
+    Imxrt1060,
+    /// i.MX RT1064
+    Imxrt1064,
+    /// i.MX RT1180, which boots with AHAB and does not support DCDs; see
+    /// [`Target::ensure_dcd_supported`].
+    Imxrt1180,
+}
+
+impl Target {
+    /// Returns the address ranges `(begin, end inclusive, description)` that the boot ROM accepts
+    /// for Write commands, as listed in the reference manual of the target.
+    ///
+    /// Upon a Write command outside these ranges, the boot ROM abandons the rest of the DCD. See
+    /// also [`lint::Lint::InvalidWriteAddress`].
+    ///
+    /// ```
+    /// # use imxrt_dcd::Target;
+    /// let semc = 0x402F_0000;
+    /// let allowed = |target: Target| {
+    ///     target.write_ranges().iter().any(|&(begin, end, _)| (begin..=end).contains(&semc))
+    /// };
+    /// assert!(allowed(Target::Imxrt1060));
+    /// assert!(!allowed(Target::Imxrt1010));
+    /// ```
+    pub const fn write_ranges(self) -> &'static [(u32, u32, &'static str)] {
+        allowed_ranges::write_ranges(self)
+    }
+
+    /// Returns the address ranges that the boot ROM accepts for Write commands; the same as
+    /// [`Target::write_ranges`], as [`AllowedRange`]s.
+    ///
+    /// The tables are generated from `src/allowed_ranges.toml` (see
+    /// `scripts/gen-allowed-ranges.py`), which records the ranges of all targets in one place for
+    /// review. Targets without DCD support have no ranges, and neither do targets whose chip
+    /// feature (e.g. `imxrt1062` for [`Target::Imxrt1060`]) is disabled. To accept more ranges
+    /// during validation, see [`lint::Validator::allow_range`].
+    ///
+    /// ```
+    /// # use imxrt_dcd::Target;
+    /// let ranges = Target::Imxrt1060.allowed_ranges();
+    /// let semc = ranges.iter().find(|range| range.contains(0x402F_0000)).unwrap();
+    /// assert_eq!(semc.name, "SEMC");
+    /// assert_eq!(semc.size(), 0x4000);
+    /// assert!(Target::Imxrt1180.allowed_ranges().is_empty());
+    /// ```
+    pub const fn allowed_ranges(self) -> &'static [AllowedRange] {
+        allowed_ranges::allowed_ranges(self)
+    }
+
+    /// Returns an error explaining the alternatives if the boot ROM of the target does not
+    /// interpret DCDs, e.g. before generating a DCD for a target chosen by the user.
+    ///
+    /// The i.MX RT1180 uses the AHAB (Advanced High Assurance Boot) container format instead of the
+    /// IVT / DCD boot image of the other families.
+    ///
+    /// ```
+    /// # use imxrt_dcd::Target;
+    /// assert!(Target::Imxrt1060.ensure_dcd_supported().is_ok());
+    /// let err = Target::Imxrt1180.ensure_dcd_supported().unwrap_err();
+    /// assert_eq!(err.kind(), std::io::ErrorKind::Unsupported);
+    /// ```
+    pub fn ensure_dcd_supported(self) -> std::io::Result<()> {
+        match self {
+            Target::Imxrt1180 => Err(std::io::Error::new(
+                std::io::ErrorKind::Unsupported,
+                format!(
+                    "the boot ROM of {:?} does not support DCDs: its AHAB boot container has no \
+                     DCD. Configure external memory with XMCD (eXternal Memory Configuration \
+                     Data) in the boot container instead, or apply the commands at runtime \
+                     (see `decompile::to_apply_fn`)",
+                    self
+                ),
+            )),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Register address that the boot ROM of a [`Target`] accepts for Write commands, aligned to the
+/// access width.
+///
+/// Building commands from an `MmioAddr` (see [`MmioAddr::write`] and [`MmioAddr::check`]) moves
+/// the address checks of [`lint::Lint::InvalidWriteAddress`] to the point where the address is
+/// written down, e.g. for addresses computed at runtime from user input.
+///
+/// ```
+/// # use imxrt_dcd::{Command, MmioAddr, Target, Width, WriteOp};
+/// let ccgr0 = MmioAddr::new(Target::Imxrt1060, 0x400F_C068, Width::B4).unwrap();
+/// assert_eq!(ccgr0.write(WriteOp::Set, 0b11), Command::set32(0x400F_C068, 0b11));
+///
+/// // misaligned
+/// assert!(MmioAddr::new(Target::Imxrt1060, 0x400F_C06A, Width::B4).is_err());
+/// // SEMC, which the RT1010 does not have
+/// assert!(MmioAddr::new(Target::Imxrt1060, 0x402F_0000, Width::B4).is_ok());
+/// assert!(MmioAddr::new(Target::Imxrt1010, 0x402F_0000, Width::B4).is_err());
+/// ```
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct MmioAddr {
+    target: Target,
+    address: u32,
+    width: Width,
+}
+
+impl MmioAddr {
+    /// Validates `address` for accesses of `width` on `target`.
+    ///
+    /// Returns [`std::io::ErrorKind::InvalidInput`] if the address is not aligned to `width`, or is
+    /// outside of [`Target::allowed_ranges`].
+    // `u32::is_multiple_of` requires Rust 1.87
+    #[allow(clippy::manual_is_multiple_of)]
+    pub fn new(target: Target, address: u32, width: Width) -> std::io::Result<Self> {
+        let invalid = |msg| Err(std::io::Error::new(std::io::ErrorKind::InvalidInput, msg));
+        if address % width as u32 != 0 {
+            return invalid(format!(
+                "address {:#010X} is not aligned to {} bytes",
+                address, width as u8
+            ));
+        }
+        if !target
+            .allowed_ranges()
+            .iter()
+            .any(|range| range.contains(address))
+        {
+            return invalid(format!(
+                "address {:#010X} is outside of the ranges writable by the DCD on {:?}",
+                address, target
+            ));
+        }
+        Ok(Self {
+            target,
+            address,
+            width,
+        })
+    }
+
+    /// Target the address was validated for.
+    pub const fn target(self) -> Target {
+        self.target
+    }
+
+    /// The address.
+    pub const fn address(self) -> u32 {
+        self.address
+    }
+
+    /// Access width the address was validated for.
+    pub const fn width(self) -> Width {
+        self.width
+    }
+
+    /// Returns a [`Command::Write`] to this address.
+    pub const fn write(self, op: WriteOp, value: u32) -> Command {
+        Command::Write(Write::new(self.width, op, self.address, value))
+    }
+
+    /// Returns a [`Command::Check`] of this address that polls indefinitely; see
+    /// [`Command::with_count`].
+    pub const fn check(self, cond: CheckCond, mask: u32) -> Command {
+        Command::Check(Check::new(self.width, cond, self.address, mask))
+    }
+}
+
+impl From<MmioAddr> for u32 {
+    fn from(address: MmioAddr) -> Self {
+        address.address
+    }
+}
+
+/// Address range that the boot ROM of a [`Target`] accepts for Write commands; see
+/// [`Target::allowed_ranges`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct AllowedRange {
+    /// First address of the range.
+    pub begin: u32,
+    /// Last address of the range (inclusive).
+    pub end: u32,
+    /// Name of the range in the reference manual.
+    pub name: &'static str,
+}
+
+impl AllowedRange {
+    /// Returns whether `address` is within the range.
+    pub const fn contains(&self, address: u32) -> bool {
+        self.begin <= address && address <= self.end
+    }
+
+    /// Returns the number of bytes in the range.
+    pub const fn size(&self) -> u64 {
+        self.end as u64 - self.begin as u64 + 1
+    }
+}
+
 ///////////////////////////////////////////////////////////////////////////
 
+/// Maximum byte length of a DCD, and of a single command, as given by their 16-bit headers.
+const MAX_BYTE_LEN: usize = u16::MAX as usize;
+
+/// Error for a DCD of `byte_len` bytes, more than [`MAX_BYTE_LEN`].
+fn too_large(byte_len: usize) -> std::io::Error {
+    std::io::Error::new(
+        std::io::ErrorKind::InvalidInput,
+        format!(
+            "DCD byte length {} exceeds the maximum of {} bytes; see `split` for spreading the \
+             commands over several DCDs",
+            byte_len, MAX_BYTE_LEN
+        ),
+    )
+}
+
+/// Checks that no [`WriteGroup`] is empty; an empty Write command is not a valid DCD command.
+fn ensure_groups_not_empty(commands: &[Command]) -> std::io::Result<()> {
+    let empty = commands.iter().position(
+        |command| matches!(command, Command::WriteGroup(group) if group.pairs.is_empty()),
+    );
+    match empty {
+        Some(index) => Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidInput,
+            format!(
+                "command #{}: write group without address / value pairs",
+                index
+            ),
+        )),
+        None => Ok(()),
+    }
+}
+
+/// Returns the commands with their indices, with the writes of each [`WriteGroup`] as separate
+/// [`Command::Write`]s (of the index of the group), for consumers that apply writes one by one.
+pub(crate) fn expand_groups(commands: &[Command]) -> impl Iterator<Item = (usize, Command)> + '_ {
+    commands.iter().enumerate().flat_map(|(index, command)| {
+        let expanded = match command {
+            Command::WriteGroup(group) => group.writes().map(Command::Write).collect(),
+            command => vec![command.clone()],
+        };
+        expanded.into_iter().map(move |command| (index, command))
+    })
+}
+
 fn dcd_header(byte_len: u16) -> [u8; 4] {
     let mut header = [0xD2, 0x00, 0x00, 0x41];
     header[1..=2].copy_from_slice(&byte_len.to_be_bytes()[0..=1]);
@@ -153,15 +918,28 @@ fn dcd_header(byte_len: u16) -> [u8; 4] {
 const NOP_HEADER: [u8; 4] = [0xC0, 0x00, 0x04, 0x00];
 
 impl Write {
-    fn byte_len(group_size: usize) -> u16 {
-        let n = 4 + group_size * 8;
-        assert!(n <= u16::MAX as usize);
-        n as u16
+    /// Returns the byte length of a write command with `group_size` address / value pairs, or an
+    /// error if it exceeds the 16-bit length of the command header.
+    fn byte_len(group_size: usize) -> std::io::Result<u16> {
+        group_size
+            .checked_mul(8)
+            .and_then(|n| n.checked_add(4))
+            .and_then(|n| u16::try_from(n).ok())
+            .ok_or_else(|| {
+                std::io::Error::new(
+                    std::io::ErrorKind::InvalidInput,
+                    format!(
+                        "write command with {} address / value pairs exceeds the maximum command \
+                         length of {} bytes",
+                        group_size, MAX_BYTE_LEN
+                    ),
+                )
+            })
     }
-    fn header(&self, group_size: usize) -> [u8; 4] {
+    fn header(&self, group_size: usize) -> std::io::Result<[u8; 4]> {
         let mut header = [0xCC, 0x00, 0x00, self.width as u8 | self.op as u8];
-        header[1..=2].copy_from_slice(&Self::byte_len(group_size).to_be_bytes()[0..=1]);
-        header
+        header[1..=2].copy_from_slice(&Self::byte_len(group_size)?.to_be_bytes()[0..=1]);
+        Ok(header)
     }
     fn payload(&self) -> [u8; 8] {
         let mut payload = [0u8; 8];
@@ -171,8 +949,28 @@ impl Write {
     }
 }
 
+impl Unlock {
+    const fn byte_len(&self) -> u16 {
+        if self.uid.is_some() {
+            16
+        } else {
+            8
+        }
+    }
+    /// Returns the whole command, header included.
+    fn bytes(&self) -> Vec<u8> {
+        let mut bytes = vec![0xB2, 0x00, 0x00, self.engine];
+        bytes[1..=2].copy_from_slice(&self.byte_len().to_be_bytes()[0..=1]);
+        bytes.extend_from_slice(&self.features.to_be_bytes());
+        if let Some(uid) = self.uid {
+            bytes.extend_from_slice(&uid.to_be_bytes());
+        }
+        bytes
+    }
+}
+
 impl Check {
-    fn byte_len(&self) -> u16 {
+    const fn byte_len(&self) -> u16 {
         if self.count.is_some() {
             16
         } else {
@@ -190,11 +988,11 @@ impl Check {
         payload[4..8].copy_from_slice(&self.mask.to_be_bytes()[0..4]);
         payload
     }
-    fn payload_with_count(&self) -> [u8; 12] {
+    fn payload_with_count(&self, count: u32) -> [u8; 12] {
         let mut payload = [0u8; 12];
         payload[0..4].copy_from_slice(&self.address.to_be_bytes()[0..4]);
         payload[4..8].copy_from_slice(&self.mask.to_be_bytes()[0..4]);
-        payload[8..12].copy_from_slice(&self.count.unwrap().to_be_bytes()[0..4]);
+        payload[8..12].copy_from_slice(&count.to_be_bytes()[0..4]);
         payload
     }
 }
@@ -208,83 +1006,1087 @@ fn group_key(index: usize, command: &Command) -> (usize, Width, WriteOp) {
     }
 }
 
-///////////////////////////////////////////////////////////////////////////
-
-/// Serializes given commands as a complete DCD block into a byte stream.
-/// Consecutive write commands with the same width and op are automatically combined.
-///
-/// While the ROM may enforce tighter byte size limits, this
-///
-/// Returns the number of bytes written or error.
-///
-/// # Examples
-///
-/// See [crate-level doc](crate).
-///
-pub fn serialize(mut w: impl std::io::Write, commands: &[Command]) -> std::io::Result<usize> {
-    if commands.is_empty() {
-        return Ok(0);
-    }
-    // count num of bytes first
-    let mut byte_len: usize = 4; // DCD header
+/// Returns the number of bytes each command contributes to the serialized DCD (excluding the DCD
+/// header). The command header of merged writes is attributed to the first write of the group.
+fn command_byte_lens(commands: &[Command]) -> Vec<usize> {
+    let mut lens = Vec::with_capacity(commands.len());
     for (_, mut group) in &commands
-        .into_iter()
+        .iter()
         .enumerate()
         .group_by(|&(index, command)| group_key(index, command))
     {
         let Some((_, head)) = group.next() else { continue; };
         match head {
-            Command::Nop => {
-                byte_len += 4;
-            }
-            Command::Check(check) => {
-                byte_len += check.byte_len() as usize;
-            }
+            Command::Nop => lens.push(Command::NOP_BYTES),
+            Command::Check(check) => lens.push(check.byte_len() as usize),
             Command::Write(_) => {
-                byte_len += Write::byte_len(group.count() + 1) as usize;
+                lens.push(4 + 8);
+                lens.extend(group.map(|_| 8));
             }
+            Command::WriteGroup(group) => lens.push(4 + 8 * group.pairs.len()),
+            Command::Unlock(unlock) => lens.push(unlock.byte_len() as usize),
         }
     }
-    if byte_len > u16::MAX as usize {
-        return Err(std::io::Error::new(
-            std::io::ErrorKind::InvalidInput,
-            "DCD byte length too large",
-        ));
+    lens
+}
+
+///////////////////////////////////////////////////////////////////////////
+
+/// The commands of a whole DCD, for methods describing the DCD as a whole.
+///
+/// The free functions of this crate take the commands as a slice; `Dcd` only bundles them.
+///
+/// ```
+/// # use imxrt_dcd::*;
+/// let dcd = Dcd::new(vec![Command::write32(0x400F_C068, 0xFFFF_FFFF), Command::Nop]);
+/// let mut buf = vec![];
+/// assert_eq!(dcd.serialize(&mut buf).unwrap(), 20);
+/// assert_eq!(deserialize(&buf).unwrap(), dcd.commands);
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Dcd {
+    /// Commands in order of execution.
+    pub commands: Vec<Command>,
+}
+
+impl Dcd {
+    /// Creates a DCD from its commands.
+    pub fn new(commands: Vec<Command>) -> Self {
+        Self { commands }
     }
-    w.write_all(&dcd_header(byte_len as u16))?;
-    for (_, mut group) in &commands
-        .into_iter()
-        .enumerate()
-        .group_by(|&(index, command)| group_key(index, command))
-    {
-        let Some((_, head)) = group.next() else { continue; };
-        match head {
-            Command::Nop => {
-                w.write_all(&NOP_HEADER)?;
-            }
-            Command::Check(check) => {
-                w.write_all(&check.header())?;
-                if check.count.is_some() {
-                    w.write_all(&check.payload_with_count())?;
-                } else {
-                    w.write_all(&check.payload())?;
-                }
+
+    /// Same as [`serialize`].
+    pub fn serialize(&self, w: impl std::io::Write) -> std::io::Result<usize> {
+        serialize(w, &self.commands)
+    }
+
+    /// Counts commands, bytes and polls, e.g. for release notes; see [`stats::Statistics`].
+    pub fn statistics(&self) -> stats::Statistics {
+        stats::Statistics::of(&self.commands)
+    }
+
+    /// Returns the commands accessing any byte in `addresses`, with their indices.
+    ///
+    /// A command accesses the bytes from its address up to its width, so e.g. a byte write to
+    /// `0x400F_C069` is found by `0x400F_C068..0x400F_C06C`. NOPs and Unlock commands are never
+    /// found.
+    ///
+    /// ```
+    /// # use imxrt_dcd::*;
+    /// let dcd = Dcd::new(vec![
+    ///     Command::write32(0x400F_C068, 0xFFFF_FFFF),
+    ///     Command::write32(0x402F_0000, 0x1000_0004),
+    ///     Command::check32(CheckCond::AllClear, 0x402F_0000, 1),
+    /// ]);
+    /// let semc: Vec<_> = dcd.find(0x402F_0000..=0x402F_3FFF).map(|(index, _)| index).collect();
+    /// assert_eq!(semc, [1, 2]);
+    /// assert_eq!(dcd.find(0x400F_C06A..=0x400F_C06A).count(), 1);
+    /// ```
+    pub fn find(
+        &self,
+        addresses: impl std::ops::RangeBounds<u32>,
+    ) -> impl Iterator<Item = (usize, &Command)> {
+        let (begin, end) = address_bounds(&addresses);
+        self.commands
+            .iter()
+            .enumerate()
+            .filter(move |(_, command)| command.overlaps(begin, end))
+    }
+
+    /// Sets the value of all Write commands to `address` (of any op and width) to `value`, e.g.
+    /// to patch a timing parameter in a vendor DCD. Returns the number of commands changed; a
+    /// [`WriteGroup`] counts once per pair changed.
+    ///
+    /// ```
+    /// # use imxrt_dcd::*;
+    /// let mut dcd = Dcd::new(vec![Command::write32(0x402F_0008, 0x0000_0000)]);
+    /// assert_eq!(dcd.replace_value(0x402F_0008, 0x0000_0081), 1);
+    /// assert_eq!(dcd[0], Command::write32(0x402F_0008, 0x0000_0081));
+    /// ```
+    pub fn replace_value(&mut self, address: u32, value: u32) -> usize {
+        let mut replaced = 0;
+        for command in &mut self.commands {
+            match command {
+                Command::Write(write) if write.address == address => {
+                    write.value = value;
+                    replaced += 1;
+                }
+                Command::WriteGroup(group) => {
+                    for pair in group.pairs.iter_mut().filter(|pair| pair.0 == address) {
+                        pair.1 = value;
+                        replaced += 1;
+                    }
+                }
+                _ => {}
+            }
+        }
+        replaced
+    }
+
+    /// Removes the commands for which `predicate` returns `true`, keeping the order of the others.
+    /// Returns the number of commands removed.
+    ///
+    /// ```
+    /// # use imxrt_dcd::*;
+    /// let mut dcd = Dcd::new(vec![Command::Nop, Command::write32(0x400F_C068, 0), Command::Nop]);
+    /// assert_eq!(dcd.remove_matching(|command| *command == Command::Nop), 2);
+    /// assert_eq!(dcd.len(), 1);
+    /// ```
+    pub fn remove_matching(&mut self, mut predicate: impl FnMut(&Command) -> bool) -> usize {
+        let len = self.commands.len();
+        self.commands.retain(|command| !predicate(command));
+        len - self.commands.len()
+    }
+
+    /// Keeps only the commands for which `predicate` returns `true`, in order, e.g. to strip the
+    /// external RAM setup from a variant without SDRAM. Returns the number of commands removed.
+    ///
+    /// [`Command::accesses`], [`Command::accesses_peripheral`] and matching on the command type
+    /// cover the usual predicates:
+    ///
+    /// ```
+    /// # use imxrt_dcd::*;
+    /// let mut dcd = Dcd::new(vec![
+    ///     Command::write32(0x400F_C068, 0xFFFF_FFFF),
+    ///     Command::write32(0x402F_0000, 0x1000_0004),
+    ///     Command::check32(CheckCond::AllClear, 0x402F_0000, 1),
+    ///     Command::Nop,
+    /// ]);
+    /// assert_eq!(dcd.retain(|command| !command.accesses_peripheral("SEMC")), 2);
+    /// assert_eq!(dcd.retain(|command| !matches!(command, Command::Nop)), 1);
+    /// assert_eq!(dcd.commands, [Command::write32(0x400F_C068, 0xFFFF_FFFF)]);
+    /// ```
+    pub fn retain(&mut self, mut predicate: impl FnMut(&Command) -> bool) -> usize {
+        self.remove_matching(|command| !predicate(command))
+    }
+
+    /// Returns a DCD of the commands for which `predicate` returns `true`, in order; see
+    /// [`Dcd::retain`].
+    ///
+    /// ```
+    /// # use imxrt_dcd::*;
+    /// let dcd = Dcd::new(vec![
+    ///     Command::write32(0x400F_C068, 0xFFFF_FFFF),
+    ///     Command::check32(CheckCond::AllSet, 0x400D_8000, 1 << 31),
+    /// ]);
+    /// let ccm = dcd.filter(|command| command.accesses(0x400F_C000..0x4010_0000));
+    /// assert_eq!(ccm.len(), 1);
+    /// ```
+    pub fn filter(&self, mut predicate: impl FnMut(&Command) -> bool) -> Dcd {
+        Dcd::new(
+            self.commands
+                .iter()
+                .filter(|command| predicate(command))
+                .cloned()
+                .collect(),
+        )
+    }
+}
+
+/// Returns `addresses` as `[begin, end)`, in u64 to avoid overflow at the end of the address space.
+fn address_bounds(addresses: &impl std::ops::RangeBounds<u32>) -> (u64, u64) {
+    use std::ops::Bound;
+    let begin = match addresses.start_bound() {
+        Bound::Included(&begin) => begin as u64,
+        Bound::Excluded(&begin) => begin as u64 + 1,
+        Bound::Unbounded => 0,
+    };
+    let end = match addresses.end_bound() {
+        Bound::Included(&end) => end as u64 + 1,
+        Bound::Excluded(&end) => end as u64,
+        Bound::Unbounded => 1 << 32,
+    };
+    (begin, end)
+}
+
+impl From<Vec<Command>> for Dcd {
+    fn from(commands: Vec<Command>) -> Self {
+        Self::new(commands)
+    }
+}
+
+/// Collects generated commands, e.g. from an iterator pipeline, in order.
+impl FromIterator<Command> for Dcd {
+    fn from_iter<I: IntoIterator<Item = Command>>(iter: I) -> Self {
+        Self::new(iter.into_iter().collect())
+    }
+}
+
+impl Extend<Command> for Dcd {
+    fn extend<I: IntoIterator<Item = Command>>(&mut self, iter: I) {
+        self.commands.extend(iter)
+    }
+}
+
+impl<'a> Extend<&'a Command> for Dcd {
+    fn extend<I: IntoIterator<Item = &'a Command>>(&mut self, iter: I) {
+        self.commands.extend(iter.into_iter().cloned())
+    }
+}
+
+/// Gives slice methods (e.g. `len`, `iter`, indexing) and lets a `&Dcd` be passed where the
+/// functions of this crate take `&[Command]`.
+impl std::ops::Deref for Dcd {
+    type Target = [Command];
+
+    fn deref(&self) -> &[Command] {
+        &self.commands
+    }
+}
+
+impl std::ops::DerefMut for Dcd {
+    fn deref_mut(&mut self) -> &mut [Command] {
+        &mut self.commands
+    }
+}
+
+impl AsRef<[Command]> for Dcd {
+    fn as_ref(&self) -> &[Command] {
+        &self.commands
+    }
+}
+
+impl IntoIterator for Dcd {
+    type Item = Command;
+    type IntoIter = std::vec::IntoIter<Command>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.commands.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a Dcd {
+    type Item = &'a Command;
+    type IntoIter = std::slice::Iter<'a, Command>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.commands.iter()
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////
+
+/// Serializes given commands as a complete DCD block into a byte stream.
+/// Consecutive write commands with the same width and op are automatically combined.
+///
+/// While the ROM may enforce tighter byte size limits (see [`ensure_fits`]), this only rejects DCDs
+/// beyond the 65535 bytes that the DCD header can express, and empty [`WriteGroup`]s, with
+/// [`std::io::ErrorKind::InvalidInput`] before writing anything.
+///
+/// Returns the number of bytes written or error.
+///
+/// # Examples
+///
+/// See [crate-level doc](crate).
+///
+pub fn serialize(mut w: impl std::io::Write, commands: &[Command]) -> std::io::Result<usize> {
+    if commands.is_empty() {
+        return Ok(0);
+    }
+    ensure_groups_not_empty(commands)?;
+    // count num of bytes first
+    let byte_len: usize = 4 + command_byte_lens(commands).iter().sum::<usize>(); // + DCD header
+    let Ok(header_len) = u16::try_from(byte_len) else {
+        return Err(too_large(byte_len));
+    };
+    debug!(
+        "serializing {} commands into {} bytes",
+        commands.len(),
+        byte_len
+    );
+    w.write_all(&dcd_header(header_len))?;
+    let mut offset = 4;
+    let mut prev_write: Option<(usize, &Write)> = None;
+    for (_, mut group) in &commands
+        .iter()
+        .enumerate()
+        .group_by(|&(index, command)| group_key(index, command))
+    {
+        let Some((index, head)) = group.next() else { continue; };
+        match head {
+            Command::Nop => {
+                debug!("offset {}: command #{}: nop", offset, index);
+                w.write_all(&NOP_HEADER)?;
+                offset += NOP_HEADER.len();
+            }
+            Command::Unlock(unlock) => {
+                debug!(
+                    "offset {}: command #{}: {}",
+                    offset,
+                    index,
+                    text::render_command(head)
+                );
+                w.write_all(&unlock.bytes())?;
+                offset += unlock.byte_len() as usize;
+            }
+            Command::Check(check) => {
+                debug!(
+                    "offset {}: command #{}: {}",
+                    offset,
+                    index,
+                    text::render_command(head)
+                );
+                w.write_all(&check.header())?;
+                match check.count {
+                    Some(count) => w.write_all(&check.payload_with_count(count))?,
+                    None => w.write_all(&check.payload())?,
+                }
+                offset += check.byte_len() as usize;
             }
             Command::Write(write) => {
                 let (counter, rest) = group.tee();
-                w.write_all(&write.header(counter.count() + 1))?;
+                let group_size = counter.count() + 1;
+                if let Some((prev_index, prev)) = prev_write.filter(|&(i, _)| i + 1 == index) {
+                    debug!(
+                        "not merging command #{} into #{}: {:?} {:?} differs from {:?} {:?}",
+                        index, prev_index, write.width, write.op, prev.width, prev.op
+                    );
+                }
+                debug!(
+                    "offset {}: commands #{}..=#{}: {} write(s), {:?} {:?}",
+                    offset,
+                    index,
+                    index + group_size - 1,
+                    group_size,
+                    write.width,
+                    write.op
+                );
+                w.write_all(&write.header(group_size)?)?;
                 w.write_all(&write.payload())?;
-                for (_, command) in rest {
+                let mut last = (index, write);
+                for (index, command) in rest {
                     if let Command::Write(write) = command {
                         w.write_all(&write.payload())?;
+                        last = (index, write);
                     }
                 }
+                prev_write = Some(last);
+                offset += Write::byte_len(group_size)? as usize;
+            }
+            Command::WriteGroup(group) => {
+                let group_size = group.pairs.len();
+                debug!(
+                    "offset {}: command #{}: group of {} write(s), {:?} {:?}",
+                    offset, index, group_size, group.width, group.op
+                );
+                let mut writes = group.writes();
+                if let Some(first) = writes.next() {
+                    w.write_all(&first.header(group_size)?)?;
+                    w.write_all(&first.payload())?;
+                }
+                for write in writes {
+                    w.write_all(&write.payload())?;
+                }
+                offset += Write::byte_len(group_size)? as usize;
             }
         }
     }
     Ok(byte_len)
 }
 
+/// Location of a command in a serialized DCD; see [`command_offsets`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CommandOffset {
+    /// Byte offset of the command from the start of the DCD (including the DCD header): of its
+    /// address / value pair for a write, or of its command header otherwise.
+    pub offset: usize,
+    /// Number of bytes of the command at `offset`: 8 for a write, the whole command otherwise.
+    pub len: usize,
+    /// Byte offset of the command header, shared by all writes of a group.
+    pub header_offset: usize,
+    /// Indices of the commands sharing the command header, i.e. the group of merged writes the
+    /// command belongs to, or only the command itself.
+    pub group: std::ops::Range<usize>,
+}
+
+/// Returns where each command ends up in the DCD serialized by [`serialize`], e.g. to point at
+/// the bytes of a command in a binary patch tool or debugger.
+///
+/// ```
+/// # use imxrt_dcd::*;
+/// let commands = [
+///     Command::Nop,
+///     Command::write32(0x400F_C068, 0xFFFF_FFFF),
+///     Command::write32(0x400F_C06C, 0xFFFF_FFFF),
+/// ];
+/// let offsets = command_offsets(&commands);
+/// assert_eq!(
+///     offsets[2],
+///     CommandOffset { offset: 20, len: 8, header_offset: 8, group: 1..3 },
+/// );
+/// let mut bytes = vec![];
+/// serialize(&mut bytes, &commands).unwrap();
+/// assert_eq!(bytes[20..24], 0x400F_C06Cu32.to_be_bytes());
+/// ```
+pub fn command_offsets(commands: &[Command]) -> Vec<CommandOffset> {
+    let mut offsets = Vec::with_capacity(commands.len());
+    let mut header_offset = 4; // DCD header
+    for (_, group) in &commands
+        .iter()
+        .enumerate()
+        .group_by(|&(index, command)| group_key(index, command))
+    {
+        let group: Vec<_> = group.collect();
+        let indices = group[0].0..group[0].0 + group.len();
+        let mut offset = header_offset;
+        for (_, command) in group {
+            let len = match command {
+                Command::Nop => Command::NOP_BYTES,
+                Command::Check(check) => check.byte_len() as usize,
+                Command::Write(_) => {
+                    if offset == header_offset {
+                        offset += 4;
+                    }
+                    8
+                }
+                Command::WriteGroup(group) => 4 + 8 * group.pairs.len(),
+                Command::Unlock(unlock) => unlock.byte_len() as usize,
+            };
+            offsets.push(CommandOffset {
+                offset,
+                len,
+                header_offset,
+                group: indices.clone(),
+            });
+            offset += len;
+        }
+        header_offset = offset;
+    }
+    offsets
+}
+
+/// Serializes commands like [`serialize`], additionally returning where each command ends up;
+/// see [`command_offsets`].
+pub fn serialize_with_offsets(
+    w: impl std::io::Write,
+    commands: &[Command],
+) -> std::io::Result<(usize, Vec<CommandOffset>)> {
+    let byte_len = serialize(w, commands)?;
+    Ok((byte_len, command_offsets(commands)))
+}
+
+/// How [`SerializeOptions`] pads the output.
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq)]
+pub enum Padding {
+    /// Zero bytes after the DCD, outside of the byte length in its header.
+    #[default]
+    ZeroFill,
+    /// NOP commands at the end of the DCD, counted in the byte length of its header; the padding
+    /// must be a multiple of [`Command::NOP_BYTES`].
+    Nops,
+    /// The given byte after the DCD, outside of the byte length in its header, e.g. `0xFF` to
+    /// match erased flash.
+    Fill(u8),
+}
+
+/// Options for serializing commands, e.g. to fill a slot of fixed size in the flash layout.
+///
+/// Without options, [`SerializeOptions::serialize`] is the same as [`serialize`].
+///
+/// ```
+/// # use imxrt_dcd::*;
+/// let commands = [Command::write32(0x400F_C068, 0xFFFF_FFFF)];
+/// let mut slot = vec![];
+/// let options = SerializeOptions::new().size(2048);
+/// assert_eq!(options.serialize(&mut slot, &commands).unwrap(), 2048);
+/// assert_eq!(slot[1..3], [0, 16]); // header byte length
+/// assert!(slot[16..].iter().all(|&byte| byte == 0));
+///
+/// let mut aligned = vec![];
+/// let options = SerializeOptions::new().align(32).padding(Padding::Nops);
+/// assert_eq!(options.serialize(&mut aligned, &commands).unwrap(), 32);
+/// assert_eq!(deserialize(&aligned).unwrap().len(), 1 + 4);
+/// ```
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct SerializeOptions {
+    align: Option<usize>,
+    size: Option<usize>,
+    padding: Padding,
+}
+
+impl SerializeOptions {
+    /// Creates options without padding.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Pads the output to a multiple of `align` bytes; [`SerializeOptions::serialize`] rejects an
+    /// alignment of 0.
+    pub fn align(mut self, align: usize) -> Self {
+        self.align = Some(align);
+        self
+    }
+
+    /// Pads the output to exactly `bytes` bytes, after any alignment; larger DCDs are an error.
+    pub fn size(mut self, bytes: usize) -> Self {
+        self.size = Some(bytes);
+        self
+    }
+
+    /// Sets how to pad; [`Padding::ZeroFill`] by default.
+    pub fn padding(mut self, padding: Padding) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Serializes the commands like [`serialize`], followed by the padding. Returns the number of
+    /// bytes written, padding included.
+    ///
+    /// Returns [`std::io::ErrorKind::InvalidInput`] before writing anything if the
+    /// [alignment](SerializeOptions::align) is 0, the DCD is larger than the
+    /// [size](SerializeOptions::size), or NOPs cannot fill the padding exactly.
+    pub fn serialize(
+        &self,
+        mut w: impl std::io::Write,
+        commands: &[Command],
+    ) -> std::io::Result<usize> {
+        let invalid =
+            |message: String| std::io::Error::new(std::io::ErrorKind::InvalidInput, message);
+        let byte_len = if commands.is_empty() {
+            0
+        } else {
+            4 + command_byte_lens(commands).iter().sum::<usize>()
+        };
+        let mut padded = byte_len;
+        if let Some(align) = self.align {
+            if align == 0 {
+                return Err(invalid("alignment must not be 0".into()));
+            }
+            padded = padded.next_multiple_of(align);
+        }
+        if let Some(size) = self.size {
+            if padded > size {
+                return Err(invalid(format!(
+                    "DCD byte length {} exceeds the padded size of {} bytes",
+                    padded, size
+                )));
+            }
+            padded = size;
+        }
+        debug!("padding {} bytes to {} bytes", byte_len, padded);
+        match self.padding {
+            Padding::ZeroFill | Padding::Fill(_) => {
+                let fill = match self.padding {
+                    Padding::Fill(fill) => fill,
+                    _ => 0,
+                };
+                serialize(&mut w, commands)?;
+                w.write_all(&vec![fill; padded - byte_len])?;
+            }
+            Padding::Nops if padded == 0 => {}
+            Padding::Nops => {
+                // NOPs alone still need a DCD header
+                let padding = padded.saturating_sub(byte_len.max(4));
+                if byte_len.max(4) + padding != padded || padding % Command::NOP_BYTES != 0 {
+                    return Err(invalid(format!(
+                        "cannot pad a DCD of {} bytes to {} bytes with {}-byte NOPs",
+                        byte_len,
+                        padded,
+                        Command::NOP_BYTES
+                    )));
+                }
+                let mut commands = commands.to_vec();
+                commands.resize(commands.len() + padding / Command::NOP_BYTES, Command::Nop);
+                if commands.is_empty() {
+                    // a DCD of only the header, which `serialize` leaves out
+                    w.write_all(&dcd_header(4))?;
+                } else {
+                    serialize(&mut w, &commands)?;
+                }
+            }
+        }
+        Ok(padded)
+    }
+}
+
+/// Checks that the serialized DCD fits in `budget` bytes, e.g. the space reserved for it in the
+/// flash layout.
+///
+/// Returns the serialized byte length, or [`std::io::ErrorKind::InvalidInput`] with a breakdown of
+/// the byte length by peripheral if the budget is exceeded. In a build script, propagating this
+/// error (or unwrapping it) fails the build.
+///
+/// ```
+/// # use imxrt_dcd::*;
+/// let commands = [
+///     Command::write32(0x400F_C068, 0xFFFF_FFFF),
+///     Command::write32(0x400F_C06C, 0xFFFF_FFFF),
+///     Command::check32(CheckCond::AllSet, 0x400D_8000, 1 << 31),
+/// ];
+/// assert_eq!(ensure_fits(&commands, 64).unwrap(), 36);
+/// let err = ensure_fits(&commands, 32).unwrap_err();
+/// assert!(err.to_string().starts_with("DCD byte length 36 exceeds budget of 32 bytes by 4"));
+/// ```
+///
+/// The error message of the example above reads:
+///
+/// ```text
+/// DCD byte length 36 exceeds budget of 32 bytes by 4
+///   bytes  commands  peripheral
+///      20         2  CCM
+///      12         1  CCM_ANALOG
+///       4         -  (DCD header)
+/// ```
+pub fn ensure_fits(commands: &[Command], budget: usize) -> std::io::Result<usize> {
+    let lens = command_byte_lens(commands);
+    let byte_len = if commands.is_empty() {
+        0
+    } else {
+        4 + lens.iter().sum::<usize>()
+    };
+    if byte_len <= budget {
+        return Ok(byte_len);
+    }
+    // (peripheral, bytes, commands), in order of first appearance
+    let mut breakdown: Vec<(&str, usize, usize)> = vec![];
+    for (command, len) in commands.iter().zip(lens) {
+        let name = peripheral_name(command);
+        match breakdown.iter_mut().find(|(n, _, _)| *n == name) {
+            Some((_, bytes, count)) => {
+                *bytes += len;
+                *count += 1;
+            }
+            None => breakdown.push((name, len, 1)),
+        }
+    }
+    breakdown.sort_by_key(|&(_, bytes, _)| std::cmp::Reverse(bytes));
+    let mut message = format!(
+        "DCD byte length {} exceeds budget of {} bytes by {}\n",
+        byte_len,
+        budget,
+        byte_len - budget
+    );
+    message += "  bytes  commands  peripheral\n";
+    for (name, bytes, count) in breakdown {
+        message += &format!("{:>7}  {:>8}  {}\n", bytes, count, name);
+    }
+    message += &format!("{:>7}  {:>8}  (DCD header)\n", 4, "-");
+    Err(std::io::Error::new(
+        std::io::ErrorKind::InvalidInput,
+        message,
+    ))
+}
+
+/// Returns the byte length of the serialized DCD, the same as [`serialize`] returns, as a
+/// `const fn`; see [`assert_dcd_fits`].
+///
+/// ```
+/// # use imxrt_dcd::*;
+/// const DCD: &[Command] = &[
+///     Command::write32(0x400F_C068, 0xFFFF_FFFF),
+///     Command::write32(0x400F_C06C, 0xFFFF_FFFF),
+///     Command::Nop,
+/// ];
+/// const LEN: usize = serialized_len(DCD);
+/// assert_eq!(LEN, 4 + 4 + 2 * 8 + 4);
+/// ```
+pub const fn serialized_len(commands: &[Command]) -> usize {
+    if commands.is_empty() {
+        return 0;
+    }
+    // DCD header
+    let mut len = 4;
+    // width and op of the previous command if it was a write, to merge writes like `group_key`
+    let mut prev_write: Option<(u8, u8)> = None;
+    let mut index = 0;
+    while index < commands.len() {
+        let mut write = None;
+        len += match &commands[index] {
+            Command::Nop => Command::NOP_BYTES,
+            Command::Check(check) => check.byte_len() as usize,
+            Command::Write(Write { width, op, .. }) => {
+                write = Some((*width as u8, *op as u8));
+                match prev_write {
+                    Some((w, o)) if w == *width as u8 && o == *op as u8 => 8,
+                    _ => 4 + 8,
+                }
+            }
+            Command::WriteGroup(group) => 4 + 8 * group.pairs.len(),
+            Command::Unlock(unlock) => unlock.byte_len() as usize,
+        };
+        prev_write = write;
+        index += 1;
+    }
+    len
+}
+
+/// Asserts that the serialized DCD is at most `MAX` bytes, and returns its byte length.
+///
+/// Evaluated in a constant, this fails compilation if the DCD exceeds the limit, which enforces
+/// the limit even before a build script runs (see [`ensure_fits`] for a check at run time that
+/// reports which peripherals take up the most space):
+///
+/// ```
+/// # use imxrt_dcd::*;
+/// const DCD: &[Command] = &[
+///     Command::write32(0x400F_C068, 0xFFFF_FFFF),
+///     Command::check32(CheckCond::AllSet, 0x400D_8000, 1 << 31),
+/// ];
+/// const DCD_LEN: usize = assert_dcd_fits::<32>(DCD);
+/// assert_eq!(DCD_LEN, 28);
+/// ```
+///
+/// ```compile_fail
+/// # use imxrt_dcd::*;
+/// const DCD: &[Command] = &[
+///     Command::write32(0x400F_C068, 0xFFFF_FFFF),
+///     Command::check32(CheckCond::AllSet, 0x400D_8000, 1 << 31),
+/// ];
+/// const _: usize = assert_dcd_fits::<16>(DCD);
+/// ```
+///
+/// Outside of a constant, this panics instead.
+pub const fn assert_dcd_fits<const MAX: usize>(commands: &[Command]) -> usize {
+    let len = serialized_len(commands);
+    assert!(len <= MAX, "serialized DCD exceeds the size limit");
+    len
+}
+
+/// Converts a table of `(address, value)` pairs, the form in which vendor app notes and SDK
+/// sources publish register settings, into Write commands of the same width and op.
+///
+/// As a `const fn`, this makes a pasted table a constant command list:
+///
+/// ```
+/// # use imxrt_dcd::*;
+/// const SDRAM: &[Command] = &write_table(
+///     Width::B4,
+///     WriteOp::Write,
+///     &[
+///         (0x402F_0000, 0x1000_0004),
+///         (0x402F_0008, 0x0000_0081),
+///         (0x402F_0010, 0x8000_001B),
+///     ],
+/// );
+/// assert_eq!(SDRAM[1], Command::write32(0x402F_0008, 0x0000_0081));
+/// const _: usize = assert_dcd_fits::<1768>(SDRAM);
+/// ```
+pub const fn write_table<const N: usize>(
+    width: Width,
+    op: WriteOp,
+    table: &[(u32, u32); N],
+) -> [Command; N] {
+    const NOP: Command = Command::Nop;
+    let mut commands = [NOP; N];
+    let mut index = 0;
+    while index < N {
+        let (address, value) = table[index];
+        let write = Command::Write(Write::new(width, op, address, value));
+        // A `const fn` cannot drop the `Nop` being replaced, which owns nothing anyway.
+        std::mem::forget(std::mem::replace(&mut commands[index], write));
+        index += 1;
+    }
+    commands
+}
+
+/// Name of the peripheral a command accesses, for summaries by peripheral.
+fn peripheral_name(command: &Command) -> &'static str {
+    let address = match command {
+        Command::Nop => None,
+        Command::Unlock(_) => return "(unlock)",
+        Command::Write(write) => Some(write.address),
+        Command::Check(check) => Some(check.address),
+        Command::WriteGroup(group) => group.pairs.first().map(|&(address, _)| address),
+    };
+    match address.map(symbols::peripheral) {
+        None => "(nop)",
+        Some(None) => "(unknown)",
+        Some(Some((name, _))) => name,
+    }
+}
+
+/// Parses a complete DCD block (e.g. produced by [`serialize`]) back into commands.
+///
+/// Write commands with multiple address / value pairs are split into one [`Command::Write`] each;
+/// [`serialize`] merges them again. Trailing bytes after the DCD block are ignored, and an empty
+/// input gives an empty command list.
+///
+/// Returns [`std::io::ErrorKind::InvalidData`] if the bytes are not a well-formed DCD block.
+///
+/// ```
+/// # use imxrt_dcd::*;
+/// let commands = [Command::write32(0x400F_C068, 0xFFFF_FFFF), Command::Nop];
+/// let mut buf = vec![];
+/// serialize(&mut buf, &commands).unwrap();
+/// assert_eq!(deserialize(&buf).unwrap(), commands);
+/// ```
+pub fn deserialize(bytes: &[u8]) -> std::io::Result<Vec<Command>> {
+    let invalid = |message: String| std::io::Error::new(std::io::ErrorKind::InvalidData, message);
+    if bytes.is_empty() {
+        return Ok(vec![]);
+    }
+    if bytes.len() < 4 || bytes[0] != 0xD2 || bytes[3] != 0x41 {
+        return Err(invalid("invalid DCD header".into()));
+    }
+    let byte_len = u16::from_be_bytes([bytes[1], bytes[2]]) as usize;
+    if byte_len < 4 || byte_len > bytes.len() {
+        return Err(invalid(format!(
+            "DCD byte length {} out of bounds (have {} bytes)",
+            byte_len,
+            bytes.len()
+        )));
+    }
+    let mut commands = vec![];
+    let mut offset = 4;
+    while offset < byte_len {
+        offset += decode_command(&bytes[offset..byte_len], offset, &mut commands)?;
+    }
+    Ok(commands)
+}
+
+/// Decodes the command at the start of `rest`, found at `offset` of the DCD (for error messages),
+/// into `commands`. Returns the byte length of the command.
+fn decode_command(
+    rest: &[u8],
+    offset: usize,
+    commands: &mut Vec<Command>,
+) -> std::io::Result<usize> {
+    let invalid = |message: String| std::io::Error::new(std::io::ErrorKind::InvalidData, message);
+    let be_u32 = |bytes: &[u8]| u32::from_be_bytes(bytes[0..4].try_into().unwrap());
+    if rest.len() < 4 {
+        return Err(invalid(format!(
+            "truncated command header at offset {}",
+            offset
+        )));
+    }
+    let (tag, param) = (rest[0], rest[3]);
+    let len = u16::from_be_bytes([rest[1], rest[2]]) as usize;
+    if len < 4 || len > rest.len() {
+        return Err(invalid(format!(
+            "command length {} out of bounds at offset {}",
+            len, offset
+        )));
+    }
+    let body = &rest[4..len];
+    let width = match param & 0b111 {
+        1 => Width::B1,
+        2 => Width::B2,
+        4 => Width::B4,
+        // NOP has no width, Unlock holds the engine instead
+        _ if tag == 0xC0 || tag == 0xB2 => Width::default(),
+        _ => return Err(invalid(format!("invalid width at offset {}", offset))),
+    };
+    match (tag, param & !0b111, body.len()) {
+        (0xC0, _, 0) => commands.push(Command::Nop),
+        (0xCC, _, 0) => {
+            return Err(invalid(format!(
+                "write command without address / value pairs at offset {}",
+                offset
+            )))
+        }
+        (0xCC, op, n) if n % 8 == 0 => {
+            let op = match op {
+                0b00_000 => WriteOp::Write,
+                0b01_000 => WriteOp::Clear,
+                0b11_000 => WriteOp::Set,
+                _ => return Err(invalid(format!("invalid write op at offset {}", offset))),
+            };
+            for pair in body.chunks_exact(8) {
+                commands.push(Command::Write(Write::new(
+                    width,
+                    op,
+                    be_u32(&pair[0..4]),
+                    be_u32(&pair[4..8]),
+                )));
+            }
+        }
+        (0xCF, cond, 8 | 12) => {
+            let cond = match cond {
+                0b00_000 => CheckCond::AllClear,
+                0b01_000 => CheckCond::AnyClear,
+                0b10_000 => CheckCond::AllSet,
+                0b11_000 => CheckCond::AnySet,
+                _ => {
+                    return Err(invalid(format!(
+                        "invalid check condition at offset {}",
+                        offset
+                    )))
+                }
+            };
+            let check = Check::new(width, cond, be_u32(&body[0..4]), be_u32(&body[4..8]));
+            commands.push(Command::Check(if body.len() == 12 {
+                check.with_count(be_u32(&body[8..12]))
+            } else {
+                check
+            }));
+        }
+        (0xB2, _, 4 | 12) => {
+            let unlock = Unlock::new(param, be_u32(&body[0..4]));
+            commands.push(Command::Unlock(match body.get(4..12) {
+                Some(uid) => unlock.with_uid(u64::from_be_bytes(uid.try_into().unwrap())),
+                None => unlock,
+            }));
+        }
+        _ => {
+            return Err(invalid(format!(
+                "invalid command (tag {:#04X}, length {}) at offset {}",
+                tag, len, offset
+            )))
+        }
+    }
+    Ok(len)
+}
+
+/// Decodes a single serialized command, without DCD header, e.g. from a buffer of commands taken
+/// apart by other tooling.
+///
+/// The bytes must hold exactly one command, without trailing bytes; a Write command with several
+/// address / value pairs gives a [`Command::WriteGroup`]. Returns
+/// [`std::io::ErrorKind::InvalidData`] if the bytes are not a well-formed command, or a Write
+/// command without any pairs.
+///
+/// ```
+/// # use imxrt_dcd::*;
+/// let bytes: &[u8] = &[0xCC, 0x00, 0x0C, 0x04, 0x40, 0x0F, 0xC0, 0x68, 0xFF, 0xFF, 0xFF, 0xFF];
+/// assert_eq!(Command::try_from(bytes).unwrap(), Command::write32(0x400F_C068, 0xFFFF_FFFF));
+/// assert!(Command::try_from(&[0xC0, 0x00, 0x04][..]).is_err());
+/// ```
+impl TryFrom<&[u8]> for Command {
+    type Error = std::io::Error;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        let invalid =
+            |message: String| std::io::Error::new(std::io::ErrorKind::InvalidData, message);
+        let mut commands = vec![];
+        let len = decode_command(bytes, 0, &mut commands)?;
+        if len != bytes.len() {
+            return Err(invalid(format!(
+                "{} trailing bytes after the command",
+                bytes.len() - len
+            )));
+        }
+        match commands.as_slice() {
+            [command] => Ok(command.clone()),
+            [Command::Write(write), ..] => Ok(Command::WriteGroup(WriteGroup::new(
+                write.width,
+                write.op,
+                commands
+                    .iter()
+                    .flat_map(Command::writes)
+                    .map(|write| (write.address, write.value))
+                    .collect(),
+            ))),
+            _ => unreachable!("only Write commands decode to several (or no) commands"),
+        }
+    }
+}
+
+/// Joins command lists (e.g. clock, pin and external memory setup kept apart) into one.
+///
+/// [`serialize`] turns the result into a single DCD block with one header, merging writes at the
+/// end of a fragment with compatible writes at the start of the next one.
+///
+/// ```
+/// # use imxrt_dcd::*;
+/// let clocks = [Command::write32(0x400F_C068, 0xFFFF_FFFF)];
+/// let gates = [Command::write32(0x400F_C06C, 0xFFFF_FFFF), Command::Nop];
+/// let commands = concat(&[&clocks[..], &gates[..]]);
+/// assert_eq!(commands.len(), 3);
+/// let mut buf = vec![];
+/// // one DCD header, one write command with two address / value pairs, one nop
+/// assert_eq!(serialize(&mut buf, &commands).unwrap(), 4 + 4 + 2 * 8 + 4);
+/// ```
+pub fn concat<C: AsRef<[Command]>>(fragments: &[C]) -> Vec<Command> {
+    fragments
+        .iter()
+        .flat_map(|fragment| fragment.as_ref())
+        .cloned()
+        .collect()
+}
+
+/// Joins serialized DCD blocks into one, recomputing the DCD header and merging writes across the
+/// block boundaries, as [`concat()`] does for command lists.
+///
+/// Returns [`std::io::ErrorKind::InvalidData`] if a block is not a well-formed DCD block, or
+/// [`std::io::ErrorKind::InvalidInput`] if the joined DCD is too large.
+///
+/// ```
+/// # use imxrt_dcd::*;
+/// let mut a = vec![];
+/// serialize(&mut a, &[Command::write32(0x400F_C068, 0xFFFF_FFFF)]).unwrap();
+/// let mut b = vec![];
+/// serialize(&mut b, &[Command::write32(0x400F_C06C, 0xFFFF_FFFF)]).unwrap();
+/// let merged = merge_serialized(&[&a[..], &b[..]]).unwrap();
+/// assert_eq!(merged.len(), 4 + 4 + 2 * 8);
+/// assert_eq!(
+///     deserialize(&merged).unwrap(),
+///     [deserialize(&a).unwrap(), deserialize(&b).unwrap()].concat(),
+/// );
+/// ```
+pub fn merge_serialized(blocks: &[&[u8]]) -> std::io::Result<Vec<u8>> {
+    let mut commands = vec![];
+    for (index, block) in blocks.iter().enumerate() {
+        let block = deserialize(block).map_err(|err| {
+            std::io::Error::new(err.kind(), format!("DCD block #{}: {}", index, err))
+        })?;
+        commands.extend(block);
+    }
+    let mut bytes = vec![];
+    serialize(&mut bytes, &commands)?;
+    Ok(bytes)
+}
+
+/// Splits commands into consecutive chunks that each serialize to a standalone DCD block of at
+/// most `max_byte_len` bytes (DCD header included), e.g. to apply a configuration over the serial
+/// download protocol in pieces.
+///
+/// Chunks are filled greedily in order. A group of merged writes may be cut between two chunks;
+/// the rest of the group then gets its own write command header in the next chunk. A
+/// [`WriteGroup`] is never cut.
+///
+/// Returns [`std::io::ErrorKind::InvalidInput`] if a single command does not fit in a DCD block of
+/// `max_byte_len` bytes.
+///
+/// ```
+/// # use imxrt_dcd::*;
+/// let commands: Vec<_> = (0..4).map(|i| Command::write32(0x400F_C068 + 4 * i, !0)).collect();
+/// let chunks = split(&commands, 32).unwrap();
+/// assert_eq!(chunks, [&commands[..3], &commands[3..]]);
+/// let mut buf = vec![];
+/// assert_eq!(serialize(&mut buf, &chunks[0]).unwrap(), 4 + 4 + 3 * 8);
+/// ```
+pub fn split(commands: &[Command], max_byte_len: usize) -> std::io::Result<Vec<Vec<Command>>> {
+    let max_byte_len = max_byte_len.min(MAX_BYTE_LEN);
+    let mut chunks: Vec<Vec<Command>> = vec![];
+    // byte length of the last chunk, including the DCD header
+    let mut chunk_len = 0;
+    for (index, command) in commands.iter().enumerate() {
+        let byte_len = |prev: Option<&Command>| match (prev, command) {
+            (_, Command::Nop) => Command::NOP_BYTES,
+            (_, Command::Check(check)) => check.byte_len() as usize,
+            (Some(Command::Write(prev)), Command::Write(write))
+                if (prev.width, prev.op) == (write.width, write.op) =>
+            {
+                8
+            }
+            (_, Command::Write(_)) => 4 + 8,
+            (_, Command::WriteGroup(group)) => 4 + 8 * group.pairs.len(),
+            (_, Command::Unlock(unlock)) => unlock.byte_len() as usize,
+        };
+        match chunks.last_mut() {
+            Some(chunk) if chunk_len + byte_len(chunk.last()) <= max_byte_len => {
+                chunk_len += byte_len(chunk.last());
+                chunk.push(command.clone());
+            }
+            _ => {
+                chunk_len = 4 + byte_len(None);
+                if chunk_len > max_byte_len {
+                    return Err(std::io::Error::new(
+                        std::io::ErrorKind::InvalidInput,
+                        format!(
+                            "command #{} takes {} bytes as a DCD block, more than {}",
+                            index, chunk_len, max_byte_len
+                        ),
+                    ));
+                }
+                debug!("command #{} starts DCD chunk #{}", index, chunks.len());
+                chunks.push(vec![command.clone()]);
+            }
+        }
+    }
+    Ok(chunks)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -338,6 +2140,52 @@ mod tests {
         );
     }
 
+    #[test]
+    fn const_constructors() {
+        static COMMANDS: [Command; 4] = [
+            Command::write32(0x01234567, 0xdeadbeef),
+            Command::set32(0x01234567, 1 << 3),
+            Command::check32(CheckCond::AllSet, 0x89abcdef, 0x55aa55aa).with_count(16),
+            Command::Check(Check::new(
+                Width::B1,
+                CheckCond::AnyClear,
+                0x89abcdef,
+                0x55aa55aa,
+            )),
+        ];
+        assert_eq!(
+            COMMANDS,
+            [
+                Command::Write(Write {
+                    width: Width::B4,
+                    op: WriteOp::Write,
+                    address: 0x01234567,
+                    value: 0xdeadbeef,
+                }),
+                Command::Write(Write {
+                    width: Width::B4,
+                    op: WriteOp::Set,
+                    address: 0x01234567,
+                    value: 1 << 3,
+                }),
+                Command::Check(Check {
+                    width: Width::B4,
+                    cond: CheckCond::AllSet,
+                    address: 0x89abcdef,
+                    mask: 0x55aa55aa,
+                    count: Some(16),
+                }),
+                Command::Check(Check {
+                    width: Width::B1,
+                    cond: CheckCond::AnyClear,
+                    address: 0x89abcdef,
+                    mask: 0x55aa55aa,
+                    count: None,
+                }),
+            ]
+        );
+    }
+
     #[test]
     #[rustfmt::skip]
     fn serialize_merge() {
@@ -419,4 +2267,588 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn deserialize_roundtrip() {
+        let commands = [
+            Command::write32(0x01234567, 0xdeadbeef),
+            Command::write32(0x89abcdef, 0x13370000),
+            Command::Nop,
+            Command::Write(Write::new(Width::B1, WriteOp::Clear, 0x89abcdef, 0x80)),
+            Command::Write(Write::new(Width::B2, WriteOp::Set, 0x89abcdef, 0x8000)),
+            Command::check32(CheckCond::AnySet, 0x89abcdef, 0x55aa55aa).with_count(16),
+            Command::Check(Check::new(
+                Width::B1,
+                CheckCond::AnyClear,
+                0x89abcdef,
+                0x55aa55aa,
+            )),
+        ];
+        let mut buf = vec![];
+        serialize(&mut buf, &commands).expect("IO failure");
+        assert_eq!(deserialize(&buf).unwrap(), commands);
+        assert_eq!(deserialize(&[]).unwrap(), []);
+    }
+
+    #[test]
+    fn deserialize_invalid() {
+        let invalid = |bytes: &[u8]| {
+            deserialize(bytes).unwrap_err().kind() == std::io::ErrorKind::InvalidData
+        };
+        // bad header
+        assert!(invalid(&[0xD1, 0, 4, 0x41]));
+        // length beyond input
+        assert!(invalid(&[0xD2, 0, 8, 0x41]));
+        // truncated write
+        assert!(invalid(&[0xD2, 0, 12, 0x41, 0xCC, 0, 8, 0x04, 0, 0, 0, 0]));
+        // invalid width
+        assert!(invalid(&[
+            0xD2, 0, 16, 0x41, 0xCC, 0, 12, 0x03, 0, 0, 0, 0, 0, 0, 0, 0
+        ]));
+        // unknown tag
+        assert!(invalid(&[0xD2, 0, 8, 0x41, 0xCA, 0, 4, 0x00]));
+        // write without pairs, as for `Command::try_from`
+        let err = deserialize(&[0xD2, 0, 12, 0x41, 0xC0, 0, 4, 0, 0xCC, 0, 4, 0x04]).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+        assert_eq!(
+            err.to_string(),
+            "write command without address / value pairs at offset 8"
+        );
+    }
+
+    #[cfg(feature = "imxrt1062")]
+    #[test]
+    fn ensure_fits_breakdown() {
+        let commands = [
+            Command::write32(0x400F_C068, 0xFFFF_FFFF),
+            Command::Nop,
+            Command::write32(0x2000_0000, 0),
+            Command::write32(0x400F_C06C, 0xFFFF_FFFF),
+            Command::check32(CheckCond::AllSet, 0x400D_8000, 1 << 31).with_count(10),
+        ];
+        assert_eq!(ensure_fits(&[], 0).unwrap(), 0);
+        assert_eq!(ensure_fits(&commands, 56).unwrap(), 56);
+        let err = ensure_fits(&commands, 55).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidInput);
+        assert_eq!(
+            err.to_string(),
+            "DCD byte length 56 exceeds budget of 55 bytes by 1\n\
+             \x20 bytes  commands  peripheral\n\
+             \x20    20         2  CCM\n\
+             \x20    16         1  CCM_ANALOG\n\
+             \x20    12         1  (unknown)\n\
+             \x20     4         1  (nop)\n\
+             \x20     4         -  (DCD header)\n",
+        );
+    }
+
+    #[test]
+    fn merge_blocks() {
+        let mut a = vec![];
+        serialize(&mut a, &[Command::Nop, Command::write32(0x400F_C068, 1)]).unwrap();
+        let mut b = vec![];
+        serialize(&mut b, &[Command::write32(0x400F_C06C, 2), Command::Nop]).unwrap();
+        // empty blocks are empty command lists
+        assert!(merge_serialized(&[]).unwrap().is_empty());
+        assert_eq!(merge_serialized(&[&[], &a]).unwrap(), a);
+        #[rustfmt::skip]
+        assert_eq!(
+            merge_serialized(&[&a, &b]).unwrap(),
+            [
+                0xD2, 0, 32, 0x41,
+                0xC0, 0, 4, 0,
+                0xCC, 0, 20, 0x04,
+                0x40, 0x0F, 0xC0, 0x68, 0, 0, 0, 1,
+                0x40, 0x0F, 0xC0, 0x6C, 0, 0, 0, 2,
+                0xC0, 0, 4, 0,
+            ]
+        );
+        let err = merge_serialized(&[&a, &[0xD1, 0, 4, 0x41]]).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+        assert_eq!(err.to_string(), "DCD block #1: invalid DCD header");
+    }
+
+    #[test]
+    fn split_chunks() {
+        let commands = [
+            Command::write32(0x400F_C068, 1),
+            Command::write32(0x400F_C06C, 2),
+            Command::Nop,
+            Command::set32(0x400F_C070, 3),
+            Command::set32(0x400F_C074, 4),
+            Command::set32(0x400F_C078, 5),
+            Command::check32(CheckCond::AllSet, 0x400D_8000, 1 << 31).with_count(10),
+            Command::write32(0x400F_C07C, 6),
+        ];
+        assert!(split(&[], 4).unwrap().is_empty());
+        assert_eq!(split(&commands, usize::MAX).unwrap(), [commands.to_vec()]);
+        for max_byte_len in 20..100 {
+            let chunks = split(&commands, max_byte_len).unwrap();
+            assert_eq!(chunks.concat(), commands);
+            let byte_len = |commands: &[Command]| serialize(&mut vec![], commands).unwrap();
+            assert!(chunks.iter().all(|chunk| byte_len(chunk) <= max_byte_len));
+            // chunks are filled as far as possible
+            for pair in chunks.windows(2) {
+                let extended = [&pair[0][..], &pair[1][..1]].concat();
+                assert!(byte_len(&extended) > max_byte_len);
+            }
+        }
+        // cutting the group of set writes
+        assert_eq!(
+            split(&commands, 28).unwrap(),
+            [
+                &commands[..3],
+                &commands[3..5],
+                &commands[5..6],
+                &commands[6..7],
+                &commands[7..]
+            ]
+        );
+        let err = split(&commands, 19).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidInput);
+        assert_eq!(
+            err.to_string(),
+            "command #6 takes 20 bytes as a DCD block, more than 19"
+        );
+    }
+
+    #[cfg(feature = "imxrt1062")]
+    #[test]
+    fn mmio_addr() {
+        let addr = MmioAddr::new(Target::Imxrt1060, 0x401F_8002, Width::B2).unwrap();
+        assert_eq!(u32::from(addr), 0x401F_8002);
+        assert_eq!(
+            addr.check(CheckCond::AnySet, 1).with_count(3),
+            Command::Check(Check::new(Width::B2, CheckCond::AnySet, 0x401F_8002, 1).with_count(3))
+        );
+        let err = MmioAddr::new(Target::Imxrt1060, 0x401F_8002, Width::B4).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidInput);
+        assert_eq!(
+            err.to_string(),
+            "address 0x401F8002 is not aligned to 4 bytes"
+        );
+        let err = MmioAddr::new(Target::Imxrt1180, 0x400F_C068, Width::B4).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "address 0x400FC068 is outside of the ranges writable by the DCD on Imxrt1180"
+        );
+    }
+
+    #[test]
+    fn command_try_from() {
+        let commands = [
+            Command::Nop,
+            Command::set32(0x400F_C068, 3),
+            Command::check32(CheckCond::AnyClear, 0x400D_8000, 1),
+            Command::check32(CheckCond::AnyClear, 0x400D_8000, 1).with_count(7),
+        ];
+        for command in commands {
+            let mut bytes = vec![];
+            serialize(&mut bytes, std::slice::from_ref(&command)).unwrap();
+            assert_eq!(Command::try_from(&bytes[4..]).unwrap(), command);
+        }
+
+        let mut bytes = vec![];
+        let writes = [
+            Command::write32(0x400F_C068, 1),
+            Command::write32(0x400F_C06C, 2),
+        ];
+        serialize(&mut bytes, &writes).unwrap();
+        assert_eq!(
+            Command::try_from(&bytes[4..]).unwrap(),
+            Command::WriteGroup(WriteGroup::new(
+                Width::B4,
+                WriteOp::Write,
+                vec![(0x400F_C068, 1), (0x400F_C06C, 2)],
+            ))
+        );
+        let err = Command::try_from(&[0xCC, 0x00, 0x04, 0x04][..]).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+        assert_eq!(
+            err.to_string(),
+            "write command without address / value pairs at offset 0"
+        );
+        let err = Command::try_from(&[0xC0, 0x00, 0x04, 0x00, 0x00][..]).unwrap_err();
+        assert_eq!(err.to_string(), "1 trailing bytes after the command");
+    }
+
+    #[test]
+    fn find_replace() {
+        let mut dcd = Dcd::new(vec![
+            Command::Nop,
+            Command::Write(Write::new(Width::B1, WriteOp::Set, 0xFFFF_FFFF, 1)),
+            Command::Check(Check::new(Width::B2, CheckCond::AllSet, 0x1002, 1)),
+            Command::write32(0x1000, 2),
+        ]);
+        use std::ops::Bound::{self, *};
+        let indices = |dcd: &Dcd, range: (Bound<u32>, Bound<u32>)| {
+            dcd.find(range).map(|(index, _)| index).collect::<Vec<_>>()
+        };
+        assert_eq!(indices(&dcd, (Unbounded, Unbounded)), [1, 2, 3]);
+        assert_eq!(indices(&dcd, (Included(0xFFFF_FFFF), Unbounded)), [1]);
+        assert!(indices(&dcd, (Unbounded, Excluded(0))).is_empty());
+        assert_eq!(indices(&dcd, (Excluded(0x1002), Excluded(0x1004))), [2, 3]);
+        assert_eq!(indices(&dcd, (Included(0x1004), Unbounded)), [1]);
+
+        assert_eq!(dcd.replace_value(0x1002, 7), 0);
+        assert_eq!(dcd.replace_value(0xFFFF_FFFF, 7), 1);
+        assert_eq!(
+            dcd[1],
+            Command::Write(Write::new(Width::B1, WriteOp::Set, 0xFFFF_FFFF, 7))
+        );
+        assert_eq!(dcd.remove_matching(|c| matches!(c, Command::Write(_))), 2);
+        assert_eq!(dcd.len(), 2);
+    }
+
+    #[cfg(feature = "imxrt1062")]
+    #[test]
+    fn retain_filter() {
+        let group = Command::WriteGroup(WriteGroup::new(
+            Width::B4,
+            WriteOp::Write,
+            vec![(0x400F_C068, 0xFFFF_FFFF), (0x402F_0000, 0x1000_0004)],
+        ));
+        let mut dcd = Dcd::new(vec![
+            Command::write32(0x400F_C068, 0xFFFF_FFFF),
+            group.clone(),
+            Command::Unlock(Unlock::new(Unlock::ENGINE_CAAM, Unlock::CAAM_MID)),
+            Command::check32(CheckCond::AllClear, 0x402F_0000, 1),
+        ]);
+        assert!(group.accesses_peripheral("CCM") && group.accesses_peripheral("SEMC"));
+        assert!(!Command::write32(0x1000, 0).accesses_peripheral("(unknown)"));
+
+        let semc = dcd.filter(|command| command.accesses_peripheral("SEMC"));
+        assert_eq!(semc.commands, [dcd[1].clone(), dcd[3].clone()]);
+        assert_eq!(
+            dcd.filter(|command| command.accesses(..)).len(),
+            3,
+            "unlock accesses no address"
+        );
+        assert_eq!(dcd.len(), 4);
+
+        assert_eq!(dcd.retain(|c| !c.accesses_peripheral("SEMC")), 2);
+        assert_eq!(dcd.retain(|c| matches!(c, Command::Unlock(_))), 1);
+        assert_eq!(dcd.len(), 1);
+        assert_eq!(dcd.retain(|_| true), 0);
+    }
+
+    #[cfg(all(
+        feature = "imxrt1011",
+        feature = "imxrt1015",
+        feature = "imxrt1021",
+        feature = "imxrt1052",
+        feature = "imxrt1062",
+        feature = "imxrt1064"
+    ))]
+    #[test]
+    fn allowed_ranges() {
+        let targets = [
+            Target::Imxrt1010,
+            Target::Imxrt1015,
+            Target::Imxrt1020,
+            Target::Imxrt1050,
+            Target::Imxrt1060,
+            Target::Imxrt1064,
+            Target::Imxrt1180,
+        ];
+        for target in targets {
+            let ranges = target.allowed_ranges();
+            assert_eq!(ranges.len(), target.write_ranges().len());
+            for (range, &(begin, end, name)) in ranges.iter().zip(target.write_ranges()) {
+                assert_eq!(*range, AllowedRange { begin, end, name });
+                assert!(range.begin <= range.end);
+            }
+            // every target accepts at least the ranges of the RT1010
+            if target != Target::Imxrt1180 {
+                for range in Target::Imxrt1010.allowed_ranges() {
+                    assert!(ranges.contains(range), "{:?}: {}", target, range.name);
+                }
+            }
+        }
+        assert!(!Target::Imxrt1015
+            .allowed_ranges()
+            .iter()
+            .any(|r| r.name == "SEMC"));
+        assert_eq!(
+            Target::Imxrt1050.allowed_ranges().last().unwrap().name,
+            "SEMC"
+        );
+    }
+
+    // also run with a single chip, e.g. `cargo test --no-default-features --features imxrt1011`
+    #[test]
+    fn chip_features() {
+        let chips = [
+            (Target::Imxrt1010, cfg!(feature = "imxrt1011")),
+            (Target::Imxrt1015, cfg!(feature = "imxrt1015")),
+            (Target::Imxrt1020, cfg!(feature = "imxrt1021")),
+            (Target::Imxrt1050, cfg!(feature = "imxrt1052")),
+            (Target::Imxrt1060, cfg!(feature = "imxrt1062")),
+            (Target::Imxrt1064, cfg!(feature = "imxrt1064")),
+        ];
+        for (target, enabled) in chips {
+            assert_eq!(!target.allowed_ranges().is_empty(), enabled, "{:?}", target);
+            assert_eq!(!target.write_ranges().is_empty(), enabled, "{:?}", target);
+        }
+        let detected = detect::scores(&[]).into_iter().map(|(target, _)| target);
+        let enabled = chips.iter().filter(|(_, enabled)| *enabled);
+        assert!(detected.eq(enabled.map(|&(target, _)| target)));
+
+        let tables = cfg!(any(
+            feature = "imxrt1052",
+            feature = "imxrt1062",
+            feature = "imxrt1064"
+        ));
+        assert_eq!(symbols::peripheral(0x400F_C068).is_some(), tables);
+        #[cfg(feature = "ral")]
+        assert_eq!(registers::lookup(0x400F_C068).is_some(), tables);
+    }
+
+    #[test]
+    fn dcd_iterators() {
+        let mut dcd: Dcd = (0..3)
+            .map(|i| Command::write32(0x400F_C068 + 4 * i, 0))
+            .collect();
+        dcd.extend([Command::Nop]);
+        dcd.extend(&[Command::Nop]);
+        assert_eq!(dcd.len(), 5);
+        assert_eq!(dcd[3..], [Command::Nop, Command::Nop]);
+        dcd[4] = Command::set32(0x400F_C068, 1);
+        assert_eq!(ensure_fits(&dcd, 64).unwrap(), 4 + 28 + 4 + 12);
+        assert_eq!(
+            (&dcd).into_iter().filter(|c| **c == Command::Nop).count(),
+            1
+        );
+        let commands: Vec<_> = dcd.clone().into_iter().collect();
+        assert_eq!(commands, dcd.commands);
+        assert_eq!(concat(&[dcd.clone(), dcd]).len(), 10);
+    }
+
+    #[test]
+    fn too_large() {
+        // 4-byte DCD header, 4-byte write header, 8 bytes per write
+        let commands: Vec<_> = (0..8191)
+            .map(|i| Command::write32(0x2000_0000, i))
+            .collect();
+        let mut bytes = vec![];
+        let err = serialize(&mut bytes, &commands).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidInput);
+        assert_eq!(
+            err.to_string(),
+            "DCD byte length 65536 exceeds the maximum of 65535 bytes; see `split` for spreading \
+             the commands over several DCDs"
+        );
+        assert!(bytes.is_empty());
+        assert_eq!(serialize(&mut bytes, &commands[1..]).unwrap(), 65528);
+        assert!(Write::byte_len(8191).is_ok());
+        assert!(Write::byte_len(8192).is_err());
+        assert!(Write::byte_len(usize::MAX).is_err());
+    }
+
+    #[test]
+    fn offsets() {
+        let commands = [
+            Command::check32(CheckCond::AllSet, 0x400D_8000, 1 << 31).with_count(100),
+            Command::set32(0x400F_C068, 1),
+            Command::set32(0x400F_C06C, 1),
+            Command::clear32(0x400F_C06C, 1),
+            Command::Nop,
+            Command::check32(CheckCond::AllClear, 0x402F_0000, 1),
+        ];
+        let mut bytes = vec![];
+        let (byte_len, offsets) = serialize_with_offsets(&mut bytes, &commands).unwrap();
+        let summary: Vec<_> = offsets
+            .iter()
+            .map(|o| (o.offset, o.len, o.header_offset, o.group.clone()))
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                (4, 16, 4, 0..1),
+                (24, 8, 20, 1..3),
+                (32, 8, 20, 1..3),
+                (44, 8, 40, 3..4),
+                (52, 4, 52, 4..5),
+                (56, 12, 56, 5..6),
+            ]
+        );
+        assert_eq!(byte_len, 68);
+        for (command, offset) in commands.iter().zip(&offsets) {
+            let tag = match command {
+                Command::Nop => 0xC0,
+                Command::Write(write) => {
+                    assert_eq!(bytes[offset.offset..][..4], write.address.to_be_bytes());
+                    0xCC
+                }
+                Command::Check(_) => 0xCF,
+                Command::WriteGroup(_) => 0xCC,
+                Command::Unlock(_) => 0xB2,
+            };
+            assert_eq!(bytes[offset.header_offset], tag);
+        }
+        assert!(command_offsets(&[]).is_empty());
+    }
+
+    #[test]
+    fn names() {
+        for &width in Width::ALL {
+            assert_eq!(width.to_string().parse(), Ok(width));
+        }
+        for &op in WriteOp::ALL {
+            assert_eq!(op.to_string().parse(), Ok(op));
+        }
+        for &cond in CheckCond::ALL {
+            assert_eq!(cond.to_string().parse(), Ok(cond));
+        }
+        assert_eq!(CheckCond::AnyClear.to_string(), "any_clear");
+        assert_eq!(
+            "or".parse::<WriteOp>().unwrap_err().to_string(),
+            "invalid write operation `or`, expected one of `write`, `clear`, `set`"
+        );
+        assert!("AllSet".parse::<CheckCond>().is_err());
+    }
+
+    #[test]
+    #[rustfmt::skip]
+    fn write_group() {
+        let group = WriteGroup::new(Width::B4, WriteOp::Set, vec![(0x400F_C068, 1), (0x400F_C06C, 2)]);
+        let commands = [
+            Command::set32(0x400F_C064, 3),
+            Command::WriteGroup(group.clone()),
+            Command::set32(0x400F_C070, 4),
+        ];
+        let mut bytes = vec![];
+        assert_eq!(serialize(&mut bytes, &commands).unwrap(), 4 + 12 + 20 + 12);
+        assert_eq!(
+            bytes[16..36],
+            [
+                0xCC, 0, 20, 0x1C,
+                0x40, 0x0F, 0xC0, 0x68, 0, 0, 0, 1,
+                0x40, 0x0F, 0xC0, 0x6C, 0, 0, 0, 2,
+            ]
+        );
+        // deserializing gives separate writes
+        let writes: Vec<_> = commands.iter().flat_map(Command::writes).map(Command::Write).collect();
+        assert_eq!(deserialize(&bytes).unwrap(), writes);
+        assert_eq!(command_offsets(&commands)[1], CommandOffset { offset: 16, len: 20, header_offset: 16, group: 1..2 });
+        assert_eq!(split(&commands, 40).unwrap().len(), 2);
+
+        let mut dcd = Dcd::new(commands.to_vec());
+        assert_eq!(dcd.find(0x400F_C06C..=0x400F_C06C).count(), 1);
+        assert_eq!(dcd.replace_value(0x400F_C06C, 5), 1);
+        assert_eq!(dcd[1], Command::WriteGroup(WriteGroup::new(Width::B4, WriteOp::Set, vec![(0x400F_C068, 1), (0x400F_C06C, 5)])));
+
+        let empty = [Command::Nop, Command::WriteGroup(WriteGroup::new(Width::B4, WriteOp::Write, vec![]))];
+        let err = serialize(&mut bytes, &empty).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidInput);
+        assert_eq!(err.to_string(), "command #1: write group without address / value pairs");
+    }
+
+    #[test]
+    fn unlock() {
+        let commands = [
+            Command::write32(0x400F_C068, 1),
+            Command::Unlock(Unlock::new(Unlock::ENGINE_SNVS, Unlock::SNVS_LP_SWR)),
+            Command::Unlock(
+                Unlock::new(Unlock::ENGINE_OCOTP, Unlock::OCOTP_FIELD_RETURN).with_uid(7),
+            ),
+            Command::write32(0x400F_C06C, 2),
+        ];
+        let mut bytes = vec![];
+        assert_eq!(
+            serialize(&mut bytes, &commands).unwrap(),
+            4 + 12 + 8 + 16 + 12
+        );
+        assert_eq!(bytes[16..24], [0xB2, 0x00, 0x08, 0x1E, 0, 0, 0, 1]);
+        assert_eq!(deserialize(&bytes).unwrap(), commands);
+        assert_eq!(command_byte_lens(&commands), [12, 8, 16, 12]);
+        // no length for a partial UID
+        let err =
+            Command::try_from(&[0xB2, 0x00, 0x0C, 0x21, 0, 0, 0, 1, 0, 0, 0, 7][..]).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn write_table_commands() {
+        const TABLE: [(u32, u32); 2] = [(0x401F_8014, 5), (0x401F_8018, 0x10)];
+        const COMMANDS: [Command; 2] = write_table(Width::B1, WriteOp::Set, &TABLE);
+        assert_eq!(
+            COMMANDS,
+            [
+                Command::Write(Write::new(Width::B1, WriteOp::Set, 0x401F_8014, 5)),
+                Command::Write(Write::new(Width::B1, WriteOp::Set, 0x401F_8018, 0x10)),
+            ]
+        );
+        assert_eq!(write_table(Width::B4, WriteOp::Write, &[]), []);
+    }
+
+    #[test]
+    fn serialized_len_matches_serialize() {
+        let commands = [
+            Command::write32(0x400F_C068, 0xFFFF_FFFF),
+            Command::write32(0x400F_C06C, 0xFFFF_FFFF),
+            Command::set32(0x400F_C070, 1),
+            Command::Nop,
+            Command::write32(0x400F_C074, 0xFFFF_FFFF),
+            Command::WriteGroup(WriteGroup::new(
+                Width::B4,
+                WriteOp::Write,
+                vec![(0x400F_C078, 0xFFFF_FFFF), (0x400F_C07C, 0xFFFF_FFFF)],
+            )),
+            Command::check32(CheckCond::AllSet, 0x400D_8000, 1 << 31).with_count(100),
+            Command::Unlock(Unlock::new(Unlock::ENGINE_OCOTP, 1).with_uid(7)),
+        ];
+        for end in 0..=commands.len() {
+            let mut bytes = vec![];
+            let len = serialize(&mut bytes, &commands[..end]).unwrap();
+            assert_eq!(serialized_len(&commands[..end]), len);
+        }
+        assert_eq!(assert_dcd_fits::<1024>(&commands), 104);
+    }
+
+    #[test]
+    fn serialize_options() {
+        let commands = [Command::write32(0x400F_C068, 0xFFFF_FFFF)];
+        let mut plain = vec![];
+        serialize(&mut plain, &commands).unwrap();
+        let mut out = vec![];
+        let options = SerializeOptions::new();
+        assert_eq!(options.serialize(&mut out, &commands).unwrap(), 16);
+        assert_eq!(out, plain);
+
+        out.clear();
+        let options = SerializeOptions::new().align(64).size(128);
+        assert_eq!(options.serialize(&mut out, &commands).unwrap(), 128);
+        assert_eq!(out[..16], plain);
+        assert!(out[16..].iter().all(|&byte| byte == 0));
+
+        out.clear();
+        let options = SerializeOptions::new().size(24).padding(Padding::Nops);
+        assert_eq!(options.serialize(&mut out, &commands).unwrap(), 24);
+        assert_eq!(
+            deserialize(&out).unwrap(),
+            [commands[0].clone(), Command::Nop, Command::Nop]
+        );
+
+        out.clear();
+        let options = SerializeOptions::new().size(8).padding(Padding::Nops);
+        assert_eq!(options.serialize(&mut out, &[]).unwrap(), 8);
+        assert_eq!(deserialize(&out).unwrap(), [Command::Nop]);
+
+        out.clear();
+        let options = SerializeOptions::new().size(4).padding(Padding::Nops);
+        assert_eq!(options.serialize(&mut out, &[]).unwrap(), 4);
+        assert_eq!(out, [0xD2, 0x00, 0x04, 0x41]);
+        assert!(deserialize(&out).unwrap().is_empty());
+
+        for (options, commands) in [
+            (SerializeOptions::new().size(8), &commands[..]),
+            (SerializeOptions::new().size(2).padding(Padding::Nops), &[]),
+            (options.size(18), &commands[..]),
+            (SerializeOptions::new().align(0), &commands[..]),
+        ] {
+            let error = options.serialize(std::io::sink(), commands).unwrap_err();
+            assert_eq!(error.kind(), std::io::ErrorKind::InvalidInput);
+        }
+    }
 }
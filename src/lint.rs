@@ -0,0 +1,1190 @@
+//! Static checks on DCD command lists.
+//!
+//! [`validate`] runs all lints with their default levels; use [`Validator`] to customize.
+//!
+//! ```
+//! # use imxrt_dcd::{lint::{self, Level, Lint}, Command};
+//! let commands = [
+//!     Command::write32(0x400F_C068, 0xFFFF_FFFF),
+//!     Command::write32(0x401F_4000, 0x1234),  // OCOTP_CTRL
+//! ];
+//! let diagnostics = lint::validate(&commands);
+//! assert_eq!(diagnostics.len(), 1);
+//! assert_eq!(diagnostics[0].index, 1);
+//! assert_eq!(diagnostics[0].lint, Lint::DangerousWrite);
+//! assert_eq!(diagnostics[0].level, Level::Deny);
+//! ```
+//!
+//! With the `"lint-config"` feature, a project can keep its lint policy in a
+//! [`dcd-lints.toml`](CONFIG_FILE_NAME) file; see [`Validator::from_config`].
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+
+use crate::{AllowedRange, Check, CheckCond, Command, Target, Write, WriteOp};
+
+#[cfg(feature = "lint-config")]
+mod config;
+
+/// Conventional name of a lint configuration file; see [`Validator::from_config`].
+pub const CONFIG_FILE_NAME: &str = "dcd-lints.toml";
+
+/// Severity of a [`Lint`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd)]
+pub enum Level {
+    /// Not reported.
+    Allow,
+    /// Reported, but the DCD may still be intended.
+    Warn,
+    /// Reported; the DCD should not be used as-is.
+    Deny,
+}
+
+/// Kinds of issues detected by [`Validator`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd)]
+#[non_exhaustive]
+pub enum Lint {
+    /// Write to a region where a wrong value may permanently damage or lock the device, e.g. OCOTP
+    /// fuse programming or SNVS security configuration.
+    DangerousWrite,
+    /// Write outside the address ranges accepted by the boot ROM of the target (see
+    /// [`Target::write_ranges`]), which makes the boot ROM abandon the rest of the DCD. Only checked
+    /// if a target is set with [`Validator::target`], or with [`Validator::hab_closed`].
+    InvalidWriteAddress,
+    /// Write to on-chip memory (ITCM, DTCM or OCRAM), e.g. to preload data. No reference manual
+    /// lists on-chip memory among the [allowed ranges](Target::allowed_ranges), so boot ROMs reject
+    /// such writes like those of [`Lint::InvalidWriteAddress`], abandoning the rest of the DCD.
+    /// Only checked if a target is set, with [`Validator::hab_closed`], or if explicitly enabled
+    /// with [`Validator::sram_writes`]; the latter also accepts them for boot ROM revisions that
+    /// do.
+    SramWrite,
+    /// Write to an address range that the project forbids, e.g. registers owned by a later boot
+    /// stage. Only checked for ranges set with [`Validator::deny_range`].
+    DeniedWrite,
+    /// SEMC register write before the SEMC clock root (`CCM_CBCDR`) or clock gate (`CCM_CCGR3`) is
+    /// configured. Depending on the clock state left by the boot ROM, such writes may silently
+    /// fail, leading to SDRAM that only works sometimes.
+    SemcBeforeClock,
+    /// Write setting bits of a known register that are not part of any of its fields, likely due
+    /// to a value shifted by the wrong offset. Only checked with the `"ral"` feature, which
+    /// provides the register definitions (see [`registers`](crate::registers)), and only for the
+    /// [targets](Validator::target) [`Target::Imxrt1060`] and [`Target::Imxrt1064`], whose
+    /// register layout the definitions describe.
+    ReservedBits,
+    /// Check that does not actually wait for anything: with a zero mask, it is either always or
+    /// never satisfied (depending on the condition); with a poll count of 0, it is a NOP.
+    DegenerateCheck,
+    /// Check with an implausible poll count: either so large (more than 10 million polls) that it
+    /// amounts to polling forever, or, for status bits with a known settling time (e.g. the `LOCK`
+    /// bit of a PLL), too small to cover that time even if each poll took as long as 10 µs.
+    PollCount,
+    /// Check that is already satisfied by the values of preceding writes to the same register,
+    /// i.e. does not wait for anything --- usually a sign that the mask or address is wrong.
+    DeadCheck,
+    /// Check that is not satisfied by the values of preceding writes to the same register, i.e.
+    /// relies on hardware changing the register. This is expected for status bits (e.g. waiting
+    /// for a PLL to lock), so the lint is allowed by default, but may point out a wrong mask or
+    /// address for registers that only hold configuration.
+    UnsatisfiedCheck,
+    /// Serialized DCD larger than the budget set with [`Validator::size_budget`], e.g. the space
+    /// reserved for it in the boot header. Reported at the first command beyond the budget.
+    SizeBudget,
+    /// HAB Unlock command (see [`crate::Unlock`]), which belongs to the command sequence file
+    /// (CSF) rather than the DCD: the boot ROM only accepts Write, Check and NOP commands in a DCD,
+    /// and abandons the rest of the DCD at any other command.
+    UnlockCommand,
+}
+
+impl Lint {
+    /// All lints, in declaration order.
+    pub const ALL: &'static [Lint] = &[
+        Lint::DangerousWrite,
+        Lint::InvalidWriteAddress,
+        Lint::SramWrite,
+        Lint::DeniedWrite,
+        Lint::SemcBeforeClock,
+        Lint::ReservedBits,
+        Lint::DegenerateCheck,
+        Lint::PollCount,
+        Lint::DeadCheck,
+        Lint::UnsatisfiedCheck,
+        Lint::SizeBudget,
+        Lint::UnlockCommand,
+    ];
+
+    /// Returns the `snake_case` name of the lint.
+    pub const fn name(self) -> &'static str {
+        match self {
+            Lint::DangerousWrite => "dangerous_write",
+            Lint::InvalidWriteAddress => "invalid_write_address",
+            Lint::SramWrite => "sram_write",
+            Lint::DeniedWrite => "denied_write",
+            Lint::SemcBeforeClock => "semc_before_clock",
+            Lint::ReservedBits => "reserved_bits",
+            Lint::DegenerateCheck => "degenerate_check",
+            Lint::PollCount => "poll_count",
+            Lint::DeadCheck => "dead_check",
+            Lint::UnsatisfiedCheck => "unsatisfied_check",
+            Lint::SizeBudget => "size_budget",
+            Lint::UnlockCommand => "unlock_command",
+        }
+    }
+
+    /// Finds a lint by its [name](Lint::name).
+    pub fn from_name(name: &str) -> Option<Lint> {
+        Lint::ALL.iter().copied().find(|lint| lint.name() == name)
+    }
+
+    /// Returns the level of the lint unless overridden with [`Validator::level`].
+    pub const fn default_level(self) -> Level {
+        match self {
+            Lint::DangerousWrite => Level::Deny,
+            Lint::InvalidWriteAddress => Level::Deny,
+            Lint::SramWrite => Level::Deny,
+            Lint::DeniedWrite => Level::Deny,
+            Lint::SemcBeforeClock => Level::Warn,
+            Lint::ReservedBits => Level::Warn,
+            Lint::DegenerateCheck => Level::Warn,
+            Lint::PollCount => Level::Warn,
+            Lint::DeadCheck => Level::Warn,
+            Lint::UnsatisfiedCheck => Level::Allow,
+            Lint::SizeBudget => Level::Deny,
+            Lint::UnlockCommand => Level::Deny,
+        }
+    }
+}
+
+impl fmt::Display for Lint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// An issue found in a command list.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Diagnostic {
+    /// Which lint found the issue.
+    pub lint: Lint,
+    /// Level of the lint at the time of validation (never [`Level::Allow`]); [`Level::Deny`]
+    /// for warnings with [`Validator::warnings_as_errors`].
+    pub level: Level,
+    /// Index of the offending command in the command list.
+    pub index: usize,
+    /// Human-readable description.
+    pub message: String,
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let level = match self.level {
+            Level::Allow => "allow",
+            Level::Warn => "warning",
+            Level::Deny => "error",
+        };
+        write!(
+            f,
+            "{}[{}]: command #{}: {}",
+            level, self.lint, self.index, self.message
+        )
+    }
+}
+
+/// Lints reported at [`Level::Deny`] with [`Validator::hab_closed`].
+const HAB_CLOSED_DENIED: [Lint; 3] = [
+    Lint::InvalidWriteAddress,
+    Lint::SramWrite,
+    Lint::UnlockCommand,
+];
+
+/// Configurable command list validator.
+#[derive(Default, Debug, Clone)]
+pub struct Validator {
+    levels: BTreeMap<Lint, Level>,
+    target: Option<Target>,
+    hab_closed: bool,
+    extra_ranges: Vec<AllowedRange>,
+    sram_writes: Option<bool>,
+    denied_ranges: Vec<(u32, u32, String)>,
+    size_budget: Option<usize>,
+    warnings_as_errors: bool,
+    allowed_at: BTreeSet<(usize, Lint)>,
+}
+
+impl Validator {
+    /// Creates a validator with all lints at their default levels.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a validator from the contents of a lint configuration file (see
+    /// [`CONFIG_FILE_NAME`]).
+    ///
+    /// All keys are optional, and correspond to the builder methods of the same names:
+    ///
+    /// ```
+    /// # use imxrt_dcd::{lint::{Level, Lint, Validator}, Command};
+    /// let validator = Validator::from_config(r#"
+    ///     target = "imxrt1060"     # imxrt1010, imxrt1015, imxrt1020, imxrt1050, imxrt1064, ...
+    ///     hab_closed = false
+    ///     sram_writes = false
+    ///     size_budget = 1024       # bytes
+    ///     warnings_as_errors = true
+    ///
+    ///     [levels]
+    ///     unsatisfied_check = "warn"
+    ///     dangerous_write = "allow"
+    ///
+    ///     [[allow_ranges]]
+    ///     begin = 0x401F_4000
+    ///     end = 0x401F_7FFF
+    ///
+    ///     [[deny_ranges]]
+    ///     begin = 0x401F_8000
+    ///     end = 0x401F_BFFF
+    ///     reason = "pins are set up by the HAL"
+    /// "#)
+    /// .unwrap();
+    /// assert_eq!(validator.level_of(Lint::UnsatisfiedCheck), Level::Warn);
+    /// let diagnostics = validator.validate(&[Command::write32(0x401F_4000, 0)]);
+    /// assert!(diagnostics.is_empty());
+    ///
+    /// let err = Validator::from_config("[levels]\npoll_counts = \"deny\"").unwrap_err();
+    /// assert_eq!(err.to_string(), "unknown lint `poll_counts`");
+    /// ```
+    #[cfg(feature = "lint-config")]
+    pub fn from_config(config: &str) -> std::io::Result<Self> {
+        config::validator(config)
+    }
+
+    /// Reads a lint configuration file; see [`Validator::from_config`].
+    #[cfg(feature = "lint-config")]
+    pub fn load_config(path: impl AsRef<std::path::Path>) -> std::io::Result<Self> {
+        Self::from_config(&std::fs::read_to_string(path)?)
+    }
+
+    /// Overrides the level of a lint.
+    pub fn level(mut self, lint: Lint, level: Level) -> Self {
+        self.levels.insert(lint, level);
+        self
+    }
+
+    /// Sets the chip family the DCD is intended for, enabling target-specific lints.
+    pub fn target(mut self, target: Target) -> Self {
+        self.target = Some(target);
+        self
+    }
+
+    /// Assumes a device with HAB in the closed configuration, i.e. with secure boot enforced by
+    /// fuses.
+    ///
+    /// Upon a Write command outside the accepted address ranges, an open device skips the rest of
+    /// the DCD and logs a HAB event, which may go unnoticed during development; a closed device
+    /// treats the event as a failure and refuses to boot the image. In this mode,
+    /// [`Lint::InvalidWriteAddress`] is checked even without a [target](Validator::target),
+    /// against the ranges accepted by all targets of the enabled chip features, and the lints for
+    /// commands that fail the boot of a closed device ([`Lint::InvalidWriteAddress`],
+    /// [`Lint::SramWrite`] and [`Lint::UnlockCommand`]) are reported at [`Level::Deny`], even if
+    /// overridden with [`Validator::level`].
+    ///
+    /// ```
+    /// # use imxrt_dcd::lint::{Level, Lint, Validator};
+    /// let validator = Validator::new().level(Lint::UnlockCommand, Level::Warn);
+    /// assert_eq!(validator.level_of(Lint::UnlockCommand), Level::Warn);
+    /// assert_eq!(validator.hab_closed().level_of(Lint::UnlockCommand), Level::Deny);
+    /// ```
+    pub fn hab_closed(mut self) -> Self {
+        self.hab_closed = true;
+        self
+    }
+
+    /// Accepts Write commands in `range` in addition to the ranges of the target (see
+    /// [`Target::allowed_ranges`]), e.g. for a range that a boot ROM revision is known to accept
+    /// but the reference manual does not list.
+    ///
+    /// ```
+    /// # use imxrt_dcd::{lint::Validator, AllowedRange, Command, Target};
+    /// let commands = [Command::write32(0x401F_4000, 0)];
+    /// let validator = Validator::new().target(Target::Imxrt1060);
+    /// assert_eq!(validator.validate(&commands).len(), 2);
+    /// let ocotp = AllowedRange { begin: 0x401F_4000, end: 0x401F_7FFF, name: "OCOTP" };
+    /// assert_eq!(validator.allow_range(ocotp).validate(&commands).len(), 1);
+    /// ```
+    pub fn allow_range(mut self, range: AllowedRange) -> Self {
+        self.extra_ranges.push(range);
+        self
+    }
+
+    /// Overrides whether the boot ROM accepts writes to on-chip memory (see [`Lint::SramWrite`]),
+    /// which otherwise are rejected if a [target](Validator::target) is set.
+    ///
+    /// With `false`, such writes are reported even without a target; with `true`, they are not
+    /// reported by [`Lint::InvalidWriteAddress`] either, for boot ROM revisions known to accept
+    /// them.
+    ///
+    /// ```
+    /// # use imxrt_dcd::{lint::{Lint, Validator}, Command, Target};
+    /// let commands = [Command::write32(0x2020_0000, 0)]; // OCRAM
+    /// let lints = |validator: Validator| {
+    ///     validator.validate(&commands).iter().map(|d| d.lint).collect::<Vec<_>>()
+    /// };
+    /// assert_eq!(lints(Validator::new()), []);
+    /// assert_eq!(lints(Validator::new().sram_writes(false)), [Lint::SramWrite]);
+    /// assert_eq!(lints(Validator::new().target(Target::Imxrt1060)), [Lint::SramWrite]);
+    /// assert_eq!(lints(Validator::new().target(Target::Imxrt1060).sram_writes(true)), []);
+    /// ```
+    pub fn sram_writes(mut self, accepted: bool) -> Self {
+        self.sram_writes = Some(accepted);
+        self
+    }
+
+    /// Reports Write commands in `begin..=end` (see [`Lint::DeniedWrite`]), with `reason` in the
+    /// message, e.g. for registers that the firmware configures itself.
+    ///
+    /// ```
+    /// # use imxrt_dcd::{lint::Validator, Command};
+    /// let validator = Validator::new().deny_range(0x401F_8000, 0x401F_BFFF, "pins are set up by the HAL");
+    /// let diagnostics = validator.validate(&[Command::write32(0x401F_8014, 5)]);
+    /// assert_eq!(
+    ///     diagnostics[0].to_string(),
+    ///     "error[denied_write]: command #0: write to 0x401F8014 (pins are set up by the HAL)",
+    /// );
+    /// ```
+    pub fn deny_range(mut self, begin: u32, end: u32, reason: impl Into<String>) -> Self {
+        self.denied_ranges.push((begin, end, reason.into()));
+        self
+    }
+
+    /// Reports a serialized DCD (including its header) of more than `bytes` bytes; see
+    /// [`Lint::SizeBudget`] and [`ensure_fits`](crate::ensure_fits).
+    pub fn size_budget(mut self, bytes: usize) -> Self {
+        self.size_budget = Some(bytes);
+        self
+    }
+
+    /// Reports all lints at [`Level::Warn`] as [`Level::Deny`], e.g. to fail CI on any warning.
+    ///
+    /// ```
+    /// # use imxrt_dcd::{lint::{Level, Validator}, CheckCond, Command};
+    /// let commands = [Command::check32(CheckCond::AllSet, 0x400D_8000, 0)];
+    /// assert_eq!(Validator::new().validate(&commands)[0].level, Level::Warn);
+    /// let diagnostics = Validator::new().warnings_as_errors().validate(&commands);
+    /// assert_eq!(diagnostics[0].level, Level::Deny);
+    /// ```
+    pub fn warnings_as_errors(mut self) -> Self {
+        self.warnings_as_errors = true;
+        self
+    }
+
+    /// Suppresses a lint for the command at `index` only, e.g. for a deliberately indefinite poll,
+    /// without lowering the level of the lint for the whole list.
+    ///
+    /// The text format can mark commands with `allow(<lint>, ...)` comments for this; see
+    /// [`text::parse_with_allows`](crate::text::parse_with_allows).
+    ///
+    /// ```
+    /// # use imxrt_dcd::{lint::{Lint, Validator}, Command};
+    /// let commands = [Command::write32(0x401F_4000, 0), Command::write32(0x401F_4004, 0)];
+    /// let validator = Validator::new().allow_at(1, Lint::DangerousWrite);
+    /// let diagnostics = validator.validate(&commands);
+    /// assert_eq!(diagnostics.len(), 1);
+    /// assert_eq!(diagnostics[0].index, 0);
+    /// ```
+    pub fn allow_at(mut self, index: usize, lint: Lint) -> Self {
+        self.allowed_at.insert((index, lint));
+        self
+    }
+
+    /// Returns the effective level of a lint.
+    pub fn level_of(&self, lint: Lint) -> Level {
+        if self.hab_closed && HAB_CLOSED_DENIED.contains(&lint) {
+            return Level::Deny;
+        }
+        self.levels
+            .get(&lint)
+            .copied()
+            .unwrap_or(lint.default_level())
+    }
+
+    /// Runs all lints not set to [`Level::Allow`] over the commands.
+    ///
+    /// Diagnostics are ordered by command index.
+    pub fn validate(&self, commands: &[Command]) -> Vec<Diagnostic> {
+        let mut diagnostics = vec![];
+        let mut report = |lint: Lint, index: usize, message: String| {
+            let level = match self.level_of(lint) {
+                Level::Warn if self.warnings_as_errors => Level::Deny,
+                level => level,
+            };
+            if level != Level::Allow && !self.allowed_at.contains(&(index, lint)) {
+                diagnostics.push(Diagnostic {
+                    lint,
+                    level,
+                    index,
+                    message,
+                });
+            }
+        };
+        for (index, command) in commands.iter().enumerate() {
+            for write in &command.writes() {
+                if let Some(region) = dangerous_region(write.address) {
+                    report(
+                        Lint::DangerousWrite,
+                        index,
+                        format!("write to {:#010X} ({})", write.address, region),
+                    );
+                }
+                if let Some((_, _, reason)) = self
+                    .denied_ranges
+                    .iter()
+                    .find(|(begin, end, _)| (*begin..=*end).contains(&write.address))
+                {
+                    report(
+                        Lint::DeniedWrite,
+                        index,
+                        format!("write to {:#010X} ({})", write.address, reason),
+                    );
+                }
+                let ranges = match (self.target, self.hab_closed) {
+                    (Some(target), _) => Some((target.allowed_ranges(), format!("{:?}", target))),
+                    // RT1010 accepts the fewest ranges, which all other targets accept, too;
+                    // otherwise the enabled target (see the chip features) accepting the fewest
+                    (None, true) => [
+                        Target::Imxrt1010,
+                        Target::Imxrt1015,
+                        Target::Imxrt1020,
+                        Target::Imxrt1050,
+                        Target::Imxrt1060,
+                        Target::Imxrt1064,
+                    ]
+                    .into_iter()
+                    .map(Target::allowed_ranges)
+                    .filter(|ranges| !ranges.is_empty())
+                    .min_by_key(|ranges| ranges.len())
+                    .map(|ranges| (ranges, "every target".to_owned())),
+                    (None, false) => None,
+                };
+                let sram_writes = self
+                    .sram_writes
+                    // accepted by no documented boot ROM
+                    .or((self.target.is_some() || self.hab_closed).then_some(false));
+                if let Some(region) = sram_region(write.address) {
+                    if sram_writes == Some(false) {
+                        let mut message = format!(
+                            "write to {:#010X} ({}), which the boot ROM does not accept",
+                            write.address, region
+                        );
+                        if self.hab_closed {
+                            message += ", and fails the boot with HAB closed";
+                        }
+                        report(Lint::SramWrite, index, message);
+                    }
+                } else if let Some((ranges, target)) = ranges {
+                    let valid = ranges
+                        .iter()
+                        .chain(&self.extra_ranges)
+                        .any(|range| range.contains(write.address));
+                    if !valid {
+                        let mut message = format!(
+                            "write to {:#010X} is not accepted by the boot ROM of {}",
+                            write.address, target
+                        );
+                        if self.hab_closed {
+                            message += ", and fails the boot with HAB closed";
+                        }
+                        if ranges.is_empty() {
+                            message += " (no DCD support)";
+                        }
+                        report(Lint::InvalidWriteAddress, index, message);
+                    }
+                }
+                // the register definitions are of the RT1060 (and RT1064)
+                #[cfg(feature = "ral")]
+                if matches!(self.target, Some(Target::Imxrt1060 | Target::Imxrt1064)) {
+                    if let Some(message) = reserved_bits(write) {
+                        report(Lint::ReservedBits, index, message);
+                    }
+                }
+            }
+            if let Command::Unlock(unlock) = command {
+                report(
+                    Lint::UnlockCommand,
+                    index,
+                    format!(
+                        "HAB Unlock command (engine {:#04X}), which the boot ROM does not accept in \
+                         a DCD",
+                        unlock.engine
+                    ),
+                );
+            }
+            if let Command::Check(check) = command {
+                if let Some(message) = degenerate_check(check) {
+                    report(Lint::DegenerateCheck, index, message);
+                }
+                if let Some(message) = poll_count(check) {
+                    report(Lint::PollCount, index, message);
+                }
+            }
+        }
+        semc_before_clock(commands, &mut report);
+        checks_against_writes(commands, &mut report);
+        if let Some(budget) = self.size_budget {
+            size_budget(commands, budget, &mut report);
+        }
+        diagnostics.sort_by_key(|diagnostic| diagnostic.index);
+        diagnostics
+    }
+}
+
+/// Runs all lints at their default levels over the commands; see [`Validator::validate`].
+pub fn validate(commands: &[Command]) -> Vec<Diagnostic> {
+    Validator::new().validate(commands)
+}
+
+/// Address ranges (begin, end inclusive, description) where a stray write may brick the device.
+#[rustfmt::skip]
+const DANGEROUS_REGIONS: &[(u32, u32, &str)] = &[
+    (0x400D_4000, 0x400D_7FFF, "SNVS: tamper detection, security state, zeroizable keys"),
+    (0x400D_C000, 0x400D_FFFF, "CSU: security access policy, lockable"),
+    (0x400F_8000, 0x400F_BFFF, "SRC: resets, persistent boot configuration"),
+    (0x401F_4000, 0x401F_7FFF, "OCOTP: fuse programming"),
+];
+
+pub(crate) fn dangerous_region(address: u32) -> Option<&'static str> {
+    DANGEROUS_REGIONS
+        .iter()
+        .find(|&&(begin, end, _)| (begin..=end).contains(&address))
+        .map(|&(_, _, description)| description)
+}
+
+/// Address ranges (begin, end inclusive, description) of on-chip memory, spanning the largest
+/// configuration of all targets.
+#[rustfmt::skip]
+const SRAM_REGIONS: &[(u32, u32, &str)] = &[
+    (0x0000_0000, 0x0007_FFFF, "ITCM"),
+    (0x2000_0000, 0x2007_FFFF, "DTCM"),
+    (0x2020_0000, 0x202F_FFFF, "OCRAM"),
+];
+
+fn sram_region(address: u32) -> Option<&'static str> {
+    SRAM_REGIONS
+        .iter()
+        .find(|&&(begin, end, _)| (begin..=end).contains(&address))
+        .map(|&(_, _, description)| description)
+}
+
+/// Describes the bits outside of all register fields that a write sets, if any.
+#[cfg(feature = "ral")]
+fn reserved_bits(write: &Write) -> Option<String> {
+    if write.op == WriteOp::Clear {
+        return None;
+    }
+    let register = crate::registers::lookup(write.address)?;
+    if register.fields.is_empty() {
+        // no field definitions, i.e. nothing to compare with
+        return None;
+    }
+    let reserved = write.value & !register.fields_mask();
+    (reserved != 0).then(|| {
+        format!(
+            "write to {}_{} sets bits {:#010X} outside of any field",
+            register.peripheral, register.name, reserved
+        )
+    })
+}
+
+/// Describes why a check does not wait for anything, if it does not.
+fn degenerate_check(check: &Check) -> Option<String> {
+    if check.mask == 0 {
+        let outcome = match check.cond {
+            CheckCond::AllClear | CheckCond::AllSet => "always satisfied",
+            CheckCond::AnyClear | CheckCond::AnySet => "never satisfied",
+        };
+        return Some(format!(
+            "check of {:#010X} with mask 0 is {}",
+            check.address, outcome
+        ));
+    }
+    (check.count == Some(0)).then(|| {
+        format!(
+            "check of {:#010X} with count=0 does not poll at all",
+            check.address
+        )
+    })
+}
+
+/// Poll count above which a check effectively polls forever.
+const MAX_POLL_COUNT: u32 = 10_000_000;
+/// Generous upper bound of the time the boot ROM takes per poll, in ns.
+const MAX_POLL_NS: u32 = 10_000;
+
+/// Status bits (address, mask, name, settling time in µs) that take a known time to be set, e.g.
+/// the `LOCK` bits of the PLLs, with the lock times (in 24 MHz reference clock cycles) of the
+/// data sheets rounded up.
+#[rustfmt::skip]
+const SETTLING_TIMES: &[(u32, u32, &str, u32)] = &[
+    (0x400D_8000, 1 << 31, "CCM_ANALOG_PLL_ARM[LOCK]", 94),    // 2250 cycles
+    (0x400D_8010, 1 << 31, "CCM_ANALOG_PLL_USB1[LOCK]", 16),   // 383 cycles
+    (0x400D_8020, 1 << 31, "CCM_ANALOG_PLL_USB2[LOCK]", 16),   // 383 cycles
+    (0x400D_8030, 1 << 31, "CCM_ANALOG_PLL_SYS[LOCK]", 469),   // 11250 cycles
+    (0x400D_8070, 1 << 31, "CCM_ANALOG_PLL_AUDIO[LOCK]", 469), // 11250 cycles
+    (0x400D_80A0, 1 << 31, "CCM_ANALOG_PLL_VIDEO[LOCK]", 469), // 11250 cycles
+    (0x400D_80E0, 1 << 31, "CCM_ANALOG_PLL_ENET[LOCK]", 469),  // 11250 cycles
+];
+
+/// Describes why the poll count of a check is implausible, if it is.
+fn poll_count(check: &Check) -> Option<String> {
+    // a count of 0 is a `DegenerateCheck`
+    let count = check.count.filter(|&count| count > 0)?;
+    if count > MAX_POLL_COUNT {
+        return Some(format!(
+            "check of {:#010X} with count={} amounts to polling forever; omit the count to poll \
+             indefinitely, or use a count that gives up in bounded time",
+            check.address, count
+        ));
+    }
+    if !matches!(check.cond, CheckCond::AllSet | CheckCond::AnySet) {
+        return None;
+    }
+    let &(_, _, name, settling_us) = SETTLING_TIMES
+        .iter()
+        .find(|&&(address, mask, _, _)| address == check.address && check.mask & mask != 0)?;
+    let min_count = (settling_us * 1000).div_ceil(MAX_POLL_NS);
+    (count < min_count).then(|| {
+        format!(
+            "check of {} with count={} gives up before the typical settling time of {} µs \
+             (at least {} polls)",
+            name, count, settling_us, min_count
+        )
+    })
+}
+
+/// Callback reporting a lint at a command index.
+type Report<'a> = dyn FnMut(Lint, usize, String) + 'a;
+
+/// SEMC register block.
+const SEMC_RANGE: std::ops::RangeInclusive<u32> = 0x402F_0000..=0x402F_3FFF;
+/// `CCM_CBCDR`, and its `SEMC_PODF`, `SEMC_ALT_CLK_SEL` and `SEMC_CLK_SEL` fields.
+const CBCDR_ADDRESS: u32 = 0x400F_C014;
+const CBCDR_SEMC_MASK: u32 = (0b111 << 16) | (1 << 7) | (1 << 6);
+/// `CCM_CCGR3`, and its `CG2` (SEMC) field.
+const CCGR3_ADDRESS: u32 = 0x400F_C074;
+const CCGR3_SEMC_MASK: u32 = 0b11 << 4;
+
+/// Reports SEMC writes before the last command configuring the SEMC clock.
+fn semc_before_clock(commands: &[Command], report: &mut Report) {
+    let configures_clock = |write: &Write| {
+        let mask = match write.address {
+            CBCDR_ADDRESS => CBCDR_SEMC_MASK,
+            CCGR3_ADDRESS => CCGR3_SEMC_MASK,
+            _ => return false,
+        };
+        match write.op {
+            // a full write to CBCDR always (re)configures the root clock
+            WriteOp::Write => write.address == CBCDR_ADDRESS || write.value & mask != 0,
+            WriteOp::Set => write.value & mask != 0,
+            // only gates the clock / selects the default root clock
+            WriteOp::Clear => write.address == CBCDR_ADDRESS && write.value & mask != 0,
+        }
+    };
+    let writes = || {
+        commands.iter().enumerate().flat_map(|(index, command)| {
+            command
+                .writes()
+                .into_iter()
+                .map(move |write| (index, write))
+        })
+    };
+    let Some((last_clock, _)) = writes().rev().find(|(_, write)| configures_clock(write)) else {
+        return;
+    };
+    for (index, write) in writes().take_while(|&(index, _)| index < last_clock) {
+        if SEMC_RANGE.contains(&write.address) {
+            report(
+                Lint::SemcBeforeClock,
+                index,
+                format!(
+                    "SEMC write to {:#010X} before the SEMC clock is configured (command #{})",
+                    write.address, last_clock
+                ),
+            );
+        }
+    }
+}
+
+/// Reports the first command beyond the size budget, if the serialized DCD exceeds it.
+fn size_budget(commands: &[Command], budget: usize, report: &mut Report) {
+    let lens = crate::command_byte_lens(commands);
+    let byte_len = 4 + lens.iter().sum::<usize>();
+    if commands.is_empty() || byte_len <= budget {
+        return;
+    }
+    let mut end = 4;
+    for (index, len) in lens.into_iter().enumerate() {
+        end += len;
+        if end > budget {
+            report(
+                Lint::SizeBudget,
+                index,
+                format!(
+                    "the DCD is {} bytes, over the budget of {} bytes from this command on",
+                    byte_len, budget
+                ),
+            );
+            return;
+        }
+    }
+}
+
+/// Reports checks whose outcome at the first poll follows from the preceding writes.
+fn checks_against_writes(commands: &[Command], report: &mut Report) {
+    let mut known = BTreeMap::new();
+    for (index, command) in commands.iter().enumerate() {
+        match command {
+            Command::Write(_) | Command::WriteGroup(_) => {
+                crate::effects::apply(&mut known, index, command);
+            }
+            Command::Check(check) => {
+                let Some(bits) = known.get(&check.address) else {
+                    continue;
+                };
+                // zero masks are reported as degenerate checks
+                if bits.width != check.width || check.mask == 0 {
+                    continue;
+                }
+                let last_write = bits.writes[bits.writes.len() - 1];
+                let mask = check.mask;
+                let all_known = mask & !bits.known == 0;
+                let known_set = mask & bits.known_set();
+                let known_clear = mask & bits.known_clear();
+                let (satisfied, unsatisfied) = match check.cond {
+                    CheckCond::AllClear => (all_known && known_set == 0, known_set != 0),
+                    CheckCond::AllSet => (all_known && known_clear == 0, known_clear != 0),
+                    CheckCond::AnyClear => (known_clear != 0, all_known && known_clear == 0),
+                    CheckCond::AnySet => (known_set != 0, all_known && known_set == 0),
+                };
+                if satisfied {
+                    report(
+                        Lint::DeadCheck,
+                        index,
+                        format!(
+                            "check of {:#010X} is already satisfied by the preceding writes \
+                             (command #{})",
+                            check.address, last_write
+                        ),
+                    );
+                } else if unsatisfied {
+                    report(
+                        Lint::UnsatisfiedCheck,
+                        index,
+                        format!(
+                            "check of {:#010X} is not satisfied by the preceding writes \
+                             (command #{})",
+                            check.address, last_write
+                        ),
+                    );
+                }
+            }
+            Command::Nop | Command::Unlock(_) => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Width;
+
+    #[test]
+    fn dangerous_write() {
+        let commands = [
+            Command::write32(0x400F_C068, 0xFFFF_FFFF),
+            Command::set32(0x400D_4038, 1),
+            // reads are harmless
+            Command::check32(CheckCond::AllClear, 0x401F_4000, 1 << 8),
+            Command::clear32(0x401F_4000, 1 << 8),
+        ];
+        let diagnostics = validate(&commands);
+        assert_eq!(
+            diagnostics
+                .iter()
+                .map(|d| (d.lint, d.level, d.index))
+                .collect::<Vec<_>>(),
+            [
+                (Lint::DangerousWrite, Level::Deny, 1),
+                (Lint::DangerousWrite, Level::Deny, 3),
+            ],
+        );
+        assert_eq!(
+            diagnostics[1].to_string(),
+            "error[dangerous_write]: command #3: write to 0x401F4000 (OCOTP: fuse programming)",
+        );
+    }
+
+    #[cfg(all(feature = "imxrt1011", feature = "imxrt1015", feature = "imxrt1062"))]
+    #[test]
+    fn invalid_write_address() {
+        let commands = [
+            Command::write32(0x400F_C068, 0xFFFF_FFFF),
+            Command::write32(0x402F_0000, 0x10000004), // SEMC_MCR
+            Command::check32(CheckCond::AllSet, 0x402F_0000, 1),
+            Command::set32(0x401B_8000, 1), // GPIO1_DR
+        ];
+        let indices = |validator: Validator| {
+            validator
+                .validate(&commands)
+                .iter()
+                .filter(|d| d.lint == Lint::InvalidWriteAddress)
+                .map(|d| d.index)
+                .collect::<Vec<_>>()
+        };
+        assert!(indices(Validator::new()).is_empty());
+        assert_eq!(indices(Validator::new().target(Target::Imxrt1060)), [3]);
+        assert_eq!(indices(Validator::new().target(Target::Imxrt1010)), [1, 3]);
+        assert_eq!(
+            indices(Validator::new().target(Target::Imxrt1180)),
+            [0, 1, 3]
+        );
+        assert_eq!(
+            Validator::new()
+                .target(Target::Imxrt1015)
+                .validate(&commands)[0]
+                .to_string(),
+            "error[invalid_write_address]: command #1: \
+             write to 0x402F0000 is not accepted by the boot ROM of Imxrt1015",
+        );
+    }
+
+    #[cfg(all(feature = "imxrt1011", feature = "imxrt1062"))]
+    #[test]
+    fn hab_closed() {
+        let commands = [
+            Command::write32(0x400F_C068, 0xFFFF_FFFF),
+            Command::write32(0x402F_0000, 0x10000004), // SEMC_MCR
+            Command::set32(0x401B_8000, 1),            // GPIO1_DR
+        ];
+        let diagnostics = Validator::new().hab_closed().validate(&commands);
+        assert_eq!(
+            diagnostics
+                .iter()
+                .map(|d| (d.lint, d.index))
+                .collect::<Vec<_>>(),
+            [
+                (Lint::InvalidWriteAddress, 1),
+                (Lint::InvalidWriteAddress, 2)
+            ]
+        );
+        assert_eq!(
+            diagnostics[1].message,
+            "write to 0x401B8000 is not accepted by the boot ROM of every target, \
+             and fails the boot with HAB closed"
+        );
+        let diagnostics = Validator::new()
+            .hab_closed()
+            .target(Target::Imxrt1060)
+            .validate(&commands);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(
+            diagnostics[0].message,
+            "write to 0x401B8000 is not accepted by the boot ROM of Imxrt1060, \
+             and fails the boot with HAB closed"
+        );
+        let levels = |validator: Validator| {
+            validator
+                .validate(&commands)
+                .iter()
+                .map(|d| d.level)
+                .collect::<Vec<_>>()
+        };
+        let lowered = Validator::new()
+            .target(Target::Imxrt1060)
+            .level(Lint::InvalidWriteAddress, Level::Warn);
+        assert_eq!(levels(lowered.clone()), [Level::Warn]);
+        assert_eq!(levels(lowered.hab_closed()), [Level::Deny]);
+        let allowed = Validator::new().level(Lint::InvalidWriteAddress, Level::Allow);
+        assert_eq!(levels(allowed.hab_closed()), [Level::Deny, Level::Deny]);
+    }
+
+    #[cfg(feature = "imxrt1062")]
+    #[test]
+    fn sram_write() {
+        let commands = [
+            Command::write32(0x0000_1000, 0), // ITCM
+            Command::write32(0x2000_0000, 0), // DTCM
+            Command::write32(0x202F_FFFC, 0), // OCRAM
+            Command::write32(0x2030_0000, 0),
+            Command::check32(CheckCond::AllSet, 0x2020_0000, 1),
+        ];
+        let diagnostics = |validator: Validator| {
+            validator
+                .validate(&commands)
+                .into_iter()
+                .map(|d| (d.lint, d.index))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(diagnostics(Validator::new()), []);
+        let rejected = [
+            (Lint::SramWrite, 0),
+            (Lint::SramWrite, 1),
+            (Lint::SramWrite, 2),
+            (Lint::InvalidWriteAddress, 3),
+        ];
+        assert_eq!(
+            diagnostics(Validator::new().target(Target::Imxrt1050)),
+            rejected
+        );
+        assert_eq!(diagnostics(Validator::new().hab_closed()), rejected);
+        assert_eq!(
+            diagnostics(Validator::new().sram_writes(false)),
+            [
+                (Lint::SramWrite, 0),
+                (Lint::SramWrite, 1),
+                (Lint::SramWrite, 2)
+            ]
+        );
+        assert_eq!(
+            diagnostics(Validator::new().target(Target::Imxrt1050).sram_writes(true)),
+            [(Lint::InvalidWriteAddress, 3)]
+        );
+        assert_eq!(
+            Validator::new().hab_closed().validate(&commands)[1].message,
+            "write to 0x20000000 (DTCM), which the boot ROM does not accept, \
+             and fails the boot with HAB closed"
+        );
+    }
+
+    #[test]
+    fn semc_clock_order() {
+        let commands = [
+            Command::write32(0x402F_0000, 0x1000_0004), // SEMC_MCR
+            Command::set32(0x400F_C074, 0b11 << 4),     // CCGR3: SEMC gate on
+            Command::write32(0x402F_0004, 0x0000_0081), // SEMC_IOCR
+            Command::clear32(0x400F_C014, 0b111 << 16), // CBCDR: SEMC_PODF
+            Command::set32(0x400F_C014, 0b001 << 16),
+            Command::write32(0x402F_0008, 0x0000_0000), // SEMC_BMCR0
+        ];
+        let diagnostics = validate(&commands);
+        assert_eq!(
+            diagnostics
+                .iter()
+                .map(|d| (d.lint, d.level, d.index))
+                .collect::<Vec<_>>(),
+            [
+                (Lint::SemcBeforeClock, Level::Warn, 0),
+                (Lint::SemcBeforeClock, Level::Warn, 2),
+            ],
+        );
+        assert_eq!(
+            diagnostics[1].message,
+            "SEMC write to 0x402F0004 before the SEMC clock is configured (command #4)",
+        );
+        // clock configuration touching other fields / gates only
+        let commands = [
+            Command::write32(0x402F_0000, 0x1000_0004),
+            Command::set32(0x400F_C074, 0b11 << 6),
+            Command::set32(0x400F_C014, 1 << 25),
+        ];
+        assert!(validate(&commands).is_empty());
+    }
+
+    #[cfg(all(feature = "ral", feature = "imxrt1062"))]
+    #[test]
+    fn reserved_bits() {
+        let commands = [
+            Command::write32(0x400F_C014, 0b001 << 16), // CBCDR: SEMC_PODF
+            Command::set32(0x400F_C014, 0b001 << 15),   // off by one bit
+            // clearing reserved bits is harmless
+            Command::clear32(0x400F_C014, 0b111 << 13),
+            Command::write32(0x400F_C068, 0xFFFF_FFFF),
+        ];
+        let lints = |target| {
+            Validator::new()
+                .target(target)
+                .validate(&commands)
+                .iter()
+                // other targets may have no ranges without their chip feature
+                .filter(|d| d.lint != Lint::InvalidWriteAddress)
+                .map(|d| (d.lint, d.level, d.index))
+                .collect::<Vec<_>>()
+        };
+        for target in [Target::Imxrt1060, Target::Imxrt1064] {
+            assert_eq!(lints(target), [(Lint::ReservedBits, Level::Warn, 1)]);
+        }
+        // other register layouts, or unknown
+        assert!(lints(Target::Imxrt1020).is_empty());
+        assert!(validate(&commands).is_empty());
+        let diagnostics = Validator::new()
+            .target(Target::Imxrt1060)
+            .validate(&commands);
+        assert_eq!(
+            diagnostics[0].message,
+            "write to CCM_CBCDR sets bits 0x00008000 outside of any field",
+        );
+    }
+
+    #[test]
+    fn degenerate_check() {
+        let commands = [
+            Command::check32(CheckCond::AllSet, 0x400D_8000, 0),
+            Command::check32(CheckCond::AnySet, 0x400D_8000, 0).with_count(10),
+            Command::check32(CheckCond::AllSet, 0x400D_8000, 1 << 31).with_count(0),
+            Command::check32(CheckCond::AllSet, 0x400D_8000, 1 << 31).with_count(10),
+        ];
+        let diagnostics = validate(&commands);
+        assert_eq!(
+            diagnostics
+                .iter()
+                .map(|d| (d.lint, d.index, d.message.as_str()))
+                .collect::<Vec<_>>(),
+            [
+                (
+                    Lint::DegenerateCheck,
+                    0,
+                    "check of 0x400D8000 with mask 0 is always satisfied"
+                ),
+                (
+                    Lint::DegenerateCheck,
+                    1,
+                    "check of 0x400D8000 with mask 0 is never satisfied"
+                ),
+                (
+                    Lint::DegenerateCheck,
+                    2,
+                    "check of 0x400D8000 with count=0 does not poll at all"
+                ),
+            ],
+        );
+    }
+
+    #[test]
+    fn poll_count() {
+        let commands = [
+            Command::check32(CheckCond::AllSet, 0x400D_8000, 1 << 31).with_count(9),
+            Command::check32(CheckCond::AllSet, 0x400D_8000, 1 << 31).with_count(10),
+            // not the LOCK bit
+            Command::check32(CheckCond::AllSet, 0x400D_8000, 1 << 13).with_count(1),
+            Command::check32(CheckCond::AllClear, 0x400D_8030, 1 << 31).with_count(1),
+            Command::check32(CheckCond::AnySet, 0x400D_8030, 1 << 31).with_count(46),
+            Command::check32(CheckCond::AnySet, 0x400D_8030, 1 << 31).with_count(47),
+            Command::check32(CheckCond::AllSet, 0x402F_003C, 1).with_count(10_000_000),
+            Command::check32(CheckCond::AllSet, 0x402F_003C, 1).with_count(10_000_001),
+            Command::check32(CheckCond::AllSet, 0x402F_003C, 1),
+        ];
+        let diagnostics = validate(&commands);
+        assert_eq!(
+            diagnostics
+                .iter()
+                .map(|d| (d.lint, d.level, d.index))
+                .collect::<Vec<_>>(),
+            [
+                (Lint::PollCount, Level::Warn, 0),
+                (Lint::PollCount, Level::Warn, 4),
+                (Lint::PollCount, Level::Warn, 7),
+            ],
+        );
+        assert_eq!(
+            diagnostics[1].message,
+            "check of CCM_ANALOG_PLL_SYS[LOCK] with count=46 gives up before the typical settling \
+             time of 469 µs (at least 47 polls)"
+        );
+        assert_eq!(
+            diagnostics[2].message,
+            "check of 0x402F003C with count=10000001 amounts to polling forever; omit the count to \
+             poll indefinitely, or use a count that gives up in bounded time"
+        );
+    }
+
+    #[test]
+    fn checks_against_writes() {
+        let commands = [
+            Command::write32(0x400D_8000, 0x0001_2042), // PLL_ARM
+            Command::check32(CheckCond::AllSet, 0x400D_8000, 1 << 31), // LOCK
+            Command::check32(CheckCond::AllSet, 0x400D_8000, 1 << 13), // ENABLE
+            Command::clear32(0x400D_8000, 1 << 16),     // BYPASS
+            Command::check32(CheckCond::AllClear, 0x400D_8000, 1 << 16),
+            Command::check32(CheckCond::AnyClear, 0x400D_8000, (1 << 16) | (1 << 31)),
+            // partially known bits
+            Command::set32(0x400F_C074, 0b11 << 4),
+            Command::check32(CheckCond::AllSet, 0x400F_C074, 0b1111 << 4),
+            Command::check32(CheckCond::AnySet, 0x400F_C074, 0b1111 << 4),
+            // a byte write invalidates the overlapping byte of the word
+            Command::Write(Write::new(Width::B1, WriteOp::Set, 0x400F_C074, 1)),
+            Command::check32(CheckCond::AnySet, 0x400F_C074, 0b1111 << 4),
+        ];
+        let diagnostics = |validator: Validator| {
+            validator
+                .validate(&commands)
+                .into_iter()
+                .map(|d| (d.lint, d.index))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            diagnostics(Validator::new()),
+            [
+                (Lint::DeadCheck, 2),
+                (Lint::DeadCheck, 4),
+                (Lint::DeadCheck, 5),
+                (Lint::DeadCheck, 8),
+            ],
+        );
+        assert_eq!(
+            diagnostics(Validator::new().level(Lint::UnsatisfiedCheck, Level::Warn)),
+            [
+                (Lint::UnsatisfiedCheck, 1),
+                (Lint::DeadCheck, 2),
+                (Lint::DeadCheck, 4),
+                (Lint::DeadCheck, 5),
+                (Lint::DeadCheck, 8),
+            ],
+        );
+        assert_eq!(
+            validate(&commands)[0].message,
+            "check of 0x400D8000 is already satisfied by the preceding writes (command #0)",
+        );
+    }
+
+    #[test]
+    fn level_override() {
+        let commands = [Command::write32(0x401F_4000, 0)];
+        let validator = Validator::new().level(Lint::DangerousWrite, Level::Allow);
+        assert!(validator.validate(&commands).is_empty());
+        let validator = Validator::new().level(Lint::DangerousWrite, Level::Warn);
+        assert_eq!(validator.validate(&commands)[0].level, Level::Warn);
+    }
+
+    #[test]
+    fn size_budget() {
+        // 4 (DCD header) + 4 (write header) + 3 * 8
+        let commands = [
+            Command::write32(0x400F_C068, 0xFFFF_FFFF),
+            Command::write32(0x400F_C06C, 0xFFFF_FFFF),
+            Command::write32(0x400F_C070, 0xFFFF_FFFF),
+        ];
+        assert!(Validator::new()
+            .size_budget(32)
+            .validate(&commands)
+            .is_empty());
+        let diagnostics = Validator::new().size_budget(31).validate(&commands);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(
+            diagnostics[0].to_string(),
+            "error[size_budget]: command #2: the DCD is 32 bytes, over the budget of 31 bytes \
+             from this command on",
+        );
+        assert!(Validator::new().size_budget(0).validate(&[]).is_empty());
+    }
+
+    #[test]
+    fn unlock_command() {
+        let commands = [
+            Command::write32(0x400F_C068, 0xFFFF_FFFF),
+            Command::Unlock(crate::Unlock::new(crate::Unlock::ENGINE_CAAM, 0)),
+        ];
+        let diagnostics = validate(&commands);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(
+            diagnostics[0].to_string(),
+            "error[unlock_command]: command #1: HAB Unlock command (engine 0x1D), which the boot \
+             ROM does not accept in a DCD",
+        );
+    }
+}
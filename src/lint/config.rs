@@ -0,0 +1,111 @@
+//! Lint configuration files (`dcd-lints.toml`).
+
+use std::collections::BTreeMap;
+use std::io;
+
+use serde::Deserialize;
+
+use super::{Level, Lint, Validator};
+use crate::{AllowedRange, Target};
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Config {
+    target: Option<String>,
+    #[serde(default)]
+    hab_closed: bool,
+    sram_writes: Option<bool>,
+    size_budget: Option<usize>,
+    #[serde(default)]
+    warnings_as_errors: bool,
+    #[serde(default)]
+    levels: BTreeMap<String, String>,
+    #[serde(default)]
+    allow_ranges: Vec<Range>,
+    #[serde(default)]
+    deny_ranges: Vec<Range>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Range {
+    begin: u32,
+    end: u32,
+    #[serde(default)]
+    reason: String,
+}
+
+const TARGETS: &[(&str, Target)] = &[
+    ("imxrt1010", Target::Imxrt1010),
+    ("imxrt1015", Target::Imxrt1015),
+    ("imxrt1020", Target::Imxrt1020),
+    ("imxrt1050", Target::Imxrt1050),
+    ("imxrt1060", Target::Imxrt1060),
+    ("imxrt1064", Target::Imxrt1064),
+    ("imxrt1180", Target::Imxrt1180),
+];
+
+pub(super) fn validator(config: &str) -> io::Result<Validator> {
+    let invalid = |message: String| io::Error::new(io::ErrorKind::InvalidData, message);
+    let config: Config = toml::from_str(config).map_err(|err| invalid(err.to_string()))?;
+    let mut validator = Validator::new();
+    if let Some(name) = config.target {
+        let &(_, target) = TARGETS
+            .iter()
+            .find(|(n, _)| *n == name)
+            .ok_or_else(|| invalid(format!("unknown target `{}`", name)))?;
+        validator = validator.target(target);
+    }
+    if config.hab_closed {
+        validator = validator.hab_closed();
+    }
+    if let Some(accepted) = config.sram_writes {
+        validator = validator.sram_writes(accepted);
+    }
+    if let Some(bytes) = config.size_budget {
+        validator = validator.size_budget(bytes);
+    }
+    if config.warnings_as_errors {
+        validator = validator.warnings_as_errors();
+    }
+    for (name, level) in config.levels {
+        let lint =
+            Lint::from_name(&name).ok_or_else(|| invalid(format!("unknown lint `{}`", name)))?;
+        let level = match level.as_str() {
+            "allow" => Level::Allow,
+            "warn" => Level::Warn,
+            "deny" => Level::Deny,
+            _ => {
+                return Err(invalid(format!(
+                    "invalid level `{}` of `{}`, expected `allow`, `warn` or `deny`",
+                    level, name
+                )))
+            }
+        };
+        validator = validator.level(lint, level);
+    }
+    for range in config.allow_ranges.iter().chain(&config.deny_ranges) {
+        if range.begin > range.end {
+            return Err(invalid(format!(
+                "range {:#010X}..={:#010X} ends before it begins",
+                range.begin, range.end
+            )));
+        }
+    }
+    for range in config.allow_ranges {
+        validator = validator.allow_range(AllowedRange {
+            begin: range.begin,
+            end: range.end,
+            name: "lint configuration",
+        });
+    }
+    for range in config.deny_ranges {
+        let reason = if range.reason.is_empty() {
+            "denied by the lint configuration".to_owned()
+        } else {
+            range.reason
+        };
+        validator = validator.deny_range(range.begin, range.end, reason);
+    }
+    Ok(validator)
+}
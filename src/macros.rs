@@ -14,6 +14,26 @@ macro_rules! internal {
     // Macro Args:
     // - `access`: e.g. `{W::*, RW::*}` (for importing the correct field value enumerators)
 
+    // `field: literal` --- same as `field: value`, but a literal that does not fit in the field is
+    // rejected at compile time instead of being silently truncated by the field mask.
+    (@build_value
+     $access:tt $field:ident : $value:literal $(, $($rest:tt)*)?) => {
+        {
+            // `0 <= mask` for a literal 0 is trivially true, which is fine here.
+            #[allow(unused_comparisons)]
+            const _: () = ::core::assert!(
+                $value <= (reg::$field::mask >> reg::$field::offset),
+                ::core::concat!(
+                    "value `", ::core::stringify!($value),
+                    "` does not fit in field `", ::core::stringify!($field), "`"),
+            );
+            (($value << reg::$field::offset) & reg::$field::mask)
+        }
+        $(
+            | $crate::internal!(@build_value $access $($rest)*)
+        )?
+    };
+
     // `field: value`
     (@build_value
      $access:tt $field:ident : $value:expr $(, $($rest:tt)*)?) => {
@@ -53,6 +73,48 @@ macro_rules! internal {
     (@build_value
      $access:tt) => {0};
 
+    // Recursively collects the masks of all fields named in `field: value` / `@field` args.
+    // Arbitrary expressions are rejected since they do not name a field.
+
+    // `field: value`
+    (@build_mask
+     $field:ident : $value:expr $(, $($rest:tt)*)?) => {
+        reg::$field::mask
+        $(
+            | $crate::internal!(@build_mask $($rest)*)
+        )?
+    };
+
+    // `@field`
+    (@build_mask
+     @ $field:ident $(, $($rest:tt)*)?) => {
+        reg::$field::mask
+        $(
+            | $crate::internal!(@build_mask $($rest)*)
+        )?
+    };
+
+    // termination for trailing comma
+    (@build_mask) => {0};
+
+    // Computes `(address, width)` of a RAL register. Expects `periph` to be in scope.
+    //
+    // The address is the instance base address plus the register offset within `RegisterBlock`,
+    // computed on an uninitialized `RegisterBlock` instead of dereferencing the instance pointer.
+    // This keeps the expansion const-evaluable, e.g. in `static` command tables.
+    (@locate_reg
+     $instance:ident, $reg:ident $([$offset:expr])*) => {{
+        let block = ::core::mem::MaybeUninit::<periph::RegisterBlock>::uninit();
+        let base = block.as_ptr();
+        let reg = unsafe { ::core::ptr::addr_of!((*base).$reg $([$offset])*) };
+        let offset = unsafe { (reg as *const u8).offset_from(base as *const u8) } as u32;
+        // NOTE: pointer-to-integer casts are not allowed in const contexts, while transmuting an
+        // integer-derived pointer (as RAL instances are) is.
+        #[allow(clippy::transmutes_expressible_as_ptr_casts)]
+        let base_address: usize = unsafe { ::core::mem::transmute(periph::$instance) };
+        (base_address as u32 + offset, $crate::Width::from_ptr(reg))
+    }};
+
     // Constructs a generic Write command from RAL parts. This is shared between all Write macros.
     //
     // - `width` is inferred from the RAL register type (e.g. `RWRegister<u16>` => `Width::B2`)
@@ -65,12 +127,11 @@ macro_rules! internal {
         #[allow(unused_imports)]
         use $periph::{$reg as reg};
 
+        let (address, width) = $crate::internal!(@locate_reg $instance, $reg $([$offset])*);
         $crate::Command::Write($crate::Write {
-            width: $crate::Width::from_reg(unsafe { &(*(periph::$instance)).$reg $([$offset])* }),
+            width,
             op: $crate::WriteOp::$op,
-            address: unsafe {
-                ::core::ptr::addr_of!((*(periph::$instance)).$reg $([$offset])*) as u32
-            },
+            address,
             value: $value,
         })
     }};
@@ -86,12 +147,12 @@ macro_rules! internal {
         use $periph as periph;
         #[allow(unused_imports)]
         use periph::$reg as reg;
+
+        let (address, width) = $crate::internal!(@locate_reg $instance, $reg $([$offset])*);
         $crate::Command::Check($crate::Check {
-            width: $crate::Width::from_reg(unsafe { &(*(periph::$instance)).$reg $([$offset])* }),
+            width,
             cond: $crate::CheckCond::$cond,
-            address: unsafe {
-                ::core::ptr::addr_of!((*(periph::$instance)).$reg $([$offset])*) as u32
-            },
+            address,
             mask: $mask,
             count: $count,
         })
@@ -121,6 +182,17 @@ macro_rules! internal {
 ///     ral::ccm_analog, CCM_ANALOG, PLL_ARM, @BYPASS, BYPASS_CLK_SRC: CLK1)
 /// # ;
 /// ```
+///
+/// A literal `FIELD: value` that does not fit in the field is a compile error
+/// (`DIV_SELECT` is 7 bits wide):
+///
+/// ```compile_fail
+/// # use imxrt_dcd as dcd;
+/// # use imxrt_ral as ral;
+/// # _ =
+/// dcd::write_reg!(ral::ccm_analog, CCM_ANALOG, PLL_ARM, DIV_SELECT: 200)
+/// # ;
+/// ```
 #[macro_export]
 macro_rules! write_reg {
     ($periph:path, $instance:ident, $reg:ident $([$offset:expr])*, $($args:tt)+) => {{
@@ -195,6 +267,86 @@ macro_rules! clear_reg {
     }};
 }
 
+/// Creates DCD commands that (over-)write several registers of the same RAL peripheral instance,
+/// i.e. one [`write_reg`] per register, in order.
+///
+/// Syntax:
+/// ```ignore
+/// write_regs!(ral::path::to::peripheral, INSTANCE, { REGISTER1: value1, REGISTER2: value2, ... })
+/// ```
+/// Each `value` is an expression, which may refer to fields of its register (as for arbitrary
+/// expressions in [`write_reg`]).
+///
+/// Returns an array of [`crate::Command::Write`] with [`crate::WriteOp::Write`]. Consecutive
+/// writes of the same width are merged during serialization, so a block of e.g. pad
+/// configurations costs 8 bytes per register.
+///
+/// # Example
+///
+/// ```
+/// # use imxrt_dcd as dcd;
+/// # use imxrt_ral as ral;
+/// # _ =
+/// dcd::write_regs!(ral::iomuxc, IOMUXC, {
+///     SW_PAD_CTL_PAD_GPIO_EMC_00: 0x110F9,
+///     SW_PAD_CTL_PAD_GPIO_EMC_01: 0x110F9,
+///     SW_MUX_CTL_PAD_GPIO_EMC_00: 0, // ALT0: SEMC_DATA00
+/// })
+/// # ;
+/// ```
+#[macro_export]
+macro_rules! write_regs {
+    ($periph:path, $instance:ident, { $($reg:ident $([$offset:expr])* : $value:expr),+ $(,)? }) => {{
+        [
+            $(
+                $crate::write_reg!($periph, $instance, $reg $([$offset])*, $value),
+            )+
+        ]
+    }};
+}
+
+/// Creates a pair of DCD commands that update the specified fields of the RAL register while
+/// preserving all other fields, i.e. `register = (register & !(mask1 | mask2 | ...)) | arg1 | arg2 | ...` .
+///
+/// Syntax:
+/// ```ignore
+/// write_field!(ral::path::to::peripheral, INSTANCE, REGISTER, ...args)
+/// ```
+/// Each `arg` can be `FIELD: value` or `@FIELD` (= all bits of the field). Unlike [`write_reg`],
+/// arbitrary expressions are not accepted since they do not name the fields to be cleared.
+///
+/// Returns `[clear, set]`, where `clear` is a [`crate::Command::Write`] with
+/// [`crate::WriteOp::Clear`] of all named field masks, and `set` is a [`crate::Command::Write`]
+/// with [`crate::WriteOp::Set`] of the new field values.
+///
+/// NOTE: This performs two read-modify-write cycles on the register (see "Command Combos" in the
+/// [crate-level docs](crate)), so the register briefly holds all named fields cleared.
+///
+/// # Example
+///
+/// ```
+/// # use imxrt_dcd as dcd;
+/// # use imxrt_ral as ral;
+/// # _ =
+/// dcd::write_field!(ral::ccm_analog, CCM_ANALOG, PLL_ARM, DIV_SELECT: 100, BYPASS_CLK_SRC: CLK1)
+/// # ;
+/// ```
+#[macro_export]
+macro_rules! write_field {
+    ($periph:path, $instance:ident, $reg:ident $([$offset:expr])*, $($args:tt)+) => {{
+        [
+            $crate::internal!(@make_write_command
+                Clear, $periph, $instance, $reg $([$offset])*,
+                $crate::internal!(@build_mask $($args)+)
+            ),
+            $crate::internal!(@make_write_command
+                Set, $periph, $instance, $reg $([$offset])*,
+                $crate::internal!(@build_value {W::*, RW::*} $($args)+)
+            ),
+        ]
+    }};
+}
+
 /// Creates a DCD command that polls (indefinitely) to check if all specified bits / fields are 0
 /// in the specified RAL register, i.e. `(register & (arg1 | arg2 | ...)) == 0` .
 ///
@@ -327,6 +479,57 @@ macro_rules! check_any_set {
     }};
 }
 
+/// Creates a pair of DCD commands that poll (indefinitely) until the specified fields of the RAL
+/// register equal the specified values, i.e. `(register & (mask1 | mask2 | ...)) == (arg1 | arg2 | ...)` .
+///
+/// Syntax:
+/// ```ignore
+/// check_field_eq!(ral::path::to::peripheral, INSTANCE, REGISTER, ...args)
+/// ```
+/// Each `arg` can be `FIELD: value` or `@FIELD` (= all bits of the field). Unlike
+/// [`check_all_set`], arbitrary expressions are not accepted since they do not name the fields to
+/// be compared.
+///
+/// Returns a `Vec` of `[set, clear]`, where `set` is a [`crate::Command::Check`] with
+/// [`crate::CheckCond::AllSet`] of the bits that should be 1, and `clear` is a
+/// [`crate::Command::Check`] with [`crate::CheckCond::AllClear`] of the remaining bits of the
+/// named fields. If the values are all zeros (or all ones), `set` (or `clear`) would have a zero
+/// mask and is left out. Since the number of commands depends on the values, this macro is not
+/// const-evaluable, unlike the others.
+///
+/// NOTE: This does NOT work if the register can change between the two checks (see "Command
+/// Combos" in the [crate-level docs](crate)).
+///
+/// # Example
+///
+/// ```
+/// # use imxrt_dcd as dcd;
+/// # use imxrt_ral as ral;
+/// # _ =
+/// dcd::check_field_eq!(ral::ccm, CCM, CBCDR, SEMC_PODF: 2)
+/// # ;
+/// ```
+#[macro_export]
+macro_rules! check_field_eq {
+    ($periph:path, $instance:ident, $reg:ident $([$offset:expr])*, $($args:tt)+) => {{
+        let checks = [
+            $crate::internal!(@make_check_command
+                AllSet, None, $periph, $instance, $reg $([$offset])*,
+                $crate::internal!(@build_value {R::*, RW::*} $($args)+)
+            ),
+            $crate::internal!(@make_check_command
+                AllClear, None, $periph, $instance, $reg $([$offset])*,
+                $crate::internal!(@build_mask $($args)+)
+                    & !$crate::internal!(@build_value {R::*, RW::*} $($args)+)
+            ),
+        ];
+        checks
+            .into_iter()
+            .filter(|check| !::core::matches!(check, $crate::Command::Check($crate::Check { mask: 0, .. })))
+            .collect::<::std::vec::Vec<_>>()
+    }};
+}
+
 #[cfg(test)]
 mod tests {
     use crate as dcd;
@@ -402,6 +605,28 @@ mod tests {
         );
     }
 
+    #[test]
+    fn write_field_example() {
+        assert_eq!(
+            dcd::write_field!(
+                ral::ccm_analog, CCM_ANALOG, PLL_ARM, DIV_SELECT: 100, BYPASS_CLK_SRC: CLK1, @BYPASS),
+            [
+                dcd::Command::Write(dcd::Write {
+                    width: dcd::Width::B4,
+                    op: dcd::WriteOp::Clear,
+                    address: 0x400D_8000,
+                    value: 0x7f | (0b11 << 14) | (1 << 16),
+                }),
+                dcd::Command::Write(dcd::Write {
+                    width: dcd::Width::B4,
+                    op: dcd::WriteOp::Set,
+                    address: 0x400D_8000,
+                    value: 100 | (0b01 << 14) | (1 << 16),
+                }),
+            ],
+        );
+    }
+
     #[test]
     fn check_all_clear_example() {
         assert_eq!(
@@ -458,6 +683,112 @@ mod tests {
         )
     }
 
+    #[test]
+    fn check_field_eq_example() {
+        assert_eq!(
+            dcd::check_field_eq!(ral::ccm, CCM, CBCDR, SEMC_PODF: 2, @SEMC_CLK_SEL),
+            [
+                dcd::Command::Check(dcd::Check {
+                    width: dcd::Width::B4,
+                    cond: dcd::CheckCond::AllSet,
+                    address: 0x400F_C014,
+                    mask: (0b010 << 16) | (1 << 6),
+                    count: None,
+                }),
+                dcd::Command::Check(dcd::Check {
+                    width: dcd::Width::B4,
+                    cond: dcd::CheckCond::AllClear,
+                    address: 0x400F_C014,
+                    mask: 0b101 << 16,
+                    count: None,
+                }),
+            ],
+        );
+        assert_eq!(
+            dcd::check_field_eq!(ral::ccm, CCM, CBCDR, SEMC_PODF: 0),
+            [dcd::Command::check32(
+                dcd::CheckCond::AllClear,
+                0x400F_C014,
+                0b111 << 16
+            )],
+        );
+        assert_eq!(
+            dcd::check_field_eq!(ral::ccm, CCM, CBCDR, SEMC_PODF: 7, @SEMC_CLK_SEL),
+            [dcd::Command::check32(
+                dcd::CheckCond::AllSet,
+                0x400F_C014,
+                0b111 << 16 | 1 << 6
+            )],
+        );
+    }
+
+    #[test]
+    fn write_regs_example() {
+        const PAD_CONFIG: [dcd::Command; 3] = dcd::write_regs!(ral::iomuxc, IOMUXC, {
+            SW_PAD_CTL_PAD_GPIO_EMC_00: 0x110F9,
+            SW_PAD_CTL_PAD_GPIO_EMC_01: (0b110 << DSE::offset) | SRE::mask,
+            SW_PAD_CTL_PAD_GPIO_EMC_02: 0x110F9,
+        });
+        assert_eq!(
+            PAD_CONFIG,
+            [
+                dcd::Command::write32(0x401F_8204, 0x110F9),
+                dcd::Command::write32(0x401F_8208, 0x31),
+                dcd::Command::write32(0x401F_820C, 0x110F9),
+            ],
+        );
+        assert_eq!(
+            dcd::write_regs!(ral::ccm, CCM, { CCGR0: 0, CCGR1: 1 }),
+            [
+                dcd::write_reg!(ral::ccm, CCM, CCGR0, 0),
+                dcd::write_reg!(ral::ccm, CCM, CCGR1, 1),
+            ],
+        );
+        let mut bytes = vec![];
+        assert_eq!(
+            dcd::serialize(&mut bytes, &PAD_CONFIG).unwrap(),
+            4 + 4 + 3 * 8
+        );
+    }
+
+    #[test]
+    fn static_table() {
+        static COMMANDS: [dcd::Command; 4] = [
+            dcd::write_reg!(ral::ccm_analog, CCM_ANALOG, PLL_ARM, @BYPASS, BYPASS_CLK_SRC: CLK1),
+            dcd::check_all_set!(ral::ccm_analog, CCM_ANALOG, PLL_ARM, @LOCK),
+            dcd::write_reg!(ral::usb, USB1, HCIVERSION, 0),
+            dcd::write_reg!(ral::flexio, FLEXIO1, SHIFTCTL[3], 0),
+        ];
+        assert_eq!(
+            COMMANDS,
+            [
+                dcd::write_reg!(ral::ccm_analog, CCM_ANALOG, PLL_ARM, @BYPASS, BYPASS_CLK_SRC: CLK1),
+                dcd::check_all_set!(ral::ccm_analog, CCM_ANALOG, PLL_ARM, @LOCK),
+                dcd::Command::Write(dcd::Write {
+                    width: dcd::Width::B2,
+                    op: dcd::WriteOp::Write,
+                    address: 0x402E_0102,
+                    value: 0,
+                }),
+                dcd::Command::Write(dcd::Write {
+                    width: dcd::Width::B4,
+                    op: dcd::WriteOp::Write,
+                    address: 0x401A_C08C,
+                    value: 0,
+                }),
+            ],
+        );
+    }
+
+    #[test]
+    fn register_array_runtime_index() {
+        let index = 3;
+        assert_eq!(
+            dcd::write_reg!(ral::flexio, FLEXIO1, SHIFTCTL[index], 0),
+            dcd::write_reg!(ral::flexio, FLEXIO1, SHIFTCTL[3], 0),
+        );
+    }
+
     #[test]
     fn auto_detect_width() {
         {
@@ -0,0 +1,332 @@
+//! Host side of the MCU-Boot protocol spoken by `blhost` and the flashloader, to apply the same
+//! command list as the boot image through the flashloader, e.g. during manufacturing.
+//!
+//! [`Client`] sends `read-memory` / `write-memory` commands over a UART (any port implementing
+//! [`io::Read`] and [`io::Write`], e.g. of the `serialport` crate), and implements
+//! [`WriteMemory`] so that [`exec::apply`](crate::exec::apply) performs the commands through it:
+//!
+//! ```no_run
+//! # fn main() -> std::io::Result<()> {
+//! # let (port, commands) = (std::io::Cursor::new(vec![]), vec![]);
+//! use imxrt_dcd::{exec::apply, mboot::Client, sim::Outcome};
+//! use std::time::Duration;
+//!
+//! let mut flashloader = Client::new(port);
+//! flashloader.ping()?;
+//! assert_eq!(apply(&commands, &mut flashloader, Duration::from_secs(1))?, Outcome::Completed);
+//! # Ok(())
+//! # }
+//! ```
+
+use std::io;
+
+use crate::exec::WriteMemory;
+use crate::verify::ReadMemory;
+use crate::Width;
+
+/// Start byte of every framing packet.
+const START: u8 = 0x5A;
+/// Framing packet types.
+const ACK: u8 = 0xA1;
+const NAK: u8 = 0xA2;
+const ACK_ABORT: u8 = 0xA3;
+const COMMAND: u8 = 0xA4;
+const DATA: u8 = 0xA5;
+const PING: u8 = 0xA6;
+const PING_RESPONSE: u8 = 0xA7;
+/// Command tags.
+const READ_MEMORY: u8 = 0x03;
+const WRITE_MEMORY: u8 = 0x04;
+/// Response tags.
+const GENERIC_RESPONSE: u8 = 0xA0;
+const READ_MEMORY_RESPONSE: u8 = 0xA3;
+/// Command flag: a data phase follows.
+const FLAG_DATA_PHASE: u8 = 0x01;
+/// Maximum payload of a data packet over UART.
+const MAX_DATA_LEN: usize = 32;
+
+/// A connection to the flashloader (or a boot ROM speaking MCU-Boot) over a UART.
+#[derive(Debug)]
+pub struct Client<T> {
+    port: T,
+}
+
+impl<T: io::Read + io::Write> Client<T> {
+    /// Wraps a serial port, e.g. at the 115200 baud the flashloader detects on a ping.
+    pub fn new(port: T) -> Self {
+        Self { port }
+    }
+
+    /// Returns the serial port.
+    pub fn into_inner(self) -> T {
+        self.port
+    }
+
+    /// Pings the flashloader, which lets it detect the baud rate, and returns its protocol version
+    /// as `[bugfix, minor, major, b'P']`.
+    pub fn ping(&mut self) -> io::Result<[u8; 4]> {
+        self.port.write_all(&[START, PING])?;
+        self.port.flush()?;
+        let mut response = [0; 10];
+        self.read_start()?;
+        self.port.read_exact(&mut response[1..])?;
+        response[0] = START;
+        if response[1] != PING_RESPONSE {
+            return Err(invalid(format!(
+                "unexpected packet type {:#04X} instead of a ping response",
+                response[1]
+            )));
+        }
+        if crc16(&response[..8]) != u16::from_le_bytes([response[8], response[9]]) {
+            return Err(invalid("CRC mismatch in the ping response".into()));
+        }
+        Ok(response[2..6].try_into().unwrap())
+    }
+
+    /// Reads `len` bytes at `address` with `read-memory`.
+    pub fn read_memory(&mut self, address: u32, len: u32) -> io::Result<Vec<u8>> {
+        self.send_command(READ_MEMORY, 0, &[address, len])?;
+        let params = self.read_response(READ_MEMORY_RESPONSE, READ_MEMORY)?;
+        let len = params.get(1).copied().unwrap_or(0) as usize;
+        let mut data = vec![];
+        while data.len() < len {
+            let payload = self.read_packet(DATA)?;
+            self.send_ack()?;
+            data.extend(payload);
+        }
+        self.read_response(GENERIC_RESPONSE, READ_MEMORY)?;
+        data.truncate(len);
+        Ok(data)
+    }
+
+    /// Writes `data` at `address` with `write-memory`.
+    pub fn write_memory(&mut self, address: u32, data: &[u8]) -> io::Result<()> {
+        self.send_command(WRITE_MEMORY, FLAG_DATA_PHASE, &[address, data.len() as u32])?;
+        self.read_response(GENERIC_RESPONSE, WRITE_MEMORY)?;
+        for chunk in data.chunks(MAX_DATA_LEN) {
+            self.send_packet(DATA, chunk)?;
+            self.expect_ack()?;
+        }
+        self.read_response(GENERIC_RESPONSE, WRITE_MEMORY)?;
+        Ok(())
+    }
+
+    fn send_command(&mut self, tag: u8, flags: u8, params: &[u32]) -> io::Result<()> {
+        let mut payload = vec![tag, flags, 0, params.len() as u8];
+        for param in params {
+            payload.extend(param.to_le_bytes());
+        }
+        self.send_packet(COMMAND, &payload)?;
+        self.expect_ack()
+    }
+
+    /// Receives a response with the given tag to the command `command`, acknowledges it, and
+    /// returns its parameters if the status is success.
+    fn read_response(&mut self, tag: u8, command: u8) -> io::Result<Vec<u32>> {
+        let payload = self.read_packet(COMMAND)?;
+        self.send_ack()?;
+        let params: Vec<u32> = payload
+            .get(4..)
+            .unwrap_or_default()
+            .chunks_exact(4)
+            .map(|param| u32::from_le_bytes(param.try_into().unwrap()))
+            .collect();
+        if payload.first() != Some(&tag) || params.is_empty() {
+            return Err(invalid(format!("unexpected response {:02X?}", payload)));
+        }
+        if params[0] != 0 {
+            return Err(invalid(format!(
+                "command {:#04X} failed with status {}",
+                command, params[0]
+            )));
+        }
+        Ok(params)
+    }
+
+    fn send_packet(&mut self, kind: u8, payload: &[u8]) -> io::Result<()> {
+        let len = u16::try_from(payload.len()).map_err(|_| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("packet payload of {} bytes is too large", payload.len()),
+            )
+        })?;
+        let mut packet = vec![START, kind];
+        packet.extend(len.to_le_bytes());
+        let crc = crc16(&[&packet[..], payload].concat());
+        packet.extend(crc.to_le_bytes());
+        packet.extend_from_slice(payload);
+        self.port.write_all(&packet)?;
+        self.port.flush()
+    }
+
+    fn send_ack(&mut self) -> io::Result<()> {
+        self.port.write_all(&[START, ACK])?;
+        self.port.flush()
+    }
+
+    fn expect_ack(&mut self) -> io::Result<()> {
+        self.read_start()?;
+        match self.read_byte()? {
+            ACK => Ok(()),
+            NAK | ACK_ABORT => Err(invalid("packet rejected by the flashloader".into())),
+            kind => Err(invalid(format!(
+                "unexpected packet type {:#04X} instead of an ACK",
+                kind
+            ))),
+        }
+    }
+
+    /// Receives a command or data packet, and returns its payload.
+    fn read_packet(&mut self, kind: u8) -> io::Result<Vec<u8>> {
+        self.read_start()?;
+        let mut header = [START, self.read_byte()?, 0, 0, 0, 0];
+        if header[1] != kind {
+            return Err(invalid(format!(
+                "unexpected packet type {:#04X} instead of {:#04X}",
+                header[1], kind
+            )));
+        }
+        self.port.read_exact(&mut header[2..])?;
+        let len = u16::from_le_bytes([header[2], header[3]]) as usize;
+        let mut payload = vec![0; len];
+        self.port.read_exact(&mut payload)?;
+        let crc = u16::from_le_bytes([header[4], header[5]]);
+        if crc16(&[&header[..4], &payload].concat()) != crc {
+            return Err(invalid("CRC mismatch in a received packet".into()));
+        }
+        Ok(payload)
+    }
+
+    /// Skips bytes up to and including the next start byte.
+    fn read_start(&mut self) -> io::Result<()> {
+        while self.read_byte()? != START {}
+        Ok(())
+    }
+
+    fn read_byte(&mut self) -> io::Result<u8> {
+        let mut byte = [0];
+        self.port.read_exact(&mut byte)?;
+        Ok(byte[0])
+    }
+}
+
+impl<T: io::Read + io::Write> ReadMemory for Client<T> {
+    fn read(&mut self, address: u32, width: Width) -> io::Result<u32> {
+        let data = self.read_memory(address, width as u32)?;
+        let mut bytes = [0; 4];
+        bytes[..data.len()].copy_from_slice(&data);
+        Ok(u32::from_le_bytes(bytes))
+    }
+}
+
+impl<T: io::Read + io::Write> WriteMemory for Client<T> {
+    fn write(&mut self, address: u32, width: Width, value: u32) -> io::Result<()> {
+        self.write_memory(address, &value.to_le_bytes()[..width as usize])
+    }
+}
+
+/// CRC-16/XMODEM of the framing packets.
+fn crc16(bytes: &[u8]) -> u16 {
+    bytes.iter().fold(0, |crc, &byte| {
+        (0..8).fold(crc ^ (byte as u16) << 8, |crc, _| {
+            if crc & 0x8000 != 0 {
+                crc << 1 ^ 0x1021
+            } else {
+                crc << 1
+            }
+        })
+    })
+}
+
+fn invalid(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A serial port recording what is sent, and replaying what the flashloader sends.
+    struct Port {
+        sent: Vec<u8>,
+        received: io::Cursor<Vec<u8>>,
+    }
+
+    impl io::Read for Port {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            self.received.read(buf)
+        }
+    }
+
+    impl io::Write for Port {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.sent.write(buf)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    fn packet(kind: u8, payload: &[u8]) -> Vec<u8> {
+        let mut client = Client::new(Port {
+            sent: vec![],
+            received: io::Cursor::new(vec![]),
+        });
+        client.send_packet(kind, payload).unwrap();
+        client.into_inner().sent
+    }
+
+    fn response(tag: u8, params: &[u32]) -> Vec<u8> {
+        let mut payload = vec![tag, 0, 0, params.len() as u8];
+        for param in params {
+            payload.extend(param.to_le_bytes());
+        }
+        packet(COMMAND, &payload)
+    }
+
+    #[test]
+    fn read_write() {
+        assert_eq!(crc16(b"123456789"), 0x31C3);
+
+        let received = [
+            // write-memory
+            vec![START, ACK],
+            response(GENERIC_RESPONSE, &[0, WRITE_MEMORY as u32]),
+            vec![START, ACK],
+            response(GENERIC_RESPONSE, &[0, WRITE_MEMORY as u32]),
+            // read-memory
+            vec![START, ACK],
+            response(READ_MEMORY_RESPONSE, &[0, 2]),
+            packet(DATA, &[0x34, 0x12]),
+            response(GENERIC_RESPONSE, &[0, READ_MEMORY as u32]),
+            // write-memory, rejected
+            vec![START, ACK],
+            response(GENERIC_RESPONSE, &[10200, WRITE_MEMORY as u32]),
+        ]
+        .concat();
+        let mut client = Client::new(Port {
+            sent: vec![],
+            received: io::Cursor::new(received),
+        });
+        WriteMemory::write(&mut client, 0x2020_0000, Width::B4, 0xAABB_CCDD).unwrap();
+        assert_eq!(client.read(0x2020_0000, Width::B2).unwrap(), 0x1234);
+        let err = client.write_memory(0, &[0]).unwrap_err();
+        assert_eq!(err.to_string(), "command 0x04 failed with status 10200");
+
+        let sent = client.into_inner().sent;
+        let write = [
+            packet(COMMAND, &[4, 1, 0, 2, 0, 0, 0x20, 0x20, 4, 0, 0, 0]),
+            vec![START, ACK],
+            packet(DATA, &[0xDD, 0xCC, 0xBB, 0xAA]),
+            vec![START, ACK],
+        ]
+        .concat();
+        assert_eq!(sent[..write.len()], write);
+        // GetProperty(CurrentVersion) of the reference manual
+        assert_eq!(
+            packet(COMMAND, &[7, 0, 0, 2, 1, 0, 0, 0, 0, 0, 0, 0])[..6],
+            [0x5A, 0xA4, 12, 0, 0x4B, 0x33]
+        );
+    }
+}
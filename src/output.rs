@@ -0,0 +1,428 @@
+//! Emitting serialized DCDs in formats other than a raw binary.
+
+use std::fmt::Write as _;
+use std::io;
+
+use crate::{serialize, Command, Padding, SerializeOptions};
+
+/// Serializes given commands as a DCD block into a C++17 header defining
+/// `inline constexpr std::array<std::uint8_t, N> <name>`.
+///
+/// `name` must be an unqualified C++ identifier.
+///
+/// Returns the number of DCD bytes (not header bytes) written or error.
+///
+/// ```
+/// # use imxrt_dcd::{output::write_cpp_header, Command};
+/// let mut header = vec![];
+/// let len = write_cpp_header(&mut header, "dcd", &[Command::Nop]).unwrap();
+/// assert_eq!(len, 8);
+/// assert!(String::from_utf8(header).unwrap().contains(
+///     "inline constexpr std::array<std::uint8_t, 8> dcd = {\n    0xD2, 0x00, 0x08, 0x41, 0xC0, 0x00, 0x04, 0x00,\n};"
+/// ));
+/// ```
+pub fn write_cpp_header(
+    mut w: impl io::Write,
+    name: &str,
+    commands: &[Command],
+) -> io::Result<usize> {
+    let valid_name = name.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+    if !valid_name {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("`{}` is not a valid C++ identifier", name),
+        ));
+    }
+    let mut bytes = vec![];
+    serialize(&mut bytes, commands)?;
+
+    let mut out = String::new();
+    out.push_str("// Generated by imxrt-dcd; do not edit.\n");
+    out.push_str("#pragma once\n\n#include <array>\n#include <cstdint>\n\n");
+    writeln!(
+        out,
+        "inline constexpr std::array<std::uint8_t, {}> {} = {{",
+        bytes.len(),
+        name
+    )
+    .unwrap();
+    for line in bytes.chunks(12) {
+        out.push_str("   ");
+        for byte in line {
+            write!(out, " {:#04X},", byte).unwrap();
+        }
+        out.push('\n');
+    }
+    out.push_str("};\n");
+    w.write_all(out.replace("0X", "0x").as_bytes())?;
+    Ok(bytes.len())
+}
+
+/// Serializes given commands as a DCD block into a relocatable ELF object file for 32-bit ARM,
+/// with the DCD in its own section and a global symbol, so that it can be passed to the linker
+/// directly (e.g. `-C link-arg=dcd.o`), without `include_bytes!` or an assembly stub.
+///
+/// The section is read-only data; place it with the linker script, e.g. for `imxrt-rt`-style
+/// scripts: `.dcd : { KEEP(*(.dcd)) } > FLASH`.
+///
+/// Returns the number of DCD bytes (not object file bytes) written or error.
+///
+/// ```
+/// # use imxrt_dcd::{output::write_object, Command};
+/// let mut object = vec![];
+/// let len = write_object(&mut object, ".dcd", "DCD", &[Command::Nop]).unwrap();
+/// assert_eq!(len, 8);
+/// assert_eq!(&object[..4], b"\x7FELF");
+/// ```
+#[cfg(feature = "object")]
+pub fn write_object(
+    mut w: impl io::Write,
+    section: &str,
+    symbol: &str,
+    commands: &[Command],
+) -> io::Result<usize> {
+    use object::write::{Object, StandardSegment, Symbol, SymbolSection};
+    use object::{
+        elf, Architecture, BinaryFormat, Endianness, FileFlags, SectionKind, SymbolFlags,
+        SymbolKind, SymbolScope,
+    };
+
+    let mut bytes = vec![];
+    serialize(&mut bytes, commands)?;
+
+    let mut object = Object::new(BinaryFormat::Elf, Architecture::Arm, Endianness::Little);
+    // same ABI version as objects from `arm-none-eabi-gcc` / `rustc`, for the linker to accept it
+    object.flags = FileFlags::Elf {
+        os_abi: elf::ELFOSABI_NONE,
+        abi_version: 0,
+        e_flags: elf::EF_ARM_EABI_VER5,
+    };
+    let segment = object.segment_name(StandardSegment::Data).to_vec();
+    let section_id = object.add_section(segment, section.into(), SectionKind::ReadOnlyData);
+    let offset = object.append_section_data(section_id, &bytes, 4);
+    object.add_symbol(Symbol {
+        name: symbol.into(),
+        value: offset,
+        size: bytes.len() as u64,
+        kind: SymbolKind::Data,
+        scope: SymbolScope::Dynamic,
+        weak: false,
+        section: SymbolSection::Section(section_id),
+        flags: SymbolFlags::None,
+    });
+    let object = object
+        .write()
+        .map_err(|err| io::Error::other(err.to_string()))?;
+    w.write_all(&object)?;
+    Ok(bytes.len())
+}
+
+/// Magic bytes starting the footer written by [`write_with_footer`].
+pub const FOOTER_MAGIC: [u8; 4] = *b"DCDF";
+
+/// CRC-32 (IEEE 802.3, as used by zlib / PNG / `crc32` tools).
+fn crc32(bytes: &[u8]) -> u32 {
+    !bytes.iter().fold(!0u32, |crc, &byte| {
+        (0..8).fold(crc ^ byte as u32, |crc, _| {
+            (crc >> 1) ^ (0xEDB8_8320 & (crc & 1).wrapping_neg())
+        })
+    })
+}
+
+/// Serializes given commands as a DCD block followed by a 12-byte integrity footer, for tools
+/// verifying the artifact before programming it:
+///
+/// | offset | size | content                                |
+/// |--------|------|----------------------------------------|
+/// | 0      | 4    | [`FOOTER_MAGIC`] (`DCDF`)              |
+/// | 4      | 4    | byte length of the DCD (little-endian) |
+/// | 8      | 4    | CRC-32 of the DCD (little-endian)      |
+///
+/// The footer is not part of the DCD, so it must be stripped (see [`strip_footer`]) before
+/// embedding the DCD in a firmware image.
+///
+/// Returns the number of bytes written (DCD and footer) or error.
+///
+/// ```
+/// # use imxrt_dcd::{output::{strip_footer, write_with_footer}, serialize, Command};
+/// let commands = [Command::Nop];
+/// let mut bytes = vec![];
+/// assert_eq!(write_with_footer(&mut bytes, &commands).unwrap(), 8 + 12);
+/// let mut dcd = vec![];
+/// serialize(&mut dcd, &commands).unwrap();
+/// assert_eq!(strip_footer(&bytes).unwrap(), dcd);
+/// ```
+pub fn write_with_footer(mut w: impl io::Write, commands: &[Command]) -> io::Result<usize> {
+    let mut bytes = vec![];
+    serialize(&mut bytes, commands)?;
+    let len = bytes.len() as u32;
+    let crc = crc32(&bytes);
+    bytes.extend_from_slice(&FOOTER_MAGIC);
+    bytes.extend_from_slice(&len.to_le_bytes());
+    bytes.extend_from_slice(&crc.to_le_bytes());
+    w.write_all(&bytes)?;
+    Ok(bytes.len())
+}
+
+/// Verifies the footer written by [`write_with_footer`], and returns the DCD without it.
+///
+/// Returns an [`io::ErrorKind::InvalidData`] error if the footer is missing, or the length or
+/// CRC does not match.
+pub fn strip_footer(bytes: &[u8]) -> io::Result<&[u8]> {
+    let invalid = |message: &str| io::Error::new(io::ErrorKind::InvalidData, message.to_owned());
+    let Some((dcd, footer)) = bytes
+        .len()
+        .checked_sub(12)
+        .map(|split| bytes.split_at(split))
+    else {
+        return Err(invalid("too short for a footer"));
+    };
+    let field = |index: usize| u32::from_le_bytes(footer[index..index + 4].try_into().unwrap());
+    if footer[..4] != FOOTER_MAGIC {
+        return Err(invalid("missing footer"));
+    }
+    if field(4) as usize != dcd.len() {
+        return Err(invalid("DCD length does not match the footer"));
+    }
+    if field(8) != crc32(dcd) {
+        return Err(invalid("DCD CRC does not match the footer"));
+    }
+    Ok(dcd)
+}
+
+/// Serializes given commands into a minimal boot image without code, for tools that take the
+/// DCD from the IVT of an image (e.g. `imx_usb_loader`, see [`crate::script::imx_usb_loader`]):
+///
+/// | offset | size | content                                                |
+/// |--------|------|--------------------------------------------------------|
+/// | 0x00   | 32   | IVT, with `self` = `address` and no entry point        |
+/// | 0x20   | 16   | boot data, with the image starting at `address`        |
+/// | 0x30   |      | DCD                                                    |
+///
+/// This is the same layout as the IVT / boot data / DCD of an `imxrt-rt` image (see
+/// [`crate::rt`]), except that the IVT is at the start of the image.
+///
+/// Returns the number of bytes written or error, e.g. [`io::ErrorKind::InvalidInput`] if the image
+/// does not fit below the end of the address space.
+///
+/// ```
+/// # use imxrt_dcd::{output::write_boot_image, Command};
+/// let mut image = vec![];
+/// assert_eq!(write_boot_image(&mut image, 0x2020_0000, &[Command::Nop]).unwrap(), 0x30 + 8);
+/// assert_eq!(image[..4], [0xD1, 0x00, 0x20, 0x40]);
+/// // DCD pointer
+/// assert_eq!(image[12..16], 0x2020_0030u32.to_le_bytes());
+/// assert!(write_boot_image(&mut image, 0xFFFF_FFE0, &[Command::Nop]).is_err());
+/// ```
+pub fn write_boot_image(
+    mut w: impl io::Write,
+    address: u32,
+    commands: &[Command],
+) -> io::Result<usize> {
+    let mut dcd = vec![];
+    serialize(&mut dcd, commands)?;
+    let len = 0x30 + dcd.len();
+    if address as u64 + len as u64 > 1 << 32 {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!(
+                "boot image of {} bytes at {:#010X} exceeds the address space",
+                len, address
+            ),
+        ));
+    }
+    let dcd_address = if dcd.is_empty() { 0 } else { address + 0x30 };
+    #[rustfmt::skip]
+    let words: [u32; 12] = [
+        // IVT: header, entry, reserved, DCD, boot data, self, CSF, reserved
+        0x4020_00D1, 0, 0, dcd_address, address + 0x20, address, 0, 0,
+        // boot data: start, length, plugin, padding
+        address, len as u32, 0, 0,
+    ];
+    let mut bytes: Vec<u8> = words.iter().flat_map(|word| word.to_le_bytes()).collect();
+    bytes.extend_from_slice(&dcd);
+    w.write_all(&bytes)?;
+    Ok(bytes.len())
+}
+
+/// Sector size of SD cards and most eMMC devices, for [`write_sectors`].
+pub const SECTOR_BYTES: usize = 512;
+
+/// Serializes given commands as a DCD block padded with `fill` bytes to a multiple of `sector`
+/// bytes (e.g. [`SECTOR_BYTES`]), for programming tools that write whole sectors.
+///
+/// The padding is not part of the DCD: the byte length in its header stays the same.
+///
+/// Returns the number of bytes written (DCD and padding) or error, e.g.
+/// [`io::ErrorKind::InvalidInput`] if `sector` is 0.
+///
+/// ```
+/// # use imxrt_dcd::{output::{write_sectors, SECTOR_BYTES}, deserialize, Command};
+/// let mut bytes = vec![];
+/// let len = write_sectors(&mut bytes, SECTOR_BYTES, 0xFF, &[Command::Nop]).unwrap();
+/// assert_eq!(len, 512);
+/// assert_eq!(deserialize(&bytes).unwrap(), [Command::Nop]);
+/// assert!(bytes[8..].iter().all(|&byte| byte == 0xFF));
+/// ```
+pub fn write_sectors(
+    w: impl io::Write,
+    sector: usize,
+    fill: u8,
+    commands: &[Command],
+) -> io::Result<usize> {
+    if sector == 0 {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "sector size must not be 0",
+        ));
+    }
+    SerializeOptions::new()
+        .align(sector)
+        .padding(Padding::Fill(fill))
+        .serialize(w, commands)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cpp_header() {
+        let mut header = vec![];
+        let commands = [
+            Command::write32(0x400F_C068, 0xFFFF_FFFF),
+            Command::write32(0x400F_C06C, 0xFFFF_FFFF),
+        ];
+        assert_eq!(
+            write_cpp_header(&mut header, "dcd_data", &commands).unwrap(),
+            24
+        );
+        assert_eq!(
+            String::from_utf8(header).unwrap(),
+            "\
+// Generated by imxrt-dcd; do not edit.
+#pragma once
+
+#include <array>
+#include <cstdint>
+
+inline constexpr std::array<std::uint8_t, 24> dcd_data = {
+    0xD2, 0x00, 0x18, 0x41, 0xCC, 0x00, 0x14, 0x04, 0x40, 0x0F, 0xC0, 0x68,
+    0xFF, 0xFF, 0xFF, 0xFF, 0x40, 0x0F, 0xC0, 0x6C, 0xFF, 0xFF, 0xFF, 0xFF,
+};
+"
+        );
+        assert_eq!(
+            write_cpp_header(vec![], "board::dcd", &commands)
+                .unwrap_err()
+                .kind(),
+            io::ErrorKind::InvalidInput
+        );
+    }
+
+    #[test]
+    fn footer() {
+        assert_eq!(crc32(b"123456789"), 0xCBF4_3926);
+
+        let mut bytes = vec![];
+        write_with_footer(&mut bytes, &[Command::Nop]).unwrap();
+        assert_eq!(
+            bytes[8..],
+            [b'D', b'C', b'D', b'F', 8, 0, 0, 0, 0x9E, 0x76, 0xFB, 0xBA]
+        );
+        assert_eq!(strip_footer(&bytes).unwrap(), &bytes[..8]);
+
+        let error = |bytes: &[u8]| strip_footer(bytes).unwrap_err().to_string();
+        assert_eq!(error(&bytes[..8]), "too short for a footer");
+        assert_eq!(error(&bytes[..bytes.len() - 1]), "missing footer");
+        assert_eq!(
+            error(&[&[0u8; 4], &bytes[..]].concat()),
+            "DCD length does not match the footer"
+        );
+        bytes[7] ^= 1;
+        assert_eq!(error(&bytes), "DCD CRC does not match the footer");
+    }
+
+    #[cfg(feature = "object")]
+    #[test]
+    fn object() {
+        use object::{Object, ObjectSection, ObjectSymbol};
+
+        let commands = [Command::write32(0x400F_C068, 0xFFFF_FFFF)];
+        let mut dcd = vec![];
+        serialize(&mut dcd, &commands).unwrap();
+        let mut bytes = vec![];
+        assert_eq!(
+            write_object(&mut bytes, ".dcd", "DCD", &commands).unwrap(),
+            dcd.len()
+        );
+
+        let file = object::File::parse(&*bytes).unwrap();
+        assert_eq!(file.architecture(), object::Architecture::Arm);
+        assert!(file.is_little_endian());
+        let section = file.section_by_name(".dcd").unwrap();
+        assert_eq!(section.data().unwrap(), dcd);
+        assert_eq!(section.kind(), object::SectionKind::ReadOnlyData);
+        let symbol = file.symbol_by_name("DCD").unwrap();
+        assert!(symbol.is_global());
+        assert_eq!(symbol.section_index(), Some(section.index()));
+        assert_eq!((symbol.address(), symbol.size()), (0, dcd.len() as u64));
+    }
+
+    #[test]
+    fn boot_image() {
+        let mut image = vec![];
+        let commands = [Command::write32(0x400F_C068, 0xFFFF_FFFF)];
+        assert_eq!(
+            write_boot_image(&mut image, 0x2000, &commands).unwrap(),
+            0x30 + 16
+        );
+        let word =
+            |offset: usize| u32::from_le_bytes(image[offset..offset + 4].try_into().unwrap());
+        assert_eq!(
+            (0..12).map(|i| word(4 * i)).collect::<Vec<_>>(),
+            [
+                0x4020_00D1,
+                0,
+                0,
+                0x2030,
+                0x2020,
+                0x2000,
+                0,
+                0,
+                0x2000,
+                0x40,
+                0,
+                0
+            ]
+        );
+        assert_eq!(crate::deserialize(&image[0x30..]).unwrap(), commands);
+
+        let mut image = vec![];
+        assert_eq!(write_boot_image(&mut image, 0x2000, &[]).unwrap(), 0x30);
+        assert_eq!(image[12..16], [0; 4]);
+    }
+
+    #[test]
+    fn sectors() {
+        let commands = [Command::write32(0x400F_C068, 0xFFFF_FFFF)];
+        let mut dcd = vec![];
+        serialize(&mut dcd, &commands).unwrap();
+        let mut bytes = vec![];
+        assert_eq!(write_sectors(&mut bytes, 8, 0xA5, &commands).unwrap(), 16);
+        assert_eq!(bytes, dcd);
+
+        bytes.clear();
+        assert_eq!(write_sectors(&mut bytes, 12, 0xA5, &commands).unwrap(), 24);
+        assert_eq!(bytes[..16], dcd);
+        assert_eq!(bytes[16..], [0xA5; 8]);
+
+        bytes.clear();
+        assert_eq!(write_sectors(&mut bytes, SECTOR_BYTES, 0, &[]).unwrap(), 0);
+        assert_eq!(
+            write_sectors(vec![], 0, 0, &commands).unwrap_err().kind(),
+            io::ErrorKind::InvalidInput
+        );
+    }
+}
@@ -0,0 +1,299 @@
+//! A compact representation of command lists, for tools that hold very many commands at once
+//! (e.g. batch board generation or fuzzing).
+//!
+//! A [`Command`] takes 32 bytes, since it is as large as its largest variant. [`PackedCommands`]
+//! stores each command in 16 bytes instead, with the address / value pairs of all
+//! [`WriteGroup`]s in one shared buffer, and unpacks commands on access:
+//!
+//! ```
+//! # use imxrt_dcd::{packed::PackedCommands, Command};
+//! let commands: PackedCommands = (0..1000).map(|i| Command::write32(0x2000_0000, i)).collect();
+//! assert_eq!(commands.len(), 1000);
+//! assert_eq!(commands.get(7), Some(Command::write32(0x2000_0000, 7)));
+//!
+//! let mut bytes = vec![];
+//! let byte_len = commands.serialize(&mut bytes).unwrap();
+//! assert_eq!(imxrt_dcd::deserialize(&bytes).unwrap(), commands.to_vec());
+//! # assert_eq!(byte_len, 4 + 4 + 1000 * 8);
+//! ```
+
+use std::io::{self, Cursor};
+
+use crate::{
+    stream::Encoder, Check, CheckCond, Command, Unlock, Width, Write, WriteGroup, WriteOp,
+};
+
+const NOP: u8 = 0;
+const WRITE: u8 = 1;
+const CHECK: u8 = 2;
+const WRITE_GROUP: u8 = 3;
+const UNLOCK: u8 = 4;
+
+/// `flags` bit of a Check with a poll count, or an Unlock with a UID.
+const HAS_OPTION: u8 = 1;
+
+/// One command in 16 bytes.
+///
+/// | kind          | `params`         | `a`           | `b`              | `c`           |
+/// |---------------|------------------|---------------|------------------|---------------|
+/// | `WRITE`       | width \| op      | address       | value            | -             |
+/// | `CHECK`       | width \| cond    | address       | mask             | count         |
+/// | `WRITE_GROUP` | width \| op      | first pair    | number of pairs  | -             |
+/// | `UNLOCK`      | engine           | features      | UID (high word)  | UID (low)     |
+///
+/// `params` holds the bits of the command header byte, as in the serialized DCD.
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq)]
+struct Packed {
+    kind: u8,
+    params: u8,
+    flags: u8,
+    a: u32,
+    b: u32,
+    c: u32,
+}
+
+const _: () = assert!(std::mem::size_of::<Packed>() == 16);
+
+/// Commands in a compact representation; see the [module-level docs](self).
+///
+/// Commands go in with [`PackedCommands::push`] (or [`Extend`] / [`FromIterator`]), and come out
+/// unpacked with [`PackedCommands::get`] or [`PackedCommands::iter`].
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct PackedCommands {
+    commands: Vec<Packed>,
+    /// Address / value pairs of all write groups, in order.
+    pairs: Vec<(u32, u32)>,
+}
+
+impl PackedCommands {
+    /// Creates an empty command list.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates an empty command list with room for `capacity` commands (not counting the pairs
+    /// of write groups).
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            commands: Vec::with_capacity(capacity),
+            pairs: vec![],
+        }
+    }
+
+    /// Returns the number of commands.
+    pub fn len(&self) -> usize {
+        self.commands.len()
+    }
+
+    /// Returns whether there are no commands.
+    pub fn is_empty(&self) -> bool {
+        self.commands.is_empty()
+    }
+
+    /// Appends a command.
+    pub fn push(&mut self, command: &Command) {
+        let packed = match command {
+            Command::Nop => Packed::default(),
+            Command::Write(write) => Packed {
+                kind: WRITE,
+                params: write.width as u8 | write.op as u8,
+                a: write.address,
+                b: write.value,
+                ..Packed::default()
+            },
+            Command::Check(check) => Packed {
+                kind: CHECK,
+                params: check.width as u8 | check.cond as u8,
+                flags: if check.count.is_some() { HAS_OPTION } else { 0 },
+                a: check.address,
+                b: check.mask,
+                c: check.count.unwrap_or(0),
+            },
+            Command::WriteGroup(group) => {
+                let packed = Packed {
+                    kind: WRITE_GROUP,
+                    params: group.width as u8 | group.op as u8,
+                    a: self.pairs.len() as u32,
+                    b: group.pairs.len() as u32,
+                    ..Packed::default()
+                };
+                self.pairs.extend_from_slice(&group.pairs);
+                packed
+            }
+            Command::Unlock(unlock) => Packed {
+                kind: UNLOCK,
+                params: unlock.engine,
+                flags: if unlock.uid.is_some() { HAS_OPTION } else { 0 },
+                a: unlock.features,
+                b: (unlock.uid.unwrap_or(0) >> 32) as u32,
+                c: unlock.uid.unwrap_or(0) as u32,
+            },
+        };
+        self.commands.push(packed);
+    }
+
+    /// Returns the command at `index`, or `None` if out of bounds.
+    pub fn get(&self, index: usize) -> Option<Command> {
+        self.commands.get(index).map(|packed| self.unpack(packed))
+    }
+
+    /// Returns the commands in order.
+    pub fn iter(&self) -> impl Iterator<Item = Command> + '_ {
+        self.commands.iter().map(|packed| self.unpack(packed))
+    }
+
+    /// Returns the commands as a `Vec`, e.g. for the functions taking a slice of commands.
+    pub fn to_vec(&self) -> Vec<Command> {
+        self.iter().collect()
+    }
+
+    /// Same as [`crate::serialize`], without unpacking all commands at once.
+    pub fn serialize(&self, mut w: impl io::Write) -> io::Result<usize> {
+        let mut encoder = Encoder::new(Cursor::new(vec![]));
+        for command in self.iter() {
+            encoder.push(&command)?;
+        }
+        let (cursor, byte_len) = encoder.finish()?;
+        w.write_all(cursor.get_ref())?;
+        Ok(byte_len)
+    }
+
+    fn unpack(&self, packed: &Packed) -> Command {
+        let width = || Width::from_num_bytes((packed.params & 0b111) as usize);
+        let op = || match packed.params & 0b11_000 {
+            0b00_000 => WriteOp::Write,
+            0b01_000 => WriteOp::Clear,
+            _ => WriteOp::Set,
+        };
+        match packed.kind {
+            NOP => Command::Nop,
+            WRITE => Command::Write(Write::new(width(), op(), packed.a, packed.b)),
+            CHECK => {
+                let cond = match packed.params & 0b11_000 {
+                    0b00_000 => CheckCond::AllClear,
+                    0b01_000 => CheckCond::AnyClear,
+                    0b10_000 => CheckCond::AllSet,
+                    _ => CheckCond::AnySet,
+                };
+                let check = Check::new(width(), cond, packed.a, packed.b);
+                Command::Check(match packed.flags & HAS_OPTION {
+                    0 => check,
+                    _ => check.with_count(packed.c),
+                })
+            }
+            WRITE_GROUP => {
+                let pairs = &self.pairs[packed.a as usize..][..packed.b as usize];
+                Command::WriteGroup(WriteGroup::new(width(), op(), pairs.to_vec()))
+            }
+            UNLOCK => {
+                let unlock = Unlock::new(packed.params, packed.a);
+                Command::Unlock(match packed.flags & HAS_OPTION {
+                    0 => unlock,
+                    _ => unlock.with_uid((packed.b as u64) << 32 | packed.c as u64),
+                })
+            }
+            kind => unreachable!("packed command of unknown kind {}", kind),
+        }
+    }
+}
+
+impl Extend<Command> for PackedCommands {
+    fn extend<I: IntoIterator<Item = Command>>(&mut self, commands: I) {
+        for command in commands {
+            self.push(&command);
+        }
+    }
+}
+
+impl<'a> Extend<&'a Command> for PackedCommands {
+    fn extend<I: IntoIterator<Item = &'a Command>>(&mut self, commands: I) {
+        for command in commands {
+            self.push(command);
+        }
+    }
+}
+
+impl FromIterator<Command> for PackedCommands {
+    fn from_iter<I: IntoIterator<Item = Command>>(commands: I) -> Self {
+        let mut packed = Self::new();
+        packed.extend(commands);
+        packed
+    }
+}
+
+impl From<&[Command]> for PackedCommands {
+    fn from(commands: &[Command]) -> Self {
+        let mut packed = Self::with_capacity(commands.len());
+        packed.extend(commands);
+        packed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let mut commands = vec![Command::Nop];
+        for width in [Width::B1, Width::B2, Width::B4] {
+            for op in [WriteOp::Write, WriteOp::Clear, WriteOp::Set] {
+                commands.push(Command::Write(Write::new(
+                    width,
+                    op,
+                    0x2000_0000,
+                    0xFFFF_FFFF,
+                )));
+                commands.push(Command::WriteGroup(WriteGroup::new(
+                    width,
+                    op,
+                    vec![(0x2000_0004, 1), (0x2000_0008, 2)],
+                )));
+            }
+            for cond in [
+                CheckCond::AllClear,
+                CheckCond::AllSet,
+                CheckCond::AnyClear,
+                CheckCond::AnySet,
+            ] {
+                let check = Check::new(width, cond, 0x2000_0000, 0x8000_0001);
+                commands.push(Command::Check(check.clone()));
+                commands.push(Command::Check(check.with_count(u32::MAX)));
+            }
+        }
+        commands.push(Command::WriteGroup(WriteGroup::new(
+            Width::B4,
+            WriteOp::Write,
+            vec![],
+        )));
+        commands.push(Command::Unlock(Unlock::new(Unlock::ENGINE_CAAM, 7)));
+        commands.push(Command::Unlock(
+            Unlock::new(Unlock::ENGINE_OCOTP, 1).with_uid(0x0123_4567_89AB_CDEF),
+        ));
+
+        let packed = PackedCommands::from(&commands[..]);
+        assert_eq!(packed.len(), commands.len());
+        assert_eq!(packed.to_vec(), commands);
+        assert_eq!(packed.get(commands.len()), None);
+        assert_eq!(packed.pairs.len(), 9 * 2);
+    }
+
+    #[test]
+    fn serialize() {
+        let commands = [
+            Command::write32(0x400F_C068, 0xFFFF_FFFF),
+            Command::write32(0x400F_C06C, 0xFFFF_FFFF),
+            Command::check32(CheckCond::AllSet, 0x400D_8000, 1 << 31),
+        ];
+        let mut expected = vec![];
+        let byte_len = crate::serialize(&mut expected, &commands).unwrap();
+        let mut bytes = vec![];
+        let packed: PackedCommands = commands.iter().cloned().collect();
+        assert_eq!(packed.serialize(&mut bytes).unwrap(), byte_len);
+        assert_eq!(bytes, expected);
+
+        let mut bytes = vec![];
+        assert_eq!(PackedCommands::new().serialize(&mut bytes).unwrap(), 0);
+        assert!(bytes.is_empty());
+    }
+}
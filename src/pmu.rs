@@ -0,0 +1,306 @@
+//! Helpers for the linear regulators of the Power Management Unit (`PMU_REG_1P1`, `PMU_REG_2P5`,
+//! `PMU_REG_3P0`), e.g. on boards powering an external PHY from them.
+//!
+//! ```
+//! # use imxrt_dcd::{pmu::*, CheckCond, Command, Target};
+//! let commands = RegulatorConfig::new(Target::Imxrt1060, Regulator::Reg2P5)
+//!     .output_mv(2_525)
+//!     .brownout(Some(100))
+//!     .enable(true)
+//!     .commands()
+//!     .unwrap();
+//! assert_eq!(
+//!     commands,
+//!     [
+//!         // OUTPUT_TRG = 0x11
+//!         Command::clear32(0x400D_8130, 0x1F << 8),
+//!         Command::set32(0x400D_8130, 0x11 << 8),
+//!         // BO_OFFSET = 4, ENABLE_BO
+//!         Command::clear32(0x400D_8130, 7 << 4),
+//!         Command::set32(0x400D_8130, 4 << 4),
+//!         Command::set32(0x400D_8130, 1 << 1),
+//!         // ENABLE_LINREG, then wait for OK_VDD2P5
+//!         Command::set32(0x400D_8130, 1 << 0),
+//!         Command::check32(CheckCond::AllSet, 0x400D_8130, 1 << 17),
+//!     ],
+//! );
+//! ```
+//!
+//! The register layout is that of the RT1050 / RT1060 / RT1064; other targets are rejected.
+
+use std::io;
+
+use crate::board::DcdFragment;
+use crate::{registers, CheckCond, Command, Target, Width};
+
+/// `ENABLE_LINREG`: enables the regulator.
+const ENABLE_LINREG: u32 = 1 << 0;
+/// `ENABLE_BO`: enables the brownout detection.
+const ENABLE_BO: u32 = 1 << 1;
+/// `BO_OFFSET`: brownout threshold below the output voltage, in 25 mV steps.
+const BO_OFFSET: (u32, u32) = (4, 0b111);
+/// `OUTPUT_TRG`: output voltage, in 25 mV steps.
+const OUTPUT_TRG: (u32, u32) = (8, 0x1F);
+/// `OK_VDDxPx`: the output voltage is within range.
+const OK_VDD: u32 = 1 << 17;
+/// Step of `OUTPUT_TRG` and `BO_OFFSET`, in mV.
+const STEP_MV: u32 = 25;
+
+/// A linear regulator of the PMU.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Regulator {
+    /// `PMU_REG_1P1` (nominal 1.1 V).
+    Reg1P1,
+    /// `PMU_REG_2P5` (nominal 2.5 V).
+    Reg2P5,
+    /// `PMU_REG_3P0` (nominal 3.0 V).
+    Reg3P0,
+}
+
+impl Regulator {
+    /// Address of the control register.
+    pub const fn address(self) -> u32 {
+        match self {
+            Regulator::Reg1P1 => 0x400D_8110,
+            Regulator::Reg2P5 => 0x400D_8130,
+            Regulator::Reg3P0 => 0x400D_8120,
+        }
+    }
+
+    /// Range of output voltages in mV, i.e. the voltages at the smallest and largest `OUTPUT_TRG`
+    /// values.
+    ///
+    /// ```
+    /// # use imxrt_dcd::pmu::Regulator;
+    /// assert_eq!(Regulator::Reg3P0.output_range_mv(), (2_625, 3_400));
+    /// ```
+    pub const fn output_range_mv(self) -> (u32, u32) {
+        let (min_trg, max_trg) = self.output_trg_range();
+        (self.output_mv_at(min_trg), self.output_mv_at(max_trg))
+    }
+
+    /// Valid values of `OUTPUT_TRG`.
+    const fn output_trg_range(self) -> (u32, u32) {
+        match self {
+            Regulator::Reg1P1 => (0x04, 0x1B),
+            Regulator::Reg2P5 | Regulator::Reg3P0 => (0x00, 0x1F),
+        }
+    }
+
+    /// Output voltage at a value of `OUTPUT_TRG`, extrapolated outside of the valid values.
+    const fn output_mv_at(self, trg: u32) -> u32 {
+        let base = match self {
+            Regulator::Reg1P1 => 700,
+            Regulator::Reg2P5 => 2_100,
+            Regulator::Reg3P0 => 2_625,
+        };
+        base + trg * STEP_MV
+    }
+}
+
+/// Changes to the configuration of a [`Regulator`]; settings not given are not changed.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct RegulatorConfig {
+    target: Target,
+    regulator: Regulator,
+    output_mv: Option<u32>,
+    brownout: Option<Option<u32>>,
+    enable: Option<bool>,
+    poll_count: Option<u32>,
+}
+
+impl RegulatorConfig {
+    /// Creates a configuration changing nothing.
+    pub fn new(target: Target, regulator: Regulator) -> Self {
+        Self {
+            target,
+            regulator,
+            output_mv: None,
+            brownout: None,
+            enable: None,
+            poll_count: None,
+        }
+    }
+
+    /// Sets the output voltage in mV, a multiple of 25 mV within
+    /// [`Regulator::output_range_mv`].
+    pub fn output_mv(mut self, mv: u32) -> Self {
+        self.output_mv = Some(mv);
+        self
+    }
+
+    /// Enables the brownout detection at the given offset below the output voltage in mV (a
+    /// multiple of 25 mV up to 175 mV), or disables it with `None`.
+    pub fn brownout(mut self, offset_mv: Option<u32>) -> Self {
+        self.brownout = Some(offset_mv);
+        self
+    }
+
+    /// Enables or disables the regulator.
+    pub fn enable(mut self, enable: bool) -> Self {
+        self.enable = Some(enable);
+        self
+    }
+
+    /// Sets the poll count of the check waiting for the output voltage; by default, the check
+    /// polls indefinitely.
+    pub fn poll_count(mut self, count: u32) -> Self {
+        self.poll_count = Some(count);
+        self
+    }
+
+    /// Returns the commands applying the configuration: output voltage, brownout detection, then
+    /// enable. Unless the regulator is being disabled, a change of the output voltage or enable
+    /// is followed by waiting for the `OK_VDDxPx` flag.
+    ///
+    /// Returns [`io::ErrorKind::Unsupported`] if the register layout of the target is not known,
+    /// and [`io::ErrorKind::InvalidInput`] if a voltage is out of range or not a multiple of
+    /// 25 mV.
+    // `u32::is_multiple_of` requires Rust 1.87
+    #[allow(clippy::manual_is_multiple_of)]
+    pub fn commands(&self) -> io::Result<Vec<Command>> {
+        if !matches!(
+            self.target,
+            Target::Imxrt1050 | Target::Imxrt1060 | Target::Imxrt1064
+        ) {
+            return Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                format!("the PMU layout of {:?} is not known", self.target),
+            ));
+        }
+        let address = self.regulator.address();
+        let steps = |mv: u32, what: &str| {
+            if mv % STEP_MV != 0 {
+                return Err(invalid(format!(
+                    "{} of {} mV is not a multiple of {} mV",
+                    what, mv, STEP_MV
+                )));
+            }
+            Ok(mv / STEP_MV)
+        };
+        let mut commands = vec![];
+        if let Some(mv) = self.output_mv {
+            let (min, max) = self.regulator.output_range_mv();
+            if !(min..=max).contains(&mv) {
+                return Err(invalid(format!(
+                    "output voltage of {} mV is outside of {} to {} mV for {:?}",
+                    mv, min, max, self.regulator
+                )));
+            }
+            let trg = steps(mv, "output voltage")? - self.regulator.output_mv_at(0) / STEP_MV;
+            commands.extend(modify_field(address, OUTPUT_TRG, trg));
+        }
+        match self.brownout {
+            Some(Some(mv)) => {
+                let offset = steps(mv, "brownout offset")?;
+                if offset > BO_OFFSET.1 {
+                    return Err(invalid(format!(
+                        "brownout offset of {} mV is larger than {} mV",
+                        mv,
+                        BO_OFFSET.1 * STEP_MV
+                    )));
+                }
+                commands.extend(modify_field(address, BO_OFFSET, offset));
+                commands.push(Command::set32(address, ENABLE_BO));
+            }
+            Some(None) => commands.push(Command::clear32(address, ENABLE_BO)),
+            None => {}
+        }
+        match self.enable {
+            Some(true) => commands.push(Command::set32(address, ENABLE_LINREG)),
+            Some(false) => commands.push(Command::clear32(address, ENABLE_LINREG)),
+            None => {}
+        }
+        if self.enable == Some(true) || (self.enable.is_none() && self.output_mv.is_some()) {
+            let check = Command::check32(CheckCond::AllSet, address, OK_VDD);
+            commands.push(match self.poll_count {
+                Some(count) => check.with_count(count),
+                None => check,
+            });
+        }
+        Ok(commands)
+    }
+}
+
+impl DcdFragment for RegulatorConfig {
+    fn name(&self) -> &str {
+        match self.regulator {
+            Regulator::Reg1P1 => "PMU_REG_1P1",
+            Regulator::Reg2P5 => "PMU_REG_2P5",
+            Regulator::Reg3P0 => "PMU_REG_3P0",
+        }
+    }
+
+    /// # Panics
+    ///
+    /// Panics if the configuration is invalid (see [`RegulatorConfig::commands`]).
+    fn commands(&self) -> Vec<Command> {
+        RegulatorConfig::commands(self).unwrap()
+    }
+}
+
+fn invalid(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidInput, message)
+}
+
+/// Returns the commands changing the field `(offset, mask)` of a register to `value`; see
+/// [`registers::modify`].
+fn modify_field(address: u32, (offset, mask): (u32, u32), value: u32) -> Vec<Command> {
+    registers::modify(Width::B4, address, mask << offset, value << offset)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn voltages() {
+        assert_eq!(Regulator::Reg1P1.output_range_mv(), (800, 1_375));
+        assert_eq!(Regulator::Reg2P5.output_range_mv(), (2_100, 2_875));
+
+        let commands = |config: RegulatorConfig| config.commands().map_err(|e| e.to_string());
+        let config = |regulator| RegulatorConfig::new(Target::Imxrt1060, regulator);
+        assert_eq!(
+            commands(config(Regulator::Reg1P1).output_mv(1_100)).unwrap(),
+            [
+                Command::clear32(0x400D_8110, 0x1F << 8),
+                Command::set32(0x400D_8110, 0x10 << 8),
+                Command::check32(CheckCond::AllSet, 0x400D_8110, 1 << 17),
+            ]
+        );
+        assert_eq!(
+            commands(config(Regulator::Reg3P0).brownout(None).enable(false)).unwrap(),
+            [
+                Command::clear32(0x400D_8120, 1 << 1),
+                Command::clear32(0x400D_8120, 1 << 0),
+            ]
+        );
+        assert_eq!(
+            commands(config(Regulator::Reg3P0).enable(true).poll_count(1000)).unwrap()[1],
+            Command::check32(CheckCond::AllSet, 0x400D_8120, 1 << 17).with_count(1000)
+        );
+
+        assert_eq!(
+            commands(config(Regulator::Reg1P1).output_mv(775)).unwrap_err(),
+            "output voltage of 775 mV is outside of 800 to 1375 mV for Reg1P1"
+        );
+        assert_eq!(
+            commands(config(Regulator::Reg2P5).output_mv(2_510)).unwrap_err(),
+            "output voltage of 2510 mV is not a multiple of 25 mV"
+        );
+        assert_eq!(
+            commands(config(Regulator::Reg2P5).brownout(Some(200))).unwrap_err(),
+            "brownout offset of 200 mV is larger than 175 mV"
+        );
+
+        let config = RegulatorConfig::new(Target::Imxrt1020, Regulator::Reg1P1);
+        assert_eq!(
+            config.commands().unwrap_err().kind(),
+            io::ErrorKind::Unsupported
+        );
+        assert_eq!(
+            commands(config).unwrap_err(),
+            "the PMU layout of Imxrt1020 is not known"
+        );
+    }
+}
@@ -0,0 +1,206 @@
+//! Read-back verification of writes.
+//!
+//! Some registers silently ignore writes (e.g. when the peripheral clock is gated, or when the
+//! field is locked). During bring-up, it is useful to catch such writes at the point of failure
+//! instead of debugging their consequences. [`readback`] follows writes with bounded checks, so
+//! that the boot ROM abandons the rest of the DCD as soon as a write did not take effect.
+//!
+//! NOTE: The inserted checks prevent consecutive writes from being merged (see [`crate::serialize`]),
+//! costing 4--8 extra bytes per write on top of the checks themselves.
+
+use crate::{dump, symbols, Check, CheckCond, Command, Width, Write, WriteOp};
+
+/// Returns a copy of `commands` where each selected write is immediately followed by up to two
+/// checks verifying that the write took effect, each polling at most `count` times.
+///
+/// The writes of a [`crate::WriteGroup`] are checked after the whole group, which is kept intact.
+///
+/// `select` is called for each write with its index in `commands`, and returns the bits of the
+/// register to be verified, or `None` to skip the write. Read-only bits and bits that do not read
+/// back as written (e.g. write-1-to-clear, self-clearing) must be excluded from this mask. RAL field
+/// masks (e.g. `ral::ccm::CBCDR::SEMC_PODF::mask`) are handy for this.
+///
+/// Depending on [`Write::op`], the verified bits are checked as follows:
+/// - [`WriteOp::Write`] => bits set in `value` are all set, and other bits are all clear
+/// - [`WriteOp::Set`] => bits set in `value` are all set
+/// - [`WriteOp::Clear`] => bits set in `value` are all clear
+///
+/// # Example
+///
+/// ```
+/// # use imxrt_dcd::{readback::readback, Command, CheckCond};
+/// let commands = [
+///     Command::write32(0x400F_C014, 0x000A_8300),
+///     Command::set32(0x400F_C068, 0b11 << 4),
+/// ];
+/// assert_eq!(
+///     readback(&commands, 100, |_, write| (write.address == 0x400F_C014).then_some(0x0000_FF00)),
+///     [
+///         Command::write32(0x400F_C014, 0x000A_8300),
+///         Command::check32(CheckCond::AllSet, 0x400F_C014, 0x0000_8300).with_count(100),
+///         Command::check32(CheckCond::AllClear, 0x400F_C014, 0x0000_7C00).with_count(100),
+///         Command::set32(0x400F_C068, 0b11 << 4),
+///     ],
+/// );
+/// ```
+pub fn readback(
+    commands: &[Command],
+    count: u32,
+    mut select: impl FnMut(usize, &Write) -> Option<u32>,
+) -> Vec<Command> {
+    let mut result = Vec::with_capacity(commands.len());
+    for (index, command) in commands.iter().enumerate() {
+        result.push(command.clone());
+        for write in command.writes() {
+            let Some(mask) = select(index, &write) else {
+                continue;
+            };
+            let (set, clear) = match write.op {
+                WriteOp::Write => (write.value & mask, !write.value & mask),
+                WriteOp::Set => (write.value & mask, 0),
+                WriteOp::Clear => (0, write.value & mask),
+            };
+            for (cond, mask) in [(CheckCond::AllSet, set), (CheckCond::AllClear, clear)] {
+                if mask != 0 {
+                    result.push(Command::Check(
+                        Check::new(write.width, cond, write.address, mask).with_count(count),
+                    ));
+                }
+            }
+        }
+    }
+    result
+}
+
+/// Same as [`readback`], verifying all written bits that read back as written; see
+/// [`readback_mask`].
+pub fn readback_all(commands: &[Command], count: u32) -> Vec<Command> {
+    readback(commands, count, |_, write| {
+        Some(readback_mask(write.address, write.width))
+    })
+}
+
+/// Returns the bits of the register at `address` that read back as written.
+///
+/// Registers where writing has side effects (e.g. write-1-to-clear status flags, or triggering an
+/// IP command; see [`dump::diff`]) do not read back at all. With the `"ral"` feature, known
+/// registers read back the bits of their writable fields (see
+/// [`Register::writable_mask`](crate::registers::Register::writable_mask)), excluding reserved
+/// and read-only bits. Other registers are assumed to read back all bits.
+///
+/// ```
+/// # use imxrt_dcd::{readback::readback_mask, Width};
+/// assert_eq!(readback_mask(0x402F_003C, Width::B4), 0); // SEMC_INTR
+/// # #[cfg(feature = "ral")]
+/// assert_eq!(readback_mask(0x400D_8000, Width::B4), 0x0009_F07F); // CCM_ANALOG_PLL_ARM
+/// assert_eq!(readback_mask(0x2020_0000, Width::B2), 0xFFFF);
+/// ```
+#[cfg_attr(not(feature = "ral"), allow(unused_variables))]
+pub fn readback_mask(address: u32, width: Width) -> u32 {
+    let write_sensitive = symbols::peripheral(address)
+        .and_then(|(name, offset)| dump::write_sensitive(name, offset))
+        .is_some();
+    if write_sensitive {
+        return 0;
+    }
+    #[cfg(feature = "ral")]
+    if let Some(register) =
+        crate::registers::lookup(address).filter(|register| register.width == width)
+    {
+        return register.writable_mask();
+    }
+    width.mask()
+}
+
+/// Returns `write` followed by the checks of [`readback`] verifying the bits in `mask`, e.g. to
+/// write and verify a register in one step while building a list of commands.
+///
+/// See also [`Command::write_then_check32`] for the common case of a 32-bit [`WriteOp::Write`].
+///
+/// ```
+/// # use imxrt_dcd::{readback::write_then_check, CheckCond, Command, Width, Write, WriteOp};
+/// let write = Write::new(Width::B4, WriteOp::Set, 0x400F_C068, 0b11 << 4);
+/// assert_eq!(
+///     write_then_check(write.clone(), u32::MAX, 100),
+///     [
+///         Command::Write(write),
+///         Command::check32(CheckCond::AllSet, 0x400F_C068, 0b11 << 4).with_count(100),
+///     ],
+/// );
+/// ```
+pub fn write_then_check(write: Write, mask: u32, count: u32) -> Vec<Command> {
+    readback(&[Command::Write(write)], count, |_, _| Some(mask))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Width;
+
+    #[test]
+    fn all_ops() {
+        let commands = [
+            Command::Nop,
+            Command::Write(Write::new(Width::B2, WriteOp::Write, 0x1000, 0x00F0)),
+            Command::clear32(0x2000, 0x0F00_0000),
+            Command::set32(0x3000, 0),
+        ];
+        assert_eq!(
+            readback_all(&commands, 10),
+            [
+                Command::Nop,
+                Command::Write(Write::new(Width::B2, WriteOp::Write, 0x1000, 0x00F0)),
+                Command::Check(
+                    Check::new(Width::B2, CheckCond::AllSet, 0x1000, 0x00F0).with_count(10)
+                ),
+                Command::Check(
+                    Check::new(Width::B2, CheckCond::AllClear, 0x1000, 0xFF0F).with_count(10)
+                ),
+                Command::clear32(0x2000, 0x0F00_0000),
+                Command::check32(CheckCond::AllClear, 0x2000, 0x0F00_0000).with_count(10),
+                // nothing to verify
+                Command::set32(0x3000, 0),
+            ],
+        );
+    }
+
+    #[cfg(feature = "imxrt1062")]
+    #[test]
+    fn all_ops_masked() {
+        let commands = [
+            Command::set32(0x402F_003C, 0b11), // SEMC_INTR, write 1 to clear
+            Command::write32(0x400D_8000, 0x8000_2064), // CCM_ANALOG_PLL_ARM, LOCK is read-only
+        ];
+        let checks = readback_all(&commands, 10)
+            .into_iter()
+            .filter(|command| matches!(command, Command::Check(_)))
+            .collect::<Vec<_>>();
+        #[cfg(feature = "ral")]
+        assert_eq!(
+            checks,
+            [
+                Command::check32(CheckCond::AllSet, 0x400D_8000, 0x0000_2064).with_count(10),
+                Command::check32(CheckCond::AllClear, 0x400D_8000, 0x0009_D01B).with_count(10),
+            ]
+        );
+        #[cfg(not(feature = "ral"))]
+        assert_eq!(checks.len(), 2);
+    }
+
+    #[test]
+    fn write_then_check32() {
+        assert_eq!(
+            Command::write_then_check32(0x1000, 0x0000_00F0, 0x0000_0FF0, 5),
+            [
+                Command::write32(0x1000, 0x0000_00F0),
+                Command::check32(CheckCond::AllSet, 0x1000, 0x0000_00F0).with_count(5),
+                Command::check32(CheckCond::AllClear, 0x1000, 0x0000_0F00).with_count(5),
+            ],
+        );
+        let write = Write::new(Width::B1, WriteOp::Clear, 0x1000, 0xF0);
+        assert_eq!(
+            write_then_check(write.clone(), 0x0F, 5),
+            [Command::Write(write)]
+        );
+    }
+}
@@ -0,0 +1,403 @@
+//! Register and field definitions for addresses that DCD Write commands may target.
+//!
+//! The table is generated from `imxrt-ral` (see `scripts/gen-registers.py`) and covers the
+//! peripherals within the valid Write command address ranges of RT1050/1060/1064 (see
+//! [`Target::write_ranges`](crate::Target::write_ranges)). Names match `imxrt-ral`, so they can be
+//! used with the [convenience macros](crate#convenience-macros).
+//!
+//! ```
+//! # use imxrt_dcd::registers;
+//! let cbcdr = registers::lookup(0x400F_C014).unwrap();
+//! assert_eq!((cbcdr.peripheral, cbcdr.name), ("CCM", "CBCDR"));
+//! assert_eq!(cbcdr.field("SEMC_PODF").unwrap().mask(), 0b111 << 16);
+//! ```
+//!
+//! [`FieldWrites`] programs fields by name, e.g. from configuration files or the
+//! [`DcdFragment`](crate::board::DcdFragment) derive.
+
+use std::io;
+
+use crate::{Command, Width, Write, WriteOp};
+
+#[cfg(any(feature = "imxrt1052", feature = "imxrt1062", feature = "imxrt1064"))]
+#[rustfmt::skip]
+mod imxrt1062;
+
+/// Access permissions of a register.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Access {
+    ReadOnly,
+    WriteOnly,
+    ReadWrite,
+}
+
+/// A register, e.g. `CCM_CBCDR`.
+#[derive(Debug, Eq, PartialEq)]
+pub struct Register {
+    /// `imxrt-ral` module of the peripheral, e.g. `ccm`.
+    pub module: &'static str,
+    /// Peripheral instance, e.g. `CCM`.
+    pub peripheral: &'static str,
+    /// Register name within the peripheral, e.g. `CBCDR`; elements of register arrays are named
+    /// like `GPR[3]`.
+    pub name: &'static str,
+    pub address: u32,
+    pub width: Width,
+    pub access: Access,
+    pub description: &'static str,
+    /// Fields, ordered by offset.
+    pub fields: &'static [Field],
+}
+
+/// A bit field of a [`Register`].
+#[derive(Debug, Eq, PartialEq)]
+pub struct Field {
+    pub name: &'static str,
+    /// Offset of the lowest bit.
+    pub offset: u8,
+    /// Number of bits.
+    pub width: u8,
+    /// Access permissions; read-only fields of read-write registers (e.g. `LOCK` of the PLLs) are
+    /// taken from the reference manual, since `imxrt-ral` does not define them.
+    pub access: Access,
+    pub description: &'static str,
+}
+
+impl Field {
+    /// Returns the bits of the field within the register.
+    pub const fn mask(&self) -> u32 {
+        (u32::MAX >> (32 - self.width)) << self.offset
+    }
+}
+
+impl Register {
+    /// Returns the union of the masks of all fields.
+    pub fn fields_mask(&self) -> u32 {
+        self.fields
+            .iter()
+            .fold(0, |mask, field| mask | field.mask())
+    }
+
+    /// Returns the bits that read back as written: the bits of the writable fields, or all bits
+    /// of a read-write register without fields. Empty for read-only and write-only registers.
+    pub fn writable_mask(&self) -> u32 {
+        match self.access {
+            Access::ReadWrite if self.fields.is_empty() => self.width.mask(),
+            Access::ReadWrite => self
+                .fields
+                .iter()
+                .filter(|field| field.access == Access::ReadWrite)
+                .fold(0, |mask, field| mask | field.mask()),
+            _ => 0,
+        }
+    }
+
+    /// Finds a field by name.
+    pub const fn field(&self, name: &str) -> Option<&'static Field> {
+        let fields: &'static [Field] = self.fields;
+        let mut i = 0;
+        while i < fields.len() {
+            if str_eq(fields[i].name, name) {
+                return Some(&fields[i]);
+            }
+            i += 1;
+        }
+        None
+    }
+}
+
+/// All known registers, ordered by address; empty unless one of the `imxrt1052`, `imxrt1062` or
+/// `imxrt1064` features is enabled.
+pub const fn all() -> &'static [Register] {
+    #[cfg(any(feature = "imxrt1052", feature = "imxrt1062", feature = "imxrt1064"))]
+    return imxrt1062::REGISTERS;
+    #[cfg(not(any(feature = "imxrt1052", feature = "imxrt1062", feature = "imxrt1064")))]
+    &[]
+}
+
+/// Finds the register at the given address.
+pub fn lookup(address: u32) -> Option<&'static Register> {
+    let registers = all();
+    registers
+        .binary_search_by_key(&address, |register| register.address)
+        .ok()
+        .map(|index| &registers[index])
+}
+
+/// Finds a register by peripheral instance and register name, e.g. `("CCM", "CBCDR")`.
+///
+/// Like [`Register::field`], this is a `const fn`, so that names can be checked at compile time:
+///
+/// ```
+/// # use imxrt_dcd::registers;
+/// const CBCDR: &registers::Register = registers::by_name("CCM", "CBCDR").unwrap();
+/// const _: () = assert!(CBCDR.field("SEMC_PODF").is_some());
+/// ```
+pub const fn by_name(peripheral: &str, name: &str) -> Option<&'static Register> {
+    let registers = all();
+    let mut i = 0;
+    while i < registers.len() {
+        let register = &registers[i];
+        if str_eq(register.peripheral, peripheral) && str_eq(register.name, name) {
+            return Some(register);
+        }
+        i += 1;
+    }
+    None
+}
+
+/// `a == b`, in const contexts.
+const fn str_eq(a: &str, b: &str) -> bool {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < a.len() {
+        if a[i] != b[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Field values to program into registers given by name.
+///
+/// Produces the same `[clear, set]` command pair per register as
+/// [`write_field!`](crate::write_field) (see [`modify`]), with all fields of a register combined,
+/// and a single Write for a register that is [set as a whole](FieldWrites::register):
+///
+/// ```
+/// # use imxrt_dcd::{registers::FieldWrites, Command};
+/// let mut writes = FieldWrites::new();
+/// writes.field("CCM", "CBCDR", "SEMC_PODF", 2).unwrap();
+/// writes.field("CCM", "CBCDR", "AHB_PODF", 0).unwrap();
+/// writes.register("SEMC", "MCR", 0x1000_0004).unwrap();
+/// assert_eq!(
+///     writes.commands(),
+///     [
+///         Command::clear32(0x400F_C014, 0b111 << 16 | 0b111 << 10),
+///         Command::set32(0x400F_C014, 2 << 16),
+///         Command::write32(0x402F_0000, 0x1000_0004),
+///     ],
+/// );
+/// assert!(writes.field("CCM", "CBCDR", "SEMC_PODF", 8).is_err());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct FieldWrites {
+    /// Registers in order of first use, with the mask and value of the bits set so far.
+    registers: Vec<(&'static Register, u32, u32)>,
+}
+
+impl FieldWrites {
+    /// Creates an empty set of writes.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets a field of a register, e.g. `("CCM", "CBCDR", "SEMC_PODF", 2)`; `value` is not
+    /// shifted to the field offset yet.
+    ///
+    /// Returns [`io::ErrorKind::InvalidInput`] if the register or field is unknown, the register
+    /// is read-only, or the value does not fit in the field.
+    pub fn field(
+        &mut self,
+        peripheral: &str,
+        register: &str,
+        field: &str,
+        value: u32,
+    ) -> io::Result<()> {
+        let reg = writable(peripheral, register)?;
+        let field = reg.field(field).ok_or_else(|| {
+            invalid_input(format!(
+                "unknown field `{}` of register `{}_{}`",
+                field, peripheral, register
+            ))
+        })?;
+        if value > field.mask() >> field.offset {
+            return Err(invalid_input(format!(
+                "value {:#X} does not fit in field `{}_{}.{}`",
+                value, peripheral, register, field.name
+            )));
+        }
+        self.set(reg, field.mask(), value << field.offset);
+        Ok(())
+    }
+
+    /// Sets all bits of a register, e.g. `("SEMC", "MCR", 0x1000_0004)`.
+    ///
+    /// Returns [`io::ErrorKind::InvalidInput`] if the register is unknown or read-only, or the
+    /// value does not fit in the register.
+    pub fn register(&mut self, peripheral: &str, register: &str, value: u32) -> io::Result<()> {
+        let reg = writable(peripheral, register)?;
+        let mask = reg.width.mask();
+        if value & !mask != 0 {
+            return Err(invalid_input(format!(
+                "value {:#X} does not fit in register `{}_{}`",
+                value, peripheral, register
+            )));
+        }
+        self.set(reg, mask, value);
+        Ok(())
+    }
+
+    /// Returns the commands programming the registers, in order of first use; see [`modify`].
+    pub fn commands(&self) -> Vec<Command> {
+        self.registers
+            .iter()
+            .flat_map(|&(register, mask, value)| {
+                modify(register.width, register.address, mask, value)
+            })
+            .collect()
+    }
+
+    fn set(&mut self, register: &'static Register, mask: u32, value: u32) {
+        match self
+            .registers
+            .iter_mut()
+            .find(|(other, _, _)| other.address == register.address)
+        {
+            Some((_, old_mask, old_value)) => {
+                *old_mask |= mask;
+                *old_value = *old_value & !mask | value;
+            }
+            None => self.registers.push((register, mask, value)),
+        }
+    }
+}
+
+/// Returns the commands changing the bits in `mask` of the register at `address` to `value`,
+/// given shifted: a Clear of `mask`, then a Set of the new bits (left out if there are none), or a
+/// single Write if `mask` covers the whole register.
+///
+/// The bits are cleared first, as with [`write_field!`](crate::write_field), so that a multi-bit
+/// field never holds a mix of the old and the new value, e.g. a clock mux selecting a source
+/// that is neither the old nor the new one.
+///
+/// ```
+/// # use imxrt_dcd::{registers::modify, Command, Width};
+/// assert_eq!(
+///     modify(Width::B4, 0x400F_C014, 0b111 << 16, 2 << 16),
+///     [Command::clear32(0x400F_C014, 0b111 << 16), Command::set32(0x400F_C014, 2 << 16)],
+/// );
+/// assert_eq!(
+///     modify(Width::B4, 0x400F_C014, 0b111 << 16, 0),
+///     [Command::clear32(0x400F_C014, 0b111 << 16)],
+/// );
+/// ```
+pub fn modify(width: Width, address: u32, mask: u32, value: u32) -> Vec<Command> {
+    let write = |op, value| Command::Write(Write::new(width, op, address, value));
+    let value = value & mask;
+    if mask == width.mask() {
+        return vec![write(WriteOp::Write, value)];
+    }
+    let mut commands = vec![write(WriteOp::Clear, mask)];
+    if value != 0 {
+        commands.push(write(WriteOp::Set, value));
+    }
+    commands
+}
+
+fn writable(peripheral: &str, name: &str) -> io::Result<&'static Register> {
+    let register = by_name(peripheral, name)
+        .ok_or_else(|| invalid_input(format!("unknown register `{}_{}`", peripheral, name)))?;
+    if register.access == Access::ReadOnly {
+        return Err(invalid_input(format!(
+            "register `{}_{}` is read-only",
+            peripheral, name
+        )));
+    }
+    Ok(register)
+}
+
+fn invalid_input(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidInput, message)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn table() {
+        assert!(all().windows(2).all(|w| w[0].address < w[1].address));
+        for register in all() {
+            let mut mask = 0;
+            for field in register.fields {
+                assert!(field.offset + field.width <= 8 * register.width as u8);
+                assert_eq!(mask & field.mask(), 0, "{} overlaps", field.name);
+                mask |= field.mask();
+            }
+        }
+    }
+
+    #[test]
+    fn lookup_by_address_and_name() {
+        let pll_arm = lookup(0x400D_8000).unwrap();
+        assert_eq!(
+            (pll_arm.peripheral, pll_arm.name),
+            ("CCM_ANALOG", "PLL_ARM")
+        );
+        assert_eq!(pll_arm.field("DIV_SELECT").unwrap().mask(), 0x7F);
+        assert_eq!(pll_arm.field("LOCK").unwrap().access, Access::ReadOnly);
+        assert_eq!(pll_arm.writable_mask(), pll_arm.fields_mask() & !(1 << 31));
+        assert_eq!(lookup(0x400F_C048).unwrap().writable_mask(), 0); // CCM_CDHIPR
+        assert_eq!(by_name("CCM_ANALOG", "PLL_ARM"), Some(pll_arm));
+        assert_eq!(
+            by_name("IOMUXC", "SW_MUX_CTL_PAD_GPIO_AD_B0_09")
+                .unwrap()
+                .address,
+            0x401F_80E0
+        );
+        assert_eq!(lookup(0x400D_8002), None);
+        assert_eq!(lookup(0x401B_8000), None);
+    }
+
+    #[test]
+    fn field_writes() {
+        let mut writes = FieldWrites::new();
+        writes
+            .field("CCM_ANALOG", "PLL_ARM", "DIV_SELECT", 100)
+            .unwrap();
+        writes
+            .register("IOMUXC", "SW_MUX_CTL_PAD_GPIO_AD_B0_09", 5)
+            .unwrap();
+        writes.field("CCM_ANALOG", "PLL_ARM", "BYPASS", 1).unwrap();
+        writes
+            .field("CCM_ANALOG", "PLL_ARM", "DIV_SELECT", 88)
+            .unwrap();
+        assert_eq!(
+            writes.commands(),
+            [
+                Command::clear32(0x400D_8000, 0x7F | 1 << 16),
+                Command::set32(0x400D_8000, 88 | 1 << 16),
+                Command::write32(0x401F_80E0, 5),
+            ]
+        );
+
+        let error = |result: io::Result<()>| {
+            let err = result.unwrap_err();
+            assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+            err.to_string()
+        };
+        let mut writes = FieldWrites::new();
+        assert_eq!(
+            error(writes.field("CCM", "CBCDR", "PODF", 0)),
+            "unknown field `PODF` of register `CCM_CBCDR`"
+        );
+        assert_eq!(
+            error(writes.register("CCM", "CBCDR2", 0)),
+            "unknown register `CCM_CBCDR2`"
+        );
+        assert_eq!(
+            error(writes.field("CCM_ANALOG", "PLL_ARM", "DIV_SELECT", 0x80)),
+            "value 0x80 does not fit in field `CCM_ANALOG_PLL_ARM.DIV_SELECT`"
+        );
+        assert_eq!(
+            error(writes.register("IOMUXC_SNVS_GPR", "GPR0", 0)),
+            "register `IOMUXC_SNVS_GPR_GPR0` is read-only"
+        );
+        assert!(writes.commands().is_empty());
+    }
+}
@@ -0,0 +1,2000 @@
+//! Generated by `scripts/gen-registers.py` from imxrt-ral (imxrt-ral-0.5.3); do not edit.
+
+use super::{Access, Field, Register};
+use crate::Width;
+
+const F0: &[Field] = &[
+];
+const F1: &[Field] = &[
+    Field { name: "LPSR_MODE_ENABLE", offset: 0, width: 1, access: Access::ReadWrite, description: "Set to enable LPSR mode." },
+    Field { name: "DCDC_STATUS_CAPT_CLR", offset: 1, width: 1, access: Access::ReadWrite, description: "DCDC captured status clear" },
+    Field { name: "POR_PULL_TYPE", offset: 2, width: 2, access: Access::ReadWrite, description: "POR_B pad control" },
+    Field { name: "DCDC_IN_LOW_VOL", offset: 16, width: 1, access: Access::ReadWrite, description: "DCDC_IN low voltage detect." },
+    Field { name: "DCDC_OVER_CUR", offset: 17, width: 1, access: Access::ReadWrite, description: "DCDC output over current alert" },
+    Field { name: "DCDC_OVER_VOL", offset: 18, width: 1, access: Access::ReadWrite, description: "DCDC output over voltage alert" },
+    Field { name: "DCDC_STS_DC_OK", offset: 19, width: 1, access: Access::ReadWrite, description: "DCDC status OK" },
+];
+const F2: &[Field] = &[
+    Field { name: "MUX_MODE", offset: 0, width: 3, access: Access::ReadWrite, description: "MUX Mode Select Field." },
+    Field { name: "SION", offset: 4, width: 1, access: Access::ReadWrite, description: "Software Input On Field." },
+];
+const F3: &[Field] = &[
+    Field { name: "SRE", offset: 0, width: 1, access: Access::ReadWrite, description: "Slew Rate Field" },
+    Field { name: "DSE", offset: 3, width: 3, access: Access::ReadWrite, description: "Drive Strength Field" },
+    Field { name: "SPEED", offset: 6, width: 2, access: Access::ReadWrite, description: "Speed Field" },
+    Field { name: "ODE", offset: 11, width: 1, access: Access::ReadWrite, description: "Open Drain Enable Field" },
+    Field { name: "PKE", offset: 12, width: 1, access: Access::ReadWrite, description: "Pull / Keep Enable Field" },
+    Field { name: "PUE", offset: 13, width: 1, access: Access::ReadWrite, description: "Pull / Keep Select Field" },
+    Field { name: "PUS", offset: 14, width: 2, access: Access::ReadWrite, description: "Pull Up / Down Config. Field" },
+    Field { name: "HYS", offset: 16, width: 1, access: Access::ReadWrite, description: "Hyst. Enable Field" },
+];
+const F4: &[Field] = &[
+    Field { name: "SAI1_MCLK1_SEL", offset: 0, width: 3, access: Access::ReadWrite, description: "SAI1 MCLK1 source select" },
+    Field { name: "SAI1_MCLK2_SEL", offset: 3, width: 3, access: Access::ReadWrite, description: "SAI1 MCLK2 source select" },
+    Field { name: "SAI1_MCLK3_SEL", offset: 6, width: 2, access: Access::ReadWrite, description: "SAI1 MCLK3 source select" },
+    Field { name: "SAI2_MCLK3_SEL", offset: 8, width: 2, access: Access::ReadWrite, description: "SAI2 MCLK3 source select" },
+    Field { name: "SAI3_MCLK3_SEL", offset: 10, width: 2, access: Access::ReadWrite, description: "SAI3 MCLK3 source select" },
+    Field { name: "GINT", offset: 12, width: 1, access: Access::ReadWrite, description: "Global interrupt \\\"0\\\" bit (connected to ARM M7 IRQ#0 and GPC)" },
+    Field { name: "ENET1_CLK_SEL", offset: 13, width: 1, access: Access::ReadWrite, description: "ENET1 reference clock mode select." },
+    Field { name: "ENET2_CLK_SEL", offset: 14, width: 1, access: Access::ReadWrite, description: "ENET2 reference clock mode select." },
+    Field { name: "USB_EXP_MODE", offset: 15, width: 1, access: Access::ReadWrite, description: "USB Exposure mode" },
+    Field { name: "ENET1_TX_CLK_DIR", offset: 17, width: 1, access: Access::ReadWrite, description: "ENET1_TX_CLK data direction control" },
+    Field { name: "ENET2_TX_CLK_DIR", offset: 18, width: 1, access: Access::ReadWrite, description: "ENET2_TX_CLK data direction control" },
+    Field { name: "SAI1_MCLK_DIR", offset: 19, width: 1, access: Access::ReadWrite, description: "sai1.MCLK signal direction control" },
+    Field { name: "SAI2_MCLK_DIR", offset: 20, width: 1, access: Access::ReadWrite, description: "sai2.MCLK signal direction control" },
+    Field { name: "SAI3_MCLK_DIR", offset: 21, width: 1, access: Access::ReadWrite, description: "sai3.MCLK signal direction control" },
+    Field { name: "EXC_MON", offset: 22, width: 1, access: Access::ReadWrite, description: "Exclusive monitor response select of illegal command" },
+    Field { name: "ENET_IPG_CLK_S_EN", offset: 23, width: 1, access: Access::ReadWrite, description: "ENET and ENET2 ipg_clk_s clock gating enable" },
+    Field { name: "CM7_FORCE_HCLK_EN", offset: 31, width: 1, access: Access::ReadWrite, description: "ARM CM7 platform AHB clock enable" },
+];
+const F5: &[Field] = &[
+    Field { name: "AXBS_L_AHBXL_HIGH_PRIORITY", offset: 0, width: 1, access: Access::ReadWrite, description: "AXBS_L AHBXL master has higher priority.Do not set both DMA and AHBXL to high priority." },
+    Field { name: "AXBS_L_DMA_HIGH_PRIORITY", offset: 1, width: 1, access: Access::ReadWrite, description: "AXBS_L DMA master has higher priority.Do not set both DMA and AHBXL to high priority." },
+    Field { name: "AXBS_L_FORCE_ROUND_ROBIN", offset: 2, width: 1, access: Access::ReadWrite, description: "Force Round Robin in AXBS_L" },
+    Field { name: "AXBS_P_M0_HIGH_PRIORITY", offset: 3, width: 1, access: Access::ReadWrite, description: "AXBS_P M0 master has higher priority.Do not set both M1 and M0 to high priority." },
+    Field { name: "AXBS_P_M1_HIGH_PRIORITY", offset: 4, width: 1, access: Access::ReadWrite, description: "AXBS_P M1 master has higher priority.Do not set both M1 and M0 to high priority." },
+    Field { name: "AXBS_P_FORCE_ROUND_ROBIN", offset: 5, width: 1, access: Access::ReadWrite, description: "Force Round Robin in AXBS_P. This bit can override master M0 M1 high priority configuration." },
+    Field { name: "CANFD_FILTER_BYPASS", offset: 6, width: 1, access: Access::ReadWrite, description: "Disable CANFD filter" },
+    Field { name: "L2_MEM_EN_POWERSAVING", offset: 12, width: 1, access: Access::ReadWrite, description: "enable power saving features on L2 memory" },
+    Field { name: "RAM_AUTO_CLK_GATING_EN", offset: 13, width: 1, access: Access::ReadWrite, description: "Automatically gate off RAM clock when RAM is not accessed." },
+    Field { name: "L2_MEM_DEEPSLEEP", offset: 14, width: 1, access: Access::ReadWrite, description: "control how memory enter Deep Sleep mode (shutdown periphery power, but maintain memory contents, outputs of memory are pulled low)" },
+    Field { name: "MQS_CLK_DIV", offset: 16, width: 8, access: Access::ReadWrite, description: "Divider ratio control for mclk from hmclk. mclk frequency = 1/(n+1) * hmclk frequency." },
+    Field { name: "MQS_SW_RST", offset: 24, width: 1, access: Access::ReadWrite, description: "MQS software reset" },
+    Field { name: "MQS_EN", offset: 25, width: 1, access: Access::ReadWrite, description: "MQS enable." },
+    Field { name: "MQS_OVERSAMPLE", offset: 26, width: 1, access: Access::ReadWrite, description: "Used to control the PWM oversampling rate compared with mclk." },
+    Field { name: "QTIMER1_TMR_CNTS_FREEZE", offset: 28, width: 1, access: Access::ReadWrite, description: "QTIMER1 timer counter freeze" },
+    Field { name: "QTIMER2_TMR_CNTS_FREEZE", offset: 29, width: 1, access: Access::ReadWrite, description: "QTIMER2 timer counter freeze" },
+    Field { name: "QTIMER3_TMR_CNTS_FREEZE", offset: 30, width: 1, access: Access::ReadWrite, description: "QTIMER3 timer counter freeze" },
+    Field { name: "QTIMER4_TMR_CNTS_FREEZE", offset: 31, width: 1, access: Access::ReadWrite, description: "QTIMER4 timer counter freeze" },
+];
+const F6: &[Field] = &[
+    Field { name: "OCRAM_CTL", offset: 0, width: 4, access: Access::ReadWrite, description: "OCRAM_CTL\\\\[3\\\\] - write address pipeline control bit" },
+    Field { name: "DCP_KEY_SEL", offset: 4, width: 1, access: Access::ReadWrite, description: "Select 128-bit dcp key from 256-bit key from snvs/ocotp" },
+    Field { name: "OCRAM2_CTL", offset: 8, width: 4, access: Access::ReadWrite, description: "OCRAM2_CTL\\\\[3\\\\] - write address pipeline control bit" },
+    Field { name: "AXBS_L_HALT_REQ", offset: 15, width: 1, access: Access::ReadWrite, description: "Request to halt axbs_l" },
+    Field { name: "OCRAM_STATUS", offset: 16, width: 4, access: Access::ReadWrite, description: "This field shows the OCRAM pipeline settings status, controlled by OCRAM_CTL bits respectively" },
+    Field { name: "OCRAM2_STATUS", offset: 24, width: 4, access: Access::ReadWrite, description: "This field shows the OCRAM2 pipeline settings status, controlled by OCRAM2_CTL bits respectively" },
+    Field { name: "AXBS_L_HALTED", offset: 31, width: 1, access: Access::ReadWrite, description: "This bit shows the status of axbs_l" },
+];
+const F7: &[Field] = &[
+    Field { name: "EDMA_STOP_REQ", offset: 0, width: 1, access: Access::ReadWrite, description: "EDMA stop request." },
+    Field { name: "CAN1_STOP_REQ", offset: 1, width: 1, access: Access::ReadWrite, description: "CAN1 stop request." },
+    Field { name: "CAN2_STOP_REQ", offset: 2, width: 1, access: Access::ReadWrite, description: "CAN2 stop request." },
+    Field { name: "TRNG_STOP_REQ", offset: 3, width: 1, access: Access::ReadWrite, description: "TRNG stop request." },
+    Field { name: "ENET_STOP_REQ", offset: 4, width: 1, access: Access::ReadWrite, description: "ENET stop request." },
+    Field { name: "SAI1_STOP_REQ", offset: 5, width: 1, access: Access::ReadWrite, description: "SAI1 stop request." },
+    Field { name: "SAI2_STOP_REQ", offset: 6, width: 1, access: Access::ReadWrite, description: "SAI2 stop request." },
+    Field { name: "SAI3_STOP_REQ", offset: 7, width: 1, access: Access::ReadWrite, description: "SAI3 stop request." },
+    Field { name: "ENET2_STOP_REQ", offset: 8, width: 1, access: Access::ReadWrite, description: "ENET2 stop request." },
+    Field { name: "SEMC_STOP_REQ", offset: 9, width: 1, access: Access::ReadWrite, description: "SEMC stop request." },
+    Field { name: "PIT_STOP_REQ", offset: 10, width: 1, access: Access::ReadWrite, description: "PIT stop request." },
+    Field { name: "FLEXSPI_STOP_REQ", offset: 11, width: 1, access: Access::ReadWrite, description: "FlexSPI stop request." },
+    Field { name: "FLEXIO1_STOP_REQ", offset: 12, width: 1, access: Access::ReadWrite, description: "FlexIO1 stop request." },
+    Field { name: "FLEXIO2_STOP_REQ", offset: 13, width: 1, access: Access::ReadWrite, description: "FlexIO2 stop request." },
+    Field { name: "FLEXIO3_STOP_REQ", offset: 14, width: 1, access: Access::ReadWrite, description: "On-platform flexio3 stop request." },
+    Field { name: "FLEXSPI2_STOP_REQ", offset: 15, width: 1, access: Access::ReadWrite, description: "FlexSPI2 stop request." },
+    Field { name: "EDMA_STOP_ACK", offset: 16, width: 1, access: Access::ReadWrite, description: "EDMA stop acknowledge. This is a status (read-only) bit" },
+    Field { name: "CAN1_STOP_ACK", offset: 17, width: 1, access: Access::ReadWrite, description: "CAN1 stop acknowledge." },
+    Field { name: "CAN2_STOP_ACK", offset: 18, width: 1, access: Access::ReadWrite, description: "CAN2 stop acknowledge." },
+    Field { name: "TRNG_STOP_ACK", offset: 19, width: 1, access: Access::ReadWrite, description: "TRNG stop acknowledge" },
+    Field { name: "ENET_STOP_ACK", offset: 20, width: 1, access: Access::ReadWrite, description: "ENET stop acknowledge." },
+    Field { name: "SAI1_STOP_ACK", offset: 21, width: 1, access: Access::ReadWrite, description: "SAI1 stop acknowledge" },
+    Field { name: "SAI2_STOP_ACK", offset: 22, width: 1, access: Access::ReadWrite, description: "SAI2 stop acknowledge" },
+    Field { name: "SAI3_STOP_ACK", offset: 23, width: 1, access: Access::ReadWrite, description: "SAI3 stop acknowledge" },
+    Field { name: "ENET2_STOP_ACK", offset: 24, width: 1, access: Access::ReadWrite, description: "ENET2 stop acknowledge." },
+    Field { name: "SEMC_STOP_ACK", offset: 25, width: 1, access: Access::ReadWrite, description: "SEMC stop acknowledge" },
+    Field { name: "PIT_STOP_ACK", offset: 26, width: 1, access: Access::ReadWrite, description: "PIT stop acknowledge" },
+    Field { name: "FLEXSPI_STOP_ACK", offset: 27, width: 1, access: Access::ReadWrite, description: "FLEXSPI stop acknowledge" },
+    Field { name: "FLEXIO1_STOP_ACK", offset: 28, width: 1, access: Access::ReadWrite, description: "FLEXIO1 stop acknowledge" },
+    Field { name: "FLEXIO2_STOP_ACK", offset: 29, width: 1, access: Access::ReadWrite, description: "FLEXIO2 stop acknowledge" },
+    Field { name: "FLEXIO3_STOP_ACK", offset: 30, width: 1, access: Access::ReadWrite, description: "On-platform FLEXIO3 stop acknowledge" },
+    Field { name: "FLEXSPI2_STOP_ACK", offset: 31, width: 1, access: Access::ReadWrite, description: "FLEXSPI2 stop acknowledge" },
+];
+const F8: &[Field] = &[
+    Field { name: "WDOG1_MASK", offset: 6, width: 1, access: Access::ReadWrite, description: "WDOG1 Timeout Mask" },
+    Field { name: "WDOG2_MASK", offset: 7, width: 1, access: Access::ReadWrite, description: "WDOG2 Timeout Mask" },
+    Field { name: "GPT2_CAPIN1_SEL", offset: 23, width: 1, access: Access::ReadWrite, description: "GPT2 input capture channel 1 source select" },
+    Field { name: "GPT2_CAPIN2_SEL", offset: 24, width: 1, access: Access::ReadWrite, description: "GPT2 input capture channel 2 source select" },
+    Field { name: "ENET_EVENT3IN_SEL", offset: 25, width: 1, access: Access::ReadWrite, description: "ENET input timer event3 source select" },
+    Field { name: "ENET2_EVENT3IN_SEL", offset: 26, width: 1, access: Access::ReadWrite, description: "ENET2 input timer event3 source select" },
+    Field { name: "VREF_1M_CLK_GPT1", offset: 28, width: 1, access: Access::ReadWrite, description: "GPT1 1 MHz clock source select" },
+    Field { name: "VREF_1M_CLK_GPT2", offset: 29, width: 1, access: Access::ReadWrite, description: "GPT2 1 MHz clock source select" },
+];
+const F9: &[Field] = &[
+    Field { name: "QTIMER1_TRM0_INPUT_SEL", offset: 0, width: 1, access: Access::ReadWrite, description: "QTIMER1 TMR0 input select" },
+    Field { name: "QTIMER1_TRM1_INPUT_SEL", offset: 1, width: 1, access: Access::ReadWrite, description: "QTIMER1 TMR1 input select" },
+    Field { name: "QTIMER1_TRM2_INPUT_SEL", offset: 2, width: 1, access: Access::ReadWrite, description: "QTIMER1 TMR2 input select" },
+    Field { name: "QTIMER1_TRM3_INPUT_SEL", offset: 3, width: 1, access: Access::ReadWrite, description: "QTIMER1 TMR3 input select" },
+    Field { name: "QTIMER2_TRM0_INPUT_SEL", offset: 4, width: 1, access: Access::ReadWrite, description: "QTIMER2 TMR0 input select" },
+    Field { name: "QTIMER2_TRM1_INPUT_SEL", offset: 5, width: 1, access: Access::ReadWrite, description: "QTIMER2 TMR1 input select" },
+    Field { name: "QTIMER2_TRM2_INPUT_SEL", offset: 6, width: 1, access: Access::ReadWrite, description: "QTIMER2 TMR2 input select" },
+    Field { name: "QTIMER2_TRM3_INPUT_SEL", offset: 7, width: 1, access: Access::ReadWrite, description: "QTIMER2 TMR3 input select" },
+    Field { name: "QTIMER3_TRM0_INPUT_SEL", offset: 8, width: 1, access: Access::ReadWrite, description: "QTIMER3 TMR0 input select" },
+    Field { name: "QTIMER3_TRM1_INPUT_SEL", offset: 9, width: 1, access: Access::ReadWrite, description: "QTIMER3 TMR1 input select" },
+    Field { name: "QTIMER3_TRM2_INPUT_SEL", offset: 10, width: 1, access: Access::ReadWrite, description: "QTIMER3 TMR2 input select" },
+    Field { name: "QTIMER3_TRM3_INPUT_SEL", offset: 11, width: 1, access: Access::ReadWrite, description: "QTIMER3 TMR3 input select" },
+    Field { name: "QTIMER4_TRM0_INPUT_SEL", offset: 12, width: 1, access: Access::ReadWrite, description: "QTIMER4 TMR0 input select" },
+    Field { name: "QTIMER4_TRM1_INPUT_SEL", offset: 13, width: 1, access: Access::ReadWrite, description: "QTIMER4 TMR1 input select" },
+    Field { name: "QTIMER4_TRM2_INPUT_SEL", offset: 14, width: 1, access: Access::ReadWrite, description: "QTIMER4 TMR2 input select" },
+    Field { name: "QTIMER4_TRM3_INPUT_SEL", offset: 15, width: 1, access: Access::ReadWrite, description: "QTIMER4 TMR3 input select" },
+    Field { name: "IOMUXC_XBAR_DIR_SEL_4", offset: 16, width: 1, access: Access::ReadWrite, description: "IOMUXC XBAR_INOUT4 function direction select" },
+    Field { name: "IOMUXC_XBAR_DIR_SEL_5", offset: 17, width: 1, access: Access::ReadWrite, description: "IOMUXC XBAR_INOUT5 function direction select" },
+    Field { name: "IOMUXC_XBAR_DIR_SEL_6", offset: 18, width: 1, access: Access::ReadWrite, description: "IOMUXC XBAR_INOUT6 function direction select" },
+    Field { name: "IOMUXC_XBAR_DIR_SEL_7", offset: 19, width: 1, access: Access::ReadWrite, description: "IOMUXC XBAR_INOUT7 function direction select" },
+    Field { name: "IOMUXC_XBAR_DIR_SEL_8", offset: 20, width: 1, access: Access::ReadWrite, description: "IOMUXC XBAR_INOUT8 function direction select" },
+    Field { name: "IOMUXC_XBAR_DIR_SEL_9", offset: 21, width: 1, access: Access::ReadWrite, description: "IOMUXC XBAR_INOUT9 function direction select" },
+    Field { name: "IOMUXC_XBAR_DIR_SEL_10", offset: 22, width: 1, access: Access::ReadWrite, description: "IOMUXC XBAR_INOUT10 function direction select" },
+    Field { name: "IOMUXC_XBAR_DIR_SEL_11", offset: 23, width: 1, access: Access::ReadWrite, description: "IOMUXC XBAR_INOUT11 function direction select" },
+    Field { name: "IOMUXC_XBAR_DIR_SEL_12", offset: 24, width: 1, access: Access::ReadWrite, description: "IOMUXC XBAR_INOUT12 function direction select" },
+    Field { name: "IOMUXC_XBAR_DIR_SEL_13", offset: 25, width: 1, access: Access::ReadWrite, description: "IOMUXC XBAR_INOUT13 function direction select" },
+    Field { name: "IOMUXC_XBAR_DIR_SEL_14", offset: 26, width: 1, access: Access::ReadWrite, description: "IOMUXC XBAR_INOUT14 function direction select" },
+    Field { name: "IOMUXC_XBAR_DIR_SEL_15", offset: 27, width: 1, access: Access::ReadWrite, description: "IOMUXC XBAR_INOUT15 function direction select" },
+    Field { name: "IOMUXC_XBAR_DIR_SEL_16", offset: 28, width: 1, access: Access::ReadWrite, description: "IOMUXC XBAR_INOUT16 function direction select" },
+    Field { name: "IOMUXC_XBAR_DIR_SEL_17", offset: 29, width: 1, access: Access::ReadWrite, description: "IOMUXC XBAR_INOUT17 function direction select" },
+    Field { name: "IOMUXC_XBAR_DIR_SEL_18", offset: 30, width: 1, access: Access::ReadWrite, description: "IOMUXC XBAR_INOUT18 function direction select" },
+    Field { name: "IOMUXC_XBAR_DIR_SEL_19", offset: 31, width: 1, access: Access::ReadWrite, description: "IOMUXC XBAR_INOUT19 function direction select" },
+];
+const F10: &[Field] = &[
+    Field { name: "LPI2C1_STOP_REQ", offset: 0, width: 1, access: Access::ReadWrite, description: "LPI2C1 stop request" },
+    Field { name: "LPI2C2_STOP_REQ", offset: 1, width: 1, access: Access::ReadWrite, description: "LPI2C2 stop request" },
+    Field { name: "LPI2C3_STOP_REQ", offset: 2, width: 1, access: Access::ReadWrite, description: "LPI2C3 stop request" },
+    Field { name: "LPI2C4_STOP_REQ", offset: 3, width: 1, access: Access::ReadWrite, description: "LPI2C4 stop request" },
+    Field { name: "LPSPI1_STOP_REQ", offset: 4, width: 1, access: Access::ReadWrite, description: "LPSPI1 stop request" },
+    Field { name: "LPSPI2_STOP_REQ", offset: 5, width: 1, access: Access::ReadWrite, description: "LPSPI2 stop request" },
+    Field { name: "LPSPI3_STOP_REQ", offset: 6, width: 1, access: Access::ReadWrite, description: "LPSPI3 stop request" },
+    Field { name: "LPSPI4_STOP_REQ", offset: 7, width: 1, access: Access::ReadWrite, description: "LPSPI4 stop request" },
+    Field { name: "LPUART1_STOP_REQ", offset: 8, width: 1, access: Access::ReadWrite, description: "LPUART1 stop request" },
+    Field { name: "LPUART2_STOP_REQ", offset: 9, width: 1, access: Access::ReadWrite, description: "LPUART1 stop request" },
+    Field { name: "LPUART3_STOP_REQ", offset: 10, width: 1, access: Access::ReadWrite, description: "LPUART3 stop request" },
+    Field { name: "LPUART4_STOP_REQ", offset: 11, width: 1, access: Access::ReadWrite, description: "LPUART4 stop request" },
+    Field { name: "LPUART5_STOP_REQ", offset: 12, width: 1, access: Access::ReadWrite, description: "LPUART5 stop request" },
+    Field { name: "LPUART6_STOP_REQ", offset: 13, width: 1, access: Access::ReadWrite, description: "LPUART6 stop request" },
+    Field { name: "LPUART7_STOP_REQ", offset: 14, width: 1, access: Access::ReadWrite, description: "LPUART7 stop request" },
+    Field { name: "LPUART8_STOP_REQ", offset: 15, width: 1, access: Access::ReadWrite, description: "LPUART8 stop request" },
+    Field { name: "LPI2C1_STOP_ACK", offset: 16, width: 1, access: Access::ReadWrite, description: "LPI2C1 stop acknowledge" },
+    Field { name: "LPI2C2_STOP_ACK", offset: 17, width: 1, access: Access::ReadWrite, description: "LPI2C2 stop acknowledge" },
+    Field { name: "LPI2C3_STOP_ACK", offset: 18, width: 1, access: Access::ReadWrite, description: "LPI2C3 stop acknowledge" },
+    Field { name: "LPI2C4_STOP_ACK", offset: 19, width: 1, access: Access::ReadWrite, description: "LPI2C4 stop acknowledge" },
+    Field { name: "LPSPI1_STOP_ACK", offset: 20, width: 1, access: Access::ReadWrite, description: "LPSPI1 stop acknowledge" },
+    Field { name: "LPSPI2_STOP_ACK", offset: 21, width: 1, access: Access::ReadWrite, description: "LPSPI2 stop acknowledge" },
+    Field { name: "LPSPI3_STOP_ACK", offset: 22, width: 1, access: Access::ReadWrite, description: "LPSPI3 stop acknowledge" },
+    Field { name: "LPSPI4_STOP_ACK", offset: 23, width: 1, access: Access::ReadWrite, description: "LPSPI4 stop acknowledge" },
+    Field { name: "LPUART1_STOP_ACK", offset: 24, width: 1, access: Access::ReadWrite, description: "LPUART1 stop acknowledge" },
+    Field { name: "LPUART2_STOP_ACK", offset: 25, width: 1, access: Access::ReadWrite, description: "LPUART1 stop acknowledge" },
+    Field { name: "LPUART3_STOP_ACK", offset: 26, width: 1, access: Access::ReadWrite, description: "LPUART3 stop acknowledge" },
+    Field { name: "LPUART4_STOP_ACK", offset: 27, width: 1, access: Access::ReadWrite, description: "LPUART4 stop acknowledge" },
+    Field { name: "LPUART5_STOP_ACK", offset: 28, width: 1, access: Access::ReadWrite, description: "LPUART5 stop acknowledge" },
+    Field { name: "LPUART6_STOP_ACK", offset: 29, width: 1, access: Access::ReadWrite, description: "LPUART6 stop acknowledge" },
+    Field { name: "LPUART7_STOP_ACK", offset: 30, width: 1, access: Access::ReadWrite, description: "LPUART7 stop acknowledge" },
+    Field { name: "LPUART8_STOP_ACK", offset: 31, width: 1, access: Access::ReadWrite, description: "LPUART8 stop acknowledge" },
+];
+const F11: &[Field] = &[
+    Field { name: "LPI2C1_IPG_STOP_MODE", offset: 0, width: 1, access: Access::ReadWrite, description: "LPI2C1 stop mode selection, cannot change when ipg_stop is asserted." },
+    Field { name: "LPI2C1_IPG_DOZE", offset: 1, width: 1, access: Access::ReadWrite, description: "LPI2C1 ipg_doze mode" },
+    Field { name: "LPI2C2_IPG_STOP_MODE", offset: 2, width: 1, access: Access::ReadWrite, description: "LPI2C2 stop mode selection, cannot change when ipg_stop is asserted." },
+    Field { name: "LPI2C2_IPG_DOZE", offset: 3, width: 1, access: Access::ReadWrite, description: "LPI2C2 ipg_doze mode" },
+    Field { name: "LPI2C3_IPG_STOP_MODE", offset: 4, width: 1, access: Access::ReadWrite, description: "LPI2C3 stop mode selection, cannot change when ipg_stop is asserted." },
+    Field { name: "LPI2C3_IPG_DOZE", offset: 5, width: 1, access: Access::ReadWrite, description: "LPI2C3 ipg_doze mode" },
+    Field { name: "LPI2C4_IPG_STOP_MODE", offset: 6, width: 1, access: Access::ReadWrite, description: "LPI2C4 stop mode selection, cannot change when ipg_stop is asserted." },
+    Field { name: "LPI2C4_IPG_DOZE", offset: 7, width: 1, access: Access::ReadWrite, description: "LPI2C4 ipg_doze mode" },
+    Field { name: "LPSPI1_IPG_STOP_MODE", offset: 8, width: 1, access: Access::ReadWrite, description: "LPSPI1 stop mode selection, cannot change when ipg_stop is asserted." },
+    Field { name: "LPSPI1_IPG_DOZE", offset: 9, width: 1, access: Access::ReadWrite, description: "LPSPI1 ipg_doze mode" },
+    Field { name: "LPSPI2_IPG_STOP_MODE", offset: 10, width: 1, access: Access::ReadWrite, description: "LPSPI2 stop mode selection, cannot change when ipg_stop is asserted." },
+    Field { name: "LPSPI2_IPG_DOZE", offset: 11, width: 1, access: Access::ReadWrite, description: "LPSPI2 ipg_doze mode" },
+    Field { name: "LPSPI3_IPG_STOP_MODE", offset: 12, width: 1, access: Access::ReadWrite, description: "LPSPI3 stop mode selection, cannot change when ipg_stop is asserted." },
+    Field { name: "LPSPI3_IPG_DOZE", offset: 13, width: 1, access: Access::ReadWrite, description: "LPSPI3 ipg_doze mode" },
+    Field { name: "LPSPI4_IPG_STOP_MODE", offset: 14, width: 1, access: Access::ReadWrite, description: "LPSPI4 stop mode selection, cannot change when ipg_stop is asserted." },
+    Field { name: "LPSPI4_IPG_DOZE", offset: 15, width: 1, access: Access::ReadWrite, description: "LPSPI4 ipg_doze mode" },
+    Field { name: "LPUART1_IPG_STOP_MODE", offset: 16, width: 1, access: Access::ReadWrite, description: "LPUART1 stop mode selection, cannot change when ipg_stop is asserted." },
+    Field { name: "LPUART1_IPG_DOZE", offset: 17, width: 1, access: Access::ReadWrite, description: "LPUART1 ipg_doze mode" },
+    Field { name: "LPUART2_IPG_STOP_MODE", offset: 18, width: 1, access: Access::ReadWrite, description: "LPUART2 stop mode selection, cannot change when ipg_stop is asserted." },
+    Field { name: "LPUART2_IPG_DOZE", offset: 19, width: 1, access: Access::ReadWrite, description: "LPUART2 ipg_doze mode" },
+    Field { name: "LPUART3_IPG_STOP_MODE", offset: 20, width: 1, access: Access::ReadWrite, description: "LPUART3 stop mode selection, cannot change when ipg_stop is asserted." },
+    Field { name: "LPUART3_IPG_DOZE", offset: 21, width: 1, access: Access::ReadWrite, description: "LPUART3 ipg_doze mode" },
+    Field { name: "LPUART4_IPG_STOP_MODE", offset: 22, width: 1, access: Access::ReadWrite, description: "LPUART4 stop mode selection, cannot change when ipg_stop is asserted." },
+    Field { name: "LPUART4_IPG_DOZE", offset: 23, width: 1, access: Access::ReadWrite, description: "LPUART4 ipg_doze mode" },
+    Field { name: "LPUART5_IPG_STOP_MODE", offset: 24, width: 1, access: Access::ReadWrite, description: "LPUART5 stop mode selection, cannot change when ipg_stop is asserted." },
+    Field { name: "LPUART5_IPG_DOZE", offset: 25, width: 1, access: Access::ReadWrite, description: "LPUART5 ipg_doze mode" },
+    Field { name: "LPUART6_IPG_STOP_MODE", offset: 26, width: 1, access: Access::ReadWrite, description: "LPUART6 stop mode selection, cannot change when ipg_stop is asserted." },
+    Field { name: "LPUART6_IPG_DOZE", offset: 27, width: 1, access: Access::ReadWrite, description: "LPUART6 ipg_doze mode" },
+    Field { name: "LPUART7_IPG_STOP_MODE", offset: 28, width: 1, access: Access::ReadWrite, description: "LPUART7 stop mode selection, cannot change when ipg_stop is asserted." },
+    Field { name: "LPUART7_IPG_DOZE", offset: 29, width: 1, access: Access::ReadWrite, description: "LPUART7 ipg_doze mode" },
+    Field { name: "LPUART8_IPG_STOP_MODE", offset: 30, width: 1, access: Access::ReadWrite, description: "LPUART8 stop mode selection, cannot change when ipg_stop is asserted." },
+    Field { name: "LPUART8_IPG_DOZE", offset: 31, width: 1, access: Access::ReadWrite, description: "LPUART8 ipg_doze mode" },
+];
+const F12: &[Field] = &[
+    Field { name: "NIDEN", offset: 0, width: 1, access: Access::ReadWrite, description: "ARM non-secure (non-invasive) debug enable" },
+    Field { name: "DBG_EN", offset: 1, width: 1, access: Access::ReadWrite, description: "ARM invasive debug enable" },
+    Field { name: "SEC_ERR_RESP", offset: 2, width: 1, access: Access::ReadWrite, description: "Security error response enable for all security gaskets (on both AHB and AXI buses)" },
+    Field { name: "DCPKEY_OCOTP_OR_KEYMUX", offset: 4, width: 1, access: Access::ReadWrite, description: "DCP Key selection bit." },
+    Field { name: "OCRAM_TZ_EN", offset: 8, width: 1, access: Access::ReadWrite, description: "OCRAM TrustZone (TZ) enable." },
+    Field { name: "OCRAM_TZ_ADDR", offset: 9, width: 7, access: Access::ReadWrite, description: "OCRAM TrustZone (TZ) start address" },
+    Field { name: "LOCK_NIDEN", offset: 16, width: 1, access: Access::ReadWrite, description: "Lock NIDEN field for changes" },
+    Field { name: "LOCK_DBG_EN", offset: 17, width: 1, access: Access::ReadWrite, description: "Lock DBG_EN field for changes" },
+    Field { name: "LOCK_SEC_ERR_RESP", offset: 18, width: 1, access: Access::ReadWrite, description: "Lock SEC_ERR_RESP field for changes" },
+    Field { name: "LOCK_DCPKEY_OCOTP_OR_KEYMUX", offset: 20, width: 1, access: Access::ReadWrite, description: "Lock DCP Key OCOTP/Key MUX selection bit" },
+    Field { name: "LOCK_OCRAM_TZ_EN", offset: 24, width: 1, access: Access::ReadWrite, description: "Lock OCRAM_TZ_EN field for changes" },
+    Field { name: "LOCK_OCRAM_TZ_ADDR", offset: 25, width: 7, access: Access::ReadWrite, description: "Lock OCRAM_TZ_ADDR field for changes" },
+];
+const F13: &[Field] = &[
+    Field { name: "M7_APC_AC_R0_CTRL", offset: 0, width: 2, access: Access::ReadWrite, description: "Access control of memory region-0" },
+    Field { name: "M7_APC_AC_R1_CTRL", offset: 2, width: 2, access: Access::ReadWrite, description: "Access control of memory region-1" },
+    Field { name: "M7_APC_AC_R2_CTRL", offset: 4, width: 2, access: Access::ReadWrite, description: "Access control of memory region-2" },
+    Field { name: "M7_APC_AC_R3_CTRL", offset: 6, width: 2, access: Access::ReadWrite, description: "Access control of memory region-3" },
+    Field { name: "BEE_DE_RX_EN", offset: 8, width: 4, access: Access::ReadWrite, description: "BEE data decryption of memory region-n (n = 3 to 0)" },
+];
+const F14: &[Field] = &[
+    Field { name: "FLEXIO1_IPG_STOP_MODE", offset: 0, width: 1, access: Access::ReadWrite, description: "FlexIO1 stop mode selection. Cannot change when ipg_stop is asserted." },
+    Field { name: "FLEXIO1_IPG_DOZE", offset: 1, width: 1, access: Access::ReadWrite, description: "FLEXIO1 ipg_doze mode" },
+    Field { name: "FLEXIO2_IPG_STOP_MODE", offset: 2, width: 1, access: Access::ReadWrite, description: "FlexIO2 stop mode selection. Cannot change when ipg_stop is asserted." },
+    Field { name: "FLEXIO2_IPG_DOZE", offset: 3, width: 1, access: Access::ReadWrite, description: "FLEXIO2 ipg_doze mode" },
+    Field { name: "ACMP_IPG_STOP_MODE", offset: 4, width: 1, access: Access::ReadWrite, description: "ACMP stop mode selection. Cannot change when ipg_stop is asserted." },
+    Field { name: "FLEXIO3_IPG_STOP_MODE", offset: 5, width: 1, access: Access::ReadWrite, description: "FlexIO3 stop mode selection. Cannot change when ipg_stop is asserted." },
+    Field { name: "FLEXIO3_IPG_DOZE", offset: 6, width: 1, access: Access::ReadWrite, description: "FLEXIO3 ipg_doze mode" },
+];
+const F15: &[Field] = &[
+    Field { name: "ARCACHE_USDHC", offset: 0, width: 1, access: Access::ReadWrite, description: "uSDHC block cacheable attribute value of AXI read transactions" },
+    Field { name: "AWCACHE_USDHC", offset: 1, width: 1, access: Access::ReadWrite, description: "uSDHC block cacheable attribute value of AXI write transactions" },
+    Field { name: "CANFD_STOP_REQ", offset: 4, width: 1, access: Access::ReadWrite, description: "CANFD stop request." },
+    Field { name: "CACHE_ENET", offset: 7, width: 1, access: Access::ReadWrite, description: "ENET block cacheable attribute value of AXI transactions" },
+    Field { name: "CACHE_USB", offset: 13, width: 1, access: Access::ReadWrite, description: "USB block cacheable attribute value of AXI transactions" },
+    Field { name: "CANFD_STOP_ACK", offset: 20, width: 1, access: Access::ReadWrite, description: "CANFD stop acknowledge." },
+];
+const F16: &[Field] = &[
+    Field { name: "ACMP1_CMP_IGEN_TRIM_DN", offset: 0, width: 1, access: Access::ReadWrite, description: "reduces ACMP1 internal bias current by 30%" },
+    Field { name: "ACMP2_CMP_IGEN_TRIM_DN", offset: 1, width: 1, access: Access::ReadWrite, description: "reduces ACMP2 internal bias current by 30%" },
+    Field { name: "ACMP3_CMP_IGEN_TRIM_DN", offset: 2, width: 1, access: Access::ReadWrite, description: "reduces ACMP3 internal bias current by 30%" },
+    Field { name: "ACMP4_CMP_IGEN_TRIM_DN", offset: 3, width: 1, access: Access::ReadWrite, description: "reduces ACMP4 internal bias current by 30%" },
+    Field { name: "ACMP1_CMP_IGEN_TRIM_UP", offset: 4, width: 1, access: Access::ReadWrite, description: "increases ACMP1 internal bias current by 30%" },
+    Field { name: "ACMP2_CMP_IGEN_TRIM_UP", offset: 5, width: 1, access: Access::ReadWrite, description: "increases ACMP2 internal bias current by 30%" },
+    Field { name: "ACMP3_CMP_IGEN_TRIM_UP", offset: 6, width: 1, access: Access::ReadWrite, description: "increases ACMP3 internal bias current by 30%" },
+    Field { name: "ACMP4_CMP_IGEN_TRIM_UP", offset: 7, width: 1, access: Access::ReadWrite, description: "increases ACMP4 internal bias current by 30%" },
+    Field { name: "ACMP1_SAMPLE_SYNC_EN", offset: 8, width: 1, access: Access::ReadWrite, description: "ACMP1 sample_lv source select" },
+    Field { name: "ACMP2_SAMPLE_SYNC_EN", offset: 9, width: 1, access: Access::ReadWrite, description: "ACMP2 sample_lv source select" },
+    Field { name: "ACMP3_SAMPLE_SYNC_EN", offset: 10, width: 1, access: Access::ReadWrite, description: "ACMP3 sample_lv source select" },
+    Field { name: "ACMP4_SAMPLE_SYNC_EN", offset: 11, width: 1, access: Access::ReadWrite, description: "ACMP4 sample_lv source select" },
+    Field { name: "CM7_CFGITCMSZ", offset: 16, width: 4, access: Access::ReadWrite, description: "ITCM total size configuration" },
+    Field { name: "CM7_CFGDTCMSZ", offset: 20, width: 4, access: Access::ReadWrite, description: "DTCM total size configuration" },
+];
+const F17: &[Field] = &[
+    Field { name: "INIT_ITCM_EN", offset: 0, width: 1, access: Access::ReadWrite, description: "ITCM enable initialization out of reset" },
+    Field { name: "INIT_DTCM_EN", offset: 1, width: 1, access: Access::ReadWrite, description: "DTCM enable initialization out of reset" },
+    Field { name: "FLEXRAM_BANK_CFG_SEL", offset: 2, width: 1, access: Access::ReadWrite, description: "FlexRAM bank config source select" },
+];
+const F18: &[Field] = &[
+    Field { name: "LOCK_M7_APC_AC_R0_BOT", offset: 0, width: 1, access: Access::ReadWrite, description: "lock M7_APC_AC_R0_BOT field for changes" },
+];
+const F19: &[Field] = &[
+    Field { name: "LOCK_M7_APC_AC_R0_TOP", offset: 0, width: 1, access: Access::ReadWrite, description: "lock M7_APC_AC_R0_TOP field for changes" },
+];
+const F20: &[Field] = &[
+    Field { name: "LOCK_M7_APC_AC_R1_BOT", offset: 0, width: 1, access: Access::ReadWrite, description: "lock M7_APC_AC_R1_BOT field for changes" },
+];
+const F21: &[Field] = &[
+    Field { name: "LOCK_M7_APC_AC_R1_TOP", offset: 0, width: 1, access: Access::ReadWrite, description: "lock M7_APC_AC_R1_TOP field for changes" },
+];
+const F22: &[Field] = &[
+    Field { name: "LOCK_M7_APC_AC_R2_BOT", offset: 0, width: 1, access: Access::ReadWrite, description: "lock M7_APC_AC_R2_BOT field for changes" },
+];
+const F23: &[Field] = &[
+    Field { name: "LOCK_M7_APC_AC_R2_TOP", offset: 0, width: 1, access: Access::ReadWrite, description: "lock M7_APC_AC_R2_TOP field for changes" },
+];
+const F24: &[Field] = &[
+    Field { name: "LOCK_M7_APC_AC_R3_BOT", offset: 0, width: 1, access: Access::ReadWrite, description: "lock M7_APC_AC_R3_BOT field for changes" },
+];
+const F25: &[Field] = &[
+    Field { name: "LOCK_M7_APC_AC_R3_TOP", offset: 0, width: 1, access: Access::ReadWrite, description: "lock M7_APC_AC_R3_TOP field for changes" },
+];
+const F26: &[Field] = &[
+    Field { name: "OCRAM2_TZ_EN", offset: 0, width: 1, access: Access::ReadWrite, description: "OCRAM2 TrustZone (TZ) enable." },
+    Field { name: "OCRAM2_TZ_ADDR", offset: 1, width: 7, access: Access::ReadWrite, description: "OCRAM2 TrustZone (TZ) start address" },
+    Field { name: "LOCK_OCRAM2_TZ_EN", offset: 16, width: 1, access: Access::ReadWrite, description: "Lock OCRAM2_TZ_EN field for changes" },
+    Field { name: "LOCK_OCRAM2_TZ_ADDR", offset: 17, width: 7, access: Access::ReadWrite, description: "Lock OCRAM2_TZ_ADDR field for changes" },
+];
+const F27: &[Field] = &[
+    Field { name: "SIP_TEST_MUX_BOOT_PIN_SEL", offset: 0, width: 8, access: Access::ReadWrite, description: "Boot Pin select in SIP_TEST_MUX" },
+    Field { name: "SIP_TEST_MUX_QSPI_SIP_EN", offset: 8, width: 1, access: Access::ReadWrite, description: "Enable SIP_TEST_MUX" },
+];
+const F28: &[Field] = &[
+    Field { name: "MUX_MODE", offset: 0, width: 4, access: Access::ReadWrite, description: "MUX Mode Select Field." },
+    Field { name: "SION", offset: 4, width: 1, access: Access::ReadWrite, description: "Software Input On Field." },
+];
+const F29: &[Field] = &[
+    Field { name: "DAISY", offset: 0, width: 1, access: Access::ReadWrite, description: "Selecting Pads Involved in Daisy Chain." },
+];
+const F30: &[Field] = &[
+    Field { name: "DAISY", offset: 0, width: 3, access: Access::ReadWrite, description: "Selecting Pads Involved in Daisy Chain." },
+];
+const F31: &[Field] = &[
+    Field { name: "DAISY", offset: 0, width: 2, access: Access::ReadWrite, description: "Selecting Pads Involved in Daisy Chain." },
+];
+const F32: &[Field] = &[
+    Field { name: "SION", offset: 4, width: 1, access: Access::ReadWrite, description: "Software Input On Field." },
+];
+const F33: &[Field] = &[
+    Field { name: "DIV_SELECT", offset: 0, width: 7, access: Access::ReadWrite, description: "This field controls the PLL loop divider" },
+    Field { name: "POWERDOWN", offset: 12, width: 1, access: Access::ReadWrite, description: "Powers down the PLL." },
+    Field { name: "ENABLE", offset: 13, width: 1, access: Access::ReadWrite, description: "Enable the clock output." },
+    Field { name: "BYPASS_CLK_SRC", offset: 14, width: 2, access: Access::ReadWrite, description: "Determines the bypass source" },
+    Field { name: "BYPASS", offset: 16, width: 1, access: Access::ReadWrite, description: "Bypass the PLL." },
+    Field { name: "PLL_SEL", offset: 19, width: 1, access: Access::ReadWrite, description: "Reserved" },
+    Field { name: "LOCK", offset: 31, width: 1, access: Access::ReadOnly, description: "1 - PLL is currently locked. 0 - PLL is not currently locked." },
+];
+const F34: &[Field] = &[
+    Field { name: "DIV_SELECT", offset: 1, width: 1, access: Access::ReadWrite, description: "This field controls the PLL loop divider. 0 - Fout=Fref*20; 1 - Fout=Fref*22." },
+    Field { name: "EN_USB_CLKS", offset: 6, width: 1, access: Access::ReadWrite, description: "Powers the 9-phase PLL outputs for USBPHYn" },
+    Field { name: "POWER", offset: 12, width: 1, access: Access::ReadWrite, description: "Powers up the PLL. This bit will be set automatically when USBPHY0 remote wakeup event happens." },
+    Field { name: "ENABLE", offset: 13, width: 1, access: Access::ReadWrite, description: "Enable the PLL clock output." },
+    Field { name: "BYPASS_CLK_SRC", offset: 14, width: 2, access: Access::ReadWrite, description: "Determines the bypass source." },
+    Field { name: "BYPASS", offset: 16, width: 1, access: Access::ReadWrite, description: "Bypass the PLL." },
+    Field { name: "LOCK", offset: 31, width: 1, access: Access::ReadOnly, description: "1 - PLL is currently locked. 0 - PLL is not currently locked." },
+];
+const F35: &[Field] = &[
+    Field { name: "DIV_SELECT", offset: 1, width: 1, access: Access::ReadWrite, description: "This field controls the PLL loop divider. 0 - Fout=Fref*20; 1 - Fout=Fref*22." },
+    Field { name: "EN_USB_CLKS", offset: 6, width: 1, access: Access::ReadWrite, description: "0: 8-phase PLL outputs for USBPHY1 are powered down" },
+    Field { name: "POWER", offset: 12, width: 1, access: Access::ReadWrite, description: "Powers up the PLL. This bit will be set automatically when USBPHY1 remote wakeup event happens." },
+    Field { name: "ENABLE", offset: 13, width: 1, access: Access::ReadWrite, description: "Enable the PLL clock output." },
+    Field { name: "BYPASS_CLK_SRC", offset: 14, width: 2, access: Access::ReadWrite, description: "Determines the bypass source." },
+    Field { name: "BYPASS", offset: 16, width: 1, access: Access::ReadWrite, description: "Bypass the PLL." },
+    Field { name: "LOCK", offset: 31, width: 1, access: Access::ReadOnly, description: "1 - PLL is currently locked. 0 - PLL is not currently locked." },
+];
+const F36: &[Field] = &[
+    Field { name: "DIV_SELECT", offset: 0, width: 1, access: Access::ReadWrite, description: "This field controls the PLL loop divider. 0 - Fout=Fref*20; 1 - Fout=Fref*22." },
+    Field { name: "POWERDOWN", offset: 12, width: 1, access: Access::ReadWrite, description: "Powers down the PLL." },
+    Field { name: "ENABLE", offset: 13, width: 1, access: Access::ReadWrite, description: "Enable PLL output" },
+    Field { name: "BYPASS_CLK_SRC", offset: 14, width: 2, access: Access::ReadWrite, description: "Determines the bypass source." },
+    Field { name: "BYPASS", offset: 16, width: 1, access: Access::ReadWrite, description: "Bypass the PLL." },
+    Field { name: "LOCK", offset: 31, width: 1, access: Access::ReadOnly, description: "1 - PLL is currently locked; 0 - PLL is not currently locked." },
+];
+const F37: &[Field] = &[
+    Field { name: "STEP", offset: 0, width: 15, access: Access::ReadWrite, description: "Frequency change step = step/CCM_ANALOG_PLL_SYS_DENOM\\\\[B\\\\]*24MHz." },
+    Field { name: "ENABLE", offset: 15, width: 1, access: Access::ReadWrite, description: "Enable bit" },
+    Field { name: "STOP", offset: 16, width: 16, access: Access::ReadWrite, description: "Frequency change = stop/CCM_ANALOG_PLL_SYS_DENOM\\\\[B\\\\]*24MHz." },
+];
+const F38: &[Field] = &[
+    Field { name: "DIV_SELECT", offset: 0, width: 7, access: Access::ReadWrite, description: "This field controls the PLL loop divider. Valid range for DIV_SELECT divider value: 27~54." },
+    Field { name: "POWERDOWN", offset: 12, width: 1, access: Access::ReadWrite, description: "Powers down the PLL." },
+    Field { name: "ENABLE", offset: 13, width: 1, access: Access::ReadWrite, description: "Enable PLL output" },
+    Field { name: "BYPASS_CLK_SRC", offset: 14, width: 2, access: Access::ReadWrite, description: "Determines the bypass source." },
+    Field { name: "BYPASS", offset: 16, width: 1, access: Access::ReadWrite, description: "Bypass the PLL." },
+    Field { name: "POST_DIV_SELECT", offset: 19, width: 2, access: Access::ReadWrite, description: "These bits implement a divider after the PLL, but before the enable and bypass mux." },
+    Field { name: "LOCK", offset: 31, width: 1, access: Access::ReadOnly, description: "1 - PLL is currently locked. 0 - PLL is not currently locked." },
+];
+const F39: &[Field] = &[
+    Field { name: "DIV_SELECT", offset: 0, width: 7, access: Access::ReadWrite, description: "This field controls the PLL loop divider. Valid range for DIV_SELECT divider value: 27~54." },
+    Field { name: "POWERDOWN", offset: 12, width: 1, access: Access::ReadWrite, description: "Powers down the PLL." },
+    Field { name: "ENABLE", offset: 13, width: 1, access: Access::ReadWrite, description: "Enalbe PLL output" },
+    Field { name: "BYPASS_CLK_SRC", offset: 14, width: 2, access: Access::ReadWrite, description: "Determines the bypass source." },
+    Field { name: "BYPASS", offset: 16, width: 1, access: Access::ReadWrite, description: "Bypass the PLL." },
+    Field { name: "POST_DIV_SELECT", offset: 19, width: 2, access: Access::ReadWrite, description: "These bits implement a divider after the PLL, but before the enable and bypass mux." },
+    Field { name: "LOCK", offset: 31, width: 1, access: Access::ReadOnly, description: "1 - PLL is currently locked; 0 - PLL is not currently locked." },
+];
+const F40: &[Field] = &[
+    Field { name: "DIV_SELECT", offset: 0, width: 2, access: Access::ReadWrite, description: "Controls the frequency of the ethernet reference clock" },
+    Field { name: "ENET2_DIV_SELECT", offset: 2, width: 2, access: Access::ReadWrite, description: "Controls the frequency of the ENET2 reference clock." },
+    Field { name: "POWERDOWN", offset: 12, width: 1, access: Access::ReadWrite, description: "Powers down the PLL." },
+    Field { name: "ENABLE", offset: 13, width: 1, access: Access::ReadWrite, description: "Enable the PLL providing the ENET reference clock." },
+    Field { name: "BYPASS_CLK_SRC", offset: 14, width: 2, access: Access::ReadWrite, description: "Determines the bypass source." },
+    Field { name: "BYPASS", offset: 16, width: 1, access: Access::ReadWrite, description: "Bypass the PLL." },
+    Field { name: "ENET2_REF_EN", offset: 20, width: 1, access: Access::ReadWrite, description: "Enable the PLL providing the ENET2 reference clock" },
+    Field { name: "ENET_25M_REF_EN", offset: 21, width: 1, access: Access::ReadWrite, description: "Enable the PLL providing ENET 25 MHz reference clock" },
+    Field { name: "LOCK", offset: 31, width: 1, access: Access::ReadOnly, description: "1 - PLL is currently locked; 0 - PLL is not currently locked." },
+];
+const F41: &[Field] = &[
+    Field { name: "PFD0_FRAC", offset: 0, width: 6, access: Access::ReadWrite, description: "This field controls the fractional divide value" },
+    Field { name: "PFD0_STABLE", offset: 6, width: 1, access: Access::ReadOnly, description: "This read-only bitfield is for DIAGNOSTIC PURPOSES ONLY since the fractional divider should become stable quickly enough that this field will never need to be used by either device driver or application code" },
+    Field { name: "PFD0_CLKGATE", offset: 7, width: 1, access: Access::ReadWrite, description: "If set to 1, the IO fractional divider clock (reference ref_pfd0) is off (power savings)" },
+    Field { name: "PFD1_FRAC", offset: 8, width: 6, access: Access::ReadWrite, description: "This field controls the fractional divide value" },
+    Field { name: "PFD1_STABLE", offset: 14, width: 1, access: Access::ReadOnly, description: "This read-only bitfield is for DIAGNOSTIC PURPOSES ONLY since the fractional divider should become stable quickly enough that this field will never need to be used by either device driver or application code" },
+    Field { name: "PFD1_CLKGATE", offset: 15, width: 1, access: Access::ReadWrite, description: "IO Clock Gate" },
+    Field { name: "PFD2_FRAC", offset: 16, width: 6, access: Access::ReadWrite, description: "This field controls the fractional divide value" },
+    Field { name: "PFD2_STABLE", offset: 22, width: 1, access: Access::ReadOnly, description: "This read-only bitfield is for DIAGNOSTIC PURPOSES ONLY since the fractional divider should become stable quickly enough that this field will never need to be used by either device driver or application code" },
+    Field { name: "PFD2_CLKGATE", offset: 23, width: 1, access: Access::ReadWrite, description: "IO Clock Gate" },
+    Field { name: "PFD3_FRAC", offset: 24, width: 6, access: Access::ReadWrite, description: "This field controls the fractional divide value" },
+    Field { name: "PFD3_STABLE", offset: 30, width: 1, access: Access::ReadOnly, description: "This read-only bitfield is for DIAGNOSTIC PURPOSES ONLY since the fractional divider should become stable quickly enough that this field will never need to be used by either device driver or application code" },
+    Field { name: "PFD3_CLKGATE", offset: 31, width: 1, access: Access::ReadWrite, description: "IO Clock Gate" },
+];
+const F42: &[Field] = &[
+    Field { name: "REFTOP_PWD", offset: 0, width: 1, access: Access::ReadWrite, description: "Control bit to power-down the analog bandgap reference circuitry" },
+    Field { name: "REFTOP_SELFBIASOFF", offset: 3, width: 1, access: Access::ReadWrite, description: "Control bit to disable the self-bias circuit in the analog bandgap" },
+    Field { name: "REFTOP_VBGADJ", offset: 4, width: 3, access: Access::ReadWrite, description: "Not related to CCM. See Power Management Unit (PMU)" },
+    Field { name: "REFTOP_VBGUP", offset: 7, width: 1, access: Access::ReadOnly, description: "Status bit that signals the analog bandgap voltage is up and stable" },
+    Field { name: "STOP_MODE_CONFIG", offset: 10, width: 2, access: Access::ReadWrite, description: "Configure the analog behavior in stop mode." },
+    Field { name: "DISCON_HIGH_SNVS", offset: 12, width: 1, access: Access::ReadWrite, description: "This bit controls a switch from VDD_HIGH_IN to VDD_SNVS_IN." },
+    Field { name: "OSC_I", offset: 13, width: 2, access: Access::ReadWrite, description: "This field determines the bias current in the 24MHz oscillator" },
+    Field { name: "OSC_XTALOK", offset: 15, width: 1, access: Access::ReadOnly, description: "Status bit that signals that the output of the 24-MHz crystal oscillator is stable" },
+    Field { name: "OSC_XTALOK_EN", offset: 16, width: 1, access: Access::ReadWrite, description: "This bit enables the detector that signals when the 24MHz crystal oscillator is stable" },
+    Field { name: "CLKGATE_CTRL", offset: 25, width: 1, access: Access::ReadWrite, description: "This bit allows disabling the clock gate (always ungated) for the xtal 24MHz clock that clocks the digital logic in the analog block" },
+    Field { name: "CLKGATE_DELAY", offset: 26, width: 3, access: Access::ReadWrite, description: "This field specifies the delay between powering up the XTAL 24MHz clock and releasing the clock to the digital logic inside the analog block" },
+    Field { name: "RTC_XTAL_SOURCE", offset: 29, width: 1, access: Access::ReadWrite, description: "This field indicates which chip source is being used for the rtc clock" },
+    Field { name: "XTAL_24M_PWD", offset: 30, width: 1, access: Access::ReadWrite, description: "This field powers down the 24M crystal oscillator if set true" },
+];
+const F43: &[Field] = &[
+    Field { name: "LVDS1_CLK_SEL", offset: 0, width: 5, access: Access::ReadWrite, description: "This field selects the clk to be routed to anaclk1/1b." },
+    Field { name: "LVDSCLK1_OBEN", offset: 10, width: 1, access: Access::ReadWrite, description: "This enables the LVDS output buffer for anaclk1/1b" },
+    Field { name: "LVDSCLK1_IBEN", offset: 12, width: 1, access: Access::ReadWrite, description: "This enables the LVDS input buffer for anaclk1/1b" },
+    Field { name: "PFD_480_AUTOGATE_EN", offset: 16, width: 1, access: Access::ReadWrite, description: "This enables a feature that will clkgate (reset) all PFD_480 clocks anytime the USB1_PLL_480 is unlocked or powered off" },
+    Field { name: "PFD_528_AUTOGATE_EN", offset: 17, width: 1, access: Access::ReadWrite, description: "This enables a feature that will clkgate (reset) all PFD_528 clocks anytime the PLL_528 is unlocked or powered off" },
+    Field { name: "IRQ_TEMPPANIC", offset: 27, width: 1, access: Access::ReadOnly, description: "This status bit is set to one when the temperature sensor panic interrupt asserts for a panic high temperature" },
+    Field { name: "IRQ_TEMPLOW", offset: 28, width: 1, access: Access::ReadOnly, description: "This status bit is set to one when the temperature sensor low interrupt asserts for low temperature" },
+    Field { name: "IRQ_TEMPHIGH", offset: 29, width: 1, access: Access::ReadOnly, description: "This status bit is set to one when the temperature sensor high interrupt asserts for high temperature" },
+    Field { name: "IRQ_ANA_BO", offset: 30, width: 1, access: Access::ReadOnly, description: "This status bit is set to one when when any of the analog regulator brownout interrupts assert" },
+    Field { name: "IRQ_DIG_BO", offset: 31, width: 1, access: Access::ReadOnly, description: "This status bit is set to one when when any of the digital regulator brownout interrupts assert" },
+];
+const F44: &[Field] = &[
+    Field { name: "REG0_BO_OFFSET", offset: 0, width: 3, access: Access::ReadWrite, description: "This field defines the brown out voltage offset for the CORE power domain" },
+    Field { name: "REG0_BO_STATUS", offset: 3, width: 1, access: Access::ReadOnly, description: "Reg0 brownout status bit.Not related to CCM. See Power Management Unit (PMU)" },
+    Field { name: "REG0_ENABLE_BO", offset: 5, width: 1, access: Access::ReadWrite, description: "Enables the brownout detection.Not related to CCM. See Power Management Unit (PMU)" },
+    Field { name: "REG0_OK", offset: 6, width: 1, access: Access::ReadOnly, description: "ARM supply Not related to CCM. See Power Management Unit (PMU)" },
+    Field { name: "PLL3_DISABLE", offset: 7, width: 1, access: Access::ReadWrite, description: "When USB is in low power suspend mode this Control bit is used to indicate if other system peripherals require the USB PLL3 clock when the SoC is not in low power mode" },
+    Field { name: "REG1_BO_OFFSET", offset: 8, width: 3, access: Access::ReadWrite, description: "This field defines the brown out voltage offset for the xPU power domain" },
+    Field { name: "REG1_BO_STATUS", offset: 11, width: 1, access: Access::ReadOnly, description: "Reg1 brownout status bit. Not related to CCM. See Power Management Unit (PMU)" },
+    Field { name: "REG1_ENABLE_BO", offset: 13, width: 1, access: Access::ReadWrite, description: "Enables the brownout detection.Not related to CCM. See Power Management Unit (PMU)" },
+    Field { name: "REG1_OK", offset: 14, width: 1, access: Access::ReadOnly, description: "GPU/VPU supply Not related to CCM. See Power Management Unit (PMU)" },
+    Field { name: "AUDIO_DIV_LSB", offset: 15, width: 1, access: Access::ReadWrite, description: "LSB of Post-divider for Audio PLL" },
+    Field { name: "REG2_BO_OFFSET", offset: 16, width: 3, access: Access::ReadWrite, description: "This field defines the brown out voltage offset for the xPU power domain" },
+    Field { name: "REG2_BO_STATUS", offset: 19, width: 1, access: Access::ReadOnly, description: "Reg2 brownout status bit.Not related to CCM. See Power Management Unit (PMU)" },
+    Field { name: "REG2_ENABLE_BO", offset: 21, width: 1, access: Access::ReadWrite, description: "Enables the brownout detection.Not related to CCM. See Power Management Unit (PMU)" },
+    Field { name: "REG2_OK", offset: 22, width: 1, access: Access::ReadOnly, description: "Signals that the voltage is above the brownout level for the SOC supply" },
+    Field { name: "AUDIO_DIV_MSB", offset: 23, width: 1, access: Access::ReadWrite, description: "MSB of Post-divider for Audio PLL" },
+    Field { name: "REG0_STEP_TIME", offset: 24, width: 2, access: Access::ReadWrite, description: "Number of clock periods (24MHz clock).Not related to CCM. See Power Management Unit (PMU)" },
+    Field { name: "REG1_STEP_TIME", offset: 26, width: 2, access: Access::ReadWrite, description: "Number of clock periods (24MHz clock).Not related to CCM. See Power Management Unit (PMU)" },
+    Field { name: "REG2_STEP_TIME", offset: 28, width: 2, access: Access::ReadWrite, description: "Number of clock periods (24MHz clock).Not related to CCM. See Power Management Unit (PMU)" },
+    Field { name: "VIDEO_DIV", offset: 30, width: 2, access: Access::ReadWrite, description: "Post-divider for video" },
+];
+const F45: &[Field] = &[
+    Field { name: "ENABLE_LINREG", offset: 0, width: 1, access: Access::ReadWrite, description: "Control bit to enable the regulator output." },
+    Field { name: "ENABLE_BO", offset: 1, width: 1, access: Access::ReadWrite, description: "Control bit to enable the brownout circuitry in the regulator." },
+    Field { name: "ENABLE_ILIMIT", offset: 2, width: 1, access: Access::ReadWrite, description: "Control bit to enable the current-limit circuitry in the regulator." },
+    Field { name: "ENABLE_PULLDOWN", offset: 3, width: 1, access: Access::ReadWrite, description: "Control bit to enable the pull-down circuitry in the regulator" },
+    Field { name: "BO_OFFSET", offset: 4, width: 3, access: Access::ReadWrite, description: "Control bits to adjust the regulator brownout offset voltage in 25mV steps" },
+    Field { name: "OUTPUT_TRG", offset: 8, width: 5, access: Access::ReadWrite, description: "Control bits to adjust the regulator output voltage" },
+    Field { name: "BO_VDD1P1", offset: 16, width: 1, access: Access::ReadOnly, description: "Status bit that signals when a brownout is detected on the regulator output." },
+    Field { name: "OK_VDD1P1", offset: 17, width: 1, access: Access::ReadOnly, description: "Status bit that signals when the regulator output is ok. 1 = regulator output > brownout target" },
+    Field { name: "ENABLE_WEAK_LINREG", offset: 18, width: 1, access: Access::ReadWrite, description: "Enables the weak 1p1 regulator" },
+    Field { name: "SELREF_WEAK_LINREG", offset: 19, width: 1, access: Access::ReadWrite, description: "Selects the source for the reference voltage of the weak 1p1 regulator." },
+];
+const F46: &[Field] = &[
+    Field { name: "ENABLE_LINREG", offset: 0, width: 1, access: Access::ReadWrite, description: "Control bit to enable the regulator output to be set by the programmed target voltage setting and internal bandgap reference" },
+    Field { name: "ENABLE_BO", offset: 1, width: 1, access: Access::ReadWrite, description: "Control bit to enable the brownout circuitry in the regulator." },
+    Field { name: "ENABLE_ILIMIT", offset: 2, width: 1, access: Access::ReadWrite, description: "Control bit to enable the current-limit circuitry in the regulator." },
+    Field { name: "BO_OFFSET", offset: 4, width: 3, access: Access::ReadWrite, description: "Control bits to adjust the regulator brownout offset voltage in 25mV steps" },
+    Field { name: "VBUS_SEL", offset: 7, width: 1, access: Access::ReadWrite, description: "Select input voltage source for LDO_3P0 from either USB_OTG1_VBUS or USB_OTG2_VBUS" },
+    Field { name: "OUTPUT_TRG", offset: 8, width: 5, access: Access::ReadWrite, description: "Control bits to adjust the regulator output voltage" },
+    Field { name: "BO_VDD3P0", offset: 16, width: 1, access: Access::ReadOnly, description: "Status bit that signals when a brownout is detected on the regulator output." },
+    Field { name: "OK_VDD3P0", offset: 17, width: 1, access: Access::ReadOnly, description: "Status bit that signals when the regulator output is ok. 1 = regulator output > brownout target" },
+];
+const F47: &[Field] = &[
+    Field { name: "ENABLE_LINREG", offset: 0, width: 1, access: Access::ReadWrite, description: "Control bit to enable the regulator output." },
+    Field { name: "ENABLE_BO", offset: 1, width: 1, access: Access::ReadWrite, description: "Control bit to enable the brownout circuitry in the regulator." },
+    Field { name: "ENABLE_ILIMIT", offset: 2, width: 1, access: Access::ReadWrite, description: "Control bit to enable the current-limit circuitry in the regulator." },
+    Field { name: "ENABLE_PULLDOWN", offset: 3, width: 1, access: Access::ReadWrite, description: "Control bit to enable the pull-down circuitry in the regulator" },
+    Field { name: "BO_OFFSET", offset: 4, width: 3, access: Access::ReadWrite, description: "Control bits to adjust the regulator brownout offset voltage in 25mV steps" },
+    Field { name: "OUTPUT_TRG", offset: 8, width: 5, access: Access::ReadWrite, description: "Control bits to adjust the regulator output voltage" },
+    Field { name: "BO_VDD2P5", offset: 16, width: 1, access: Access::ReadOnly, description: "Status bit that signals when a brownout is detected on the regulator output." },
+    Field { name: "OK_VDD2P5", offset: 17, width: 1, access: Access::ReadOnly, description: "Status bit that signals when the regulator output is ok. 1 = regulator output > brownout target" },
+    Field { name: "ENABLE_WEAK_LINREG", offset: 18, width: 1, access: Access::ReadWrite, description: "Enables the weak 2p5 regulator" },
+];
+const F48: &[Field] = &[
+    Field { name: "REG0_TARG", offset: 0, width: 5, access: Access::ReadWrite, description: "This field defines the target voltage for the ARM core power domain" },
+    Field { name: "REG0_ADJ", offset: 5, width: 4, access: Access::ReadWrite, description: "This bit field defines the adjustment bits to calibrate the target value of Reg0. The adjustment is applied on top on any adjustment applied to the global reference in the misc0 register." },
+    Field { name: "REG1_TARG", offset: 9, width: 5, access: Access::ReadWrite, description: "This bit field defines the target voltage for the vpu/gpu power domain. Single bit increments reflect 25mV core voltage steps. Not all steps will make sense to use either because of input supply limitations or load operation." },
+    Field { name: "REG1_ADJ", offset: 14, width: 4, access: Access::ReadWrite, description: "This bit field defines the adjustment bits to calibrate the target value of Reg1. The adjustment is applied on top on any adjustment applied to the global reference in the misc0 register." },
+    Field { name: "REG2_TARG", offset: 18, width: 5, access: Access::ReadWrite, description: "This field defines the target voltage for the SOC power domain" },
+    Field { name: "REG2_ADJ", offset: 23, width: 4, access: Access::ReadWrite, description: "This bit field defines the adjustment bits to calibrate the target value of Reg2. The adjustment is applied on top on any adjustment applied to the global reference in the misc0 register." },
+    Field { name: "RAMP_RATE", offset: 27, width: 2, access: Access::ReadWrite, description: "Regulator voltage ramp rate." },
+    Field { name: "FET_ODRIVE", offset: 29, width: 1, access: Access::ReadWrite, description: "If set, increases the gate drive on power gating FETs to reduce leakage in the off state" },
+];
+const F49: &[Field] = &[
+    Field { name: "POWER_DOWN", offset: 0, width: 1, access: Access::ReadWrite, description: "This bit powers down the temperature sensor." },
+    Field { name: "MEASURE_TEMP", offset: 1, width: 1, access: Access::ReadWrite, description: "Starts the measurement process" },
+    Field { name: "FINISHED", offset: 2, width: 1, access: Access::ReadOnly, description: "Indicates that the latest temp is valid" },
+    Field { name: "TEMP_CNT", offset: 8, width: 12, access: Access::ReadOnly, description: "This bit field contains the last measured temperature count." },
+    Field { name: "ALARM_VALUE", offset: 20, width: 12, access: Access::ReadWrite, description: "This bit field contains the temperature count (raw sensor output) that will generate a high alarm when TEMP_CNT is smaller than this field" },
+];
+const F50: &[Field] = &[
+    Field { name: "MEASURE_FREQ", offset: 0, width: 16, access: Access::ReadWrite, description: "This bits determines how many RTC clocks to wait before automatically repeating a temperature measurement" },
+];
+const F51: &[Field] = &[
+    Field { name: "LOW_ALARM_VALUE", offset: 0, width: 12, access: Access::ReadWrite, description: "This bit field contains the temperature count that will generate a low alarm interrupt when the field is exceeded by TEMP_CNT" },
+    Field { name: "PANIC_ALARM_VALUE", offset: 16, width: 12, access: Access::ReadWrite, description: "This bit field contains the temperature count that will generate a panic interrupt when TEMP_CNT is smaller than this field" },
+];
+const F52: &[Field] = &[
+    Field { name: "VBUSVALID_THRESH", offset: 0, width: 3, access: Access::ReadWrite, description: "Set the threshold for the VBUSVALID comparator" },
+    Field { name: "VBUSVALID_PWRUP_CMPS", offset: 20, width: 1, access: Access::ReadWrite, description: "Powers up comparators for vbus_valid detector." },
+    Field { name: "DISCHARGE_VBUS", offset: 26, width: 1, access: Access::ReadWrite, description: "USB OTG discharge VBUS." },
+    Field { name: "CHARGE_VBUS", offset: 27, width: 1, access: Access::ReadWrite, description: "USB OTG charge VBUS." },
+];
+const F53: &[Field] = &[
+    Field { name: "CHK_CONTACT", offset: 18, width: 1, access: Access::ReadWrite, description: "Check the contact of USB plug" },
+    Field { name: "CHK_CHRG_B", offset: 19, width: 1, access: Access::ReadWrite, description: "Check the charger connection" },
+    Field { name: "EN_B", offset: 20, width: 1, access: Access::ReadWrite, description: "Control the charger detector." },
+];
+const F54: &[Field] = &[
+    Field { name: "SESSEND", offset: 0, width: 1, access: Access::ReadOnly, description: "Session End for USB OTG" },
+    Field { name: "BVALID", offset: 1, width: 1, access: Access::ReadOnly, description: "Indicates VBus is valid for a B-peripheral" },
+    Field { name: "AVALID", offset: 2, width: 1, access: Access::ReadOnly, description: "Indicates VBus is valid for a A-peripheral" },
+    Field { name: "VBUS_VALID", offset: 3, width: 1, access: Access::ReadOnly, description: "VBus valid for USB OTG" },
+];
+const F55: &[Field] = &[
+    Field { name: "PLUG_CONTACT", offset: 0, width: 1, access: Access::ReadOnly, description: "State of the USB plug contact detector." },
+    Field { name: "CHRG_DETECTED", offset: 1, width: 1, access: Access::ReadOnly, description: "State of charger detection. This bit is a read only version of the state of the analog signal." },
+    Field { name: "DM_STATE", offset: 2, width: 1, access: Access::ReadOnly, description: "DM line state output of the charger detector." },
+    Field { name: "DP_STATE", offset: 3, width: 1, access: Access::ReadOnly, description: "DP line state output of the charger detector." },
+];
+const F56: &[Field] = &[
+    Field { name: "UTMI_TESTSTART", offset: 0, width: 1, access: Access::ReadWrite, description: "Setting this bit can enable 1" },
+];
+const F57: &[Field] = &[
+    Field { name: "HS_USE_EXTERNAL_R", offset: 0, width: 1, access: Access::ReadWrite, description: "Use external resistor to generate the current bias for the high speed transmitter" },
+    Field { name: "EN_DEGLITCH", offset: 1, width: 1, access: Access::ReadWrite, description: "Enable the deglitching circuit of the USB PLL output." },
+    Field { name: "EN_CLK_UTMI", offset: 30, width: 1, access: Access::ReadWrite, description: "Enables the clk to the UTMI block." },
+];
+const F58: &[Field] = &[
+    Field { name: "RC_OSC_EN", offset: 0, width: 1, access: Access::ReadWrite, description: "RC Osc. enable control." },
+    Field { name: "OSC_SEL", offset: 4, width: 1, access: Access::ReadWrite, description: "Select the source for the 24MHz clock." },
+    Field { name: "LPBG_SEL", offset: 5, width: 1, access: Access::ReadWrite, description: "Bandgap select. Not related to oscillator." },
+    Field { name: "LPBG_TEST", offset: 6, width: 1, access: Access::ReadWrite, description: "Low power bandgap test bit. Not related to oscillator." },
+    Field { name: "REFTOP_IBIAS_OFF", offset: 7, width: 1, access: Access::ReadWrite, description: "Low power reftop ibias disable. Not related to oscillator." },
+    Field { name: "L1_PWRGATE", offset: 8, width: 1, access: Access::ReadWrite, description: "L1 power gate control. Used as software override. Not related to oscillator." },
+    Field { name: "L2_PWRGATE", offset: 9, width: 1, access: Access::ReadWrite, description: "L2 power gate control. Used as software override. Not related to oscillator." },
+    Field { name: "CPU_PWRGATE", offset: 10, width: 1, access: Access::ReadWrite, description: "CPU power gate control. Used as software override. Test purpose only Not related to oscillator." },
+    Field { name: "DISPLAY_PWRGATE", offset: 11, width: 1, access: Access::ReadWrite, description: "Display logic power gate control. Used as software override. Not related to oscillator." },
+    Field { name: "RCOSC_CG_OVERRIDE", offset: 13, width: 1, access: Access::ReadWrite, description: "For debug purposes only" },
+    Field { name: "XTALOSC_PWRUP_DELAY", offset: 14, width: 2, access: Access::ReadWrite, description: "Specifies the time delay between when the 24MHz xtal is powered up until it is stable and ready to use" },
+    Field { name: "XTALOSC_PWRUP_STAT", offset: 16, width: 1, access: Access::ReadOnly, description: "Status of the 24MHz xtal oscillator." },
+    Field { name: "MIX_PWRGATE", offset: 17, width: 1, access: Access::ReadWrite, description: "Display power gate control. Used as software mask. Set to zero to force ungated." },
+    Field { name: "GPU_PWRGATE", offset: 18, width: 1, access: Access::ReadWrite, description: "GPU power gate control. Used as software mask. Set to zero to force ungated." },
+];
+const F59: &[Field] = &[
+    Field { name: "START", offset: 0, width: 1, access: Access::ReadWrite, description: "Start/stop bit for the RC tuning calculation logic. If stopped the tuning logic is reset." },
+    Field { name: "ENABLE", offset: 1, width: 1, access: Access::ReadWrite, description: "Enables the tuning logic to calculate new RC tuning values" },
+    Field { name: "BYPASS", offset: 2, width: 1, access: Access::ReadWrite, description: "Bypasses any calculated RC tuning value and uses the programmed register value." },
+    Field { name: "INVERT", offset: 3, width: 1, access: Access::ReadWrite, description: "Invert the stepping of the calculated RC tuning value." },
+    Field { name: "RC_OSC_PROG", offset: 4, width: 8, access: Access::ReadWrite, description: "RC osc. tuning values." },
+    Field { name: "HYST_PLUS", offset: 12, width: 4, access: Access::ReadWrite, description: "Positive hysteresis value" },
+    Field { name: "HYST_MINUS", offset: 16, width: 4, access: Access::ReadWrite, description: "Negative hysteresis value" },
+    Field { name: "RC_OSC_PROG_CUR", offset: 24, width: 8, access: Access::ReadWrite, description: "The current tuning value in use." },
+];
+const F60: &[Field] = &[
+    Field { name: "COUNT_RC_TRG", offset: 0, width: 12, access: Access::ReadWrite, description: "The target count used to tune the RC OSC frequency" },
+    Field { name: "COUNT_RC_CUR", offset: 20, width: 12, access: Access::ReadOnly, description: "The current tuning value in use." },
+];
+const F61: &[Field] = &[
+    Field { name: "COUNT_1M_TRG", offset: 0, width: 12, access: Access::ReadWrite, description: "The target count used to tune the 1MHz clock frequency" },
+    Field { name: "ENABLE_1M", offset: 16, width: 1, access: Access::ReadWrite, description: "Enable the 1MHz clock output. 0 - disabled; 1 - enabled." },
+    Field { name: "MUX_1M", offset: 17, width: 1, access: Access::ReadWrite, description: "Mux the corrected or uncorrected 1MHz clock to the output" },
+    Field { name: "CLK_1M_ERR_FL", offset: 31, width: 1, access: Access::ReadOnly, description: "Flag indicates that the count_1m count wasn't reached within 1 32kHz period" },
+];
+const F62: &[Field] = &[
+    Field { name: "RSVD0", offset: 0, width: 10, access: Access::ReadWrite, description: "Reserved." },
+    Field { name: "TXPWDFS", offset: 10, width: 1, access: Access::ReadWrite, description: "0 = Normal operation" },
+    Field { name: "TXPWDIBIAS", offset: 11, width: 1, access: Access::ReadWrite, description: "0 = Normal operation" },
+    Field { name: "TXPWDV2I", offset: 12, width: 1, access: Access::ReadWrite, description: "0 = Normal operation" },
+    Field { name: "RSVD1", offset: 13, width: 4, access: Access::ReadWrite, description: "Reserved." },
+    Field { name: "RXPWDENV", offset: 17, width: 1, access: Access::ReadWrite, description: "0 = Normal operation" },
+    Field { name: "RXPWD1PT1", offset: 18, width: 1, access: Access::ReadWrite, description: "0 = Normal operation" },
+    Field { name: "RXPWDDIFF", offset: 19, width: 1, access: Access::ReadWrite, description: "0 = Normal operation" },
+    Field { name: "RXPWDRX", offset: 20, width: 1, access: Access::ReadWrite, description: "0 = Normal operation" },
+    Field { name: "RSVD2", offset: 21, width: 11, access: Access::ReadWrite, description: "Reserved." },
+];
+const F63: &[Field] = &[
+    Field { name: "D_CAL", offset: 0, width: 4, access: Access::ReadWrite, description: "Resistor Trimming Code: 0000 = 0.16% 0111 = Nominal 1111 = +25%" },
+    Field { name: "RSVD0", offset: 4, width: 4, access: Access::ReadWrite, description: "Reserved. Note: This bit should remain clear." },
+    Field { name: "TXCAL45DN", offset: 8, width: 4, access: Access::ReadWrite, description: "Decode to select a 45-Ohm resistance to the USB_DN output pin" },
+    Field { name: "RSVD1", offset: 12, width: 4, access: Access::ReadWrite, description: "Reserved. Note: This bit should remain clear." },
+    Field { name: "TXCAL45DP", offset: 16, width: 4, access: Access::ReadWrite, description: "Decode to select a 45-Ohm resistance to the USB_DP output pin" },
+    Field { name: "RSVD2", offset: 20, width: 6, access: Access::ReadWrite, description: "Reserved." },
+    Field { name: "USBPHY_TX_EDGECTRL", offset: 26, width: 3, access: Access::ReadWrite, description: "Controls the edge-rate of the current sensing transistors used in HS transmit" },
+    Field { name: "RSVD5", offset: 29, width: 3, access: Access::ReadWrite, description: "Reserved." },
+];
+const F64: &[Field] = &[
+    Field { name: "ENVADJ", offset: 0, width: 3, access: Access::ReadWrite, description: "The ENVADJ field adjusts the trip point for the envelope detector" },
+    Field { name: "RSVD0", offset: 3, width: 1, access: Access::ReadWrite, description: "Reserved." },
+    Field { name: "DISCONADJ", offset: 4, width: 3, access: Access::ReadWrite, description: "The DISCONADJ field adjusts the trip point for the disconnect detector: 000 = Trip-Level Voltage is 0" },
+    Field { name: "RSVD1", offset: 7, width: 15, access: Access::ReadWrite, description: "Reserved." },
+    Field { name: "RXDBYPASS", offset: 22, width: 1, access: Access::ReadWrite, description: "0 = Normal operation" },
+    Field { name: "RSVD2", offset: 23, width: 9, access: Access::ReadWrite, description: "Reserved." },
+];
+const F65: &[Field] = &[
+    Field { name: "ENOTG_ID_CHG_IRQ", offset: 0, width: 1, access: Access::ReadWrite, description: "Enable OTG_ID_CHG_IRQ." },
+    Field { name: "ENHOSTDISCONDETECT", offset: 1, width: 1, access: Access::ReadWrite, description: "For host mode, enables high-speed disconnect detector" },
+    Field { name: "ENIRQHOSTDISCON", offset: 2, width: 1, access: Access::ReadWrite, description: "Enables interrupt for detection of disconnection to Device when in high-speed host mode" },
+    Field { name: "HOSTDISCONDETECT_IRQ", offset: 3, width: 1, access: Access::ReadWrite, description: "Indicates that the device has disconnected in high-speed mode" },
+    Field { name: "ENDEVPLUGINDETECT", offset: 4, width: 1, access: Access::ReadWrite, description: "For device mode, enables 200-KOhm pullups for detecting connectivity to the host." },
+    Field { name: "DEVPLUGIN_POLARITY", offset: 5, width: 1, access: Access::ReadWrite, description: "For device mode, if this bit is cleared to 0, then it trips the interrupt if the device is plugged in" },
+    Field { name: "OTG_ID_CHG_IRQ", offset: 6, width: 1, access: Access::ReadWrite, description: "OTG ID change interrupt. Indicates the value of ID pin changed." },
+    Field { name: "ENOTGIDDETECT", offset: 7, width: 1, access: Access::ReadWrite, description: "Enables circuit to detect resistance of MiniAB ID pin." },
+    Field { name: "RESUMEIRQSTICKY", offset: 8, width: 1, access: Access::ReadWrite, description: "Set to 1 will make RESUME_IRQ bit a sticky bit until software clear it" },
+    Field { name: "ENIRQRESUMEDETECT", offset: 9, width: 1, access: Access::ReadWrite, description: "Enables interrupt for detection of a non-J state on the USB line" },
+    Field { name: "RESUME_IRQ", offset: 10, width: 1, access: Access::ReadWrite, description: "Indicates that the host is sending a wake-up after suspend" },
+    Field { name: "ENIRQDEVPLUGIN", offset: 11, width: 1, access: Access::ReadWrite, description: "Enables interrupt for the detection of connectivity to the USB line." },
+    Field { name: "DEVPLUGIN_IRQ", offset: 12, width: 1, access: Access::ReadWrite, description: "Indicates that the device is connected" },
+    Field { name: "DATA_ON_LRADC", offset: 13, width: 1, access: Access::ReadWrite, description: "Enables the LRADC to monitor USB_DP and USB_DM. This is for use in non-USB modes only." },
+    Field { name: "ENUTMILEVEL2", offset: 14, width: 1, access: Access::ReadWrite, description: "Enables UTMI+ Level2. This should be enabled if needs to support LS device" },
+    Field { name: "ENUTMILEVEL3", offset: 15, width: 1, access: Access::ReadWrite, description: "Enables UTMI+ Level3" },
+    Field { name: "ENIRQWAKEUP", offset: 16, width: 1, access: Access::ReadWrite, description: "Enables interrupt for the wakeup events." },
+    Field { name: "WAKEUP_IRQ", offset: 17, width: 1, access: Access::ReadWrite, description: "Indicates that there is a wakeup event" },
+    Field { name: "ENAUTO_PWRON_PLL", offset: 18, width: 1, access: Access::ReadWrite, description: "Enables the feature to auto-enable the POWER bit of HW_CLKCTRL_PLLxCTRL0 if there is wakeup event if USB is suspended" },
+    Field { name: "ENAUTOCLR_CLKGATE", offset: 19, width: 1, access: Access::ReadWrite, description: "Enables the feature to auto-clear the CLKGATE bit if there is wakeup event while USB is suspended" },
+    Field { name: "ENAUTOCLR_PHY_PWD", offset: 20, width: 1, access: Access::ReadWrite, description: "Enables the feature to auto-clear the PWD register bits in USBPHYx_PWD if there is wakeup event while USB is suspended" },
+    Field { name: "ENDPDMCHG_WKUP", offset: 21, width: 1, access: Access::ReadWrite, description: "Enables the feature to wakeup USB if DP/DM is toggled when USB is suspended" },
+    Field { name: "ENIDCHG_WKUP", offset: 22, width: 1, access: Access::ReadWrite, description: "Enables the feature to wakeup USB if ID is toggled when USB is suspended." },
+    Field { name: "ENVBUSCHG_WKUP", offset: 23, width: 1, access: Access::ReadWrite, description: "Enables the feature to wakeup USB if VBUS is toggled when USB is suspended." },
+    Field { name: "FSDLL_RST_EN", offset: 24, width: 1, access: Access::ReadWrite, description: "Enables the feature to reset the FSDLL lock detection logic at the end of each TX packet." },
+    Field { name: "RSVD1", offset: 25, width: 2, access: Access::ReadWrite, description: "Reserved." },
+    Field { name: "OTG_ID_VALUE", offset: 27, width: 1, access: Access::ReadWrite, description: "Almost same as OTGID_STATUS in USBPHYx_STATUS Register" },
+    Field { name: "HOST_FORCE_LS_SE0", offset: 28, width: 1, access: Access::ReadWrite, description: "Forces the next FS packet that is transmitted to have a EOP with LS timing" },
+    Field { name: "UTMI_SUSPENDM", offset: 29, width: 1, access: Access::ReadWrite, description: "Used by the PHY to indicate a powered-down state" },
+    Field { name: "CLKGATE", offset: 30, width: 1, access: Access::ReadWrite, description: "Gate UTMI Clocks" },
+    Field { name: "SFTRST", offset: 31, width: 1, access: Access::ReadWrite, description: "Writing a 1 to this bit will soft-reset the USBPHYx_PWD, USBPHYx_TX, USBPHYx_RX, and USBPHYx_CTRL registers" },
+];
+const F66: &[Field] = &[
+    Field { name: "RSVD0", offset: 0, width: 3, access: Access::ReadWrite, description: "Reserved." },
+    Field { name: "HOSTDISCONDETECT_STATUS", offset: 3, width: 1, access: Access::ReadOnly, description: "Indicates that the device has disconnected while in high-speed host mode." },
+    Field { name: "RSVD1", offset: 4, width: 2, access: Access::ReadWrite, description: "Reserved." },
+    Field { name: "DEVPLUGIN_STATUS", offset: 6, width: 1, access: Access::ReadOnly, description: "Indicates that the device has been connected on the USB_DP and USB_DM lines." },
+    Field { name: "RSVD2", offset: 7, width: 1, access: Access::ReadWrite, description: "Reserved." },
+    Field { name: "OTGID_STATUS", offset: 8, width: 1, access: Access::ReadOnly, description: "Indicates the results of ID pin on MiniAB plug" },
+    Field { name: "RSVD3", offset: 9, width: 1, access: Access::ReadWrite, description: "Reserved." },
+    Field { name: "RESUME_STATUS", offset: 10, width: 1, access: Access::ReadOnly, description: "Indicates that the host is sending a wake-up after suspend and has triggered an interrupt." },
+];
+const F67: &[Field] = &[
+    Field { name: "OTGIDPIOLOCK", offset: 0, width: 1, access: Access::ReadWrite, description: "Once OTG ID from USBPHYx_STATUS_OTGID_STATUS, use this to hold the value" },
+    Field { name: "DEBUG_INTERFACE_HOLD", offset: 1, width: 1, access: Access::ReadWrite, description: "Use holding registers to assist in timing for external UTMI interface." },
+    Field { name: "HSTPULLDOWN", offset: 2, width: 2, access: Access::ReadWrite, description: "Set bit 3 to 1 to pull down 15-KOhm on USB_DP line" },
+    Field { name: "ENHSTPULLDOWN", offset: 4, width: 2, access: Access::ReadWrite, description: "Set bit 5 to 1 to override the control of the USB_DP 15-KOhm pulldown" },
+    Field { name: "RSVD0", offset: 6, width: 2, access: Access::ReadWrite, description: "Reserved." },
+    Field { name: "TX2RXCOUNT", offset: 8, width: 4, access: Access::ReadWrite, description: "Delay in between the end of transmit to the beginning of receive" },
+    Field { name: "ENTX2RXCOUNT", offset: 12, width: 1, access: Access::ReadWrite, description: "Set this bit to allow a countdown to transition in between TX and RX." },
+    Field { name: "RSVD1", offset: 13, width: 3, access: Access::ReadWrite, description: "Reserved." },
+    Field { name: "SQUELCHRESETCOUNT", offset: 16, width: 5, access: Access::ReadWrite, description: "Delay in between the detection of squelch to the reset of high-speed RX." },
+    Field { name: "RSVD2", offset: 21, width: 3, access: Access::ReadWrite, description: "Reserved." },
+    Field { name: "ENSQUELCHRESET", offset: 24, width: 1, access: Access::ReadWrite, description: "Set bit to allow squelch to reset high-speed receive." },
+    Field { name: "SQUELCHRESETLENGTH", offset: 25, width: 4, access: Access::ReadWrite, description: "Duration of RESET in terms of the number of 480-MHz cycles." },
+    Field { name: "HOST_RESUME_DEBUG", offset: 29, width: 1, access: Access::ReadWrite, description: "Choose to trigger the host resume SE0 with HOST_FORCE_LS_SE0 = 0 or UTMI_SUSPEND = 1." },
+    Field { name: "CLKGATE", offset: 30, width: 1, access: Access::ReadWrite, description: "Gate Test Clocks" },
+    Field { name: "RSVD3", offset: 31, width: 1, access: Access::ReadWrite, description: "Reserved." },
+];
+const F68: &[Field] = &[
+    Field { name: "LOOP_BACK_FAIL_COUNT", offset: 0, width: 16, access: Access::ReadOnly, description: "Running count of the failed pseudo-random generator loopback" },
+    Field { name: "UTMI_RXERROR_FAIL_COUNT", offset: 16, width: 10, access: Access::ReadOnly, description: "Running count of the UTMI_RXERROR." },
+    Field { name: "SQUELCH_COUNT", offset: 26, width: 6, access: Access::ReadOnly, description: "Running count of the squelch reset instead of normal end for HS RX." },
+];
+const F69: &[Field] = &[
+    Field { name: "RSVD0", offset: 0, width: 13, access: Access::ReadWrite, description: "Reserved. Note: This bit should remain clear." },
+    Field { name: "ENTAILADJVD", offset: 13, width: 2, access: Access::ReadWrite, description: "Delay increment of the rise of squelch: 00 = Delay is nominal 01 = Delay is +20% 10 = Delay is -20% 11 = Delay is -40%" },
+];
+const F70: &[Field] = &[
+    Field { name: "STEP", offset: 0, width: 16, access: Access::ReadOnly, description: "Fixed read-only value reflecting the stepping of the RTL version." },
+    Field { name: "MINOR", offset: 16, width: 8, access: Access::ReadOnly, description: "Fixed read-only value reflecting the MINOR field of the RTL version." },
+    Field { name: "MAJOR", offset: 24, width: 8, access: Access::ReadOnly, description: "Fixed read-only value reflecting the MAJOR field of the RTL version." },
+];
+const F71: &[Field] = &[
+    Field { name: "OSCNT", offset: 0, width: 8, access: Access::ReadWrite, description: "Oscillator ready counter value. These bits define value of 32KHz counter, that serve as counter for oscillator lock time (count to n+1 ckil's). This is used for oscillator lock time. Current estimation is ~5ms. This counter will be used in ignition sequence and in wake from stop sequence if sbyos bit was defined, to notify that on chip oscillator output is ready for the dpll_ip to use and only then the gate in dpll_ip can be opened." },
+    Field { name: "COSC_EN", offset: 12, width: 1, access: Access::ReadWrite, description: "On chip oscillator enable bit - this bit value is reflected on the output cosc_en" },
+    Field { name: "REG_BYPASS_COUNT", offset: 21, width: 6, access: Access::ReadWrite, description: "Counter for analog_reg_bypass signal assertion after standby voltage request by PMIC_STBY_REQ" },
+    Field { name: "RBC_EN", offset: 27, width: 1, access: Access::ReadWrite, description: "Enable for REG_BYPASS_COUNTER" },
+];
+const F72: &[Field] = &[
+    Field { name: "REF_EN_B", offset: 0, width: 1, access: Access::ReadOnly, description: "Status of the value of CCM_REF_EN_B output of ccm" },
+    Field { name: "CAMP2_READY", offset: 3, width: 1, access: Access::ReadOnly, description: "Status indication of CAMP2." },
+    Field { name: "COSC_READY", offset: 5, width: 1, access: Access::ReadOnly, description: "Status indication of on board oscillator" },
+];
+const F73: &[Field] = &[
+    Field { name: "PLL3_SW_CLK_SEL", offset: 0, width: 1, access: Access::ReadWrite, description: "Selects source to generate pll3_sw_clk. This bit should only be used for testing purposes." },
+];
+const F74: &[Field] = &[
+    Field { name: "ARM_PODF", offset: 0, width: 3, access: Access::ReadWrite, description: "Divider for ARM clock root" },
+];
+const F75: &[Field] = &[
+    Field { name: "SEMC_CLK_SEL", offset: 6, width: 1, access: Access::ReadWrite, description: "SEMC clock source select" },
+    Field { name: "SEMC_ALT_CLK_SEL", offset: 7, width: 1, access: Access::ReadWrite, description: "SEMC alternative clock select" },
+    Field { name: "IPG_PODF", offset: 8, width: 2, access: Access::ReadWrite, description: "Divider for ipg podf." },
+    Field { name: "AHB_PODF", offset: 10, width: 3, access: Access::ReadWrite, description: "Divider for AHB PODF" },
+    Field { name: "SEMC_PODF", offset: 16, width: 3, access: Access::ReadWrite, description: "Post divider for SEMC clock" },
+    Field { name: "PERIPH_CLK_SEL", offset: 25, width: 1, access: Access::ReadWrite, description: "Selector for peripheral main clock" },
+    Field { name: "PERIPH_CLK2_PODF", offset: 27, width: 3, access: Access::ReadWrite, description: "Divider for periph_clk2_podf." },
+];
+const F76: &[Field] = &[
+    Field { name: "LPSPI_CLK_SEL", offset: 4, width: 2, access: Access::ReadWrite, description: "Selector for lpspi clock multiplexer" },
+    Field { name: "FLEXSPI2_CLK_SEL", offset: 8, width: 2, access: Access::ReadWrite, description: "Selector for flexspi2 clock multiplexer" },
+    Field { name: "PERIPH_CLK2_SEL", offset: 12, width: 2, access: Access::ReadWrite, description: "Selector for peripheral clk2 clock multiplexer" },
+    Field { name: "TRACE_CLK_SEL", offset: 14, width: 2, access: Access::ReadWrite, description: "Selector for Trace clock multiplexer" },
+    Field { name: "PRE_PERIPH_CLK_SEL", offset: 18, width: 2, access: Access::ReadWrite, description: "Selector for pre_periph clock multiplexer" },
+    Field { name: "LCDIF_PODF", offset: 23, width: 3, access: Access::ReadWrite, description: "Post-divider for LCDIF clock." },
+    Field { name: "LPSPI_PODF", offset: 26, width: 3, access: Access::ReadWrite, description: "Divider for LPSPI. Divider should be updated when output clock is gated." },
+    Field { name: "FLEXSPI2_PODF", offset: 29, width: 3, access: Access::ReadWrite, description: "Divider for flexspi2 clock root." },
+];
+const F77: &[Field] = &[
+    Field { name: "PERCLK_PODF", offset: 0, width: 6, access: Access::ReadWrite, description: "Divider for perclk podf." },
+    Field { name: "PERCLK_CLK_SEL", offset: 6, width: 1, access: Access::ReadWrite, description: "Selector for the perclk clock multiplexor" },
+    Field { name: "SAI1_CLK_SEL", offset: 10, width: 2, access: Access::ReadWrite, description: "Selector for sai1 clock multiplexer" },
+    Field { name: "SAI2_CLK_SEL", offset: 12, width: 2, access: Access::ReadWrite, description: "Selector for sai2 clock multiplexer" },
+    Field { name: "SAI3_CLK_SEL", offset: 14, width: 2, access: Access::ReadWrite, description: "Selector for sai3 clock multiplexer" },
+    Field { name: "USDHC1_CLK_SEL", offset: 16, width: 1, access: Access::ReadWrite, description: "Selector for usdhc1 clock multiplexer" },
+    Field { name: "USDHC2_CLK_SEL", offset: 17, width: 1, access: Access::ReadWrite, description: "Selector for usdhc2 clock multiplexer" },
+    Field { name: "FLEXSPI_PODF", offset: 23, width: 3, access: Access::ReadWrite, description: "Divider for flexspi clock root." },
+    Field { name: "FLEXSPI_CLK_SEL", offset: 29, width: 2, access: Access::ReadWrite, description: "Selector for flexspi clock multiplexer" },
+];
+const F78: &[Field] = &[
+    Field { name: "CAN_CLK_PODF", offset: 2, width: 6, access: Access::ReadWrite, description: "Divider for CAN clock podf." },
+    Field { name: "CAN_CLK_SEL", offset: 8, width: 2, access: Access::ReadWrite, description: "Selector for CAN clock multiplexer" },
+    Field { name: "FLEXIO2_CLK_SEL", offset: 19, width: 2, access: Access::ReadWrite, description: "Selector for flexio2 clock multiplexer" },
+];
+const F79: &[Field] = &[
+    Field { name: "UART_CLK_PODF", offset: 0, width: 6, access: Access::ReadWrite, description: "Divider for uart clock podf." },
+    Field { name: "UART_CLK_SEL", offset: 6, width: 1, access: Access::ReadWrite, description: "Selector for the UART clock multiplexor" },
+    Field { name: "USDHC1_PODF", offset: 11, width: 3, access: Access::ReadWrite, description: "Divider for usdhc1 clock podf. Divider should be updated when output clock is gated." },
+    Field { name: "USDHC2_PODF", offset: 16, width: 3, access: Access::ReadWrite, description: "Divider for usdhc2 clock. Divider should be updated when output clock is gated." },
+    Field { name: "TRACE_PODF", offset: 25, width: 2, access: Access::ReadWrite, description: "Divider for trace clock. Divider should be updated when output clock is gated." },
+];
+const F80: &[Field] = &[
+    Field { name: "SAI1_CLK_PODF", offset: 0, width: 6, access: Access::ReadWrite, description: "Divider for sai1 clock podf. The input clock to this divider should be lower than 300Mhz, the predivider can be used to achieve this." },
+    Field { name: "SAI1_CLK_PRED", offset: 6, width: 3, access: Access::ReadWrite, description: "Divider for sai1 clock pred." },
+    Field { name: "FLEXIO2_CLK_PRED", offset: 9, width: 3, access: Access::ReadWrite, description: "Divider for flexio2 clock." },
+    Field { name: "SAI3_CLK_PODF", offset: 16, width: 6, access: Access::ReadWrite, description: "Divider for sai3 clock podf. The input clock to this divider should be lower than 300Mhz, the predivider can be used to achieve this." },
+    Field { name: "SAI3_CLK_PRED", offset: 22, width: 3, access: Access::ReadWrite, description: "Divider for sai3 clock pred." },
+    Field { name: "FLEXIO2_CLK_PODF", offset: 25, width: 3, access: Access::ReadWrite, description: "Divider for flexio2 clock. Divider should be updated when output clock is gated." },
+];
+const F81: &[Field] = &[
+    Field { name: "SAI2_CLK_PODF", offset: 0, width: 6, access: Access::ReadWrite, description: "Divider for sai2 clock podf. The input clock to this divider should be lower than 300Mhz, the predivider can be used to achieve this." },
+    Field { name: "SAI2_CLK_PRED", offset: 6, width: 3, access: Access::ReadWrite, description: "Divider for sai2 clock pred.Divider should be updated when output clock is gated." },
+];
+const F82: &[Field] = &[
+    Field { name: "FLEXIO1_CLK_SEL", offset: 7, width: 2, access: Access::ReadWrite, description: "Selector for flexio1 clock multiplexer" },
+    Field { name: "FLEXIO1_CLK_PODF", offset: 9, width: 3, access: Access::ReadWrite, description: "Divider for flexio1 clock podf. Divider should be updated when output clock is gated." },
+    Field { name: "FLEXIO1_CLK_PRED", offset: 12, width: 3, access: Access::ReadWrite, description: "Divider for flexio1 clock pred. Divider should be updated when output clock is gated." },
+    Field { name: "SPDIF0_CLK_SEL", offset: 20, width: 2, access: Access::ReadWrite, description: "Selector for spdif0 clock multiplexer" },
+    Field { name: "SPDIF0_CLK_PODF", offset: 22, width: 3, access: Access::ReadWrite, description: "Divider for spdif0 clock podf. Divider should be updated when output clock is gated." },
+    Field { name: "SPDIF0_CLK_PRED", offset: 25, width: 3, access: Access::ReadWrite, description: "Divider for spdif0 clock pred. Divider should be updated when output clock is gated." },
+];
+const F83: &[Field] = &[
+    Field { name: "LCDIF_PRED", offset: 12, width: 3, access: Access::ReadWrite, description: "Pre-divider for lcdif clock. Divider should be updated when output clock is gated." },
+    Field { name: "LCDIF_PRE_CLK_SEL", offset: 15, width: 3, access: Access::ReadWrite, description: "Selector for lcdif root clock pre-multiplexer" },
+    Field { name: "LPI2C_CLK_SEL", offset: 18, width: 1, access: Access::ReadWrite, description: "Selector for the LPI2C clock multiplexor" },
+    Field { name: "LPI2C_CLK_PODF", offset: 19, width: 6, access: Access::ReadWrite, description: "Divider for lpi2c clock podf. Divider should be updated when output clock is gated. The input clock to this divider should be lower than 300Mhz, the predivider can be used to achieve this." },
+];
+const F84: &[Field] = &[
+    Field { name: "CSI_CLK_SEL", offset: 9, width: 2, access: Access::ReadWrite, description: "Selector for csi_mclk multiplexer" },
+    Field { name: "CSI_PODF", offset: 11, width: 3, access: Access::ReadWrite, description: "Post divider for csi_mclk. Divider should be updated when output clock is gated." },
+];
+const F85: &[Field] = &[
+    Field { name: "SEMC_PODF_BUSY", offset: 0, width: 1, access: Access::ReadOnly, description: "Busy indicator for semc_podf." },
+    Field { name: "AHB_PODF_BUSY", offset: 1, width: 1, access: Access::ReadOnly, description: "Busy indicator for ahb_podf." },
+    Field { name: "PERIPH2_CLK_SEL_BUSY", offset: 3, width: 1, access: Access::ReadOnly, description: "Busy indicator for periph2_clk_sel mux control." },
+    Field { name: "PERIPH_CLK_SEL_BUSY", offset: 5, width: 1, access: Access::ReadOnly, description: "Busy indicator for periph_clk_sel mux control." },
+    Field { name: "ARM_PODF_BUSY", offset: 16, width: 1, access: Access::ReadOnly, description: "Busy indicator for arm_podf." },
+];
+const F86: &[Field] = &[
+    Field { name: "LPM", offset: 0, width: 2, access: Access::ReadWrite, description: "Setting the low power mode that system will enter on next assertion of dsm_request signal." },
+    Field { name: "ARM_CLK_DIS_ON_LPM", offset: 5, width: 1, access: Access::ReadWrite, description: "Define if ARM clocks (arm_clk, soc_mxclk, soc_pclk, soc_dbg_pclk, vl_wrck) will be disabled on wait mode" },
+    Field { name: "SBYOS", offset: 6, width: 1, access: Access::ReadWrite, description: "Standby clock oscillator bit" },
+    Field { name: "DIS_REF_OSC", offset: 7, width: 1, access: Access::ReadWrite, description: "dis_ref_osc - in run mode, software can manually control closing of external reference oscillator clock, i" },
+    Field { name: "VSTBY", offset: 8, width: 1, access: Access::ReadWrite, description: "Voltage standby request bit" },
+    Field { name: "STBY_COUNT", offset: 9, width: 2, access: Access::ReadWrite, description: "Standby counter definition" },
+    Field { name: "COSC_PWRDOWN", offset: 11, width: 1, access: Access::ReadWrite, description: "In run mode, software can manually control powering down of on chip oscillator, i" },
+    Field { name: "BYPASS_LPM_HS1", offset: 19, width: 1, access: Access::ReadWrite, description: "Bypass low power mode handshake. This bit should always be set to 1'b1 by software." },
+    Field { name: "BYPASS_LPM_HS0", offset: 21, width: 1, access: Access::ReadWrite, description: "Bypass low power mode handshake. This bit should always be set to 1'b1 by software." },
+    Field { name: "MASK_CORE0_WFI", offset: 22, width: 1, access: Access::ReadWrite, description: "Mask WFI of core0 for entering low power mode Assertion of all bits\\\\[27:22\\\\] will generate low power mode request" },
+    Field { name: "MASK_SCU_IDLE", offset: 26, width: 1, access: Access::ReadWrite, description: "Mask SCU IDLE for entering low power mode Assertion of all bits\\\\[27:22\\\\] will generate low power mode request" },
+    Field { name: "MASK_L2CC_IDLE", offset: 27, width: 1, access: Access::ReadWrite, description: "Mask L2CC IDLE for entering low power mode" },
+];
+const F87: &[Field] = &[
+    Field { name: "LRF_PLL", offset: 0, width: 1, access: Access::ReadWrite, description: "CCM interrupt request 2 generated due to lock of all enabled and not bypaseed PLLs" },
+    Field { name: "COSC_READY", offset: 6, width: 1, access: Access::ReadWrite, description: "CCM interrupt request 2 generated due to on board oscillator ready, i" },
+    Field { name: "SEMC_PODF_LOADED", offset: 17, width: 1, access: Access::ReadWrite, description: "CCM interrupt request 1 generated due to frequency change of semc_podf" },
+    Field { name: "PERIPH2_CLK_SEL_LOADED", offset: 19, width: 1, access: Access::ReadWrite, description: "CCM interrupt request 1 generated due to frequency change of periph2_clk_sel" },
+    Field { name: "AHB_PODF_LOADED", offset: 20, width: 1, access: Access::ReadWrite, description: "CCM interrupt request 1 generated due to frequency change of ahb_podf" },
+    Field { name: "PERIPH_CLK_SEL_LOADED", offset: 22, width: 1, access: Access::ReadWrite, description: "CCM interrupt request 1 generated due to update of periph_clk_sel." },
+    Field { name: "ARM_PODF_LOADED", offset: 26, width: 1, access: Access::ReadWrite, description: "CCM interrupt request 1 generated due to frequency change of arm_podf" },
+];
+const F88: &[Field] = &[
+    Field { name: "MASK_LRF_PLL", offset: 0, width: 1, access: Access::ReadWrite, description: "mask interrupt generation due to lrf of PLLs" },
+    Field { name: "MASK_COSC_READY", offset: 6, width: 1, access: Access::ReadWrite, description: "mask interrupt generation due to on board oscillator ready" },
+    Field { name: "MASK_SEMC_PODF_LOADED", offset: 17, width: 1, access: Access::ReadWrite, description: "mask interrupt generation due to frequency change of semc_podf" },
+    Field { name: "MASK_PERIPH2_CLK_SEL_LOADED", offset: 19, width: 1, access: Access::ReadWrite, description: "mask interrupt generation due to update of periph2_clk_sel." },
+    Field { name: "MASK_AHB_PODF_LOADED", offset: 20, width: 1, access: Access::ReadWrite, description: "mask interrupt generation due to frequency change of ahb_podf" },
+    Field { name: "MASK_PERIPH_CLK_SEL_LOADED", offset: 22, width: 1, access: Access::ReadWrite, description: "mask interrupt generation due to update of periph_clk_sel." },
+    Field { name: "ARM_PODF_LOADED", offset: 26, width: 1, access: Access::ReadWrite, description: "mask interrupt generation due to frequency change of arm_podf" },
+];
+const F89: &[Field] = &[
+    Field { name: "CLKO1_SEL", offset: 0, width: 4, access: Access::ReadWrite, description: "Selection of the clock to be generated on CCM_CLKO1" },
+    Field { name: "CLKO1_DIV", offset: 4, width: 3, access: Access::ReadWrite, description: "Setting the divider of CCM_CLKO1" },
+    Field { name: "CLKO1_EN", offset: 7, width: 1, access: Access::ReadWrite, description: "Enable of CCM_CLKO1 clock" },
+    Field { name: "CLK_OUT_SEL", offset: 8, width: 1, access: Access::ReadWrite, description: "CCM_CLKO1 output to reflect CCM_CLKO1 or CCM_CLKO2 clocks" },
+    Field { name: "CLKO2_SEL", offset: 16, width: 5, access: Access::ReadWrite, description: "Selection of the clock to be generated on CCM_CLKO2" },
+    Field { name: "CLKO2_DIV", offset: 21, width: 3, access: Access::ReadWrite, description: "Setting the divider of CCM_CLKO2" },
+    Field { name: "CLKO2_EN", offset: 24, width: 1, access: Access::ReadWrite, description: "Enable of CCM_CLKO2 clock" },
+];
+const F90: &[Field] = &[
+    Field { name: "PMIC_DELAY_SCALER", offset: 0, width: 1, access: Access::ReadWrite, description: "Defines clock dividion of clock for stby_count (pmic delay counter)" },
+    Field { name: "EFUSE_PROG_SUPPLY_GATE", offset: 4, width: 1, access: Access::ReadWrite, description: "Defines the value of the output signal cgpr_dout\\\\[4\\\\]. Gate of program supply for efuse programing" },
+    Field { name: "SYS_MEM_DS_CTRL", offset: 14, width: 2, access: Access::ReadWrite, description: "System memory DS control" },
+    Field { name: "FPL", offset: 16, width: 1, access: Access::ReadWrite, description: "Fast PLL enable." },
+    Field { name: "INT_MEM_CLK_LPM", offset: 17, width: 1, access: Access::ReadWrite, description: "Control for the Deep Sleep signal to the ARM Platform memories with additional control logic based on the ARM WFI signal" },
+];
+const F91: &[Field] = &[
+    Field { name: "CG0", offset: 0, width: 2, access: Access::ReadWrite, description: "aips_tz1 clocks (aips_tz1_clk_enable)" },
+    Field { name: "CG1", offset: 2, width: 2, access: Access::ReadWrite, description: "aips_tz2 clocks (aips_tz2_clk_enable)" },
+    Field { name: "CG2", offset: 4, width: 2, access: Access::ReadWrite, description: "mqs clock ( mqs_hmclk_clock_enable)" },
+    Field { name: "CG3", offset: 6, width: 2, access: Access::ReadWrite, description: "flexspi_exsc clock (flexspi_exsc_clk_enable)" },
+    Field { name: "CG4", offset: 8, width: 2, access: Access::ReadWrite, description: "sim_m_clk_r_clk_enable" },
+    Field { name: "CG5", offset: 10, width: 2, access: Access::ReadWrite, description: "dcp clock (dcp_clk_enable)" },
+    Field { name: "CG6", offset: 12, width: 2, access: Access::ReadWrite, description: "lpuart3 clock (lpuart3_clk_enable)" },
+    Field { name: "CG7", offset: 14, width: 2, access: Access::ReadWrite, description: "Reserved" },
+    Field { name: "CG8", offset: 16, width: 2, access: Access::ReadWrite, description: "Reserved" },
+    Field { name: "CG9", offset: 18, width: 2, access: Access::ReadWrite, description: "Reserved" },
+    Field { name: "CG10", offset: 20, width: 2, access: Access::ReadWrite, description: "Reserved" },
+    Field { name: "CG11", offset: 22, width: 2, access: Access::ReadWrite, description: "trace clock (trace_clk_enable)" },
+    Field { name: "CG12", offset: 24, width: 2, access: Access::ReadWrite, description: "gpt2 bus clocks (gpt2_bus_clk_enable)" },
+    Field { name: "CG13", offset: 26, width: 2, access: Access::ReadWrite, description: "gpt2 serial clocks (gpt2_serial_clk_enable)" },
+    Field { name: "CG14", offset: 28, width: 2, access: Access::ReadWrite, description: "lpuart2 clock (lpuart2_clk_enable)" },
+    Field { name: "CG15", offset: 30, width: 2, access: Access::ReadWrite, description: "gpio2_clocks (gpio2_clk_enable)" },
+];
+const F92: &[Field] = &[
+    Field { name: "CG0", offset: 0, width: 2, access: Access::ReadWrite, description: "lpspi1 clocks (lpspi1_clk_enable)" },
+    Field { name: "CG1", offset: 2, width: 2, access: Access::ReadWrite, description: "lpspi2 clocks (lpspi2_clk_enable)" },
+    Field { name: "CG2", offset: 4, width: 2, access: Access::ReadWrite, description: "Reserved" },
+    Field { name: "CG3", offset: 6, width: 2, access: Access::ReadWrite, description: "Reserved" },
+    Field { name: "CG4", offset: 8, width: 2, access: Access::ReadWrite, description: "Reserved" },
+    Field { name: "CG5", offset: 10, width: 2, access: Access::ReadWrite, description: "Reserved" },
+    Field { name: "CG6", offset: 12, width: 2, access: Access::ReadWrite, description: "pit clocks (pit_clk_enable)" },
+    Field { name: "CG7", offset: 14, width: 2, access: Access::ReadWrite, description: "Reserved" },
+    Field { name: "CG8", offset: 16, width: 2, access: Access::ReadWrite, description: "adc1 clock (adc1_clk_enable)" },
+    Field { name: "CG9", offset: 18, width: 2, access: Access::ReadWrite, description: "Reserved" },
+    Field { name: "CG10", offset: 20, width: 2, access: Access::ReadWrite, description: "gpt1 bus clock (gpt_clk_enable)" },
+    Field { name: "CG11", offset: 22, width: 2, access: Access::ReadWrite, description: "gpt1 serial clock (gpt_serial_clk_enable)" },
+    Field { name: "CG12", offset: 24, width: 2, access: Access::ReadWrite, description: "lpuart4 clock (lpuart4_clk_enable)" },
+    Field { name: "CG13", offset: 26, width: 2, access: Access::ReadWrite, description: "gpio1 clock (gpio1_clk_enable)" },
+    Field { name: "CG14", offset: 28, width: 2, access: Access::ReadWrite, description: "csu clock (csu_clk_enable)" },
+    Field { name: "CG15", offset: 30, width: 2, access: Access::ReadWrite, description: "gpio5 clock (gpio5_clk_enable)" },
+];
+const F93: &[Field] = &[
+    Field { name: "CG0", offset: 0, width: 2, access: Access::ReadWrite, description: "ocram_exsc clock (ocram_exsc_clk_enable)" },
+    Field { name: "CG1", offset: 2, width: 2, access: Access::ReadWrite, description: "Reserved" },
+    Field { name: "CG2", offset: 4, width: 2, access: Access::ReadWrite, description: "iomuxc_snvs clock (iomuxc_snvs_clk_enable)" },
+    Field { name: "CG3", offset: 6, width: 2, access: Access::ReadWrite, description: "lpi2c1 clock (lpi2c1_clk_enable)" },
+    Field { name: "CG4", offset: 8, width: 2, access: Access::ReadWrite, description: "lpi2c2 clock (lpi2c2_clk_enable)" },
+    Field { name: "CG5", offset: 10, width: 2, access: Access::ReadWrite, description: "Reserved" },
+    Field { name: "CG6", offset: 12, width: 2, access: Access::ReadWrite, description: "OCOTP_CTRL clock (iim_clk_enable)" },
+    Field { name: "CG7", offset: 14, width: 2, access: Access::ReadWrite, description: "Reserved" },
+    Field { name: "CG8", offset: 16, width: 2, access: Access::ReadWrite, description: "Reserved" },
+    Field { name: "CG9", offset: 18, width: 2, access: Access::ReadWrite, description: "Reserved" },
+    Field { name: "CG10", offset: 20, width: 2, access: Access::ReadWrite, description: "Reserved" },
+    Field { name: "CG11", offset: 22, width: 2, access: Access::ReadWrite, description: "xbar1 clock (xbar1_clk_enable)" },
+    Field { name: "CG12", offset: 24, width: 2, access: Access::ReadWrite, description: "Reserved" },
+    Field { name: "CG13", offset: 26, width: 2, access: Access::ReadWrite, description: "Reserved" },
+    Field { name: "CG14", offset: 28, width: 2, access: Access::ReadWrite, description: "Reserved" },
+    Field { name: "CG15", offset: 30, width: 2, access: Access::ReadWrite, description: "Reserved" },
+];
+const F94: &[Field] = &[
+    Field { name: "CG0", offset: 0, width: 2, access: Access::ReadWrite, description: "Reserved" },
+    Field { name: "CG1", offset: 2, width: 2, access: Access::ReadWrite, description: "Reserved" },
+    Field { name: "CG2", offset: 4, width: 2, access: Access::ReadWrite, description: "Reserved" },
+    Field { name: "CG3", offset: 6, width: 2, access: Access::ReadWrite, description: "Reserved" },
+    Field { name: "CG4", offset: 8, width: 2, access: Access::ReadWrite, description: "aoi1 clock (aoi1_clk_enable)" },
+    Field { name: "CG5", offset: 10, width: 2, access: Access::ReadWrite, description: "Reserved" },
+    Field { name: "CG6", offset: 12, width: 2, access: Access::ReadWrite, description: "Reserved" },
+    Field { name: "CG7", offset: 14, width: 2, access: Access::ReadWrite, description: "ewm clocks (ewm_clk_enable)" },
+    Field { name: "CG8", offset: 16, width: 2, access: Access::ReadWrite, description: "wdog1 clock (wdog1_clk_enable)" },
+    Field { name: "CG9", offset: 18, width: 2, access: Access::ReadWrite, description: "flexram clock (flexram_clk_enable)" },
+    Field { name: "CG10", offset: 20, width: 2, access: Access::ReadWrite, description: "Reserved" },
+    Field { name: "CG11", offset: 22, width: 2, access: Access::ReadWrite, description: "Reserved" },
+    Field { name: "CG12", offset: 24, width: 2, access: Access::ReadWrite, description: "Reserved" },
+    Field { name: "CG13", offset: 26, width: 2, access: Access::ReadWrite, description: "Reserved" },
+    Field { name: "CG14", offset: 28, width: 2, access: Access::ReadWrite, description: "The OCRAM clock cannot be turned off when the CM cache is running on this device." },
+    Field { name: "CG15", offset: 30, width: 2, access: Access::ReadWrite, description: "iomuxc_snvs_gpr clock (iomuxc_snvs_gpr_clk_enable)" },
+];
+const F95: &[Field] = &[
+    Field { name: "CG0", offset: 0, width: 2, access: Access::ReadWrite, description: "sim_m7_clk_r_enable" },
+    Field { name: "CG1", offset: 2, width: 2, access: Access::ReadWrite, description: "iomuxc clock (iomuxc_clk_enable)" },
+    Field { name: "CG2", offset: 4, width: 2, access: Access::ReadWrite, description: "iomuxc gpr clock (iomuxc_gpr_clk_enable)" },
+    Field { name: "CG3", offset: 6, width: 2, access: Access::ReadWrite, description: "Reserved" },
+    Field { name: "CG4", offset: 8, width: 2, access: Access::ReadWrite, description: "sim_m7 clock (sim_m7_clk_enable)" },
+    Field { name: "CG5", offset: 10, width: 2, access: Access::ReadWrite, description: "Reserved" },
+    Field { name: "CG6", offset: 12, width: 2, access: Access::ReadWrite, description: "sim_m clocks (sim_m_clk_enable)" },
+    Field { name: "CG7", offset: 14, width: 2, access: Access::ReadWrite, description: "sim_ems clocks (sim_ems_clk_enable)" },
+    Field { name: "CG8", offset: 16, width: 2, access: Access::ReadWrite, description: "pwm1 clocks (pwm1_clk_enable)" },
+    Field { name: "CG9", offset: 18, width: 2, access: Access::ReadWrite, description: "Reserved" },
+    Field { name: "CG10", offset: 20, width: 2, access: Access::ReadWrite, description: "Reserved" },
+    Field { name: "CG11", offset: 22, width: 2, access: Access::ReadWrite, description: "Reserved" },
+    Field { name: "CG12", offset: 24, width: 2, access: Access::ReadWrite, description: "Reserved" },
+    Field { name: "CG13", offset: 26, width: 2, access: Access::ReadWrite, description: "Reserved" },
+    Field { name: "CG14", offset: 28, width: 2, access: Access::ReadWrite, description: "Reserved" },
+    Field { name: "CG15", offset: 30, width: 2, access: Access::ReadWrite, description: "dma_ps clocks (dma_ps_clk_enable)" },
+];
+const F96: &[Field] = &[
+    Field { name: "CG0", offset: 0, width: 2, access: Access::ReadWrite, description: "rom clock (rom_clk_enable)" },
+    Field { name: "CG1", offset: 2, width: 2, access: Access::ReadWrite, description: "flexio1 clock (flexio1_clk_enable)" },
+    Field { name: "CG2", offset: 4, width: 2, access: Access::ReadWrite, description: "wdog3 clock (wdog3_clk_enable)" },
+    Field { name: "CG3", offset: 6, width: 2, access: Access::ReadWrite, description: "dma clock (dma_clk_enable)" },
+    Field { name: "CG4", offset: 8, width: 2, access: Access::ReadWrite, description: "kpp clock (kpp_clk_enable)" },
+    Field { name: "CG5", offset: 10, width: 2, access: Access::ReadWrite, description: "wdog2 clock (wdog2_clk_enable)" },
+    Field { name: "CG6", offset: 12, width: 2, access: Access::ReadWrite, description: "Reserved" },
+    Field { name: "CG7", offset: 14, width: 2, access: Access::ReadWrite, description: "spdif clock (spdif_clk_enable)" },
+    Field { name: "CG8", offset: 16, width: 2, access: Access::ReadWrite, description: "Reserved" },
+    Field { name: "CG9", offset: 18, width: 2, access: Access::ReadWrite, description: "sai1 clock (sai1_clk_enable)" },
+    Field { name: "CG10", offset: 20, width: 2, access: Access::ReadWrite, description: "Reserved" },
+    Field { name: "CG11", offset: 22, width: 2, access: Access::ReadWrite, description: "sai3 clock (sai3_clk_enable)" },
+    Field { name: "CG12", offset: 24, width: 2, access: Access::ReadWrite, description: "lpuart1 clock (lpuart1_clk_enable)" },
+    Field { name: "CG13", offset: 26, width: 2, access: Access::ReadWrite, description: "Reserved" },
+    Field { name: "CG14", offset: 28, width: 2, access: Access::ReadWrite, description: "snvs_hp clock (snvs_hp_clk_enable)" },
+    Field { name: "CG15", offset: 30, width: 2, access: Access::ReadWrite, description: "snvs_lp clock (snvs_lp_clk_enable)" },
+];
+const F97: &[Field] = &[
+    Field { name: "CG0", offset: 0, width: 2, access: Access::ReadWrite, description: "usboh3 clock (usboh3_clk_enable)" },
+    Field { name: "CG1", offset: 2, width: 2, access: Access::ReadWrite, description: "Reserved" },
+    Field { name: "CG2", offset: 4, width: 2, access: Access::ReadWrite, description: "Reserved" },
+    Field { name: "CG3", offset: 6, width: 2, access: Access::ReadWrite, description: "dcdc clocks (dcdc_clk_enable)" },
+    Field { name: "CG4", offset: 8, width: 2, access: Access::ReadWrite, description: "Reserved" },
+    Field { name: "CG5", offset: 10, width: 2, access: Access::ReadWrite, description: "flexspi clocks (flexspi_clk_enable) sim_ems_clk_enable must also be cleared, when flexspi_clk_enable is cleared" },
+    Field { name: "CG6", offset: 12, width: 2, access: Access::ReadWrite, description: "trng clock (trng_clk_enable)" },
+    Field { name: "CG7", offset: 14, width: 2, access: Access::ReadWrite, description: "Reserved" },
+    Field { name: "CG8", offset: 16, width: 2, access: Access::ReadWrite, description: "Reserved" },
+    Field { name: "CG9", offset: 18, width: 2, access: Access::ReadWrite, description: "Reserved" },
+    Field { name: "CG10", offset: 20, width: 2, access: Access::ReadWrite, description: "sim_per clock (sim_per_clk_enable)" },
+    Field { name: "CG11", offset: 22, width: 2, access: Access::ReadWrite, description: "anadig clocks (anadig_clk_enable)" },
+    Field { name: "CG12", offset: 24, width: 2, access: Access::ReadWrite, description: "Reserved" },
+    Field { name: "CG13", offset: 26, width: 2, access: Access::ReadWrite, description: "Reserved" },
+    Field { name: "CG14", offset: 28, width: 2, access: Access::ReadWrite, description: "Reserved" },
+    Field { name: "CG15", offset: 30, width: 2, access: Access::ReadWrite, description: "Reserved" },
+];
+const F98: &[Field] = &[
+    Field { name: "CG0", offset: 0, width: 2, access: Access::ReadWrite, description: "enet2_clk_enable" },
+    Field { name: "CG1", offset: 2, width: 2, access: Access::ReadWrite, description: "flexspi2_clk_enable" },
+    Field { name: "CG2", offset: 4, width: 2, access: Access::ReadWrite, description: "axbs_l_clk_enable" },
+    Field { name: "CG3", offset: 6, width: 2, access: Access::ReadWrite, description: "can3_clk_enable" },
+    Field { name: "CG4", offset: 8, width: 2, access: Access::ReadWrite, description: "can3_serial_clk_enable" },
+    Field { name: "CG5", offset: 10, width: 2, access: Access::ReadWrite, description: "aips_lite_clk_enable" },
+    Field { name: "CG6", offset: 12, width: 2, access: Access::ReadWrite, description: "flexio3_clk_enable" },
+];
+const F99: &[Field] = &[
+    Field { name: "MOD_EN_OV_GPT", offset: 5, width: 1, access: Access::ReadWrite, description: "Overide clock enable signal from GPT - clock will not be gated based on GPT's signal 'ipg_enable_clk'" },
+    Field { name: "MOD_EN_OV_PIT", offset: 6, width: 1, access: Access::ReadWrite, description: "Overide clock enable signal from PIT - clock will not be gated based on PIT's signal 'ipg_enable_clk'" },
+    Field { name: "MOD_EN_USDHC", offset: 7, width: 1, access: Access::ReadWrite, description: "overide clock enable signal from USDHC." },
+    Field { name: "MOD_EN_OV_TRNG", offset: 9, width: 1, access: Access::ReadWrite, description: "Overide clock enable signal from TRNG" },
+    Field { name: "MOD_EN_OV_CANFD_CPI", offset: 10, width: 1, access: Access::ReadWrite, description: "Overide clock enable signal from FlexCAN3(CANFD) - clock will not be gated based on CAN's signal 'enable_clk_cpi'" },
+    Field { name: "MOD_EN_OV_CAN2_CPI", offset: 28, width: 1, access: Access::ReadWrite, description: "Overide clock enable signal from CAN2 - clock will not be gated based on CAN's signal 'enable_clk_cpi'" },
+    Field { name: "MOD_EN_OV_CAN1_CPI", offset: 30, width: 1, access: Access::ReadWrite, description: "Overide clock enable signal from CAN1 - clock will not be gated based on CAN's signal 'enable_clk_cpi'" },
+];
+const F100: &[Field] = &[
+    Field { name: "SWRST", offset: 0, width: 1, access: Access::ReadWrite, description: "Software Reset" },
+    Field { name: "MDIS", offset: 1, width: 1, access: Access::ReadWrite, description: "Module Disable" },
+    Field { name: "DQSMD", offset: 2, width: 1, access: Access::ReadWrite, description: "DQS (read strobe) mode" },
+    Field { name: "WPOL0", offset: 6, width: 1, access: Access::ReadWrite, description: "WAIT/RDY# polarity for NOR/PSRAM" },
+    Field { name: "WPOL1", offset: 7, width: 1, access: Access::ReadWrite, description: "WAIT/RDY# polarity for NAND" },
+    Field { name: "DQSSEL", offset: 10, width: 1, access: Access::ReadWrite, description: "Select DQS source when DQSMD and DLLSEL both set." },
+    Field { name: "DLLSEL", offset: 11, width: 1, access: Access::ReadWrite, description: "Select DLL delay chain clock input." },
+    Field { name: "CTO", offset: 16, width: 8, access: Access::ReadWrite, description: "Command Execution timeout cycles" },
+    Field { name: "BTO", offset: 24, width: 5, access: Access::ReadWrite, description: "Bus timeout cycles" },
+];
+const F101: &[Field] = &[
+    Field { name: "MUX_A8", offset: 0, width: 3, access: Access::ReadWrite, description: "SEMC_A8 output selection" },
+    Field { name: "MUX_CSX0", offset: 3, width: 3, access: Access::ReadWrite, description: "SEMC_CSX0 output selection" },
+    Field { name: "MUX_CSX1", offset: 6, width: 3, access: Access::ReadWrite, description: "SEMC_CSX1 output selection" },
+    Field { name: "MUX_CSX2", offset: 9, width: 3, access: Access::ReadWrite, description: "SEMC_CSX2 output selection" },
+    Field { name: "MUX_CSX3", offset: 12, width: 3, access: Access::ReadWrite, description: "SEMC_CSX3 output selection" },
+    Field { name: "MUX_RDY", offset: 15, width: 3, access: Access::ReadWrite, description: "SEMC_RDY function selection" },
+    Field { name: "MUX_CLKX0", offset: 24, width: 1, access: Access::ReadWrite, description: "SEMC_CLKX0 function selection" },
+    Field { name: "MUX_CLKX1", offset: 25, width: 1, access: Access::ReadWrite, description: "SEMC_CLKX1 function selection" },
+];
+const F102: &[Field] = &[
+    Field { name: "WQOS", offset: 0, width: 4, access: Access::ReadWrite, description: "Weight of QoS" },
+    Field { name: "WAGE", offset: 4, width: 4, access: Access::ReadWrite, description: "Weight of Aging" },
+    Field { name: "WSH", offset: 8, width: 8, access: Access::ReadWrite, description: "Weight of Slave Hit (no read/write switch)" },
+    Field { name: "WRWS", offset: 16, width: 8, access: Access::ReadWrite, description: "Weight of Slave Hit (Read/Write switch)" },
+];
+const F103: &[Field] = &[
+    Field { name: "WQOS", offset: 0, width: 4, access: Access::ReadWrite, description: "Weight of QoS" },
+    Field { name: "WAGE", offset: 4, width: 4, access: Access::ReadWrite, description: "Weight of Aging" },
+    Field { name: "WPH", offset: 8, width: 8, access: Access::ReadWrite, description: "Weight of Page Hit" },
+    Field { name: "WRWS", offset: 16, width: 8, access: Access::ReadWrite, description: "Weight of Read/Write switch" },
+    Field { name: "WBR", offset: 24, width: 8, access: Access::ReadWrite, description: "Weight of Bank Rotation" },
+];
+const F104: &[Field] = &[
+    Field { name: "VLD", offset: 0, width: 1, access: Access::ReadWrite, description: "Valid" },
+    Field { name: "MS", offset: 1, width: 5, access: Access::ReadWrite, description: "Memory size" },
+];
+const F105: &[Field] = &[
+    Field { name: "DLLEN", offset: 0, width: 1, access: Access::ReadWrite, description: "DLL calibration enable." },
+    Field { name: "DLLRESET", offset: 1, width: 1, access: Access::ReadWrite, description: "Software could force a reset on DLL by setting this field to 0x1. This will cause the DLL to lose lock and re-calibrate to detect an ref_clock half period phase shift. The reset action is edge triggered, so software need to clear this bit after set this bit (no delay limitation)." },
+    Field { name: "SLVDLYTARGET", offset: 3, width: 4, access: Access::ReadWrite, description: "The delay target for slave delay line is: ((SLVDLYTARGET+1) * 1/32 * clock cycle of reference clock (ipgclock)." },
+    Field { name: "OVRDEN", offset: 8, width: 1, access: Access::ReadWrite, description: "Slave clock delay line delay cell number selection override enable." },
+    Field { name: "OVRDVAL", offset: 9, width: 6, access: Access::ReadWrite, description: "Slave clock delay line delay cell number selection override value." },
+];
+const F106: &[Field] = &[
+    Field { name: "IPCMDDONEEN", offset: 0, width: 1, access: Access::ReadWrite, description: "IP command done interrupt enable" },
+    Field { name: "IPCMDERREN", offset: 1, width: 1, access: Access::ReadWrite, description: "IP command error interrupt enable" },
+    Field { name: "AXICMDERREN", offset: 2, width: 1, access: Access::ReadWrite, description: "AXI command error interrupt enable" },
+    Field { name: "AXIBUSERREN", offset: 3, width: 1, access: Access::ReadWrite, description: "AXI bus error interrupt enable" },
+    Field { name: "NDPAGEENDEN", offset: 4, width: 1, access: Access::ReadWrite, description: "This bit enable/disable the NDPAGEEND interrupt generation." },
+    Field { name: "NDNOPENDEN", offset: 5, width: 1, access: Access::ReadWrite, description: "This bit enable/disable the NDNOPEND interrupt generation." },
+];
+const F107: &[Field] = &[
+    Field { name: "IPCMDDONE", offset: 0, width: 1, access: Access::ReadWrite, description: "IP command normal done interrupt" },
+    Field { name: "IPCMDERR", offset: 1, width: 1, access: Access::ReadWrite, description: "IP command error done interrupt" },
+    Field { name: "AXICMDERR", offset: 2, width: 1, access: Access::ReadWrite, description: "AXI command error interrupt" },
+    Field { name: "AXIBUSERR", offset: 3, width: 1, access: Access::ReadWrite, description: "AXI bus error interrupt" },
+    Field { name: "NDPAGEEND", offset: 4, width: 1, access: Access::ReadWrite, description: "This interrupt is generated when the last address of one page in NAND device is written by AXI command" },
+    Field { name: "NDNOPEND", offset: 5, width: 1, access: Access::ReadWrite, description: "This interrupt is generated when all pending AXI write command to NAND is finished on NAND interface." },
+];
+const F108: &[Field] = &[
+    Field { name: "PS", offset: 0, width: 1, access: Access::ReadWrite, description: "Port Size" },
+    Field { name: "BL", offset: 4, width: 3, access: Access::ReadWrite, description: "Burst Length" },
+    Field { name: "COL8", offset: 7, width: 1, access: Access::ReadWrite, description: "Column 8 selection bit" },
+    Field { name: "COL", offset: 8, width: 2, access: Access::ReadWrite, description: "Column address bit number" },
+    Field { name: "CL", offset: 10, width: 2, access: Access::ReadWrite, description: "CAS Latency" },
+    Field { name: "BANK2", offset: 14, width: 1, access: Access::ReadWrite, description: "2 Bank selection bit" },
+];
+const F109: &[Field] = &[
+    Field { name: "PRE2ACT", offset: 0, width: 4, access: Access::ReadWrite, description: "PRECHARGE to ACT/Refresh wait time" },
+    Field { name: "ACT2RW", offset: 4, width: 4, access: Access::ReadWrite, description: "ACT to Read/Write wait time" },
+    Field { name: "RFRC", offset: 8, width: 5, access: Access::ReadWrite, description: "Refresh recovery time" },
+    Field { name: "WRC", offset: 13, width: 3, access: Access::ReadWrite, description: "Write recovery time" },
+    Field { name: "CKEOFF", offset: 16, width: 4, access: Access::ReadWrite, description: "CKE OFF minimum time" },
+    Field { name: "ACT2PRE", offset: 20, width: 4, access: Access::ReadWrite, description: "ACT to Precharge minimum time" },
+];
+const F110: &[Field] = &[
+    Field { name: "SRRC", offset: 0, width: 8, access: Access::ReadWrite, description: "Self Refresh Recovery time" },
+    Field { name: "REF2REF", offset: 8, width: 8, access: Access::ReadWrite, description: "Refresh to Refresh wait time" },
+    Field { name: "ACT2ACT", offset: 16, width: 8, access: Access::ReadWrite, description: "ACT to ACT wait time" },
+    Field { name: "ITO", offset: 24, width: 8, access: Access::ReadWrite, description: "SDRAM Idle timeout" },
+];
+const F111: &[Field] = &[
+    Field { name: "REN", offset: 0, width: 1, access: Access::ReadWrite, description: "Refresh enable" },
+    Field { name: "REBL", offset: 1, width: 3, access: Access::ReadWrite, description: "Refresh burst length" },
+    Field { name: "PRESCALE", offset: 8, width: 8, access: Access::ReadWrite, description: "Prescaler timer period" },
+    Field { name: "RT", offset: 16, width: 8, access: Access::ReadWrite, description: "Refresh timer period" },
+    Field { name: "UT", offset: 24, width: 8, access: Access::ReadWrite, description: "Refresh urgent threshold" },
+];
+const F112: &[Field] = &[
+    Field { name: "PS", offset: 0, width: 1, access: Access::ReadWrite, description: "Port Size" },
+    Field { name: "SYNCEN", offset: 1, width: 1, access: Access::ReadWrite, description: "Select NAND controller mode." },
+    Field { name: "BL", offset: 4, width: 3, access: Access::ReadWrite, description: "Burst Length" },
+    Field { name: "EDO", offset: 7, width: 1, access: Access::ReadWrite, description: "EDO mode enabled" },
+    Field { name: "COL", offset: 8, width: 3, access: Access::ReadWrite, description: "Column address bit number" },
+];
+const F113: &[Field] = &[
+    Field { name: "CES", offset: 0, width: 4, access: Access::ReadWrite, description: "CE setup time" },
+    Field { name: "CEH", offset: 4, width: 4, access: Access::ReadWrite, description: "CE hold time" },
+    Field { name: "WEL", offset: 8, width: 4, access: Access::ReadWrite, description: "WE# LOW time" },
+    Field { name: "WEH", offset: 12, width: 4, access: Access::ReadWrite, description: "WE# HIGH time" },
+    Field { name: "REL", offset: 16, width: 4, access: Access::ReadWrite, description: "RE# LOW time" },
+    Field { name: "REH", offset: 20, width: 4, access: Access::ReadWrite, description: "RE# HIGH time" },
+    Field { name: "TA", offset: 24, width: 4, access: Access::ReadWrite, description: "Turnaround time" },
+    Field { name: "CEITV", offset: 28, width: 4, access: Access::ReadWrite, description: "CE# interval time" },
+];
+const F114: &[Field] = &[
+    Field { name: "TWHR", offset: 0, width: 6, access: Access::ReadWrite, description: "WE# HIGH to RE# LOW wait time" },
+    Field { name: "TRHW", offset: 6, width: 6, access: Access::ReadWrite, description: "RE# HIGH to WE# LOW wait time" },
+    Field { name: "TADL", offset: 12, width: 6, access: Access::ReadWrite, description: "ALE to WRITE Data start wait time" },
+    Field { name: "TRR", offset: 18, width: 6, access: Access::ReadWrite, description: "Ready to RE# LOW min wait time" },
+    Field { name: "TWB", offset: 24, width: 6, access: Access::ReadWrite, description: "WE# HIGH to busy wait time" },
+];
+const F115: &[Field] = &[
+    Field { name: "NDOPT1", offset: 0, width: 1, access: Access::ReadWrite, description: "NAND option bit 1" },
+    Field { name: "NDOPT2", offset: 1, width: 1, access: Access::ReadWrite, description: "NAND option bit 2" },
+    Field { name: "NDOPT3", offset: 2, width: 1, access: Access::ReadWrite, description: "NAND option bit 3" },
+    Field { name: "CLE", offset: 3, width: 1, access: Access::ReadWrite, description: "NAND CLE Option" },
+    Field { name: "RDS", offset: 16, width: 4, access: Access::ReadWrite, description: "Read Data Setup cycle time." },
+    Field { name: "RDH", offset: 20, width: 4, access: Access::ReadWrite, description: "Read Data Hold cycle time." },
+    Field { name: "WDS", offset: 24, width: 4, access: Access::ReadWrite, description: "Write Data Setup cycle time." },
+    Field { name: "WDH", offset: 28, width: 4, access: Access::ReadWrite, description: "Write Data Hold cycle time." },
+];
+const F116: &[Field] = &[
+    Field { name: "PS", offset: 0, width: 1, access: Access::ReadWrite, description: "Port Size" },
+    Field { name: "SYNCEN", offset: 1, width: 1, access: Access::ReadWrite, description: "Select NOR controller mode." },
+    Field { name: "BL", offset: 4, width: 3, access: Access::ReadWrite, description: "Burst Length" },
+    Field { name: "AM", offset: 8, width: 2, access: Access::ReadWrite, description: "Address Mode" },
+    Field { name: "ADVP", offset: 10, width: 1, access: Access::ReadWrite, description: "ADV# polarity" },
+    Field { name: "ADVH", offset: 11, width: 1, access: Access::ReadWrite, description: "ADV# level control during address hold state" },
+    Field { name: "COL", offset: 12, width: 4, access: Access::ReadWrite, description: "Column Address bit width" },
+];
+const F117: &[Field] = &[
+    Field { name: "CES", offset: 0, width: 4, access: Access::ReadWrite, description: "CE setup time cycle" },
+    Field { name: "CEH", offset: 4, width: 4, access: Access::ReadWrite, description: "CE hold min time (CEH+1) cycle" },
+    Field { name: "AS", offset: 8, width: 4, access: Access::ReadWrite, description: "Address setup time" },
+    Field { name: "AH", offset: 12, width: 4, access: Access::ReadWrite, description: "Address hold time" },
+    Field { name: "WEL", offset: 16, width: 4, access: Access::ReadWrite, description: "WE LOW time (WEL+1) cycle" },
+    Field { name: "WEH", offset: 20, width: 4, access: Access::ReadWrite, description: "WE HIGH time (WEH+1) cycle" },
+    Field { name: "REL", offset: 24, width: 4, access: Access::ReadWrite, description: "RE LOW time (REL+1) cycle" },
+    Field { name: "REH", offset: 28, width: 4, access: Access::ReadWrite, description: "RE HIGH time (REH+1) cycle" },
+];
+const F118: &[Field] = &[
+    Field { name: "TA", offset: 8, width: 4, access: Access::ReadWrite, description: "Turnaround time cycle" },
+    Field { name: "AWDH", offset: 12, width: 4, access: Access::ReadWrite, description: "Address to write data hold time cycle" },
+    Field { name: "LC", offset: 16, width: 4, access: Access::ReadWrite, description: "Latency count" },
+    Field { name: "RD", offset: 20, width: 4, access: Access::ReadWrite, description: "Read cycle time" },
+    Field { name: "CEITV", offset: 24, width: 4, access: Access::ReadWrite, description: "CE# interval min time" },
+    Field { name: "RDH", offset: 28, width: 4, access: Access::ReadWrite, description: "Read cycle hold time" },
+];
+const F119: &[Field] = &[
+    Field { name: "ASSR", offset: 0, width: 4, access: Access::ReadWrite, description: "Address setup time for synchronous read" },
+    Field { name: "AHSR", offset: 4, width: 4, access: Access::ReadWrite, description: "Address hold time for synchronous read" },
+];
+const F120: &[Field] = &[
+    Field { name: "PS", offset: 0, width: 1, access: Access::ReadWrite, description: "Port Size" },
+    Field { name: "SYNCEN", offset: 1, width: 1, access: Access::ReadWrite, description: "Select SRAM controller mode." },
+    Field { name: "BL", offset: 4, width: 3, access: Access::ReadWrite, description: "Burst Length" },
+    Field { name: "AM", offset: 8, width: 2, access: Access::ReadWrite, description: "Address Mode" },
+    Field { name: "ADVP", offset: 10, width: 1, access: Access::ReadWrite, description: "ADV# polarity" },
+    Field { name: "ADVH", offset: 11, width: 1, access: Access::ReadWrite, description: "ADV# level control during address hold state" },
+    Field { name: "COL", offset: 12, width: 4, access: Access::ReadWrite, description: "Column Address bit width" },
+];
+const F121: &[Field] = &[
+    Field { name: "WDS", offset: 0, width: 4, access: Access::ReadWrite, description: "Write Data setup time (WDS+1) cycle" },
+    Field { name: "WDH", offset: 4, width: 4, access: Access::ReadWrite, description: "Write Data hold time WDH cycle" },
+    Field { name: "TA", offset: 8, width: 4, access: Access::ReadWrite, description: "Turnaround time cycle" },
+    Field { name: "AWDH", offset: 12, width: 4, access: Access::ReadWrite, description: "Address to write data hold time cycle" },
+    Field { name: "LC", offset: 16, width: 4, access: Access::ReadWrite, description: "Latency count" },
+    Field { name: "RD", offset: 20, width: 4, access: Access::ReadWrite, description: "Read cycle time" },
+    Field { name: "CEITV", offset: 24, width: 4, access: Access::ReadWrite, description: "CE# interval min time" },
+    Field { name: "RDH", offset: 28, width: 4, access: Access::ReadWrite, description: "Read cycle hold time" },
+];
+const F122: &[Field] = &[
+    Field { name: "PS", offset: 0, width: 1, access: Access::ReadWrite, description: "Port Size" },
+    Field { name: "BL", offset: 4, width: 3, access: Access::ReadWrite, description: "Burst Length" },
+    Field { name: "COL", offset: 12, width: 4, access: Access::ReadWrite, description: "Column Address bit width" },
+];
+const F123: &[Field] = &[
+    Field { name: "CES", offset: 0, width: 4, access: Access::ReadWrite, description: "CSX Setup Time" },
+    Field { name: "CEH", offset: 4, width: 4, access: Access::ReadWrite, description: "CSX Hold Time" },
+    Field { name: "WEL", offset: 8, width: 4, access: Access::ReadWrite, description: "WRX Low Time" },
+    Field { name: "WEH", offset: 12, width: 4, access: Access::ReadWrite, description: "WRX High Time" },
+    Field { name: "REL", offset: 16, width: 6, access: Access::ReadWrite, description: "RDX Low Time" },
+    Field { name: "REH", offset: 22, width: 6, access: Access::ReadWrite, description: "RDX High Time" },
+    Field { name: "CEITV", offset: 28, width: 4, access: Access::ReadWrite, description: "CSX interval min time" },
+];
+const F124: &[Field] = &[
+    Field { name: "DATSZ", offset: 0, width: 3, access: Access::ReadWrite, description: "Data Size in Byte" },
+    Field { name: "NAND_EXT_ADDR", offset: 8, width: 8, access: Access::ReadWrite, description: "NAND Extended Address" },
+];
+const F125: &[Field] = &[
+    Field { name: "BM0", offset: 0, width: 1, access: Access::ReadWrite, description: "Byte Mask for Byte 0 (IPTXD bit 7:0)" },
+    Field { name: "BM1", offset: 1, width: 1, access: Access::ReadWrite, description: "Byte Mask for Byte 1 (IPTXD bit 15:8)" },
+    Field { name: "BM2", offset: 2, width: 1, access: Access::ReadWrite, description: "Byte Mask for Byte 2 (IPTXD bit 23:16)" },
+    Field { name: "BM3", offset: 3, width: 1, access: Access::ReadWrite, description: "Byte Mask for Byte 3 (IPTXD bit 31:24)" },
+];
+const F126: &[Field] = &[
+    Field { name: "CMD", offset: 0, width: 16, access: Access::ReadWrite, description: "SDRAM Commands: 0x8: READ 0x9: WRITE 0xA: MODESET 0xB: ACTIVE 0xC: AUTO REFRESH 0xD: SELF REFRESH 0xE: PRECHARGE 0xF: PRECHARGE ALL Others: RSVD SELF REFRESH will be sent to all SDRAM devices because they shared same SEMC_CLK pin" },
+    Field { name: "KEY", offset: 16, width: 16, access: Access::ReadWrite, description: "This field should be written with 0xA55A when trigging an IP command." },
+];
+const F127: &[Field] = &[
+    Field { name: "IDLE", offset: 0, width: 1, access: Access::ReadOnly, description: "Indicating whether SEMC is in IDLE state." },
+    Field { name: "NARDY", offset: 1, width: 1, access: Access::ReadOnly, description: "Indicating NAND device Ready/WAIT# pin level." },
+];
+const F128: &[Field] = &[
+    Field { name: "NDWRPEND", offset: 3, width: 1, access: Access::ReadOnly, description: "This field indicating whether there is pending AXI command (write) to NAND device." },
+];
+const F129: &[Field] = &[
+    Field { name: "SLVLOCK", offset: 0, width: 1, access: Access::ReadOnly, description: "Sample clock slave delay line locked." },
+    Field { name: "REFLOCK", offset: 1, width: 1, access: Access::ReadOnly, description: "Sample clock reference delay line locked." },
+    Field { name: "SLVSEL", offset: 2, width: 6, access: Access::ReadOnly, description: "Sample clock slave delay line delay cell number selection ." },
+    Field { name: "REFSEL", offset: 8, width: 6, access: Access::ReadOnly, description: "Sample clock reference delay line delay cell number selection." },
+];
+
+pub(super) const REGISTERS: &[Register] = &[
+    Register { module: "iomuxc_snvs_gpr", peripheral: "IOMUXC_SNVS_GPR", name: "GPR0", address: 0x400A_4000, width: Width::B4, access: Access::ReadOnly, description: "GPR0 General Purpose Register", fields: F0 },
+    Register { module: "iomuxc_snvs_gpr", peripheral: "IOMUXC_SNVS_GPR", name: "GPR1", address: 0x400A_4004, width: Width::B4, access: Access::ReadOnly, description: "GPR1 General Purpose Register", fields: F0 },
+    Register { module: "iomuxc_snvs_gpr", peripheral: "IOMUXC_SNVS_GPR", name: "GPR2", address: 0x400A_4008, width: Width::B4, access: Access::ReadOnly, description: "GPR2 General Purpose Register", fields: F0 },
+    Register { module: "iomuxc_snvs_gpr", peripheral: "IOMUXC_SNVS_GPR", name: "GPR3", address: 0x400A_400C, width: Width::B4, access: Access::ReadWrite, description: "GPR3 General Purpose Register", fields: F1 },
+    Register { module: "iomuxc_snvs", peripheral: "IOMUXC_SNVS", name: "SW_MUX_CTL_PAD_WAKEUP", address: 0x400A_8000, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_WAKEUP SW MUX Control Register", fields: F2 },
+    Register { module: "iomuxc_snvs", peripheral: "IOMUXC_SNVS", name: "SW_MUX_CTL_PAD_PMIC_ON_REQ", address: 0x400A_8004, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_PMIC_ON_REQ SW MUX Control Register", fields: F2 },
+    Register { module: "iomuxc_snvs", peripheral: "IOMUXC_SNVS", name: "SW_MUX_CTL_PAD_PMIC_STBY_REQ", address: 0x400A_8008, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_PMIC_STBY_REQ SW MUX Control Register", fields: F2 },
+    Register { module: "iomuxc_snvs", peripheral: "IOMUXC_SNVS", name: "SW_PAD_CTL_PAD_TEST_MODE", address: 0x400A_800C, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_TEST_MODE SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc_snvs", peripheral: "IOMUXC_SNVS", name: "SW_PAD_CTL_PAD_POR_B", address: 0x400A_8010, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_POR_B SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc_snvs", peripheral: "IOMUXC_SNVS", name: "SW_PAD_CTL_PAD_ONOFF", address: 0x400A_8014, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_ONOFF SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc_snvs", peripheral: "IOMUXC_SNVS", name: "SW_PAD_CTL_PAD_WAKEUP", address: 0x400A_8018, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_WAKEUP SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc_snvs", peripheral: "IOMUXC_SNVS", name: "SW_PAD_CTL_PAD_PMIC_ON_REQ", address: 0x400A_801C, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_PMIC_ON_REQ SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc_snvs", peripheral: "IOMUXC_SNVS", name: "SW_PAD_CTL_PAD_PMIC_STBY_REQ", address: 0x400A_8020, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_PMIC_STBY_REQ SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc_gpr", peripheral: "IOMUXC_GPR", name: "GPR0", address: 0x400A_C000, width: Width::B4, access: Access::ReadOnly, description: "GPR0 General Purpose Register", fields: F0 },
+    Register { module: "iomuxc_gpr", peripheral: "IOMUXC_GPR", name: "GPR1", address: 0x400A_C004, width: Width::B4, access: Access::ReadWrite, description: "GPR1 General Purpose Register", fields: F4 },
+    Register { module: "iomuxc_gpr", peripheral: "IOMUXC_GPR", name: "GPR2", address: 0x400A_C008, width: Width::B4, access: Access::ReadWrite, description: "GPR2 General Purpose Register", fields: F5 },
+    Register { module: "iomuxc_gpr", peripheral: "IOMUXC_GPR", name: "GPR3", address: 0x400A_C00C, width: Width::B4, access: Access::ReadWrite, description: "GPR3 General Purpose Register", fields: F6 },
+    Register { module: "iomuxc_gpr", peripheral: "IOMUXC_GPR", name: "GPR4", address: 0x400A_C010, width: Width::B4, access: Access::ReadWrite, description: "GPR4 General Purpose Register", fields: F7 },
+    Register { module: "iomuxc_gpr", peripheral: "IOMUXC_GPR", name: "GPR5", address: 0x400A_C014, width: Width::B4, access: Access::ReadWrite, description: "GPR5 General Purpose Register", fields: F8 },
+    Register { module: "iomuxc_gpr", peripheral: "IOMUXC_GPR", name: "GPR6", address: 0x400A_C018, width: Width::B4, access: Access::ReadWrite, description: "GPR6 General Purpose Register", fields: F9 },
+    Register { module: "iomuxc_gpr", peripheral: "IOMUXC_GPR", name: "GPR7", address: 0x400A_C01C, width: Width::B4, access: Access::ReadWrite, description: "GPR7 General Purpose Register", fields: F10 },
+    Register { module: "iomuxc_gpr", peripheral: "IOMUXC_GPR", name: "GPR8", address: 0x400A_C020, width: Width::B4, access: Access::ReadWrite, description: "GPR8 General Purpose Register", fields: F11 },
+    Register { module: "iomuxc_gpr", peripheral: "IOMUXC_GPR", name: "GPR9", address: 0x400A_C024, width: Width::B4, access: Access::ReadOnly, description: "GPR9 General Purpose Register", fields: F0 },
+    Register { module: "iomuxc_gpr", peripheral: "IOMUXC_GPR", name: "GPR10", address: 0x400A_C028, width: Width::B4, access: Access::ReadWrite, description: "GPR10 General Purpose Register", fields: F12 },
+    Register { module: "iomuxc_gpr", peripheral: "IOMUXC_GPR", name: "GPR11", address: 0x400A_C02C, width: Width::B4, access: Access::ReadWrite, description: "GPR11 General Purpose Register", fields: F13 },
+    Register { module: "iomuxc_gpr", peripheral: "IOMUXC_GPR", name: "GPR12", address: 0x400A_C030, width: Width::B4, access: Access::ReadWrite, description: "GPR12 General Purpose Register", fields: F14 },
+    Register { module: "iomuxc_gpr", peripheral: "IOMUXC_GPR", name: "GPR13", address: 0x400A_C034, width: Width::B4, access: Access::ReadWrite, description: "GPR13 General Purpose Register", fields: F15 },
+    Register { module: "iomuxc_gpr", peripheral: "IOMUXC_GPR", name: "GPR14", address: 0x400A_C038, width: Width::B4, access: Access::ReadWrite, description: "GPR14 General Purpose Register", fields: F16 },
+    Register { module: "iomuxc_gpr", peripheral: "IOMUXC_GPR", name: "GPR15", address: 0x400A_C03C, width: Width::B4, access: Access::ReadOnly, description: "GPR15 General Purpose Register", fields: F0 },
+    Register { module: "iomuxc_gpr", peripheral: "IOMUXC_GPR", name: "GPR16", address: 0x400A_C040, width: Width::B4, access: Access::ReadWrite, description: "GPR16 General Purpose Register", fields: F17 },
+    Register { module: "iomuxc_gpr", peripheral: "IOMUXC_GPR", name: "GPR17", address: 0x400A_C044, width: Width::B4, access: Access::ReadWrite, description: "GPR17 General Purpose Register", fields: F0 },
+    Register { module: "iomuxc_gpr", peripheral: "IOMUXC_GPR", name: "GPR18", address: 0x400A_C048, width: Width::B4, access: Access::ReadWrite, description: "GPR18 General Purpose Register", fields: F18 },
+    Register { module: "iomuxc_gpr", peripheral: "IOMUXC_GPR", name: "GPR19", address: 0x400A_C04C, width: Width::B4, access: Access::ReadWrite, description: "GPR19 General Purpose Register", fields: F19 },
+    Register { module: "iomuxc_gpr", peripheral: "IOMUXC_GPR", name: "GPR20", address: 0x400A_C050, width: Width::B4, access: Access::ReadWrite, description: "GPR20 General Purpose Register", fields: F20 },
+    Register { module: "iomuxc_gpr", peripheral: "IOMUXC_GPR", name: "GPR21", address: 0x400A_C054, width: Width::B4, access: Access::ReadWrite, description: "GPR21 General Purpose Register", fields: F21 },
+    Register { module: "iomuxc_gpr", peripheral: "IOMUXC_GPR", name: "GPR22", address: 0x400A_C058, width: Width::B4, access: Access::ReadWrite, description: "GPR22 General Purpose Register", fields: F22 },
+    Register { module: "iomuxc_gpr", peripheral: "IOMUXC_GPR", name: "GPR23", address: 0x400A_C05C, width: Width::B4, access: Access::ReadWrite, description: "GPR23 General Purpose Register", fields: F23 },
+    Register { module: "iomuxc_gpr", peripheral: "IOMUXC_GPR", name: "GPR24", address: 0x400A_C060, width: Width::B4, access: Access::ReadWrite, description: "GPR24 General Purpose Register", fields: F24 },
+    Register { module: "iomuxc_gpr", peripheral: "IOMUXC_GPR", name: "GPR25", address: 0x400A_C064, width: Width::B4, access: Access::ReadWrite, description: "GPR25 General Purpose Register", fields: F25 },
+    Register { module: "iomuxc_gpr", peripheral: "IOMUXC_GPR", name: "GPR26", address: 0x400A_C068, width: Width::B4, access: Access::ReadWrite, description: "GPR26 General Purpose Register", fields: F0 },
+    Register { module: "iomuxc_gpr", peripheral: "IOMUXC_GPR", name: "GPR27", address: 0x400A_C06C, width: Width::B4, access: Access::ReadWrite, description: "GPR27 General Purpose Register", fields: F0 },
+    Register { module: "iomuxc_gpr", peripheral: "IOMUXC_GPR", name: "GPR28", address: 0x400A_C070, width: Width::B4, access: Access::ReadWrite, description: "GPR28 General Purpose Register", fields: F0 },
+    Register { module: "iomuxc_gpr", peripheral: "IOMUXC_GPR", name: "GPR29", address: 0x400A_C074, width: Width::B4, access: Access::ReadWrite, description: "GPR29 General Purpose Register", fields: F0 },
+    Register { module: "iomuxc_gpr", peripheral: "IOMUXC_GPR", name: "GPR30", address: 0x400A_C078, width: Width::B4, access: Access::ReadWrite, description: "GPR30 General Purpose Register", fields: F0 },
+    Register { module: "iomuxc_gpr", peripheral: "IOMUXC_GPR", name: "GPR31", address: 0x400A_C07C, width: Width::B4, access: Access::ReadWrite, description: "GPR31 General Purpose Register", fields: F0 },
+    Register { module: "iomuxc_gpr", peripheral: "IOMUXC_GPR", name: "GPR32", address: 0x400A_C080, width: Width::B4, access: Access::ReadWrite, description: "GPR32 General Purpose Register", fields: F0 },
+    Register { module: "iomuxc_gpr", peripheral: "IOMUXC_GPR", name: "GPR33", address: 0x400A_C084, width: Width::B4, access: Access::ReadWrite, description: "GPR33 General Purpose Register", fields: F26 },
+    Register { module: "iomuxc_gpr", peripheral: "IOMUXC_GPR", name: "GPR34", address: 0x400A_C088, width: Width::B4, access: Access::ReadWrite, description: "GPR34 General Purpose Register", fields: F27 },
+    Register { module: "ccm_analog", peripheral: "CCM_ANALOG", name: "PLL_ARM", address: 0x400D_8000, width: Width::B4, access: Access::ReadWrite, description: "Analog ARM PLL control Register", fields: F33 },
+    Register { module: "ccm_analog", peripheral: "CCM_ANALOG", name: "PLL_ARM_SET", address: 0x400D_8004, width: Width::B4, access: Access::ReadWrite, description: "Analog ARM PLL control Register", fields: F33 },
+    Register { module: "ccm_analog", peripheral: "CCM_ANALOG", name: "PLL_ARM_CLR", address: 0x400D_8008, width: Width::B4, access: Access::ReadWrite, description: "Analog ARM PLL control Register", fields: F33 },
+    Register { module: "ccm_analog", peripheral: "CCM_ANALOG", name: "PLL_ARM_TOG", address: 0x400D_800C, width: Width::B4, access: Access::ReadWrite, description: "Analog ARM PLL control Register", fields: F33 },
+    Register { module: "ccm_analog", peripheral: "CCM_ANALOG", name: "PLL_USB1", address: 0x400D_8010, width: Width::B4, access: Access::ReadWrite, description: "Analog USB1 480MHz PLL Control Register", fields: F34 },
+    Register { module: "ccm_analog", peripheral: "CCM_ANALOG", name: "PLL_USB1_SET", address: 0x400D_8014, width: Width::B4, access: Access::ReadWrite, description: "Analog USB1 480MHz PLL Control Register", fields: F34 },
+    Register { module: "ccm_analog", peripheral: "CCM_ANALOG", name: "PLL_USB1_CLR", address: 0x400D_8018, width: Width::B4, access: Access::ReadWrite, description: "Analog USB1 480MHz PLL Control Register", fields: F34 },
+    Register { module: "ccm_analog", peripheral: "CCM_ANALOG", name: "PLL_USB1_TOG", address: 0x400D_801C, width: Width::B4, access: Access::ReadWrite, description: "Analog USB1 480MHz PLL Control Register", fields: F34 },
+    Register { module: "ccm_analog", peripheral: "CCM_ANALOG", name: "PLL_USB2", address: 0x400D_8020, width: Width::B4, access: Access::ReadWrite, description: "Analog USB2 480MHz PLL Control Register", fields: F35 },
+    Register { module: "ccm_analog", peripheral: "CCM_ANALOG", name: "PLL_USB2_SET", address: 0x400D_8024, width: Width::B4, access: Access::ReadWrite, description: "Analog USB2 480MHz PLL Control Register", fields: F35 },
+    Register { module: "ccm_analog", peripheral: "CCM_ANALOG", name: "PLL_USB2_CLR", address: 0x400D_8028, width: Width::B4, access: Access::ReadWrite, description: "Analog USB2 480MHz PLL Control Register", fields: F35 },
+    Register { module: "ccm_analog", peripheral: "CCM_ANALOG", name: "PLL_USB2_TOG", address: 0x400D_802C, width: Width::B4, access: Access::ReadWrite, description: "Analog USB2 480MHz PLL Control Register", fields: F35 },
+    Register { module: "ccm_analog", peripheral: "CCM_ANALOG", name: "PLL_SYS", address: 0x400D_8030, width: Width::B4, access: Access::ReadWrite, description: "Analog System PLL Control Register", fields: F36 },
+    Register { module: "ccm_analog", peripheral: "CCM_ANALOG", name: "PLL_SYS_SET", address: 0x400D_8034, width: Width::B4, access: Access::ReadWrite, description: "Analog System PLL Control Register", fields: F36 },
+    Register { module: "ccm_analog", peripheral: "CCM_ANALOG", name: "PLL_SYS_CLR", address: 0x400D_8038, width: Width::B4, access: Access::ReadWrite, description: "Analog System PLL Control Register", fields: F36 },
+    Register { module: "ccm_analog", peripheral: "CCM_ANALOG", name: "PLL_SYS_TOG", address: 0x400D_803C, width: Width::B4, access: Access::ReadWrite, description: "Analog System PLL Control Register", fields: F36 },
+    Register { module: "ccm_analog", peripheral: "CCM_ANALOG", name: "PLL_SYS_SS", address: 0x400D_8040, width: Width::B4, access: Access::ReadWrite, description: "528MHz System PLL Spread Spectrum Register", fields: F37 },
+    Register { module: "ccm_analog", peripheral: "CCM_ANALOG", name: "PLL_SYS_NUM", address: 0x400D_8050, width: Width::B4, access: Access::ReadWrite, description: "Numerator of 528MHz System PLL Fractional Loop Divider Register", fields: F0 },
+    Register { module: "ccm_analog", peripheral: "CCM_ANALOG", name: "PLL_SYS_DENOM", address: 0x400D_8060, width: Width::B4, access: Access::ReadWrite, description: "Denominator of 528MHz System PLL Fractional Loop Divider Register", fields: F0 },
+    Register { module: "ccm_analog", peripheral: "CCM_ANALOG", name: "PLL_AUDIO", address: 0x400D_8070, width: Width::B4, access: Access::ReadWrite, description: "Analog Audio PLL control Register", fields: F38 },
+    Register { module: "ccm_analog", peripheral: "CCM_ANALOG", name: "PLL_AUDIO_SET", address: 0x400D_8074, width: Width::B4, access: Access::ReadWrite, description: "Analog Audio PLL control Register", fields: F38 },
+    Register { module: "ccm_analog", peripheral: "CCM_ANALOG", name: "PLL_AUDIO_CLR", address: 0x400D_8078, width: Width::B4, access: Access::ReadWrite, description: "Analog Audio PLL control Register", fields: F38 },
+    Register { module: "ccm_analog", peripheral: "CCM_ANALOG", name: "PLL_AUDIO_TOG", address: 0x400D_807C, width: Width::B4, access: Access::ReadWrite, description: "Analog Audio PLL control Register", fields: F38 },
+    Register { module: "ccm_analog", peripheral: "CCM_ANALOG", name: "PLL_AUDIO_NUM", address: 0x400D_8080, width: Width::B4, access: Access::ReadWrite, description: "Numerator of Audio PLL Fractional Loop Divider Register", fields: F0 },
+    Register { module: "ccm_analog", peripheral: "CCM_ANALOG", name: "PLL_AUDIO_DENOM", address: 0x400D_8090, width: Width::B4, access: Access::ReadWrite, description: "Denominator of Audio PLL Fractional Loop Divider Register", fields: F0 },
+    Register { module: "ccm_analog", peripheral: "CCM_ANALOG", name: "PLL_VIDEO", address: 0x400D_80A0, width: Width::B4, access: Access::ReadWrite, description: "Analog Video PLL control Register", fields: F39 },
+    Register { module: "ccm_analog", peripheral: "CCM_ANALOG", name: "PLL_VIDEO_SET", address: 0x400D_80A4, width: Width::B4, access: Access::ReadWrite, description: "Analog Video PLL control Register", fields: F39 },
+    Register { module: "ccm_analog", peripheral: "CCM_ANALOG", name: "PLL_VIDEO_CLR", address: 0x400D_80A8, width: Width::B4, access: Access::ReadWrite, description: "Analog Video PLL control Register", fields: F39 },
+    Register { module: "ccm_analog", peripheral: "CCM_ANALOG", name: "PLL_VIDEO_TOG", address: 0x400D_80AC, width: Width::B4, access: Access::ReadWrite, description: "Analog Video PLL control Register", fields: F39 },
+    Register { module: "ccm_analog", peripheral: "CCM_ANALOG", name: "PLL_VIDEO_NUM", address: 0x400D_80B0, width: Width::B4, access: Access::ReadWrite, description: "Numerator of Video PLL Fractional Loop Divider Register", fields: F0 },
+    Register { module: "ccm_analog", peripheral: "CCM_ANALOG", name: "PLL_VIDEO_DENOM", address: 0x400D_80C0, width: Width::B4, access: Access::ReadWrite, description: "Denominator of Video PLL Fractional Loop Divider Register", fields: F0 },
+    Register { module: "ccm_analog", peripheral: "CCM_ANALOG", name: "PLL_ENET", address: 0x400D_80E0, width: Width::B4, access: Access::ReadWrite, description: "Analog ENET PLL Control Register", fields: F40 },
+    Register { module: "ccm_analog", peripheral: "CCM_ANALOG", name: "PLL_ENET_SET", address: 0x400D_80E4, width: Width::B4, access: Access::ReadWrite, description: "Analog ENET PLL Control Register", fields: F40 },
+    Register { module: "ccm_analog", peripheral: "CCM_ANALOG", name: "PLL_ENET_CLR", address: 0x400D_80E8, width: Width::B4, access: Access::ReadWrite, description: "Analog ENET PLL Control Register", fields: F40 },
+    Register { module: "ccm_analog", peripheral: "CCM_ANALOG", name: "PLL_ENET_TOG", address: 0x400D_80EC, width: Width::B4, access: Access::ReadWrite, description: "Analog ENET PLL Control Register", fields: F40 },
+    Register { module: "ccm_analog", peripheral: "CCM_ANALOG", name: "PFD_480", address: 0x400D_80F0, width: Width::B4, access: Access::ReadWrite, description: "480MHz Clock (PLL3) Phase Fractional Divider Control Register", fields: F41 },
+    Register { module: "ccm_analog", peripheral: "CCM_ANALOG", name: "PFD_480_SET", address: 0x400D_80F4, width: Width::B4, access: Access::ReadWrite, description: "480MHz Clock (PLL3) Phase Fractional Divider Control Register", fields: F41 },
+    Register { module: "ccm_analog", peripheral: "CCM_ANALOG", name: "PFD_480_CLR", address: 0x400D_80F8, width: Width::B4, access: Access::ReadWrite, description: "480MHz Clock (PLL3) Phase Fractional Divider Control Register", fields: F41 },
+    Register { module: "ccm_analog", peripheral: "CCM_ANALOG", name: "PFD_480_TOG", address: 0x400D_80FC, width: Width::B4, access: Access::ReadWrite, description: "480MHz Clock (PLL3) Phase Fractional Divider Control Register", fields: F41 },
+    Register { module: "ccm_analog", peripheral: "CCM_ANALOG", name: "PFD_528", address: 0x400D_8100, width: Width::B4, access: Access::ReadWrite, description: "528MHz Clock (PLL2) Phase Fractional Divider Control Register", fields: F41 },
+    Register { module: "ccm_analog", peripheral: "CCM_ANALOG", name: "PFD_528_SET", address: 0x400D_8104, width: Width::B4, access: Access::ReadWrite, description: "528MHz Clock (PLL2) Phase Fractional Divider Control Register", fields: F41 },
+    Register { module: "ccm_analog", peripheral: "CCM_ANALOG", name: "PFD_528_CLR", address: 0x400D_8108, width: Width::B4, access: Access::ReadWrite, description: "528MHz Clock (PLL2) Phase Fractional Divider Control Register", fields: F41 },
+    Register { module: "ccm_analog", peripheral: "CCM_ANALOG", name: "PFD_528_TOG", address: 0x400D_810C, width: Width::B4, access: Access::ReadWrite, description: "528MHz Clock (PLL2) Phase Fractional Divider Control Register", fields: F41 },
+    Register { module: "pmu", peripheral: "PMU", name: "REG_1P1", address: 0x400D_8110, width: Width::B4, access: Access::ReadWrite, description: "Regulator 1P1 Register", fields: F45 },
+    Register { module: "pmu", peripheral: "PMU", name: "REG_1P1_SET", address: 0x400D_8114, width: Width::B4, access: Access::ReadWrite, description: "Regulator 1P1 Register", fields: F45 },
+    Register { module: "pmu", peripheral: "PMU", name: "REG_1P1_CLR", address: 0x400D_8118, width: Width::B4, access: Access::ReadWrite, description: "Regulator 1P1 Register", fields: F45 },
+    Register { module: "pmu", peripheral: "PMU", name: "REG_1P1_TOG", address: 0x400D_811C, width: Width::B4, access: Access::ReadWrite, description: "Regulator 1P1 Register", fields: F45 },
+    Register { module: "pmu", peripheral: "PMU", name: "REG_3P0", address: 0x400D_8120, width: Width::B4, access: Access::ReadWrite, description: "Regulator 3P0 Register", fields: F46 },
+    Register { module: "pmu", peripheral: "PMU", name: "REG_3P0_SET", address: 0x400D_8124, width: Width::B4, access: Access::ReadWrite, description: "Regulator 3P0 Register", fields: F46 },
+    Register { module: "pmu", peripheral: "PMU", name: "REG_3P0_CLR", address: 0x400D_8128, width: Width::B4, access: Access::ReadWrite, description: "Regulator 3P0 Register", fields: F46 },
+    Register { module: "pmu", peripheral: "PMU", name: "REG_3P0_TOG", address: 0x400D_812C, width: Width::B4, access: Access::ReadWrite, description: "Regulator 3P0 Register", fields: F46 },
+    Register { module: "pmu", peripheral: "PMU", name: "REG_2P5", address: 0x400D_8130, width: Width::B4, access: Access::ReadWrite, description: "Regulator 2P5 Register", fields: F47 },
+    Register { module: "pmu", peripheral: "PMU", name: "REG_2P5_SET", address: 0x400D_8134, width: Width::B4, access: Access::ReadWrite, description: "Regulator 2P5 Register", fields: F47 },
+    Register { module: "pmu", peripheral: "PMU", name: "REG_2P5_CLR", address: 0x400D_8138, width: Width::B4, access: Access::ReadWrite, description: "Regulator 2P5 Register", fields: F47 },
+    Register { module: "pmu", peripheral: "PMU", name: "REG_2P5_TOG", address: 0x400D_813C, width: Width::B4, access: Access::ReadWrite, description: "Regulator 2P5 Register", fields: F47 },
+    Register { module: "pmu", peripheral: "PMU", name: "REG_CORE", address: 0x400D_8140, width: Width::B4, access: Access::ReadWrite, description: "Digital Regulator Core Register", fields: F48 },
+    Register { module: "pmu", peripheral: "PMU", name: "REG_CORE_SET", address: 0x400D_8144, width: Width::B4, access: Access::ReadWrite, description: "Digital Regulator Core Register", fields: F48 },
+    Register { module: "pmu", peripheral: "PMU", name: "REG_CORE_CLR", address: 0x400D_8148, width: Width::B4, access: Access::ReadWrite, description: "Digital Regulator Core Register", fields: F48 },
+    Register { module: "pmu", peripheral: "PMU", name: "REG_CORE_TOG", address: 0x400D_814C, width: Width::B4, access: Access::ReadWrite, description: "Digital Regulator Core Register", fields: F48 },
+    Register { module: "ccm_analog", peripheral: "CCM_ANALOG", name: "MISC0", address: 0x400D_8150, width: Width::B4, access: Access::ReadWrite, description: "Miscellaneous Register 0", fields: F42 },
+    Register { module: "ccm_analog", peripheral: "CCM_ANALOG", name: "MISC0_SET", address: 0x400D_8154, width: Width::B4, access: Access::ReadWrite, description: "Miscellaneous Register 0", fields: F42 },
+    Register { module: "ccm_analog", peripheral: "CCM_ANALOG", name: "MISC0_CLR", address: 0x400D_8158, width: Width::B4, access: Access::ReadWrite, description: "Miscellaneous Register 0", fields: F42 },
+    Register { module: "ccm_analog", peripheral: "CCM_ANALOG", name: "MISC0_TOG", address: 0x400D_815C, width: Width::B4, access: Access::ReadWrite, description: "Miscellaneous Register 0", fields: F42 },
+    Register { module: "ccm_analog", peripheral: "CCM_ANALOG", name: "MISC1", address: 0x400D_8160, width: Width::B4, access: Access::ReadWrite, description: "Miscellaneous Register 1", fields: F43 },
+    Register { module: "ccm_analog", peripheral: "CCM_ANALOG", name: "MISC1_SET", address: 0x400D_8164, width: Width::B4, access: Access::ReadWrite, description: "Miscellaneous Register 1", fields: F43 },
+    Register { module: "ccm_analog", peripheral: "CCM_ANALOG", name: "MISC1_CLR", address: 0x400D_8168, width: Width::B4, access: Access::ReadWrite, description: "Miscellaneous Register 1", fields: F43 },
+    Register { module: "ccm_analog", peripheral: "CCM_ANALOG", name: "MISC1_TOG", address: 0x400D_816C, width: Width::B4, access: Access::ReadWrite, description: "Miscellaneous Register 1", fields: F43 },
+    Register { module: "ccm_analog", peripheral: "CCM_ANALOG", name: "MISC2", address: 0x400D_8170, width: Width::B4, access: Access::ReadWrite, description: "Miscellaneous Register 2", fields: F44 },
+    Register { module: "ccm_analog", peripheral: "CCM_ANALOG", name: "MISC2_SET", address: 0x400D_8174, width: Width::B4, access: Access::ReadWrite, description: "Miscellaneous Register 2", fields: F44 },
+    Register { module: "ccm_analog", peripheral: "CCM_ANALOG", name: "MISC2_CLR", address: 0x400D_8178, width: Width::B4, access: Access::ReadWrite, description: "Miscellaneous Register 2", fields: F44 },
+    Register { module: "ccm_analog", peripheral: "CCM_ANALOG", name: "MISC2_TOG", address: 0x400D_817C, width: Width::B4, access: Access::ReadWrite, description: "Miscellaneous Register 2", fields: F44 },
+    Register { module: "tempmon", peripheral: "TEMPMON", name: "TEMPSENSE0", address: 0x400D_8180, width: Width::B4, access: Access::ReadWrite, description: "Tempsensor Control Register 0", fields: F49 },
+    Register { module: "tempmon", peripheral: "TEMPMON", name: "TEMPSENSE0_SET", address: 0x400D_8184, width: Width::B4, access: Access::ReadWrite, description: "Tempsensor Control Register 0", fields: F49 },
+    Register { module: "tempmon", peripheral: "TEMPMON", name: "TEMPSENSE0_CLR", address: 0x400D_8188, width: Width::B4, access: Access::ReadWrite, description: "Tempsensor Control Register 0", fields: F49 },
+    Register { module: "tempmon", peripheral: "TEMPMON", name: "TEMPSENSE0_TOG", address: 0x400D_818C, width: Width::B4, access: Access::ReadWrite, description: "Tempsensor Control Register 0", fields: F49 },
+    Register { module: "tempmon", peripheral: "TEMPMON", name: "TEMPSENSE1", address: 0x400D_8190, width: Width::B4, access: Access::ReadWrite, description: "Tempsensor Control Register 1", fields: F50 },
+    Register { module: "tempmon", peripheral: "TEMPMON", name: "TEMPSENSE1_SET", address: 0x400D_8194, width: Width::B4, access: Access::ReadWrite, description: "Tempsensor Control Register 1", fields: F50 },
+    Register { module: "tempmon", peripheral: "TEMPMON", name: "TEMPSENSE1_CLR", address: 0x400D_8198, width: Width::B4, access: Access::ReadWrite, description: "Tempsensor Control Register 1", fields: F50 },
+    Register { module: "tempmon", peripheral: "TEMPMON", name: "TEMPSENSE1_TOG", address: 0x400D_819C, width: Width::B4, access: Access::ReadWrite, description: "Tempsensor Control Register 1", fields: F50 },
+    Register { module: "usb_analog", peripheral: "USB_ANALOG", name: "USB1_VBUS_DETECT", address: 0x400D_81A0, width: Width::B4, access: Access::ReadWrite, description: "USB VBUS Detect Register", fields: F52 },
+    Register { module: "usb_analog", peripheral: "USB_ANALOG", name: "USB1_VBUS_DETECT_SET", address: 0x400D_81A4, width: Width::B4, access: Access::ReadWrite, description: "USB VBUS Detect Register", fields: F52 },
+    Register { module: "usb_analog", peripheral: "USB_ANALOG", name: "USB1_VBUS_DETECT_CLR", address: 0x400D_81A8, width: Width::B4, access: Access::ReadWrite, description: "USB VBUS Detect Register", fields: F52 },
+    Register { module: "usb_analog", peripheral: "USB_ANALOG", name: "USB1_VBUS_DETECT_TOG", address: 0x400D_81AC, width: Width::B4, access: Access::ReadWrite, description: "USB VBUS Detect Register", fields: F52 },
+    Register { module: "usb_analog", peripheral: "USB_ANALOG", name: "USB1_CHRG_DETECT", address: 0x400D_81B0, width: Width::B4, access: Access::ReadWrite, description: "USB Charger Detect Register", fields: F53 },
+    Register { module: "usb_analog", peripheral: "USB_ANALOG", name: "USB1_CHRG_DETECT_SET", address: 0x400D_81B4, width: Width::B4, access: Access::ReadWrite, description: "USB Charger Detect Register", fields: F53 },
+    Register { module: "usb_analog", peripheral: "USB_ANALOG", name: "USB1_CHRG_DETECT_CLR", address: 0x400D_81B8, width: Width::B4, access: Access::ReadWrite, description: "USB Charger Detect Register", fields: F53 },
+    Register { module: "usb_analog", peripheral: "USB_ANALOG", name: "USB1_CHRG_DETECT_TOG", address: 0x400D_81BC, width: Width::B4, access: Access::ReadWrite, description: "USB Charger Detect Register", fields: F53 },
+    Register { module: "usb_analog", peripheral: "USB_ANALOG", name: "USB1_VBUS_DETECT_STAT", address: 0x400D_81C0, width: Width::B4, access: Access::ReadOnly, description: "USB VBUS Detect Status Register", fields: F54 },
+    Register { module: "usb_analog", peripheral: "USB_ANALOG", name: "USB1_CHRG_DETECT_STAT", address: 0x400D_81D0, width: Width::B4, access: Access::ReadOnly, description: "USB Charger Detect Status Register", fields: F55 },
+    Register { module: "usb_analog", peripheral: "USB_ANALOG", name: "USB1_LOOPBACK", address: 0x400D_81E0, width: Width::B4, access: Access::ReadWrite, description: "USB Loopback Test Register", fields: F56 },
+    Register { module: "usb_analog", peripheral: "USB_ANALOG", name: "USB1_LOOPBACK_SET", address: 0x400D_81E4, width: Width::B4, access: Access::ReadWrite, description: "USB Loopback Test Register", fields: F56 },
+    Register { module: "usb_analog", peripheral: "USB_ANALOG", name: "USB1_LOOPBACK_CLR", address: 0x400D_81E8, width: Width::B4, access: Access::ReadWrite, description: "USB Loopback Test Register", fields: F56 },
+    Register { module: "usb_analog", peripheral: "USB_ANALOG", name: "USB1_LOOPBACK_TOG", address: 0x400D_81EC, width: Width::B4, access: Access::ReadWrite, description: "USB Loopback Test Register", fields: F56 },
+    Register { module: "usb_analog", peripheral: "USB_ANALOG", name: "USB1_MISC", address: 0x400D_81F0, width: Width::B4, access: Access::ReadWrite, description: "USB Misc Register", fields: F57 },
+    Register { module: "usb_analog", peripheral: "USB_ANALOG", name: "USB1_MISC_SET", address: 0x400D_81F4, width: Width::B4, access: Access::ReadWrite, description: "USB Misc Register", fields: F57 },
+    Register { module: "usb_analog", peripheral: "USB_ANALOG", name: "USB1_MISC_CLR", address: 0x400D_81F8, width: Width::B4, access: Access::ReadWrite, description: "USB Misc Register", fields: F57 },
+    Register { module: "usb_analog", peripheral: "USB_ANALOG", name: "USB1_MISC_TOG", address: 0x400D_81FC, width: Width::B4, access: Access::ReadWrite, description: "USB Misc Register", fields: F57 },
+    Register { module: "usb_analog", peripheral: "USB_ANALOG", name: "USB2_VBUS_DETECT", address: 0x400D_8200, width: Width::B4, access: Access::ReadWrite, description: "USB VBUS Detect Register", fields: F52 },
+    Register { module: "usb_analog", peripheral: "USB_ANALOG", name: "USB2_VBUS_DETECT_SET", address: 0x400D_8204, width: Width::B4, access: Access::ReadWrite, description: "USB VBUS Detect Register", fields: F52 },
+    Register { module: "usb_analog", peripheral: "USB_ANALOG", name: "USB2_VBUS_DETECT_CLR", address: 0x400D_8208, width: Width::B4, access: Access::ReadWrite, description: "USB VBUS Detect Register", fields: F52 },
+    Register { module: "usb_analog", peripheral: "USB_ANALOG", name: "USB2_VBUS_DETECT_TOG", address: 0x400D_820C, width: Width::B4, access: Access::ReadWrite, description: "USB VBUS Detect Register", fields: F52 },
+    Register { module: "usb_analog", peripheral: "USB_ANALOG", name: "USB2_CHRG_DETECT", address: 0x400D_8210, width: Width::B4, access: Access::ReadWrite, description: "USB Charger Detect Register", fields: F53 },
+    Register { module: "usb_analog", peripheral: "USB_ANALOG", name: "USB2_CHRG_DETECT_SET", address: 0x400D_8214, width: Width::B4, access: Access::ReadWrite, description: "USB Charger Detect Register", fields: F53 },
+    Register { module: "usb_analog", peripheral: "USB_ANALOG", name: "USB2_CHRG_DETECT_CLR", address: 0x400D_8218, width: Width::B4, access: Access::ReadWrite, description: "USB Charger Detect Register", fields: F53 },
+    Register { module: "usb_analog", peripheral: "USB_ANALOG", name: "USB2_CHRG_DETECT_TOG", address: 0x400D_821C, width: Width::B4, access: Access::ReadWrite, description: "USB Charger Detect Register", fields: F53 },
+    Register { module: "usb_analog", peripheral: "USB_ANALOG", name: "USB2_VBUS_DETECT_STAT", address: 0x400D_8220, width: Width::B4, access: Access::ReadOnly, description: "USB VBUS Detect Status Register", fields: F54 },
+    Register { module: "usb_analog", peripheral: "USB_ANALOG", name: "USB2_CHRG_DETECT_STAT", address: 0x400D_8230, width: Width::B4, access: Access::ReadOnly, description: "USB Charger Detect Status Register", fields: F55 },
+    Register { module: "usb_analog", peripheral: "USB_ANALOG", name: "USB2_LOOPBACK", address: 0x400D_8240, width: Width::B4, access: Access::ReadWrite, description: "USB Loopback Test Register", fields: F56 },
+    Register { module: "usb_analog", peripheral: "USB_ANALOG", name: "USB2_LOOPBACK_SET", address: 0x400D_8244, width: Width::B4, access: Access::ReadWrite, description: "USB Loopback Test Register", fields: F56 },
+    Register { module: "usb_analog", peripheral: "USB_ANALOG", name: "USB2_LOOPBACK_CLR", address: 0x400D_8248, width: Width::B4, access: Access::ReadWrite, description: "USB Loopback Test Register", fields: F56 },
+    Register { module: "usb_analog", peripheral: "USB_ANALOG", name: "USB2_LOOPBACK_TOG", address: 0x400D_824C, width: Width::B4, access: Access::ReadWrite, description: "USB Loopback Test Register", fields: F56 },
+    Register { module: "usb_analog", peripheral: "USB_ANALOG", name: "USB2_MISC", address: 0x400D_8250, width: Width::B4, access: Access::ReadWrite, description: "USB Misc Register", fields: F57 },
+    Register { module: "usb_analog", peripheral: "USB_ANALOG", name: "USB2_MISC_SET", address: 0x400D_8254, width: Width::B4, access: Access::ReadWrite, description: "USB Misc Register", fields: F57 },
+    Register { module: "usb_analog", peripheral: "USB_ANALOG", name: "USB2_MISC_CLR", address: 0x400D_8258, width: Width::B4, access: Access::ReadWrite, description: "USB Misc Register", fields: F57 },
+    Register { module: "usb_analog", peripheral: "USB_ANALOG", name: "USB2_MISC_TOG", address: 0x400D_825C, width: Width::B4, access: Access::ReadWrite, description: "USB Misc Register", fields: F57 },
+    Register { module: "usb_analog", peripheral: "USB_ANALOG", name: "DIGPROG", address: 0x400D_8260, width: Width::B4, access: Access::ReadOnly, description: "Chip Silicon Version", fields: F0 },
+    Register { module: "xtalosc24m", peripheral: "XTALOSC24M", name: "LOWPWR_CTRL", address: 0x400D_8270, width: Width::B4, access: Access::ReadWrite, description: "XTAL OSC (LP) Control Register", fields: F58 },
+    Register { module: "xtalosc24m", peripheral: "XTALOSC24M", name: "LOWPWR_CTRL_SET", address: 0x400D_8274, width: Width::B4, access: Access::ReadWrite, description: "XTAL OSC (LP) Control Register", fields: F58 },
+    Register { module: "xtalosc24m", peripheral: "XTALOSC24M", name: "LOWPWR_CTRL_CLR", address: 0x400D_8278, width: Width::B4, access: Access::ReadWrite, description: "XTAL OSC (LP) Control Register", fields: F58 },
+    Register { module: "xtalosc24m", peripheral: "XTALOSC24M", name: "LOWPWR_CTRL_TOG", address: 0x400D_827C, width: Width::B4, access: Access::ReadWrite, description: "XTAL OSC (LP) Control Register", fields: F58 },
+    Register { module: "tempmon", peripheral: "TEMPMON", name: "TEMPSENSE2", address: 0x400D_8290, width: Width::B4, access: Access::ReadWrite, description: "Tempsensor Control Register 2", fields: F51 },
+    Register { module: "tempmon", peripheral: "TEMPMON", name: "TEMPSENSE2_SET", address: 0x400D_8294, width: Width::B4, access: Access::ReadWrite, description: "Tempsensor Control Register 2", fields: F51 },
+    Register { module: "tempmon", peripheral: "TEMPMON", name: "TEMPSENSE2_CLR", address: 0x400D_8298, width: Width::B4, access: Access::ReadWrite, description: "Tempsensor Control Register 2", fields: F51 },
+    Register { module: "tempmon", peripheral: "TEMPMON", name: "TEMPSENSE2_TOG", address: 0x400D_829C, width: Width::B4, access: Access::ReadWrite, description: "Tempsensor Control Register 2", fields: F51 },
+    Register { module: "xtalosc24m", peripheral: "XTALOSC24M", name: "OSC_CONFIG0", address: 0x400D_82A0, width: Width::B4, access: Access::ReadWrite, description: "XTAL OSC Configuration 0 Register", fields: F59 },
+    Register { module: "xtalosc24m", peripheral: "XTALOSC24M", name: "OSC_CONFIG0_SET", address: 0x400D_82A4, width: Width::B4, access: Access::ReadWrite, description: "XTAL OSC Configuration 0 Register", fields: F59 },
+    Register { module: "xtalosc24m", peripheral: "XTALOSC24M", name: "OSC_CONFIG0_CLR", address: 0x400D_82A8, width: Width::B4, access: Access::ReadWrite, description: "XTAL OSC Configuration 0 Register", fields: F59 },
+    Register { module: "xtalosc24m", peripheral: "XTALOSC24M", name: "OSC_CONFIG0_TOG", address: 0x400D_82AC, width: Width::B4, access: Access::ReadWrite, description: "XTAL OSC Configuration 0 Register", fields: F59 },
+    Register { module: "xtalosc24m", peripheral: "XTALOSC24M", name: "OSC_CONFIG1", address: 0x400D_82B0, width: Width::B4, access: Access::ReadWrite, description: "XTAL OSC Configuration 1 Register", fields: F60 },
+    Register { module: "xtalosc24m", peripheral: "XTALOSC24M", name: "OSC_CONFIG1_SET", address: 0x400D_82B4, width: Width::B4, access: Access::ReadWrite, description: "XTAL OSC Configuration 1 Register", fields: F60 },
+    Register { module: "xtalosc24m", peripheral: "XTALOSC24M", name: "OSC_CONFIG1_CLR", address: 0x400D_82B8, width: Width::B4, access: Access::ReadWrite, description: "XTAL OSC Configuration 1 Register", fields: F60 },
+    Register { module: "xtalosc24m", peripheral: "XTALOSC24M", name: "OSC_CONFIG1_TOG", address: 0x400D_82BC, width: Width::B4, access: Access::ReadWrite, description: "XTAL OSC Configuration 1 Register", fields: F60 },
+    Register { module: "xtalosc24m", peripheral: "XTALOSC24M", name: "OSC_CONFIG2", address: 0x400D_82C0, width: Width::B4, access: Access::ReadWrite, description: "XTAL OSC Configuration 2 Register", fields: F61 },
+    Register { module: "xtalosc24m", peripheral: "XTALOSC24M", name: "OSC_CONFIG2_SET", address: 0x400D_82C4, width: Width::B4, access: Access::ReadWrite, description: "XTAL OSC Configuration 2 Register", fields: F61 },
+    Register { module: "xtalosc24m", peripheral: "XTALOSC24M", name: "OSC_CONFIG2_CLR", address: 0x400D_82C8, width: Width::B4, access: Access::ReadWrite, description: "XTAL OSC Configuration 2 Register", fields: F61 },
+    Register { module: "xtalosc24m", peripheral: "XTALOSC24M", name: "OSC_CONFIG2_TOG", address: 0x400D_82CC, width: Width::B4, access: Access::ReadWrite, description: "XTAL OSC Configuration 2 Register", fields: F61 },
+    Register { module: "usbphy", peripheral: "USBPHY1", name: "PWD", address: 0x400D_9000, width: Width::B4, access: Access::ReadWrite, description: "USB PHY Power-Down Register", fields: F62 },
+    Register { module: "usbphy", peripheral: "USBPHY1", name: "PWD_SET", address: 0x400D_9004, width: Width::B4, access: Access::ReadWrite, description: "USB PHY Power-Down Register", fields: F62 },
+    Register { module: "usbphy", peripheral: "USBPHY1", name: "PWD_CLR", address: 0x400D_9008, width: Width::B4, access: Access::ReadWrite, description: "USB PHY Power-Down Register", fields: F62 },
+    Register { module: "usbphy", peripheral: "USBPHY1", name: "PWD_TOG", address: 0x400D_900C, width: Width::B4, access: Access::ReadWrite, description: "USB PHY Power-Down Register", fields: F62 },
+    Register { module: "usbphy", peripheral: "USBPHY1", name: "TX", address: 0x400D_9010, width: Width::B4, access: Access::ReadWrite, description: "USB PHY Transmitter Control Register", fields: F63 },
+    Register { module: "usbphy", peripheral: "USBPHY1", name: "TX_SET", address: 0x400D_9014, width: Width::B4, access: Access::ReadWrite, description: "USB PHY Transmitter Control Register", fields: F63 },
+    Register { module: "usbphy", peripheral: "USBPHY1", name: "TX_CLR", address: 0x400D_9018, width: Width::B4, access: Access::ReadWrite, description: "USB PHY Transmitter Control Register", fields: F63 },
+    Register { module: "usbphy", peripheral: "USBPHY1", name: "TX_TOG", address: 0x400D_901C, width: Width::B4, access: Access::ReadWrite, description: "USB PHY Transmitter Control Register", fields: F63 },
+    Register { module: "usbphy", peripheral: "USBPHY1", name: "RX", address: 0x400D_9020, width: Width::B4, access: Access::ReadWrite, description: "USB PHY Receiver Control Register", fields: F64 },
+    Register { module: "usbphy", peripheral: "USBPHY1", name: "RX_SET", address: 0x400D_9024, width: Width::B4, access: Access::ReadWrite, description: "USB PHY Receiver Control Register", fields: F64 },
+    Register { module: "usbphy", peripheral: "USBPHY1", name: "RX_CLR", address: 0x400D_9028, width: Width::B4, access: Access::ReadWrite, description: "USB PHY Receiver Control Register", fields: F64 },
+    Register { module: "usbphy", peripheral: "USBPHY1", name: "RX_TOG", address: 0x400D_902C, width: Width::B4, access: Access::ReadWrite, description: "USB PHY Receiver Control Register", fields: F64 },
+    Register { module: "usbphy", peripheral: "USBPHY1", name: "CTRL", address: 0x400D_9030, width: Width::B4, access: Access::ReadWrite, description: "USB PHY General Control Register", fields: F65 },
+    Register { module: "usbphy", peripheral: "USBPHY1", name: "CTRL_SET", address: 0x400D_9034, width: Width::B4, access: Access::ReadWrite, description: "USB PHY General Control Register", fields: F65 },
+    Register { module: "usbphy", peripheral: "USBPHY1", name: "CTRL_CLR", address: 0x400D_9038, width: Width::B4, access: Access::ReadWrite, description: "USB PHY General Control Register", fields: F65 },
+    Register { module: "usbphy", peripheral: "USBPHY1", name: "CTRL_TOG", address: 0x400D_903C, width: Width::B4, access: Access::ReadWrite, description: "USB PHY General Control Register", fields: F65 },
+    Register { module: "usbphy", peripheral: "USBPHY1", name: "STATUS", address: 0x400D_9040, width: Width::B4, access: Access::ReadWrite, description: "USB PHY Status Register", fields: F66 },
+    Register { module: "usbphy", peripheral: "USBPHY1", name: "DEBUG", address: 0x400D_9050, width: Width::B4, access: Access::ReadWrite, description: "USB PHY Debug Register", fields: F67 },
+    Register { module: "usbphy", peripheral: "USBPHY1", name: "DEBUG_SET", address: 0x400D_9054, width: Width::B4, access: Access::ReadWrite, description: "USB PHY Debug Register", fields: F67 },
+    Register { module: "usbphy", peripheral: "USBPHY1", name: "DEBUG_CLR", address: 0x400D_9058, width: Width::B4, access: Access::ReadWrite, description: "USB PHY Debug Register", fields: F67 },
+    Register { module: "usbphy", peripheral: "USBPHY1", name: "DEBUG_TOG", address: 0x400D_905C, width: Width::B4, access: Access::ReadWrite, description: "USB PHY Debug Register", fields: F67 },
+    Register { module: "usbphy", peripheral: "USBPHY1", name: "DEBUG0_STATUS", address: 0x400D_9060, width: Width::B4, access: Access::ReadOnly, description: "UTMI Debug Status Register 0", fields: F68 },
+    Register { module: "usbphy", peripheral: "USBPHY1", name: "DEBUG1", address: 0x400D_9070, width: Width::B4, access: Access::ReadWrite, description: "UTMI Debug Status Register 1", fields: F69 },
+    Register { module: "usbphy", peripheral: "USBPHY1", name: "DEBUG1_SET", address: 0x400D_9074, width: Width::B4, access: Access::ReadWrite, description: "UTMI Debug Status Register 1", fields: F69 },
+    Register { module: "usbphy", peripheral: "USBPHY1", name: "DEBUG1_CLR", address: 0x400D_9078, width: Width::B4, access: Access::ReadWrite, description: "UTMI Debug Status Register 1", fields: F69 },
+    Register { module: "usbphy", peripheral: "USBPHY1", name: "DEBUG1_TOG", address: 0x400D_907C, width: Width::B4, access: Access::ReadWrite, description: "UTMI Debug Status Register 1", fields: F69 },
+    Register { module: "usbphy", peripheral: "USBPHY1", name: "VERSION", address: 0x400D_9080, width: Width::B4, access: Access::ReadOnly, description: "UTMI RTL Version", fields: F70 },
+    Register { module: "usbphy", peripheral: "USBPHY2", name: "PWD", address: 0x400D_A000, width: Width::B4, access: Access::ReadWrite, description: "USB PHY Power-Down Register", fields: F62 },
+    Register { module: "usbphy", peripheral: "USBPHY2", name: "PWD_SET", address: 0x400D_A004, width: Width::B4, access: Access::ReadWrite, description: "USB PHY Power-Down Register", fields: F62 },
+    Register { module: "usbphy", peripheral: "USBPHY2", name: "PWD_CLR", address: 0x400D_A008, width: Width::B4, access: Access::ReadWrite, description: "USB PHY Power-Down Register", fields: F62 },
+    Register { module: "usbphy", peripheral: "USBPHY2", name: "PWD_TOG", address: 0x400D_A00C, width: Width::B4, access: Access::ReadWrite, description: "USB PHY Power-Down Register", fields: F62 },
+    Register { module: "usbphy", peripheral: "USBPHY2", name: "TX", address: 0x400D_A010, width: Width::B4, access: Access::ReadWrite, description: "USB PHY Transmitter Control Register", fields: F63 },
+    Register { module: "usbphy", peripheral: "USBPHY2", name: "TX_SET", address: 0x400D_A014, width: Width::B4, access: Access::ReadWrite, description: "USB PHY Transmitter Control Register", fields: F63 },
+    Register { module: "usbphy", peripheral: "USBPHY2", name: "TX_CLR", address: 0x400D_A018, width: Width::B4, access: Access::ReadWrite, description: "USB PHY Transmitter Control Register", fields: F63 },
+    Register { module: "usbphy", peripheral: "USBPHY2", name: "TX_TOG", address: 0x400D_A01C, width: Width::B4, access: Access::ReadWrite, description: "USB PHY Transmitter Control Register", fields: F63 },
+    Register { module: "usbphy", peripheral: "USBPHY2", name: "RX", address: 0x400D_A020, width: Width::B4, access: Access::ReadWrite, description: "USB PHY Receiver Control Register", fields: F64 },
+    Register { module: "usbphy", peripheral: "USBPHY2", name: "RX_SET", address: 0x400D_A024, width: Width::B4, access: Access::ReadWrite, description: "USB PHY Receiver Control Register", fields: F64 },
+    Register { module: "usbphy", peripheral: "USBPHY2", name: "RX_CLR", address: 0x400D_A028, width: Width::B4, access: Access::ReadWrite, description: "USB PHY Receiver Control Register", fields: F64 },
+    Register { module: "usbphy", peripheral: "USBPHY2", name: "RX_TOG", address: 0x400D_A02C, width: Width::B4, access: Access::ReadWrite, description: "USB PHY Receiver Control Register", fields: F64 },
+    Register { module: "usbphy", peripheral: "USBPHY2", name: "CTRL", address: 0x400D_A030, width: Width::B4, access: Access::ReadWrite, description: "USB PHY General Control Register", fields: F65 },
+    Register { module: "usbphy", peripheral: "USBPHY2", name: "CTRL_SET", address: 0x400D_A034, width: Width::B4, access: Access::ReadWrite, description: "USB PHY General Control Register", fields: F65 },
+    Register { module: "usbphy", peripheral: "USBPHY2", name: "CTRL_CLR", address: 0x400D_A038, width: Width::B4, access: Access::ReadWrite, description: "USB PHY General Control Register", fields: F65 },
+    Register { module: "usbphy", peripheral: "USBPHY2", name: "CTRL_TOG", address: 0x400D_A03C, width: Width::B4, access: Access::ReadWrite, description: "USB PHY General Control Register", fields: F65 },
+    Register { module: "usbphy", peripheral: "USBPHY2", name: "STATUS", address: 0x400D_A040, width: Width::B4, access: Access::ReadWrite, description: "USB PHY Status Register", fields: F66 },
+    Register { module: "usbphy", peripheral: "USBPHY2", name: "DEBUG", address: 0x400D_A050, width: Width::B4, access: Access::ReadWrite, description: "USB PHY Debug Register", fields: F67 },
+    Register { module: "usbphy", peripheral: "USBPHY2", name: "DEBUG_SET", address: 0x400D_A054, width: Width::B4, access: Access::ReadWrite, description: "USB PHY Debug Register", fields: F67 },
+    Register { module: "usbphy", peripheral: "USBPHY2", name: "DEBUG_CLR", address: 0x400D_A058, width: Width::B4, access: Access::ReadWrite, description: "USB PHY Debug Register", fields: F67 },
+    Register { module: "usbphy", peripheral: "USBPHY2", name: "DEBUG_TOG", address: 0x400D_A05C, width: Width::B4, access: Access::ReadWrite, description: "USB PHY Debug Register", fields: F67 },
+    Register { module: "usbphy", peripheral: "USBPHY2", name: "DEBUG0_STATUS", address: 0x400D_A060, width: Width::B4, access: Access::ReadOnly, description: "UTMI Debug Status Register 0", fields: F68 },
+    Register { module: "usbphy", peripheral: "USBPHY2", name: "DEBUG1", address: 0x400D_A070, width: Width::B4, access: Access::ReadWrite, description: "UTMI Debug Status Register 1", fields: F69 },
+    Register { module: "usbphy", peripheral: "USBPHY2", name: "DEBUG1_SET", address: 0x400D_A074, width: Width::B4, access: Access::ReadWrite, description: "UTMI Debug Status Register 1", fields: F69 },
+    Register { module: "usbphy", peripheral: "USBPHY2", name: "DEBUG1_CLR", address: 0x400D_A078, width: Width::B4, access: Access::ReadWrite, description: "UTMI Debug Status Register 1", fields: F69 },
+    Register { module: "usbphy", peripheral: "USBPHY2", name: "DEBUG1_TOG", address: 0x400D_A07C, width: Width::B4, access: Access::ReadWrite, description: "UTMI Debug Status Register 1", fields: F69 },
+    Register { module: "usbphy", peripheral: "USBPHY2", name: "VERSION", address: 0x400D_A080, width: Width::B4, access: Access::ReadOnly, description: "UTMI RTL Version", fields: F70 },
+    Register { module: "ccm", peripheral: "CCM", name: "CCR", address: 0x400F_C000, width: Width::B4, access: Access::ReadWrite, description: "CCM Control Register", fields: F71 },
+    Register { module: "ccm", peripheral: "CCM", name: "CSR", address: 0x400F_C008, width: Width::B4, access: Access::ReadOnly, description: "CCM Status Register", fields: F72 },
+    Register { module: "ccm", peripheral: "CCM", name: "CCSR", address: 0x400F_C00C, width: Width::B4, access: Access::ReadWrite, description: "CCM Clock Switcher Register", fields: F73 },
+    Register { module: "ccm", peripheral: "CCM", name: "CACRR", address: 0x400F_C010, width: Width::B4, access: Access::ReadWrite, description: "CCM Arm Clock Root Register", fields: F74 },
+    Register { module: "ccm", peripheral: "CCM", name: "CBCDR", address: 0x400F_C014, width: Width::B4, access: Access::ReadWrite, description: "CCM Bus Clock Divider Register", fields: F75 },
+    Register { module: "ccm", peripheral: "CCM", name: "CBCMR", address: 0x400F_C018, width: Width::B4, access: Access::ReadWrite, description: "CCM Bus Clock Multiplexer Register", fields: F76 },
+    Register { module: "ccm", peripheral: "CCM", name: "CSCMR1", address: 0x400F_C01C, width: Width::B4, access: Access::ReadWrite, description: "CCM Serial Clock Multiplexer Register 1", fields: F77 },
+    Register { module: "ccm", peripheral: "CCM", name: "CSCMR2", address: 0x400F_C020, width: Width::B4, access: Access::ReadWrite, description: "CCM Serial Clock Multiplexer Register 2", fields: F78 },
+    Register { module: "ccm", peripheral: "CCM", name: "CSCDR1", address: 0x400F_C024, width: Width::B4, access: Access::ReadWrite, description: "CCM Serial Clock Divider Register 1", fields: F79 },
+    Register { module: "ccm", peripheral: "CCM", name: "CS1CDR", address: 0x400F_C028, width: Width::B4, access: Access::ReadWrite, description: "CCM Clock Divider Register", fields: F80 },
+    Register { module: "ccm", peripheral: "CCM", name: "CS2CDR", address: 0x400F_C02C, width: Width::B4, access: Access::ReadWrite, description: "CCM Clock Divider Register", fields: F81 },
+    Register { module: "ccm", peripheral: "CCM", name: "CDCDR", address: 0x400F_C030, width: Width::B4, access: Access::ReadWrite, description: "CCM D1 Clock Divider Register", fields: F82 },
+    Register { module: "ccm", peripheral: "CCM", name: "CSCDR2", address: 0x400F_C038, width: Width::B4, access: Access::ReadWrite, description: "CCM Serial Clock Divider Register 2", fields: F83 },
+    Register { module: "ccm", peripheral: "CCM", name: "CSCDR3", address: 0x400F_C03C, width: Width::B4, access: Access::ReadWrite, description: "CCM Serial Clock Divider Register 3", fields: F84 },
+    Register { module: "ccm", peripheral: "CCM", name: "CDHIPR", address: 0x400F_C048, width: Width::B4, access: Access::ReadOnly, description: "CCM Divider Handshake In-Process Register", fields: F85 },
+    Register { module: "ccm", peripheral: "CCM", name: "CLPCR", address: 0x400F_C054, width: Width::B4, access: Access::ReadWrite, description: "CCM Low Power Control Register", fields: F86 },
+    Register { module: "ccm", peripheral: "CCM", name: "CISR", address: 0x400F_C058, width: Width::B4, access: Access::ReadWrite, description: "CCM Interrupt Status Register", fields: F87 },
+    Register { module: "ccm", peripheral: "CCM", name: "CIMR", address: 0x400F_C05C, width: Width::B4, access: Access::ReadWrite, description: "CCM Interrupt Mask Register", fields: F88 },
+    Register { module: "ccm", peripheral: "CCM", name: "CCOSR", address: 0x400F_C060, width: Width::B4, access: Access::ReadWrite, description: "CCM Clock Output Source Register", fields: F89 },
+    Register { module: "ccm", peripheral: "CCM", name: "CGPR", address: 0x400F_C064, width: Width::B4, access: Access::ReadWrite, description: "CCM General Purpose Register", fields: F90 },
+    Register { module: "ccm", peripheral: "CCM", name: "CCGR0", address: 0x400F_C068, width: Width::B4, access: Access::ReadWrite, description: "CCM Clock Gating Register 0", fields: F91 },
+    Register { module: "ccm", peripheral: "CCM", name: "CCGR1", address: 0x400F_C06C, width: Width::B4, access: Access::ReadWrite, description: "CCM Clock Gating Register 1", fields: F92 },
+    Register { module: "ccm", peripheral: "CCM", name: "CCGR2", address: 0x400F_C070, width: Width::B4, access: Access::ReadWrite, description: "CCM Clock Gating Register 2", fields: F93 },
+    Register { module: "ccm", peripheral: "CCM", name: "CCGR3", address: 0x400F_C074, width: Width::B4, access: Access::ReadWrite, description: "CCM Clock Gating Register 3", fields: F94 },
+    Register { module: "ccm", peripheral: "CCM", name: "CCGR4", address: 0x400F_C078, width: Width::B4, access: Access::ReadWrite, description: "CCM Clock Gating Register 4", fields: F95 },
+    Register { module: "ccm", peripheral: "CCM", name: "CCGR5", address: 0x400F_C07C, width: Width::B4, access: Access::ReadWrite, description: "CCM Clock Gating Register 5", fields: F96 },
+    Register { module: "ccm", peripheral: "CCM", name: "CCGR6", address: 0x400F_C080, width: Width::B4, access: Access::ReadWrite, description: "CCM Clock Gating Register 6", fields: F97 },
+    Register { module: "ccm", peripheral: "CCM", name: "CCGR7", address: 0x400F_C084, width: Width::B4, access: Access::ReadWrite, description: "CCM Clock Gating Register 7", fields: F98 },
+    Register { module: "ccm", peripheral: "CCM", name: "CMEOR", address: 0x400F_C088, width: Width::B4, access: Access::ReadWrite, description: "CCM Module Enable Overide Register", fields: F99 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_EMC_00", address: 0x401F_8014, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_EMC_00 SW MUX Control Register", fields: F2 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_EMC_01", address: 0x401F_8018, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_EMC_01 SW MUX Control Register", fields: F2 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_EMC_02", address: 0x401F_801C, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_EMC_02 SW MUX Control Register", fields: F2 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_EMC_03", address: 0x401F_8020, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_EMC_03 SW MUX Control Register", fields: F2 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_EMC_04", address: 0x401F_8024, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_EMC_04 SW MUX Control Register", fields: F2 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_EMC_05", address: 0x401F_8028, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_EMC_05 SW MUX Control Register", fields: F2 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_EMC_06", address: 0x401F_802C, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_EMC_06 SW MUX Control Register", fields: F2 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_EMC_07", address: 0x401F_8030, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_EMC_07 SW MUX Control Register", fields: F2 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_EMC_08", address: 0x401F_8034, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_EMC_08 SW MUX Control Register", fields: F2 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_EMC_09", address: 0x401F_8038, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_EMC_09 SW MUX Control Register", fields: F28 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_EMC_10", address: 0x401F_803C, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_EMC_10 SW MUX Control Register", fields: F28 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_EMC_11", address: 0x401F_8040, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_EMC_11 SW MUX Control Register", fields: F28 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_EMC_12", address: 0x401F_8044, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_EMC_12 SW MUX Control Register", fields: F28 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_EMC_13", address: 0x401F_8048, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_EMC_13 SW MUX Control Register", fields: F28 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_EMC_14", address: 0x401F_804C, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_EMC_14 SW MUX Control Register", fields: F28 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_EMC_15", address: 0x401F_8050, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_EMC_15 SW MUX Control Register", fields: F28 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_EMC_16", address: 0x401F_8054, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_EMC_16 SW MUX Control Register", fields: F28 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_EMC_17", address: 0x401F_8058, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_EMC_17 SW MUX Control Register", fields: F2 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_EMC_18", address: 0x401F_805C, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_EMC_18 SW MUX Control Register", fields: F2 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_EMC_19", address: 0x401F_8060, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_EMC_19 SW MUX Control Register", fields: F2 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_EMC_20", address: 0x401F_8064, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_EMC_20 SW MUX Control Register", fields: F2 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_EMC_21", address: 0x401F_8068, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_EMC_21 SW MUX Control Register", fields: F2 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_EMC_22", address: 0x401F_806C, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_EMC_22 SW MUX Control Register", fields: F28 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_EMC_23", address: 0x401F_8070, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_EMC_23 SW MUX Control Register", fields: F28 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_EMC_24", address: 0x401F_8074, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_EMC_24 SW MUX Control Register", fields: F28 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_EMC_25", address: 0x401F_8078, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_EMC_25 SW MUX Control Register", fields: F28 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_EMC_26", address: 0x401F_807C, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_EMC_26 SW MUX Control Register", fields: F28 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_EMC_27", address: 0x401F_8080, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_EMC_27 SW MUX Control Register", fields: F28 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_EMC_28", address: 0x401F_8084, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_EMC_28 SW MUX Control Register", fields: F28 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_EMC_29", address: 0x401F_8088, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_EMC_29 SW MUX Control Register", fields: F28 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_EMC_30", address: 0x401F_808C, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_EMC_30 SW MUX Control Register", fields: F28 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_EMC_31", address: 0x401F_8090, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_EMC_31 SW MUX Control Register", fields: F28 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_EMC_32", address: 0x401F_8094, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_EMC_32 SW MUX Control Register", fields: F28 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_EMC_33", address: 0x401F_8098, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_EMC_33 SW MUX Control Register", fields: F28 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_EMC_34", address: 0x401F_809C, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_EMC_34 SW MUX Control Register", fields: F28 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_EMC_35", address: 0x401F_80A0, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_EMC_35 SW MUX Control Register", fields: F28 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_EMC_36", address: 0x401F_80A4, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_EMC_36 SW MUX Control Register", fields: F28 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_EMC_37", address: 0x401F_80A8, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_EMC_37 SW MUX Control Register", fields: F28 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_EMC_38", address: 0x401F_80AC, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_EMC_38 SW MUX Control Register", fields: F28 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_EMC_39", address: 0x401F_80B0, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_EMC_39 SW MUX Control Register", fields: F28 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_EMC_40", address: 0x401F_80B4, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_EMC_40 SW MUX Control Register", fields: F28 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_EMC_41", address: 0x401F_80B8, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_EMC_41 SW MUX Control Register", fields: F2 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_AD_B0_00", address: 0x401F_80BC, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_AD_B0_00 SW MUX Control Register", fields: F2 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_AD_B0_01", address: 0x401F_80C0, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_AD_B0_01 SW MUX Control Register", fields: F2 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_AD_B0_02", address: 0x401F_80C4, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_AD_B0_02 SW MUX Control Register", fields: F2 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_AD_B0_03", address: 0x401F_80C8, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_AD_B0_03 SW MUX Control Register", fields: F2 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_AD_B0_04", address: 0x401F_80CC, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_AD_B0_04 SW MUX Control Register", fields: F2 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_AD_B0_05", address: 0x401F_80D0, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_AD_B0_05 SW MUX Control Register", fields: F2 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_AD_B0_06", address: 0x401F_80D4, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_AD_B0_06 SW MUX Control Register", fields: F2 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_AD_B0_07", address: 0x401F_80D8, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_AD_B0_07 SW MUX Control Register", fields: F2 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_AD_B0_08", address: 0x401F_80DC, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_AD_B0_08 SW MUX Control Register", fields: F2 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_AD_B0_09", address: 0x401F_80E0, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_AD_B0_09 SW MUX Control Register", fields: F28 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_AD_B0_10", address: 0x401F_80E4, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_AD_B0_10 SW MUX Control Register", fields: F28 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_AD_B0_11", address: 0x401F_80E8, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_AD_B0_11 SW MUX Control Register", fields: F28 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_AD_B0_12", address: 0x401F_80EC, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_AD_B0_12 SW MUX Control Register", fields: F2 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_AD_B0_13", address: 0x401F_80F0, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_AD_B0_13 SW MUX Control Register", fields: F2 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_AD_B0_14", address: 0x401F_80F4, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_AD_B0_14 SW MUX Control Register", fields: F28 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_AD_B0_15", address: 0x401F_80F8, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_AD_B0_15 SW MUX Control Register", fields: F28 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_AD_B1_00", address: 0x401F_80FC, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_AD_B1_00 SW MUX Control Register", fields: F28 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_AD_B1_01", address: 0x401F_8100, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_AD_B1_01 SW MUX Control Register", fields: F28 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_AD_B1_02", address: 0x401F_8104, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_AD_B1_02 SW MUX Control Register", fields: F28 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_AD_B1_03", address: 0x401F_8108, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_AD_B1_03 SW MUX Control Register", fields: F28 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_AD_B1_04", address: 0x401F_810C, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_AD_B1_04 SW MUX Control Register", fields: F28 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_AD_B1_05", address: 0x401F_8110, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_AD_B1_05 SW MUX Control Register", fields: F28 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_AD_B1_06", address: 0x401F_8114, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_AD_B1_06 SW MUX Control Register", fields: F28 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_AD_B1_07", address: 0x401F_8118, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_AD_B1_07 SW MUX Control Register", fields: F28 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_AD_B1_08", address: 0x401F_811C, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_AD_B1_08 SW MUX Control Register", fields: F28 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_AD_B1_09", address: 0x401F_8120, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_AD_B1_09 SW MUX Control Register", fields: F28 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_AD_B1_10", address: 0x401F_8124, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_AD_B1_10 SW MUX Control Register", fields: F28 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_AD_B1_11", address: 0x401F_8128, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_AD_B1_11 SW MUX Control Register", fields: F28 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_AD_B1_12", address: 0x401F_812C, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_AD_B1_12 SW MUX Control Register", fields: F28 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_AD_B1_13", address: 0x401F_8130, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_AD_B1_13 SW MUX Control Register", fields: F28 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_AD_B1_14", address: 0x401F_8134, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_AD_B1_14 SW MUX Control Register", fields: F28 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_AD_B1_15", address: 0x401F_8138, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_AD_B1_15 SW MUX Control Register", fields: F28 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_B0_00", address: 0x401F_813C, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_B0_00 SW MUX Control Register", fields: F28 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_B0_01", address: 0x401F_8140, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_B0_01 SW MUX Control Register", fields: F28 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_B0_02", address: 0x401F_8144, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_B0_02 SW MUX Control Register", fields: F28 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_B0_03", address: 0x401F_8148, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_B0_03 SW MUX Control Register", fields: F28 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_B0_04", address: 0x401F_814C, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_B0_04 SW MUX Control Register", fields: F28 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_B0_05", address: 0x401F_8150, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_B0_05 SW MUX Control Register", fields: F28 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_B0_06", address: 0x401F_8154, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_B0_06 SW MUX Control Register", fields: F28 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_B0_07", address: 0x401F_8158, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_B0_07 SW MUX Control Register", fields: F28 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_B0_08", address: 0x401F_815C, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_B0_08 SW MUX Control Register", fields: F28 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_B0_09", address: 0x401F_8160, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_B0_09 SW MUX Control Register", fields: F28 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_B0_10", address: 0x401F_8164, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_B0_10 SW MUX Control Register", fields: F28 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_B0_11", address: 0x401F_8168, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_B0_11 SW MUX Control Register", fields: F28 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_B0_12", address: 0x401F_816C, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_B0_12 SW MUX Control Register", fields: F28 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_B0_13", address: 0x401F_8170, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_B0_13 SW MUX Control Register", fields: F28 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_B0_14", address: 0x401F_8174, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_B0_14 SW MUX Control Register", fields: F28 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_B0_15", address: 0x401F_8178, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_B0_15 SW MUX Control Register", fields: F28 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_B1_00", address: 0x401F_817C, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_B1_00 SW MUX Control Register", fields: F28 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_B1_01", address: 0x401F_8180, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_B1_01 SW MUX Control Register", fields: F28 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_B1_02", address: 0x401F_8184, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_B1_02 SW MUX Control Register", fields: F28 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_B1_03", address: 0x401F_8188, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_B1_03 SW MUX Control Register", fields: F28 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_B1_04", address: 0x401F_818C, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_B1_04 SW MUX Control Register", fields: F28 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_B1_05", address: 0x401F_8190, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_B1_05 SW MUX Control Register", fields: F28 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_B1_06", address: 0x401F_8194, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_B1_06 SW MUX Control Register", fields: F28 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_B1_07", address: 0x401F_8198, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_B1_07 SW MUX Control Register", fields: F28 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_B1_08", address: 0x401F_819C, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_B1_08 SW MUX Control Register", fields: F28 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_B1_09", address: 0x401F_81A0, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_B1_09 SW MUX Control Register", fields: F28 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_B1_10", address: 0x401F_81A4, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_B1_10 SW MUX Control Register", fields: F28 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_B1_11", address: 0x401F_81A8, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_B1_11 SW MUX Control Register", fields: F28 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_B1_12", address: 0x401F_81AC, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_B1_12 SW MUX Control Register", fields: F28 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_B1_13", address: 0x401F_81B0, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_B1_13 SW MUX Control Register", fields: F28 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_B1_14", address: 0x401F_81B4, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_B1_14 SW MUX Control Register", fields: F28 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_B1_15", address: 0x401F_81B8, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_B1_15 SW MUX Control Register", fields: F28 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_SD_B0_00", address: 0x401F_81BC, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_SD_B0_00 SW MUX Control Register", fields: F28 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_SD_B0_01", address: 0x401F_81C0, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_SD_B0_01 SW MUX Control Register", fields: F28 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_SD_B0_02", address: 0x401F_81C4, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_SD_B0_02 SW MUX Control Register", fields: F28 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_SD_B0_03", address: 0x401F_81C8, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_SD_B0_03 SW MUX Control Register", fields: F28 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_SD_B0_04", address: 0x401F_81CC, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_SD_B0_04 SW MUX Control Register", fields: F28 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_SD_B0_05", address: 0x401F_81D0, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_SD_B0_05 SW MUX Control Register", fields: F28 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_SD_B1_00", address: 0x401F_81D4, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_SD_B1_00 SW MUX Control Register", fields: F28 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_SD_B1_01", address: 0x401F_81D8, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_SD_B1_01 SW MUX Control Register", fields: F28 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_SD_B1_02", address: 0x401F_81DC, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_SD_B1_02 SW MUX Control Register", fields: F28 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_SD_B1_03", address: 0x401F_81E0, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_SD_B1_03 SW MUX Control Register", fields: F28 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_SD_B1_04", address: 0x401F_81E4, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_SD_B1_04 SW MUX Control Register", fields: F28 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_SD_B1_05", address: 0x401F_81E8, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_SD_B1_05 SW MUX Control Register", fields: F28 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_SD_B1_06", address: 0x401F_81EC, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_SD_B1_06 SW MUX Control Register", fields: F28 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_SD_B1_07", address: 0x401F_81F0, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_SD_B1_07 SW MUX Control Register", fields: F2 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_SD_B1_08", address: 0x401F_81F4, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_SD_B1_08 SW MUX Control Register", fields: F2 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_SD_B1_09", address: 0x401F_81F8, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_SD_B1_09 SW MUX Control Register", fields: F2 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_SD_B1_10", address: 0x401F_81FC, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_SD_B1_10 SW MUX Control Register", fields: F2 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_SD_B1_11", address: 0x401F_8200, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_SD_B1_11 SW MUX Control Register", fields: F2 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_EMC_00", address: 0x401F_8204, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_EMC_00 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_EMC_01", address: 0x401F_8208, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_EMC_01 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_EMC_02", address: 0x401F_820C, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_EMC_02 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_EMC_03", address: 0x401F_8210, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_EMC_03 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_EMC_04", address: 0x401F_8214, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_EMC_04 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_EMC_05", address: 0x401F_8218, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_EMC_05 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_EMC_06", address: 0x401F_821C, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_EMC_06 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_EMC_07", address: 0x401F_8220, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_EMC_07 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_EMC_08", address: 0x401F_8224, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_EMC_08 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_EMC_09", address: 0x401F_8228, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_EMC_09 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_EMC_10", address: 0x401F_822C, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_EMC_10 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_EMC_11", address: 0x401F_8230, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_EMC_11 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_EMC_12", address: 0x401F_8234, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_EMC_12 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_EMC_13", address: 0x401F_8238, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_EMC_13 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_EMC_14", address: 0x401F_823C, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_EMC_14 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_EMC_15", address: 0x401F_8240, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_EMC_15 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_EMC_16", address: 0x401F_8244, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_EMC_16 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_EMC_17", address: 0x401F_8248, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_EMC_17 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_EMC_18", address: 0x401F_824C, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_EMC_18 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_EMC_19", address: 0x401F_8250, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_EMC_19 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_EMC_20", address: 0x401F_8254, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_EMC_20 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_EMC_21", address: 0x401F_8258, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_EMC_21 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_EMC_22", address: 0x401F_825C, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_EMC_22 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_EMC_23", address: 0x401F_8260, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_EMC_23 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_EMC_24", address: 0x401F_8264, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_EMC_24 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_EMC_25", address: 0x401F_8268, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_EMC_25 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_EMC_26", address: 0x401F_826C, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_EMC_26 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_EMC_27", address: 0x401F_8270, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_EMC_27 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_EMC_28", address: 0x401F_8274, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_EMC_28 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_EMC_29", address: 0x401F_8278, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_EMC_29 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_EMC_30", address: 0x401F_827C, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_EMC_30 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_EMC_31", address: 0x401F_8280, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_EMC_31 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_EMC_32", address: 0x401F_8284, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_EMC_32 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_EMC_33", address: 0x401F_8288, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_EMC_33 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_EMC_34", address: 0x401F_828C, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_EMC_34 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_EMC_35", address: 0x401F_8290, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_EMC_35 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_EMC_36", address: 0x401F_8294, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_EMC_36 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_EMC_37", address: 0x401F_8298, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_EMC_37 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_EMC_38", address: 0x401F_829C, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_EMC_38 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_EMC_39", address: 0x401F_82A0, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_EMC_39 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_EMC_40", address: 0x401F_82A4, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_EMC_40 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_EMC_41", address: 0x401F_82A8, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_EMC_41 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_AD_B0_00", address: 0x401F_82AC, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_AD_B0_00 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_AD_B0_01", address: 0x401F_82B0, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_AD_B0_01 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_AD_B0_02", address: 0x401F_82B4, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_AD_B0_02 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_AD_B0_03", address: 0x401F_82B8, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_AD_B0_03 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_AD_B0_04", address: 0x401F_82BC, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_AD_B0_04 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_AD_B0_05", address: 0x401F_82C0, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_AD_B0_05 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_AD_B0_06", address: 0x401F_82C4, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_AD_B0_06 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_AD_B0_07", address: 0x401F_82C8, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_AD_B0_07 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_AD_B0_08", address: 0x401F_82CC, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_AD_B0_08 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_AD_B0_09", address: 0x401F_82D0, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_AD_B0_09 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_AD_B0_10", address: 0x401F_82D4, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_AD_B0_10 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_AD_B0_11", address: 0x401F_82D8, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_AD_B0_11 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_AD_B0_12", address: 0x401F_82DC, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_AD_B0_12 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_AD_B0_13", address: 0x401F_82E0, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_AD_B0_13 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_AD_B0_14", address: 0x401F_82E4, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_AD_B0_14 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_AD_B0_15", address: 0x401F_82E8, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_AD_B0_15 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_AD_B1_00", address: 0x401F_82EC, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_AD_B1_00 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_AD_B1_01", address: 0x401F_82F0, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_AD_B1_01 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_AD_B1_02", address: 0x401F_82F4, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_AD_B1_02 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_AD_B1_03", address: 0x401F_82F8, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_AD_B1_03 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_AD_B1_04", address: 0x401F_82FC, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_AD_B1_04 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_AD_B1_05", address: 0x401F_8300, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_AD_B1_05 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_AD_B1_06", address: 0x401F_8304, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_AD_B1_06 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_AD_B1_07", address: 0x401F_8308, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_AD_B1_07 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_AD_B1_08", address: 0x401F_830C, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_AD_B1_08 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_AD_B1_09", address: 0x401F_8310, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_AD_B1_09 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_AD_B1_10", address: 0x401F_8314, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_AD_B1_10 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_AD_B1_11", address: 0x401F_8318, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_AD_B1_11 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_AD_B1_12", address: 0x401F_831C, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_AD_B1_12 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_AD_B1_13", address: 0x401F_8320, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_AD_B1_13 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_AD_B1_14", address: 0x401F_8324, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_AD_B1_14 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_AD_B1_15", address: 0x401F_8328, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_AD_B1_15 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_B0_00", address: 0x401F_832C, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_B0_00 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_B0_01", address: 0x401F_8330, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_B0_01 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_B0_02", address: 0x401F_8334, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_B0_02 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_B0_03", address: 0x401F_8338, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_B0_03 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_B0_04", address: 0x401F_833C, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_B0_04 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_B0_05", address: 0x401F_8340, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_B0_05 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_B0_06", address: 0x401F_8344, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_B0_06 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_B0_07", address: 0x401F_8348, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_B0_07 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_B0_08", address: 0x401F_834C, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_B0_08 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_B0_09", address: 0x401F_8350, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_B0_09 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_B0_10", address: 0x401F_8354, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_B0_10 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_B0_11", address: 0x401F_8358, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_B0_11 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_B0_12", address: 0x401F_835C, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_B0_12 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_B0_13", address: 0x401F_8360, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_B0_13 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_B0_14", address: 0x401F_8364, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_B0_14 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_B0_15", address: 0x401F_8368, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_B0_15 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_B1_00", address: 0x401F_836C, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_B1_00 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_B1_01", address: 0x401F_8370, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_B1_01 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_B1_02", address: 0x401F_8374, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_B1_02 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_B1_03", address: 0x401F_8378, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_B1_03 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_B1_04", address: 0x401F_837C, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_B1_04 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_B1_05", address: 0x401F_8380, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_B1_05 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_B1_06", address: 0x401F_8384, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_B1_06 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_B1_07", address: 0x401F_8388, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_B1_07 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_B1_08", address: 0x401F_838C, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_B1_08 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_B1_09", address: 0x401F_8390, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_B1_09 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_B1_10", address: 0x401F_8394, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_B1_10 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_B1_11", address: 0x401F_8398, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_B1_11 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_B1_12", address: 0x401F_839C, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_B1_12 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_B1_13", address: 0x401F_83A0, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_B1_13 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_B1_14", address: 0x401F_83A4, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_B1_14 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_B1_15", address: 0x401F_83A8, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_B1_15 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_SD_B0_00", address: 0x401F_83AC, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_SD_B0_00 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_SD_B0_01", address: 0x401F_83B0, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_SD_B0_01 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_SD_B0_02", address: 0x401F_83B4, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_SD_B0_02 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_SD_B0_03", address: 0x401F_83B8, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_SD_B0_03 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_SD_B0_04", address: 0x401F_83BC, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_SD_B0_04 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_SD_B0_05", address: 0x401F_83C0, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_SD_B0_05 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_SD_B1_00", address: 0x401F_83C4, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_SD_B1_00 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_SD_B1_01", address: 0x401F_83C8, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_SD_B1_01 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_SD_B1_02", address: 0x401F_83CC, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_SD_B1_02 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_SD_B1_03", address: 0x401F_83D0, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_SD_B1_03 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_SD_B1_04", address: 0x401F_83D4, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_SD_B1_04 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_SD_B1_05", address: 0x401F_83D8, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_SD_B1_05 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_SD_B1_06", address: 0x401F_83DC, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_SD_B1_06 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_SD_B1_07", address: 0x401F_83E0, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_SD_B1_07 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_SD_B1_08", address: 0x401F_83E4, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_SD_B1_08 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_SD_B1_09", address: 0x401F_83E8, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_SD_B1_09 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_SD_B1_10", address: 0x401F_83EC, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_SD_B1_10 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_SD_B1_11", address: 0x401F_83F0, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_SD_B1_11 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "ANATOP_USB_OTG1_ID_SELECT_INPUT", address: 0x401F_83F4, width: Width::B4, access: Access::ReadWrite, description: "ANATOP_USB_OTG1_ID_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "ANATOP_USB_OTG2_ID_SELECT_INPUT", address: 0x401F_83F8, width: Width::B4, access: Access::ReadWrite, description: "ANATOP_USB_OTG2_ID_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "CCM_PMIC_READY_SELECT_INPUT", address: 0x401F_83FC, width: Width::B4, access: Access::ReadWrite, description: "CCM_PMIC_READY_SELECT_INPUT DAISY Register", fields: F30 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "CSI_DATA02_SELECT_INPUT", address: 0x401F_8400, width: Width::B4, access: Access::ReadWrite, description: "CSI_DATA02_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "CSI_DATA03_SELECT_INPUT", address: 0x401F_8404, width: Width::B4, access: Access::ReadWrite, description: "CSI_DATA03_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "CSI_DATA04_SELECT_INPUT", address: 0x401F_8408, width: Width::B4, access: Access::ReadWrite, description: "CSI_DATA04_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "CSI_DATA05_SELECT_INPUT", address: 0x401F_840C, width: Width::B4, access: Access::ReadWrite, description: "CSI_DATA05_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "CSI_DATA06_SELECT_INPUT", address: 0x401F_8410, width: Width::B4, access: Access::ReadWrite, description: "CSI_DATA06_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "CSI_DATA07_SELECT_INPUT", address: 0x401F_8414, width: Width::B4, access: Access::ReadWrite, description: "CSI_DATA07_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "CSI_DATA08_SELECT_INPUT", address: 0x401F_8418, width: Width::B4, access: Access::ReadWrite, description: "CSI_DATA08_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "CSI_DATA09_SELECT_INPUT", address: 0x401F_841C, width: Width::B4, access: Access::ReadWrite, description: "CSI_DATA09_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "CSI_HSYNC_SELECT_INPUT", address: 0x401F_8420, width: Width::B4, access: Access::ReadWrite, description: "CSI_HSYNC_SELECT_INPUT DAISY Register", fields: F31 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "CSI_PIXCLK_SELECT_INPUT", address: 0x401F_8424, width: Width::B4, access: Access::ReadWrite, description: "CSI_PIXCLK_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "CSI_VSYNC_SELECT_INPUT", address: 0x401F_8428, width: Width::B4, access: Access::ReadWrite, description: "CSI_VSYNC_SELECT_INPUT DAISY Register", fields: F31 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "ENET_IPG_CLK_RMII_SELECT_INPUT", address: 0x401F_842C, width: Width::B4, access: Access::ReadWrite, description: "ENET_IPG_CLK_RMII_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "ENET_MDIO_SELECT_INPUT", address: 0x401F_8430, width: Width::B4, access: Access::ReadWrite, description: "ENET_MDIO_SELECT_INPUT DAISY Register", fields: F31 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "ENET0_RXDATA_SELECT_INPUT", address: 0x401F_8434, width: Width::B4, access: Access::ReadWrite, description: "ENET0_RXDATA_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "ENET1_RXDATA_SELECT_INPUT", address: 0x401F_8438, width: Width::B4, access: Access::ReadWrite, description: "ENET1_RXDATA_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "ENET_RXEN_SELECT_INPUT", address: 0x401F_843C, width: Width::B4, access: Access::ReadWrite, description: "ENET_RXEN_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "ENET_RXERR_SELECT_INPUT", address: 0x401F_8440, width: Width::B4, access: Access::ReadWrite, description: "ENET_RXERR_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "ENET0_TIMER_SELECT_INPUT", address: 0x401F_8444, width: Width::B4, access: Access::ReadWrite, description: "ENET0_TIMER_SELECT_INPUT DAISY Register", fields: F31 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "ENET_TXCLK_SELECT_INPUT", address: 0x401F_8448, width: Width::B4, access: Access::ReadWrite, description: "ENET_TXCLK_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "FLEXCAN1_RX_SELECT_INPUT", address: 0x401F_844C, width: Width::B4, access: Access::ReadWrite, description: "FLEXCAN1_RX_SELECT_INPUT DAISY Register", fields: F31 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "FLEXCAN2_RX_SELECT_INPUT", address: 0x401F_8450, width: Width::B4, access: Access::ReadWrite, description: "FLEXCAN2_RX_SELECT_INPUT DAISY Register", fields: F31 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "FLEXPWM1_PWMA3_SELECT_INPUT", address: 0x401F_8454, width: Width::B4, access: Access::ReadWrite, description: "FLEXPWM1_PWMA3_SELECT_INPUT DAISY Register", fields: F30 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "FLEXPWM1_PWMA0_SELECT_INPUT", address: 0x401F_8458, width: Width::B4, access: Access::ReadWrite, description: "FLEXPWM1_PWMA0_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "FLEXPWM1_PWMA1_SELECT_INPUT", address: 0x401F_845C, width: Width::B4, access: Access::ReadWrite, description: "FLEXPWM1_PWMA1_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "FLEXPWM1_PWMA2_SELECT_INPUT", address: 0x401F_8460, width: Width::B4, access: Access::ReadWrite, description: "FLEXPWM1_PWMA2_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "FLEXPWM1_PWMB3_SELECT_INPUT", address: 0x401F_8464, width: Width::B4, access: Access::ReadWrite, description: "FLEXPWM1_PWMB3_SELECT_INPUT DAISY Register", fields: F30 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "FLEXPWM1_PWMB0_SELECT_INPUT", address: 0x401F_8468, width: Width::B4, access: Access::ReadWrite, description: "FLEXPWM1_PWMB0_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "FLEXPWM1_PWMB1_SELECT_INPUT", address: 0x401F_846C, width: Width::B4, access: Access::ReadWrite, description: "FLEXPWM1_PWMB1_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "FLEXPWM1_PWMB2_SELECT_INPUT", address: 0x401F_8470, width: Width::B4, access: Access::ReadWrite, description: "FLEXPWM1_PWMB2_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "FLEXPWM2_PWMA3_SELECT_INPUT", address: 0x401F_8474, width: Width::B4, access: Access::ReadWrite, description: "FLEXPWM2_PWMA3_SELECT_INPUT DAISY Register", fields: F30 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "FLEXPWM2_PWMA0_SELECT_INPUT", address: 0x401F_8478, width: Width::B4, access: Access::ReadWrite, description: "FLEXPWM2_PWMA0_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "FLEXPWM2_PWMA1_SELECT_INPUT", address: 0x401F_847C, width: Width::B4, access: Access::ReadWrite, description: "FLEXPWM2_PWMA1_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "FLEXPWM2_PWMA2_SELECT_INPUT", address: 0x401F_8480, width: Width::B4, access: Access::ReadWrite, description: "FLEXPWM2_PWMA2_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "FLEXPWM2_PWMB3_SELECT_INPUT", address: 0x401F_8484, width: Width::B4, access: Access::ReadWrite, description: "FLEXPWM2_PWMB3_SELECT_INPUT DAISY Register", fields: F31 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "FLEXPWM2_PWMB0_SELECT_INPUT", address: 0x401F_8488, width: Width::B4, access: Access::ReadWrite, description: "FLEXPWM2_PWMB0_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "FLEXPWM2_PWMB1_SELECT_INPUT", address: 0x401F_848C, width: Width::B4, access: Access::ReadWrite, description: "FLEXPWM2_PWMB1_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "FLEXPWM2_PWMB2_SELECT_INPUT", address: 0x401F_8490, width: Width::B4, access: Access::ReadWrite, description: "FLEXPWM2_PWMB2_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "FLEXPWM4_PWMA0_SELECT_INPUT", address: 0x401F_8494, width: Width::B4, access: Access::ReadWrite, description: "FLEXPWM4_PWMA0_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "FLEXPWM4_PWMA1_SELECT_INPUT", address: 0x401F_8498, width: Width::B4, access: Access::ReadWrite, description: "FLEXPWM4_PWMA1_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "FLEXPWM4_PWMA2_SELECT_INPUT", address: 0x401F_849C, width: Width::B4, access: Access::ReadWrite, description: "FLEXPWM4_PWMA2_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "FLEXPWM4_PWMA3_SELECT_INPUT", address: 0x401F_84A0, width: Width::B4, access: Access::ReadWrite, description: "FLEXPWM4_PWMA3_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "FLEXSPIA_DQS_SELECT_INPUT", address: 0x401F_84A4, width: Width::B4, access: Access::ReadWrite, description: "FLEXSPIA_DQS_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "FLEXSPIA_DATA0_SELECT_INPUT", address: 0x401F_84A8, width: Width::B4, access: Access::ReadWrite, description: "FLEXSPIA_DATA0_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "FLEXSPIA_DATA1_SELECT_INPUT", address: 0x401F_84AC, width: Width::B4, access: Access::ReadWrite, description: "FLEXSPIA_DATA1_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "FLEXSPIA_DATA2_SELECT_INPUT", address: 0x401F_84B0, width: Width::B4, access: Access::ReadWrite, description: "FLEXSPIA_DATA2_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "FLEXSPIA_DATA3_SELECT_INPUT", address: 0x401F_84B4, width: Width::B4, access: Access::ReadWrite, description: "FLEXSPIA_DATA3_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "FLEXSPIB_DATA0_SELECT_INPUT", address: 0x401F_84B8, width: Width::B4, access: Access::ReadWrite, description: "FLEXSPIB_DATA0_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "FLEXSPIB_DATA1_SELECT_INPUT", address: 0x401F_84BC, width: Width::B4, access: Access::ReadWrite, description: "FLEXSPIB_DATA1_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "FLEXSPIB_DATA2_SELECT_INPUT", address: 0x401F_84C0, width: Width::B4, access: Access::ReadWrite, description: "FLEXSPIB_DATA2_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "FLEXSPIB_DATA3_SELECT_INPUT", address: 0x401F_84C4, width: Width::B4, access: Access::ReadWrite, description: "FLEXSPIB_DATA3_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "FLEXSPIA_SCK_SELECT_INPUT", address: 0x401F_84C8, width: Width::B4, access: Access::ReadWrite, description: "FLEXSPIA_SCK_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "LPI2C1_SCL_SELECT_INPUT", address: 0x401F_84CC, width: Width::B4, access: Access::ReadWrite, description: "LPI2C1_SCL_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "LPI2C1_SDA_SELECT_INPUT", address: 0x401F_84D0, width: Width::B4, access: Access::ReadWrite, description: "LPI2C1_SDA_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "LPI2C2_SCL_SELECT_INPUT", address: 0x401F_84D4, width: Width::B4, access: Access::ReadWrite, description: "LPI2C2_SCL_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "LPI2C2_SDA_SELECT_INPUT", address: 0x401F_84D8, width: Width::B4, access: Access::ReadWrite, description: "LPI2C2_SDA_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "LPI2C3_SCL_SELECT_INPUT", address: 0x401F_84DC, width: Width::B4, access: Access::ReadWrite, description: "LPI2C3_SCL_SELECT_INPUT DAISY Register", fields: F31 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "LPI2C3_SDA_SELECT_INPUT", address: 0x401F_84E0, width: Width::B4, access: Access::ReadWrite, description: "LPI2C3_SDA_SELECT_INPUT DAISY Register", fields: F31 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "LPI2C4_SCL_SELECT_INPUT", address: 0x401F_84E4, width: Width::B4, access: Access::ReadWrite, description: "LPI2C4_SCL_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "LPI2C4_SDA_SELECT_INPUT", address: 0x401F_84E8, width: Width::B4, access: Access::ReadWrite, description: "LPI2C4_SDA_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "LPSPI1_PCS0_SELECT_INPUT", address: 0x401F_84EC, width: Width::B4, access: Access::ReadWrite, description: "LPSPI1_PCS0_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "LPSPI1_SCK_SELECT_INPUT", address: 0x401F_84F0, width: Width::B4, access: Access::ReadWrite, description: "LPSPI1_SCK_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "LPSPI1_SDI_SELECT_INPUT", address: 0x401F_84F4, width: Width::B4, access: Access::ReadWrite, description: "LPSPI1_SDI_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "LPSPI1_SDO_SELECT_INPUT", address: 0x401F_84F8, width: Width::B4, access: Access::ReadWrite, description: "LPSPI1_SDO_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "LPSPI2_PCS0_SELECT_INPUT", address: 0x401F_84FC, width: Width::B4, access: Access::ReadWrite, description: "LPSPI2_PCS0_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "LPSPI2_SCK_SELECT_INPUT", address: 0x401F_8500, width: Width::B4, access: Access::ReadWrite, description: "LPSPI2_SCK_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "LPSPI2_SDI_SELECT_INPUT", address: 0x401F_8504, width: Width::B4, access: Access::ReadWrite, description: "LPSPI2_SDI_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "LPSPI2_SDO_SELECT_INPUT", address: 0x401F_8508, width: Width::B4, access: Access::ReadWrite, description: "LPSPI2_SDO_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "LPSPI3_PCS0_SELECT_INPUT", address: 0x401F_850C, width: Width::B4, access: Access::ReadWrite, description: "LPSPI3_PCS0_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "LPSPI3_SCK_SELECT_INPUT", address: 0x401F_8510, width: Width::B4, access: Access::ReadWrite, description: "LPSPI3_SCK_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "LPSPI3_SDI_SELECT_INPUT", address: 0x401F_8514, width: Width::B4, access: Access::ReadWrite, description: "LPSPI3_SDI_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "LPSPI3_SDO_SELECT_INPUT", address: 0x401F_8518, width: Width::B4, access: Access::ReadWrite, description: "LPSPI3_SDO_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "LPSPI4_PCS0_SELECT_INPUT", address: 0x401F_851C, width: Width::B4, access: Access::ReadWrite, description: "LPSPI4_PCS0_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "LPSPI4_SCK_SELECT_INPUT", address: 0x401F_8520, width: Width::B4, access: Access::ReadWrite, description: "LPSPI4_SCK_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "LPSPI4_SDI_SELECT_INPUT", address: 0x401F_8524, width: Width::B4, access: Access::ReadWrite, description: "LPSPI4_SDI_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "LPSPI4_SDO_SELECT_INPUT", address: 0x401F_8528, width: Width::B4, access: Access::ReadWrite, description: "LPSPI4_SDO_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "LPUART2_RX_SELECT_INPUT", address: 0x401F_852C, width: Width::B4, access: Access::ReadWrite, description: "LPUART2_RX_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "LPUART2_TX_SELECT_INPUT", address: 0x401F_8530, width: Width::B4, access: Access::ReadWrite, description: "LPUART2_TX_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "LPUART3_CTS_B_SELECT_INPUT", address: 0x401F_8534, width: Width::B4, access: Access::ReadWrite, description: "LPUART3_CTS_B_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "LPUART3_RX_SELECT_INPUT", address: 0x401F_8538, width: Width::B4, access: Access::ReadWrite, description: "LPUART3_RX_SELECT_INPUT DAISY Register", fields: F31 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "LPUART3_TX_SELECT_INPUT", address: 0x401F_853C, width: Width::B4, access: Access::ReadWrite, description: "LPUART3_TX_SELECT_INPUT DAISY Register", fields: F31 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "LPUART4_RX_SELECT_INPUT", address: 0x401F_8540, width: Width::B4, access: Access::ReadWrite, description: "LPUART4_RX_SELECT_INPUT DAISY Register", fields: F31 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "LPUART4_TX_SELECT_INPUT", address: 0x401F_8544, width: Width::B4, access: Access::ReadWrite, description: "LPUART4_TX_SELECT_INPUT DAISY Register", fields: F31 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "LPUART5_RX_SELECT_INPUT", address: 0x401F_8548, width: Width::B4, access: Access::ReadWrite, description: "LPUART5_RX_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "LPUART5_TX_SELECT_INPUT", address: 0x401F_854C, width: Width::B4, access: Access::ReadWrite, description: "LPUART5_TX_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "LPUART6_RX_SELECT_INPUT", address: 0x401F_8550, width: Width::B4, access: Access::ReadWrite, description: "LPUART6_RX_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "LPUART6_TX_SELECT_INPUT", address: 0x401F_8554, width: Width::B4, access: Access::ReadWrite, description: "LPUART6_TX_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "LPUART7_RX_SELECT_INPUT", address: 0x401F_8558, width: Width::B4, access: Access::ReadWrite, description: "LPUART7_RX_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "LPUART7_TX_SELECT_INPUT", address: 0x401F_855C, width: Width::B4, access: Access::ReadWrite, description: "LPUART7_TX_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "LPUART8_RX_SELECT_INPUT", address: 0x401F_8560, width: Width::B4, access: Access::ReadWrite, description: "LPUART8_RX_SELECT_INPUT DAISY Register", fields: F31 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "LPUART8_TX_SELECT_INPUT", address: 0x401F_8564, width: Width::B4, access: Access::ReadWrite, description: "LPUART8_TX_SELECT_INPUT DAISY Register", fields: F31 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "NMI_SELECT_INPUT", address: 0x401F_8568, width: Width::B4, access: Access::ReadWrite, description: "NMI_GLUE_NMI_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "QTIMER2_TIMER0_SELECT_INPUT", address: 0x401F_856C, width: Width::B4, access: Access::ReadWrite, description: "QTIMER2_TIMER0_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "QTIMER2_TIMER1_SELECT_INPUT", address: 0x401F_8570, width: Width::B4, access: Access::ReadWrite, description: "QTIMER2_TIMER1_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "QTIMER2_TIMER2_SELECT_INPUT", address: 0x401F_8574, width: Width::B4, access: Access::ReadWrite, description: "QTIMER2_TIMER2_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "QTIMER2_TIMER3_SELECT_INPUT", address: 0x401F_8578, width: Width::B4, access: Access::ReadWrite, description: "QTIMER2_TIMER3_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "QTIMER3_TIMER0_SELECT_INPUT", address: 0x401F_857C, width: Width::B4, access: Access::ReadWrite, description: "QTIMER3_TIMER0_SELECT_INPUT DAISY Register", fields: F31 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "QTIMER3_TIMER1_SELECT_INPUT", address: 0x401F_8580, width: Width::B4, access: Access::ReadWrite, description: "QTIMER3_TIMER1_SELECT_INPUT DAISY Register", fields: F31 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "QTIMER3_TIMER2_SELECT_INPUT", address: 0x401F_8584, width: Width::B4, access: Access::ReadWrite, description: "QTIMER3_TIMER2_SELECT_INPUT DAISY Register", fields: F31 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "QTIMER3_TIMER3_SELECT_INPUT", address: 0x401F_8588, width: Width::B4, access: Access::ReadWrite, description: "QTIMER3_TIMER3_SELECT_INPUT DAISY Register", fields: F31 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SAI1_MCLK2_SELECT_INPUT", address: 0x401F_858C, width: Width::B4, access: Access::ReadWrite, description: "SAI1_MCLK2_SELECT_INPUT DAISY Register", fields: F31 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SAI1_RX_BCLK_SELECT_INPUT", address: 0x401F_8590, width: Width::B4, access: Access::ReadWrite, description: "SAI1_RX_BCLK_SELECT_INPUT DAISY Register", fields: F31 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SAI1_RX_DATA0_SELECT_INPUT", address: 0x401F_8594, width: Width::B4, access: Access::ReadWrite, description: "SAI1_RX_DATA0_SELECT_INPUT DAISY Register", fields: F31 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SAI1_RX_DATA1_SELECT_INPUT", address: 0x401F_8598, width: Width::B4, access: Access::ReadWrite, description: "SAI1_RX_DATA1_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SAI1_RX_DATA2_SELECT_INPUT", address: 0x401F_859C, width: Width::B4, access: Access::ReadWrite, description: "SAI1_RX_DATA2_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SAI1_RX_DATA3_SELECT_INPUT", address: 0x401F_85A0, width: Width::B4, access: Access::ReadWrite, description: "SAI1_RX_DATA3_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SAI1_RX_SYNC_SELECT_INPUT", address: 0x401F_85A4, width: Width::B4, access: Access::ReadWrite, description: "SAI1_RX_SYNC_SELECT_INPUT DAISY Register", fields: F31 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SAI1_TX_BCLK_SELECT_INPUT", address: 0x401F_85A8, width: Width::B4, access: Access::ReadWrite, description: "SAI1_TX_BCLK_SELECT_INPUT DAISY Register", fields: F31 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SAI1_TX_SYNC_SELECT_INPUT", address: 0x401F_85AC, width: Width::B4, access: Access::ReadWrite, description: "SAI1_TX_SYNC_SELECT_INPUT DAISY Register", fields: F31 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SAI2_MCLK2_SELECT_INPUT", address: 0x401F_85B0, width: Width::B4, access: Access::ReadWrite, description: "SAI2_MCLK2_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SAI2_RX_BCLK_SELECT_INPUT", address: 0x401F_85B4, width: Width::B4, access: Access::ReadWrite, description: "SAI2_RX_BCLK_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SAI2_RX_DATA0_SELECT_INPUT", address: 0x401F_85B8, width: Width::B4, access: Access::ReadWrite, description: "SAI2_RX_DATA0_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SAI2_RX_SYNC_SELECT_INPUT", address: 0x401F_85BC, width: Width::B4, access: Access::ReadWrite, description: "SAI2_RX_SYNC_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SAI2_TX_BCLK_SELECT_INPUT", address: 0x401F_85C0, width: Width::B4, access: Access::ReadWrite, description: "SAI2_TX_BCLK_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SAI2_TX_SYNC_SELECT_INPUT", address: 0x401F_85C4, width: Width::B4, access: Access::ReadWrite, description: "SAI2_TX_SYNC_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SPDIF_IN_SELECT_INPUT", address: 0x401F_85C8, width: Width::B4, access: Access::ReadWrite, description: "SPDIF_IN_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "USB_OTG2_OC_SELECT_INPUT", address: 0x401F_85CC, width: Width::B4, access: Access::ReadWrite, description: "USB_OTG2_OC_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "USB_OTG1_OC_SELECT_INPUT", address: 0x401F_85D0, width: Width::B4, access: Access::ReadWrite, description: "USB_OTG1_OC_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "USDHC1_CD_B_SELECT_INPUT", address: 0x401F_85D4, width: Width::B4, access: Access::ReadWrite, description: "USDHC1_CD_B_SELECT_INPUT DAISY Register", fields: F31 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "USDHC1_WP_SELECT_INPUT", address: 0x401F_85D8, width: Width::B4, access: Access::ReadWrite, description: "USDHC1_WP_SELECT_INPUT DAISY Register", fields: F31 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "USDHC2_CLK_SELECT_INPUT", address: 0x401F_85DC, width: Width::B4, access: Access::ReadWrite, description: "USDHC2_CLK_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "USDHC2_CD_B_SELECT_INPUT", address: 0x401F_85E0, width: Width::B4, access: Access::ReadWrite, description: "USDHC2_CD_B_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "USDHC2_CMD_SELECT_INPUT", address: 0x401F_85E4, width: Width::B4, access: Access::ReadWrite, description: "USDHC2_CMD_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "USDHC2_DATA0_SELECT_INPUT", address: 0x401F_85E8, width: Width::B4, access: Access::ReadWrite, description: "USDHC2_DATA0_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "USDHC2_DATA1_SELECT_INPUT", address: 0x401F_85EC, width: Width::B4, access: Access::ReadWrite, description: "USDHC2_DATA1_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "USDHC2_DATA2_SELECT_INPUT", address: 0x401F_85F0, width: Width::B4, access: Access::ReadWrite, description: "USDHC2_DATA2_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "USDHC2_DATA3_SELECT_INPUT", address: 0x401F_85F4, width: Width::B4, access: Access::ReadWrite, description: "USDHC2_DATA3_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "USDHC2_DATA4_SELECT_INPUT", address: 0x401F_85F8, width: Width::B4, access: Access::ReadWrite, description: "USDHC2_DATA4_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "USDHC2_DATA5_SELECT_INPUT", address: 0x401F_85FC, width: Width::B4, access: Access::ReadWrite, description: "USDHC2_DATA5_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "USDHC2_DATA6_SELECT_INPUT", address: 0x401F_8600, width: Width::B4, access: Access::ReadWrite, description: "USDHC2_DATA6_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "USDHC2_DATA7_SELECT_INPUT", address: 0x401F_8604, width: Width::B4, access: Access::ReadWrite, description: "USDHC2_DATA7_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "USDHC2_WP_SELECT_INPUT", address: 0x401F_8608, width: Width::B4, access: Access::ReadWrite, description: "USDHC2_WP_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "XBAR1_IN02_SELECT_INPUT", address: 0x401F_860C, width: Width::B4, access: Access::ReadWrite, description: "XBAR1_IN02_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "XBAR1_IN03_SELECT_INPUT", address: 0x401F_8610, width: Width::B4, access: Access::ReadWrite, description: "XBAR1_IN03_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "XBAR1_IN04_SELECT_INPUT", address: 0x401F_8614, width: Width::B4, access: Access::ReadWrite, description: "XBAR1_IN04_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "XBAR1_IN05_SELECT_INPUT", address: 0x401F_8618, width: Width::B4, access: Access::ReadWrite, description: "XBAR1_IN05_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "XBAR1_IN06_SELECT_INPUT", address: 0x401F_861C, width: Width::B4, access: Access::ReadWrite, description: "XBAR1_IN06_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "XBAR1_IN07_SELECT_INPUT", address: 0x401F_8620, width: Width::B4, access: Access::ReadWrite, description: "XBAR1_IN07_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "XBAR1_IN08_SELECT_INPUT", address: 0x401F_8624, width: Width::B4, access: Access::ReadWrite, description: "XBAR1_IN08_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "XBAR1_IN09_SELECT_INPUT", address: 0x401F_8628, width: Width::B4, access: Access::ReadWrite, description: "XBAR1_IN09_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "XBAR1_IN17_SELECT_INPUT", address: 0x401F_862C, width: Width::B4, access: Access::ReadWrite, description: "XBAR1_IN17_SELECT_INPUT DAISY Register", fields: F31 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "XBAR1_IN18_SELECT_INPUT", address: 0x401F_8630, width: Width::B4, access: Access::ReadWrite, description: "XBAR1_IN18_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "XBAR1_IN20_SELECT_INPUT", address: 0x401F_8634, width: Width::B4, access: Access::ReadWrite, description: "XBAR1_IN20_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "XBAR1_IN22_SELECT_INPUT", address: 0x401F_8638, width: Width::B4, access: Access::ReadWrite, description: "XBAR1_IN22_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "XBAR1_IN23_SELECT_INPUT", address: 0x401F_863C, width: Width::B4, access: Access::ReadWrite, description: "XBAR1_IN23_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "XBAR1_IN24_SELECT_INPUT", address: 0x401F_8640, width: Width::B4, access: Access::ReadWrite, description: "XBAR1_IN24_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "XBAR1_IN14_SELECT_INPUT", address: 0x401F_8644, width: Width::B4, access: Access::ReadWrite, description: "XBAR1_IN14_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "XBAR1_IN15_SELECT_INPUT", address: 0x401F_8648, width: Width::B4, access: Access::ReadWrite, description: "XBAR1_IN15_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "XBAR1_IN16_SELECT_INPUT", address: 0x401F_864C, width: Width::B4, access: Access::ReadWrite, description: "XBAR1_IN16_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "XBAR1_IN25_SELECT_INPUT", address: 0x401F_8650, width: Width::B4, access: Access::ReadWrite, description: "XBAR1_IN25_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "XBAR1_IN19_SELECT_INPUT", address: 0x401F_8654, width: Width::B4, access: Access::ReadWrite, description: "XBAR1_IN19_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "XBAR1_IN21_SELECT_INPUT", address: 0x401F_8658, width: Width::B4, access: Access::ReadWrite, description: "XBAR1_IN23_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_SPI_B0_00", address: 0x401F_865C, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_SPI_B0_00 SW MUX Control Register", fields: F32 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_SPI_B0_01", address: 0x401F_8660, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_SPI_B0_01 SW MUX Control Register", fields: F2 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_SPI_B0_02", address: 0x401F_8664, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_SPI_B0_02 SW MUX Control Register", fields: F2 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_SPI_B0_03", address: 0x401F_8668, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_SPI_B0_03 SW MUX Control Register", fields: F2 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_SPI_B0_04", address: 0x401F_866C, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_SPI_B0_04 SW MUX Control Register", fields: F2 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_SPI_B0_05", address: 0x401F_8670, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_SPI_B0_05 SW MUX Control Register", fields: F2 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_SPI_B0_06", address: 0x401F_8674, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_SPI_B0_06 SW MUX Control Register", fields: F2 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_SPI_B0_07", address: 0x401F_8678, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_SPI_B0_07 SW MUX Control Register", fields: F2 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_SPI_B0_08", address: 0x401F_867C, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_SPI_B0_08 SW MUX Control Register", fields: F2 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_SPI_B0_09", address: 0x401F_8680, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_SPI_B0_09 SW MUX Control Register", fields: F2 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_SPI_B0_10", address: 0x401F_8684, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_SPI_B0_10 SW MUX Control Register", fields: F2 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_SPI_B0_11", address: 0x401F_8688, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_SPI_B0_11 SW MUX Control Register", fields: F2 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_SPI_B0_12", address: 0x401F_868C, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_SPI_B0_12 SW MUX Control Register", fields: F2 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_SPI_B0_13", address: 0x401F_8690, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_SPI_B0_13 SW MUX Control Register", fields: F32 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_SPI_B1_00", address: 0x401F_8694, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_SPI_B1_00 SW MUX Control Register", fields: F2 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_SPI_B1_01", address: 0x401F_8698, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_SPI_B1_01 SW MUX Control Register", fields: F2 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_SPI_B1_02", address: 0x401F_869C, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_SPI_B1_02 SW MUX Control Register", fields: F2 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_SPI_B1_03", address: 0x401F_86A0, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_SPI_B1_03 SW MUX Control Register", fields: F2 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_SPI_B1_04", address: 0x401F_86A4, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_SPI_B1_04 SW MUX Control Register", fields: F2 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_SPI_B1_05", address: 0x401F_86A8, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_SPI_B1_05 SW MUX Control Register", fields: F2 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_SPI_B1_06", address: 0x401F_86AC, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_SPI_B1_06 SW MUX Control Register", fields: F2 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_MUX_CTL_PAD_GPIO_SPI_B1_07", address: 0x401F_86B0, width: Width::B4, access: Access::ReadWrite, description: "SW_MUX_CTL_PAD_GPIO_SPI_B1_07 SW MUX Control Register", fields: F32 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_SPI_B0_00", address: 0x401F_86B4, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_SPI_B0_00 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_SPI_B0_01", address: 0x401F_86B8, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_SPI_B0_01 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_SPI_B0_02", address: 0x401F_86BC, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_SPI_B0_02 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_SPI_B0_03", address: 0x401F_86C0, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_SPI_B0_03 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_SPI_B0_04", address: 0x401F_86C4, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_SPI_B0_04 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_SPI_B0_05", address: 0x401F_86C8, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_SPI_B0_05 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_SPI_B0_06", address: 0x401F_86CC, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_SPI_B0_06 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_SPI_B0_07", address: 0x401F_86D0, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_SPI_B0_07 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_SPI_B0_08", address: 0x401F_86D4, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_SPI_B0_08 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_SPI_B0_09", address: 0x401F_86D8, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_SPI_B0_09 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_SPI_B0_10", address: 0x401F_86DC, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_SPI_B0_10 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_SPI_B0_11", address: 0x401F_86E0, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_SPI_B0_11 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_SPI_B0_12", address: 0x401F_86E4, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_SPI_B0_12 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_SPI_B0_13", address: 0x401F_86E8, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_SPI_B0_13 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_SPI_B1_00", address: 0x401F_86EC, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_SPI_B1_00 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_SPI_B1_01", address: 0x401F_86F0, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_SPI_B1_01 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_SPI_B1_02", address: 0x401F_86F4, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_SPI_B1_02 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_SPI_B1_03", address: 0x401F_86F8, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_SPI_B1_03 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_SPI_B1_04", address: 0x401F_86FC, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_SPI_B1_04 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_SPI_B1_05", address: 0x401F_8700, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_SPI_B1_05 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_SPI_B1_06", address: 0x401F_8704, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_SPI_B1_06 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SW_PAD_CTL_PAD_GPIO_SPI_B1_07", address: 0x401F_8708, width: Width::B4, access: Access::ReadWrite, description: "SW_PAD_CTL_PAD_GPIO_SPI_B1_07 SW PAD Control Register", fields: F3 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "ENET2_IPG_CLK_RMII_SELECT_INPUT", address: 0x401F_870C, width: Width::B4, access: Access::ReadWrite, description: "ENET2_IPG_CLK_RMII_SELECT_INPUT DAISY Register", fields: F31 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "ENET2_IPP_IND_MAC0_MDIO_SELECT_INPUT", address: 0x401F_8710, width: Width::B4, access: Access::ReadWrite, description: "ENET2_IPP_IND_MAC0_MDIO_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "ENET2_IPP_IND_MAC0_RXDATA_SELECT_INPUT_0", address: 0x401F_8714, width: Width::B4, access: Access::ReadWrite, description: "ENET2_IPP_IND_MAC0_RXDATA_SELECT_INPUT_0 DAISY Register", fields: F31 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "ENET2_IPP_IND_MAC0_RXDATA_SELECT_INPUT_1", address: 0x401F_8718, width: Width::B4, access: Access::ReadWrite, description: "ENET2_IPP_IND_MAC0_RXDATA_SELECT_INPUT_1 DAISY Register", fields: F31 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "ENET2_IPP_IND_MAC0_RXEN_SELECT_INPUT", address: 0x401F_871C, width: Width::B4, access: Access::ReadWrite, description: "ENET2_IPP_IND_MAC0_RXEN_SELECT_INPUT DAISY Register", fields: F31 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "ENET2_IPP_IND_MAC0_RXERR_SELECT_INPUT", address: 0x401F_8720, width: Width::B4, access: Access::ReadWrite, description: "ENET2_IPP_IND_MAC0_RXERR_SELECT_INPUT DAISY Register", fields: F31 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "ENET2_IPP_IND_MAC0_TIMER_SELECT_INPUT_0", address: 0x401F_8724, width: Width::B4, access: Access::ReadWrite, description: "ENET2_IPP_IND_MAC0_TIMER_SELECT_INPUT_0 DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "ENET2_IPP_IND_MAC0_TXCLK_SELECT_INPUT", address: 0x401F_8728, width: Width::B4, access: Access::ReadWrite, description: "ENET2_IPP_IND_MAC0_TXCLK_SELECT_INPUT DAISY Register", fields: F31 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "FLEXSPI2_IPP_IND_DQS_FA_SELECT_INPUT", address: 0x401F_872C, width: Width::B4, access: Access::ReadWrite, description: "FLEXSPI2_IPP_IND_DQS_FA_SELECT_INPUT DAISY Register", fields: F31 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "FLEXSPI2_IPP_IND_IO_FA_BIT0_SELECT_INPUT", address: 0x401F_8730, width: Width::B4, access: Access::ReadWrite, description: "FLEXSPI2_IPP_IND_IO_FA_BIT0_SELECT_INPUT DAISY Register", fields: F31 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "FLEXSPI2_IPP_IND_IO_FA_BIT1_SELECT_INPUT", address: 0x401F_8734, width: Width::B4, access: Access::ReadWrite, description: "FLEXSPI2_IPP_IND_IO_FA_BIT1_SELECT_INPUT DAISY Register", fields: F31 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "FLEXSPI2_IPP_IND_IO_FA_BIT2_SELECT_INPUT", address: 0x401F_8738, width: Width::B4, access: Access::ReadWrite, description: "FLEXSPI2_IPP_IND_IO_FA_BIT2_SELECT_INPUT DAISY Register", fields: F31 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "FLEXSPI2_IPP_IND_IO_FA_BIT3_SELECT_INPUT", address: 0x401F_873C, width: Width::B4, access: Access::ReadWrite, description: "FLEXSPI2_IPP_IND_IO_FA_BIT3_SELECT_INPUT DAISY Register", fields: F31 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "FLEXSPI2_IPP_IND_IO_FB_BIT0_SELECT_INPUT", address: 0x401F_8740, width: Width::B4, access: Access::ReadWrite, description: "FLEXSPI2_IPP_IND_IO_FB_BIT0_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "FLEXSPI2_IPP_IND_IO_FB_BIT1_SELECT_INPUT", address: 0x401F_8744, width: Width::B4, access: Access::ReadWrite, description: "FLEXSPI2_IPP_IND_IO_FB_BIT1_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "FLEXSPI2_IPP_IND_IO_FB_BIT2_SELECT_INPUT", address: 0x401F_8748, width: Width::B4, access: Access::ReadWrite, description: "FLEXSPI2_IPP_IND_IO_FB_BIT2_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "FLEXSPI2_IPP_IND_IO_FB_BIT3_SELECT_INPUT", address: 0x401F_874C, width: Width::B4, access: Access::ReadWrite, description: "FLEXSPI2_IPP_IND_IO_FB_BIT3_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "FLEXSPI2_IPP_IND_SCK_FA_SELECT_INPUT", address: 0x401F_8750, width: Width::B4, access: Access::ReadWrite, description: "FLEXSPI2_IPP_IND_SCK_FA_SELECT_INPUT DAISY Register", fields: F31 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "FLEXSPI2_IPP_IND_SCK_FB_SELECT_INPUT", address: 0x401F_8754, width: Width::B4, access: Access::ReadWrite, description: "FLEXSPI2_IPP_IND_SCK_FB_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "GPT1_IPP_IND_CAPIN1_SELECT_INPUT", address: 0x401F_8758, width: Width::B4, access: Access::ReadWrite, description: "GPT1_IPP_IND_CAPIN1_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "GPT1_IPP_IND_CAPIN2_SELECT_INPUT", address: 0x401F_875C, width: Width::B4, access: Access::ReadWrite, description: "GPT1_IPP_IND_CAPIN2_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "GPT1_IPP_IND_CLKIN_SELECT_INPUT", address: 0x401F_8760, width: Width::B4, access: Access::ReadWrite, description: "GPT1_IPP_IND_CLKIN_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "GPT2_IPP_IND_CAPIN1_SELECT_INPUT", address: 0x401F_8764, width: Width::B4, access: Access::ReadWrite, description: "GPT2_IPP_IND_CAPIN1_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "GPT2_IPP_IND_CAPIN2_SELECT_INPUT", address: 0x401F_8768, width: Width::B4, access: Access::ReadWrite, description: "GPT2_IPP_IND_CAPIN2_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "GPT2_IPP_IND_CLKIN_SELECT_INPUT", address: 0x401F_876C, width: Width::B4, access: Access::ReadWrite, description: "GPT2_IPP_IND_CLKIN_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SAI3_IPG_CLK_SAI_MCLK_SELECT_INPUT_2", address: 0x401F_8770, width: Width::B4, access: Access::ReadWrite, description: "SAI3_IPG_CLK_SAI_MCLK_SELECT_INPUT_2 DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SAI3_IPP_IND_SAI_RXBCLK_SELECT_INPUT", address: 0x401F_8774, width: Width::B4, access: Access::ReadWrite, description: "SAI3_IPP_IND_SAI_RXBCLK_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SAI3_IPP_IND_SAI_RXDATA_SELECT_INPUT_0", address: 0x401F_8778, width: Width::B4, access: Access::ReadWrite, description: "SAI3_IPP_IND_SAI_RXDATA_SELECT_INPUT_0 DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SAI3_IPP_IND_SAI_RXSYNC_SELECT_INPUT", address: 0x401F_877C, width: Width::B4, access: Access::ReadWrite, description: "SAI3_IPP_IND_SAI_RXSYNC_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SAI3_IPP_IND_SAI_TXBCLK_SELECT_INPUT", address: 0x401F_8780, width: Width::B4, access: Access::ReadWrite, description: "SAI3_IPP_IND_SAI_TXBCLK_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SAI3_IPP_IND_SAI_TXSYNC_SELECT_INPUT", address: 0x401F_8784, width: Width::B4, access: Access::ReadWrite, description: "SAI3_IPP_IND_SAI_TXSYNC_SELECT_INPUT DAISY Register", fields: F29 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "SEMC_I_IPP_IND_DQS4_SELECT_INPUT", address: 0x401F_8788, width: Width::B4, access: Access::ReadWrite, description: "SEMC_I_IPP_IND_DQS4_SELECT_INPUT DAISY Register", fields: F31 },
+    Register { module: "iomuxc", peripheral: "IOMUXC", name: "CANFD_IPP_IND_CANRX_SELECT_INPUT", address: 0x401F_878C, width: Width::B4, access: Access::ReadWrite, description: "CANFD_IPP_IND_CANRX_SELECT_INPUT DAISY Register", fields: F31 },
+    Register { module: "semc", peripheral: "SEMC", name: "MCR", address: 0x402F_0000, width: Width::B4, access: Access::ReadWrite, description: "Module Control Register", fields: F100 },
+    Register { module: "semc", peripheral: "SEMC", name: "IOCR", address: 0x402F_0004, width: Width::B4, access: Access::ReadWrite, description: "IO Mux Control Register", fields: F101 },
+    Register { module: "semc", peripheral: "SEMC", name: "BMCR0", address: 0x402F_0008, width: Width::B4, access: Access::ReadWrite, description: "Master Bus (AXI) Control Register 0", fields: F102 },
+    Register { module: "semc", peripheral: "SEMC", name: "BMCR1", address: 0x402F_000C, width: Width::B4, access: Access::ReadWrite, description: "Master Bus (AXI) Control Register 1", fields: F103 },
+    Register { module: "semc", peripheral: "SEMC", name: "BR0", address: 0x402F_0010, width: Width::B4, access: Access::ReadWrite, description: "Base Register 0 (For SDRAM CS0 device)", fields: F104 },
+    Register { module: "semc", peripheral: "SEMC", name: "BR1", address: 0x402F_0014, width: Width::B4, access: Access::ReadWrite, description: "Base Register 1 (For SDRAM CS1 device)", fields: F104 },
+    Register { module: "semc", peripheral: "SEMC", name: "BR2", address: 0x402F_0018, width: Width::B4, access: Access::ReadWrite, description: "Base Register 2 (For SDRAM CS2 device)", fields: F104 },
+    Register { module: "semc", peripheral: "SEMC", name: "BR3", address: 0x402F_001C, width: Width::B4, access: Access::ReadWrite, description: "Base Register 3 (For SDRAM CS3 device)", fields: F104 },
+    Register { module: "semc", peripheral: "SEMC", name: "BR4", address: 0x402F_0020, width: Width::B4, access: Access::ReadWrite, description: "Base Register 4 (For NAND device)", fields: F104 },
+    Register { module: "semc", peripheral: "SEMC", name: "BR5", address: 0x402F_0024, width: Width::B4, access: Access::ReadWrite, description: "Base Register 5 (For NOR device)", fields: F104 },
+    Register { module: "semc", peripheral: "SEMC", name: "BR6", address: 0x402F_0028, width: Width::B4, access: Access::ReadWrite, description: "Base Register 6 (For PSRAM device)", fields: F104 },
+    Register { module: "semc", peripheral: "SEMC", name: "BR7", address: 0x402F_002C, width: Width::B4, access: Access::ReadWrite, description: "Base Register 7 (For DBI-B (MIPI Display Bus Interface Type B) device)", fields: F104 },
+    Register { module: "semc", peripheral: "SEMC", name: "BR8", address: 0x402F_0030, width: Width::B4, access: Access::ReadWrite, description: "Base Register 8 (For NAND device)", fields: F104 },
+    Register { module: "semc", peripheral: "SEMC", name: "DLLCR", address: 0x402F_0034, width: Width::B4, access: Access::ReadWrite, description: "DLL Control Register", fields: F105 },
+    Register { module: "semc", peripheral: "SEMC", name: "INTEN", address: 0x402F_0038, width: Width::B4, access: Access::ReadWrite, description: "Interrupt Enable Register", fields: F106 },
+    Register { module: "semc", peripheral: "SEMC", name: "INTR", address: 0x402F_003C, width: Width::B4, access: Access::ReadWrite, description: "Interrupt Enable Register", fields: F107 },
+    Register { module: "semc", peripheral: "SEMC", name: "SDRAMCR0", address: 0x402F_0040, width: Width::B4, access: Access::ReadWrite, description: "SDRAM control register 0", fields: F108 },
+    Register { module: "semc", peripheral: "SEMC", name: "SDRAMCR1", address: 0x402F_0044, width: Width::B4, access: Access::ReadWrite, description: "SDRAM control register 1", fields: F109 },
+    Register { module: "semc", peripheral: "SEMC", name: "SDRAMCR2", address: 0x402F_0048, width: Width::B4, access: Access::ReadWrite, description: "SDRAM control register 2", fields: F110 },
+    Register { module: "semc", peripheral: "SEMC", name: "SDRAMCR3", address: 0x402F_004C, width: Width::B4, access: Access::ReadWrite, description: "SDRAM control register 3", fields: F111 },
+    Register { module: "semc", peripheral: "SEMC", name: "NANDCR0", address: 0x402F_0050, width: Width::B4, access: Access::ReadWrite, description: "NAND control register 0", fields: F112 },
+    Register { module: "semc", peripheral: "SEMC", name: "NANDCR1", address: 0x402F_0054, width: Width::B4, access: Access::ReadWrite, description: "NAND control register 1", fields: F113 },
+    Register { module: "semc", peripheral: "SEMC", name: "NANDCR2", address: 0x402F_0058, width: Width::B4, access: Access::ReadWrite, description: "NAND control register 2", fields: F114 },
+    Register { module: "semc", peripheral: "SEMC", name: "NANDCR3", address: 0x402F_005C, width: Width::B4, access: Access::ReadWrite, description: "NAND control register 3", fields: F115 },
+    Register { module: "semc", peripheral: "SEMC", name: "NORCR0", address: 0x402F_0060, width: Width::B4, access: Access::ReadWrite, description: "NOR control register 0", fields: F116 },
+    Register { module: "semc", peripheral: "SEMC", name: "NORCR1", address: 0x402F_0064, width: Width::B4, access: Access::ReadWrite, description: "NOR control register 1", fields: F117 },
+    Register { module: "semc", peripheral: "SEMC", name: "NORCR2", address: 0x402F_0068, width: Width::B4, access: Access::ReadWrite, description: "NOR control register 2", fields: F118 },
+    Register { module: "semc", peripheral: "SEMC", name: "NORCR3", address: 0x402F_006C, width: Width::B4, access: Access::ReadWrite, description: "NOR control register 3", fields: F119 },
+    Register { module: "semc", peripheral: "SEMC", name: "SRAMCR0", address: 0x402F_0070, width: Width::B4, access: Access::ReadWrite, description: "SRAM control register 0", fields: F120 },
+    Register { module: "semc", peripheral: "SEMC", name: "SRAMCR1", address: 0x402F_0074, width: Width::B4, access: Access::ReadWrite, description: "SRAM control register 1", fields: F117 },
+    Register { module: "semc", peripheral: "SEMC", name: "SRAMCR2", address: 0x402F_0078, width: Width::B4, access: Access::ReadWrite, description: "SRAM control register 2", fields: F121 },
+    Register { module: "semc", peripheral: "SEMC", name: "SRAMCR3", address: 0x402F_007C, width: Width::B4, access: Access::ReadWrite, description: "SRAM control register 3", fields: F0 },
+    Register { module: "semc", peripheral: "SEMC", name: "DBICR0", address: 0x402F_0080, width: Width::B4, access: Access::ReadWrite, description: "DBI-B control register 0", fields: F122 },
+    Register { module: "semc", peripheral: "SEMC", name: "DBICR1", address: 0x402F_0084, width: Width::B4, access: Access::ReadWrite, description: "DBI-B control register 1", fields: F123 },
+    Register { module: "semc", peripheral: "SEMC", name: "IPCR0", address: 0x402F_0090, width: Width::B4, access: Access::ReadWrite, description: "IP Command control register 0", fields: F0 },
+    Register { module: "semc", peripheral: "SEMC", name: "IPCR1", address: 0x402F_0094, width: Width::B4, access: Access::ReadWrite, description: "IP Command control register 1", fields: F124 },
+    Register { module: "semc", peripheral: "SEMC", name: "IPCR2", address: 0x402F_0098, width: Width::B4, access: Access::ReadWrite, description: "IP Command control register 2", fields: F125 },
+    Register { module: "semc", peripheral: "SEMC", name: "IPCMD", address: 0x402F_009C, width: Width::B4, access: Access::ReadWrite, description: "IP Command register", fields: F126 },
+    Register { module: "semc", peripheral: "SEMC", name: "IPTXDAT", address: 0x402F_00A0, width: Width::B4, access: Access::ReadWrite, description: "TX DATA register (for IP Command)", fields: F0 },
+    Register { module: "semc", peripheral: "SEMC", name: "IPRXDAT", address: 0x402F_00B0, width: Width::B4, access: Access::ReadOnly, description: "RX DATA register (for IP Command)", fields: F0 },
+    Register { module: "semc", peripheral: "SEMC", name: "STS0", address: 0x402F_00C0, width: Width::B4, access: Access::ReadOnly, description: "Status register 0", fields: F127 },
+    Register { module: "semc", peripheral: "SEMC", name: "STS1", address: 0x402F_00C4, width: Width::B4, access: Access::ReadOnly, description: "Status register 1", fields: F0 },
+    Register { module: "semc", peripheral: "SEMC", name: "STS2", address: 0x402F_00C8, width: Width::B4, access: Access::ReadOnly, description: "Status register 2", fields: F128 },
+    Register { module: "semc", peripheral: "SEMC", name: "STS3", address: 0x402F_00CC, width: Width::B4, access: Access::ReadOnly, description: "Status register 3", fields: F0 },
+    Register { module: "semc", peripheral: "SEMC", name: "STS4", address: 0x402F_00D0, width: Width::B4, access: Access::ReadOnly, description: "Status register 4", fields: F0 },
+    Register { module: "semc", peripheral: "SEMC", name: "STS5", address: 0x402F_00D4, width: Width::B4, access: Access::ReadOnly, description: "Status register 5", fields: F0 },
+    Register { module: "semc", peripheral: "SEMC", name: "STS6", address: 0x402F_00D8, width: Width::B4, access: Access::ReadOnly, description: "Status register 6", fields: F0 },
+    Register { module: "semc", peripheral: "SEMC", name: "STS7", address: 0x402F_00DC, width: Width::B4, access: Access::ReadOnly, description: "Status register 7", fields: F0 },
+    Register { module: "semc", peripheral: "SEMC", name: "STS8", address: 0x402F_00E0, width: Width::B4, access: Access::ReadOnly, description: "Status register 8", fields: F0 },
+    Register { module: "semc", peripheral: "SEMC", name: "STS9", address: 0x402F_00E4, width: Width::B4, access: Access::ReadOnly, description: "Status register 9", fields: F0 },
+    Register { module: "semc", peripheral: "SEMC", name: "STS10", address: 0x402F_00E8, width: Width::B4, access: Access::ReadOnly, description: "Status register 10", fields: F0 },
+    Register { module: "semc", peripheral: "SEMC", name: "STS11", address: 0x402F_00EC, width: Width::B4, access: Access::ReadOnly, description: "Status register 11", fields: F0 },
+    Register { module: "semc", peripheral: "SEMC", name: "STS12", address: 0x402F_00F0, width: Width::B4, access: Access::ReadOnly, description: "Status register 12", fields: F0 },
+    Register { module: "semc", peripheral: "SEMC", name: "STS13", address: 0x402F_00F4, width: Width::B4, access: Access::ReadOnly, description: "Status register 13", fields: F129 },
+    Register { module: "semc", peripheral: "SEMC", name: "STS14", address: 0x402F_00F8, width: Width::B4, access: Access::ReadOnly, description: "Status register 14", fields: F0 },
+    Register { module: "semc", peripheral: "SEMC", name: "STS15", address: 0x402F_00FC, width: Width::B4, access: Access::ReadOnly, description: "Status register 15", fields: F0 },
+];
@@ -0,0 +1,154 @@
+//! Placing the DCD in firmware linked with the [`imxrt-rt`](https://crates.io/crates/imxrt-rt)
+//! runtime.
+//!
+//! The `imxrt-rt` linker script collects the `.dcd` input section right after the IVT boot data,
+//! and points the `dcd` field of the IVT to it (or leaves it null if the section is empty). So the
+//! firmware only needs a static in that section, which [`write_static`] emits as Rust source for
+//! the build script to generate:
+//!
+//! ```no_run
+//! // build.rs
+//! # use imxrt_dcd::{rt, Command};
+//! # let commands = [Command::Nop];
+//! let out_dir = std::path::PathBuf::from(std::env::var_os("OUT_DIR").unwrap());
+//! let placement = rt::placement(0x6000_0000, &commands).unwrap();
+//! println!("cargo:warning=DCD at {:#010X}, {} bytes", placement.address, placement.byte_len);
+//! let file = std::fs::File::create(out_dir.join("dcd.rs")).unwrap();
+//! rt::write_static(file, "DEVICE_CONFIGURATION_DATA", &commands).unwrap();
+//! ```
+//!
+//! ```ignore
+//! // main.rs
+//! include!(concat!(env!("OUT_DIR"), "/dcd.rs"));
+//! ```
+//!
+//! The generated static is marked `#[unsafe(link_section = ...)]` and `#[unsafe(no_mangle)]`, the
+//! form that edition 2024 requires, so the firmware needs Rust 1.82 or later.
+
+use std::fmt::Write as _;
+use std::io;
+
+use crate::{serialize, Command};
+
+/// Name of the linker section `imxrt-rt` places the DCD from.
+pub const SECTION: &str = ".dcd";
+
+/// Offset of the DCD from the start of the image (`ORIGIN(FLASH)`): after the IVT at `0x1000` and
+/// the boot data.
+pub const OFFSET: u32 = 0x1030;
+
+/// Alignment of the DCD, in bytes; the linker script also requires its length to be a multiple
+/// of this.
+pub const ALIGN: u32 = 4;
+
+/// Bytes between the DCD and the end of the boot header at `0x2000`.
+///
+/// The reset handler is placed after the DCD in the same space, so the DCD has to be smaller by
+/// at least its size (a few hundred bytes) for the firmware to link.
+pub const MAX_BYTE_LEN: usize = (0x2000 - OFFSET) as usize;
+
+/// Where the DCD ends up in an `imxrt-rt` image.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Placement {
+    /// Address of the DCD, which the IVT points to.
+    pub address: u32,
+    /// Length of the serialized DCD, in bytes.
+    pub byte_len: usize,
+}
+
+/// Returns the placement of the DCD made of given commands, in an image starting at
+/// `flash_origin` (e.g. `0x6000_0000` for FlexSPI NOR flash on the RT1060).
+///
+/// Returns [`io::ErrorKind::InvalidInput`] if the DCD does not fit in the boot header (see
+/// [`MAX_BYTE_LEN`]).
+///
+/// ```
+/// # use imxrt_dcd::{rt, Command};
+/// let placement = rt::placement(0x6000_0000, &[Command::Nop]).unwrap();
+/// assert_eq!(placement, rt::Placement { address: 0x6000_1030, byte_len: 8 });
+/// ```
+pub fn placement(flash_origin: u32, commands: &[Command]) -> io::Result<Placement> {
+    let mut bytes = vec![];
+    serialize(&mut bytes, commands)?;
+    if bytes.len() > MAX_BYTE_LEN {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!(
+                "DCD takes {} bytes, more than the {} bytes imxrt-rt reserves for it",
+                bytes.len(),
+                MAX_BYTE_LEN
+            ),
+        ));
+    }
+    Ok(Placement {
+        address: flash_origin.wrapping_add(OFFSET),
+        byte_len: bytes.len(),
+    })
+}
+
+/// Serializes given commands as a DCD block into Rust source defining
+/// `pub static <name>: [u8; N]` in the [`SECTION`] placed by `imxrt-rt`, to be `include!`d by the
+/// firmware.
+///
+/// `name` must be a Rust identifier; the static is not mangled, so it also shows up under this
+/// name in the linker map. The source requires Rust 1.82 or later; see the
+/// [module-level docs](self).
+///
+/// Returns the number of DCD bytes (not source bytes) written or error.
+///
+/// ```
+/// # use imxrt_dcd::{rt::write_static, Command};
+/// let mut source = vec![];
+/// let len = write_static(&mut source, "DCD", &[Command::Nop]).unwrap();
+/// assert_eq!(len, 8);
+/// assert!(String::from_utf8(source).unwrap().contains(
+///     "pub static DCD: [u8; 8] = [\n    0xD2, 0x00, 0x08, 0x41, 0xC0, 0x00, 0x04, 0x00,\n];"
+/// ));
+/// ```
+pub fn write_static(mut w: impl io::Write, name: &str, commands: &[Command]) -> io::Result<usize> {
+    let mut bytes = vec![];
+    serialize(&mut bytes, commands)?;
+
+    let mut out = String::new();
+    out.push_str("// Generated by imxrt-dcd; do not edit.\n\n");
+    out.push_str("/// Device Configuration Data, placed after the IVT boot data by `imxrt-rt`.\n");
+    writeln!(out, "#[unsafe(link_section = \"{}\")]", SECTION).unwrap();
+    out.push_str("#[unsafe(no_mangle)]\n#[used]\n");
+    writeln!(out, "pub static {}: [u8; {}] = [", name, bytes.len()).unwrap();
+    for line in bytes.chunks(12) {
+        out.push_str("   ");
+        for byte in line {
+            write!(out, " {:#04X},", byte).unwrap();
+        }
+        out.push('\n');
+    }
+    out.push_str("];\n");
+    w.write_all(out.replace("0X", "0x").as_bytes())?;
+    Ok(bytes.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn too_large() {
+        // 4-byte header, 4-byte write header, 8 bytes per write
+        let max = (MAX_BYTE_LEN - 8) / 8;
+        let commands: Vec<_> = (0..max as u32)
+            .map(|i| Command::write32(0x2000_0000 + 4 * i, i))
+            .collect();
+        let placement = placement(0x7000_0000, &commands).unwrap();
+        assert_eq!(placement.address, 0x7000_1030);
+        assert!(placement.byte_len <= MAX_BYTE_LEN);
+
+        let mut commands = commands;
+        commands.push(Command::write32(0x2000_0000, 0));
+        let err = super::placement(0x7000_0000, &commands).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+        assert_eq!(
+            err.to_string(),
+            "DCD takes 4056 bytes, more than the 4048 bytes imxrt-rt reserves for it"
+        );
+    }
+}
@@ -0,0 +1,71 @@
+//! JSON Schema of the serde representation of command lists.
+//!
+//! With the `serde` feature, a command list is represented as an array of objects tagged by
+//! `type`, e.g. in JSON:
+//!
+//! ```json
+//! [
+//!   { "type": "write", "width": "b4", "op": "set", "address": 1074774120, "value": 192 },
+//!   { "type": "check", "cond": "all_set", "address": 1074626560, "mask": 2147483648, "count": 100 },
+//!   { "type": "nop" }
+//! ]
+//! ```
+//!
+//! `width` defaults to `b4`, `op` defaults to `write`, and `count` defaults to polling
+//! indefinitely. The schema is also checked in as `schema/commands.schema.json`, so that editors
+//! and CI jobs can validate DCD description files without building this crate.
+
+use schemars::schema::RootSchema;
+
+use crate::Command;
+
+/// Returns the JSON Schema of `Vec<Command>`.
+pub fn command_list_schema() -> RootSchema {
+    schemars::schema_for!(Vec<Command>)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CheckCond, Width, Write, WriteOp};
+
+    const SCHEMA_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/schema/commands.schema.json");
+
+    #[test]
+    fn checked_in_schema_is_up_to_date() {
+        let schema = serde_json::to_string_pretty(&command_list_schema()).unwrap() + "\n";
+        if std::env::var_os(crate::golden::BLESS_ENV).is_some_and(|value| !value.is_empty()) {
+            std::fs::write(SCHEMA_PATH, &schema).unwrap();
+        }
+        assert_eq!(
+            std::fs::read_to_string(SCHEMA_PATH).unwrap(),
+            schema,
+            "run with {}=1 to update",
+            crate::golden::BLESS_ENV
+        );
+    }
+
+    #[test]
+    fn json_representation() {
+        let json = r#"[
+            { "type": "write", "width": "b2", "op": "set", "address": 1074774120, "value": 192 },
+            { "type": "write", "address": 1074774120, "value": 192 },
+            { "type": "check", "cond": "all_set", "address": 1074626560, "mask": 2147483648, "count": 100 },
+            { "type": "nop" }
+        ]"#;
+        let commands: Vec<Command> = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            commands,
+            [
+                Command::Write(Write::new(Width::B2, WriteOp::Set, 0x400F_C068, 0xC0)),
+                Command::write32(0x400F_C068, 0xC0),
+                Command::check32(CheckCond::AllSet, 0x400D_8000, 1 << 31).with_count(100),
+                Command::Nop,
+            ],
+        );
+        assert_eq!(
+            serde_json::to_string(&commands[2..]).unwrap(),
+            r#"[{"type":"check","width":"b4","cond":"all_set","address":1074626560,"mask":2147483648,"count":100},{"type":"nop"}]"#,
+        );
+    }
+}
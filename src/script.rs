@@ -0,0 +1,440 @@
+//! Emitting commands as scripts for loaders and debuggers, which apply the same register setup as
+//! the boot ROM would from the DCD, e.g. in recovery and manufacturing flows.
+//!
+//! [`imx_usb_loader`] emits a configuration line for the
+//! [`imx_usb_loader`](https://github.com/boundarydevices/imx_usb_loader) tool, which takes the DCD
+//! from a boot image (see [`crate::output::write_boot_image`]) and applies it over the USB serial
+//! downloader:
+//!
+//! ```
+//! # use imxrt_dcd::{output::write_boot_image, script, Command};
+//! # let commands = [Command::Nop];
+//! let mut image = vec![];
+//! write_boot_image(&mut image, 0x2020_0000, &commands).unwrap();
+//! // write `image` to `dcd.imx`, and append to the work configuration:
+//! assert!(script::imx_usb_loader("dcd.imx").ends_with("\ndcd.imx:dcd\n"));
+//! ```
+//!
+//! [`uuu`] emits a script for NXP's `uuu` (Universal Update Utility, mfgtools) applying the same
+//! boot image, e.g. as the first step of an open-board programming flow.
+//!
+//! The debugger scripts instead replay the commands one by one through the debug probe: writes
+//! become memory writes (Set / Clear as read-modify-write), and checks become polling loops that
+//! fail the script after the poll count. Unlike the boot ROM, the debugger does not reject writes
+//! outside of [`crate::Target::write_ranges`].
+
+use std::fmt::Write as _;
+
+use crate::{symbols, CheckCond, Command, Width, WriteOp};
+
+/// Returns a line of an `imx_usb_loader` work configuration (`<chip>_usb_work.conf`) applying
+/// the DCD of the boot image at `image_path`, without loading or running code.
+///
+/// The line goes after the line describing the chip (`hid,1024,...`) in the configuration file of
+/// the target. `imx_usb_loader` itself has no syntax for register writes, so the commands are
+/// passed in a boot image written by [`crate::output::write_boot_image`]; `imx_usb_loader` locates
+/// its IVT and sends the DCD to the boot ROM.
+pub fn imx_usb_loader(image_path: &str) -> String {
+    format!(
+        "# Generated by imxrt-dcd: applies the DCD of the image, without loading or running \
+         code.\n{}:dcd\n",
+        image_path
+    )
+}
+
+/// Returns a `uuu` script applying the DCD of the boot image at `image_path` (see
+/// [`crate::output::write_boot_image`]) over the serial downloader, without loading or running
+/// code.
+///
+/// The boot ROM applies the DCD itself, so checks and Set / Clear writes behave exactly as during
+/// boot. Further steps (e.g. loading a flashloader into the memory set up by the DCD) can be
+/// appended to the script.
+///
+/// ```
+/// # use imxrt_dcd::script;
+/// assert_eq!(
+///     script::uuu("dcd.imx"),
+///     "uuu_version 1.0.1\n\n# Generated by imxrt-dcd; do not edit.\nSDP: dcd -f dcd.imx\n",
+/// );
+/// ```
+pub fn uuu(image_path: &str) -> String {
+    format!(
+        "uuu_version 1.0.1\n\n# Generated by imxrt-dcd; do not edit.\nSDP: dcd -f {}\n",
+        image_path
+    )
+}
+
+/// Returns an OpenOCD TCL script defining a procedure `proc_name` that replays the commands, e.g.
+/// to set up SDRAM in a `reset-init` event handler before loading code into it:
+///
+/// ```text
+/// source dcd.tcl
+/// $_TARGETNAME configure -event reset-init dcd_init
+/// ```
+///
+/// A check not satisfied within its poll count makes the procedure fail with an error naming the
+/// index of the check; the boot ROM would silently skip the rest of the DCD instead.
+///
+/// ```
+/// # use imxrt_dcd::{script, CheckCond, Command};
+/// let commands = [
+///     Command::set32(0x400F_C068, 0b11 << 4),
+///     Command::check32(CheckCond::AllSet, 0x400D_8000, 1 << 31).with_count(100),
+/// ];
+/// assert_eq!(
+///     script::openocd(&commands, "dcd_init"),
+///     "\
+/// ## Generated by imxrt-dcd; do not edit.
+///
+/// proc dcd_init {} {
+///     mww 0x400FC068 [expr {[mrw 0x400FC068] | 0x00000030}] ;# CCM+0x068
+///     set polls 0 ;# CCM_ANALOG+0x000
+///     while {([mrw 0x400D8000] & 0x80000000) != 0x80000000} {
+///         if {[incr polls] >= 100} { error \"DCD check #1 not satisfied after 100 polls\" }
+///     }
+/// }
+/// ",
+/// );
+/// ```
+pub fn openocd(commands: &[Command], proc_name: &str) -> String {
+    let ops = |width: Width| match width {
+        Width::B1 => ("mwb", "mrb", 4),
+        Width::B2 => ("mwh", "mrh", 6),
+        Width::B4 => ("mww", "mrw", 10),
+    };
+    let mut out = format!(
+        "# Generated by imxrt-dcd; do not edit.\n\nproc {} {{}} {{\n",
+        proc_name
+    );
+    for (index, command) in crate::expand_groups(commands) {
+        let (statement, address) = match &command {
+            Command::Nop => ("# nop".to_owned(), None),
+            Command::Unlock(_) => ("# HAB unlock, skipped".to_owned(), None),
+            Command::WriteGroup(_) => unreachable!("groups are expanded"),
+            Command::Write(write) => {
+                let (mw, mr, digits) = ops(write.width);
+                let value = format!("{:#0digits$X}", write.value & write.width.mask());
+                let read = format!("[{} {:#010X}]", mr, write.address);
+                let value = match write.op {
+                    WriteOp::Write => value,
+                    WriteOp::Set => format!("[expr {{{} | {}}}]", read, value),
+                    WriteOp::Clear => format!("[expr {{{} & ~{}}}]", read, value),
+                };
+                let statement = format!("{} {:#010X} {}", mw, write.address, value);
+                (statement, Some(write.address))
+            }
+            Command::Check(check) if check.count == Some(0) => {
+                ("# check with count=0, i.e. nop".to_owned(), None)
+            }
+            Command::Check(check) => {
+                let (_, mr, digits) = ops(check.width);
+                let mask = format!("{:#0digits$X}", check.mask & check.width.mask());
+                let masked = format!("([{} {:#010X}] & {})", mr, check.address, mask);
+                let unsatisfied = unsatisfied(check.cond, &masked, &mask);
+                let statement = match check.count {
+                    Some(count) => format!(
+                        "set polls 0\n    while {{{}}} {{\n        if {{[incr polls] >= {}}} {{ \
+                         error \"DCD check #{} not satisfied after {} polls\" }}\n    }}",
+                        unsatisfied, count, index, count
+                    ),
+                    None => format!("while {{{}}} {{}}", unsatisfied),
+                };
+                (statement, Some(check.address))
+            }
+        };
+        push_statement(&mut out, "    ", &statement, address, ";#");
+    }
+    out += "}\n";
+    out
+}
+
+/// Returns a J-Link script file (`.JLinkScript`) defining the function `fn_name` that replays the
+/// commands, for flows based on SEGGER J-Link.
+///
+/// J-Link Commander scripts have neither read-modify-write nor loops, so Set / Clear writes and
+/// checks need a J-Link script file instead, which J-Link Commander (`-JLinkScriptFile`) and
+/// IDEs using J-Link load alike. Name the function after the hook to run it from, e.g.
+/// `SetupTarget` (after connecting) or `AfterResetTarget`.
+///
+/// The function returns 0, or -1 after reporting the index of a check not satisfied within its
+/// poll count; the boot ROM would silently skip the rest of the DCD instead.
+///
+/// ```
+/// # use imxrt_dcd::{script, CheckCond, Command};
+/// let commands = [
+///     Command::set32(0x400F_C068, 0b11 << 4),
+///     Command::check32(CheckCond::AllSet, 0x400D_8000, 1 << 31).with_count(100),
+/// ];
+/// assert_eq!(
+///     script::jlink(&commands, "SetupTarget"),
+///     "\
+/// // Generated by imxrt-dcd; do not edit.
+///
+/// int SetupTarget(void) {
+///     U32 Polls;
+///
+///     JLINK_MEM_WriteU32(0x400FC068, JLINK_MEM_ReadU32(0x400FC068) | 0x00000030); // CCM+0x068
+///     Polls = 0; // CCM_ANALOG+0x000
+///     while ((JLINK_MEM_ReadU32(0x400D8000) & 0x80000000) != 0x80000000) {
+///         Polls = Polls + 1;
+///         if (Polls >= 100) {
+///             JLINK_SYS_Report(\"DCD check #1 not satisfied after 100 polls\");
+///             return -1;
+///         }
+///     }
+///     return 0;
+/// }
+/// ",
+/// );
+/// ```
+pub fn jlink(commands: &[Command], fn_name: &str) -> String {
+    let ops = |width: Width| match width {
+        Width::B1 => ("U8", 4),
+        Width::B2 => ("U16", 6),
+        Width::B4 => ("U32", 10),
+    };
+    let mut out = format!(
+        "// Generated by imxrt-dcd; do not edit.\n\nint {}(void) {{\n",
+        fn_name
+    );
+    let polls = commands
+        .iter()
+        .any(|command| matches!(command, Command::Check(check) if check.count.is_some()));
+    if polls {
+        out += "    U32 Polls;\n\n";
+    }
+    for (index, command) in crate::expand_groups(commands) {
+        let (statement, address) = match &command {
+            Command::Nop => ("// nop".to_owned(), None),
+            Command::Unlock(_) => ("// HAB unlock, skipped".to_owned(), None),
+            Command::WriteGroup(_) => unreachable!("groups are expanded"),
+            Command::Write(write) => {
+                let (ty, digits) = ops(write.width);
+                let value = format!("{:#0digits$X}", write.value & write.width.mask());
+                let read = format!("JLINK_MEM_Read{}({:#010X})", ty, write.address);
+                let value = match write.op {
+                    WriteOp::Write => value,
+                    WriteOp::Set => format!("{} | {}", read, value),
+                    WriteOp::Clear => format!("{} & ~{}", read, value),
+                };
+                let statement =
+                    format!("JLINK_MEM_Write{}({:#010X}, {});", ty, write.address, value);
+                (statement, Some(write.address))
+            }
+            Command::Check(check) if check.count == Some(0) => {
+                ("// check with count=0, i.e. nop".to_owned(), None)
+            }
+            Command::Check(check) => {
+                let (ty, digits) = ops(check.width);
+                let mask = format!("{:#0digits$X}", check.mask & check.width.mask());
+                let masked = format!("(JLINK_MEM_Read{}({:#010X}) & {})", ty, check.address, mask);
+                let unsatisfied = unsatisfied(check.cond, &masked, &mask);
+                let statement = match check.count {
+                    Some(count) => format!(
+                        "Polls = 0;\n    while ({}) {{\n        Polls = Polls + 1;\n        \
+                         if (Polls >= {}) {{\n            JLINK_SYS_Report(\"DCD check #{} not \
+                         satisfied after {} polls\");\n            return -1;\n        }}\n    }}",
+                        unsatisfied, count, index, count
+                    ),
+                    None => format!("while ({}) {{}}", unsatisfied),
+                };
+                (statement, Some(check.address))
+            }
+        };
+        push_statement(&mut out, "    ", &statement, address, "//");
+    }
+    out += "    return 0;\n}\n";
+    out
+}
+
+/// Returns a Lauterbach TRACE32 PRACTICE script (`.cmm`) replaying the commands, e.g. to run from
+/// the startup script of a debug session before loading code into external RAM.
+///
+/// A check not satisfied within its poll count ends the script with an error message naming the
+/// index of the check, and `ENDDO FALSE()` for calling scripts to test; the boot ROM would
+/// silently skip the rest of the DCD instead.
+///
+/// ```
+/// # use imxrt_dcd::{script, CheckCond, Command};
+/// let commands = [
+///     Command::set32(0x400F_C068, 0b11 << 4),
+///     Command::check32(CheckCond::AllSet, 0x400D_8000, 1 << 31).with_count(100),
+/// ];
+/// assert_eq!(
+///     script::lauterbach(&commands),
+///     "\
+/// ; Generated by imxrt-dcd; do not edit.
+/// LOCAL &polls
+///
+/// Data.Set A:0x400FC068 %Long Data.Long(A:0x400FC068)|0x00000030 ; CCM+0x068
+/// &polls=0. ; CCM_ANALOG+0x000
+/// WHILE (Data.Long(A:0x400D8000)&0x80000000)!=0x80000000
+/// (
+///     &polls=&polls+1.
+///     IF &polls>=100.
+///     (
+///         PRINT %ERROR \"DCD check #1 not satisfied after 100 polls\"
+///         ENDDO FALSE()
+///     )
+/// )
+/// ENDDO TRUE()
+/// ",
+/// );
+/// ```
+pub fn lauterbach(commands: &[Command]) -> String {
+    let ops = |width: Width| match width {
+        Width::B1 => ("Byte", 4),
+        Width::B2 => ("Word", 6),
+        Width::B4 => ("Long", 10),
+    };
+    let mut out = "; Generated by imxrt-dcd; do not edit.\nLOCAL &polls\n\n".to_owned();
+    for (index, command) in crate::expand_groups(commands) {
+        let (statement, address) = match &command {
+            Command::Nop => ("; nop".to_owned(), None),
+            Command::Unlock(_) => ("; HAB unlock, skipped".to_owned(), None),
+            Command::WriteGroup(_) => unreachable!("groups are expanded"),
+            Command::Write(write) => {
+                let (size, digits) = ops(write.width);
+                let value = format!("{:#0digits$X}", write.value & write.width.mask());
+                let read = format!("Data.{}(A:{:#010X})", size, write.address);
+                let value = match write.op {
+                    WriteOp::Write => value,
+                    WriteOp::Set => format!("{}|{}", read, value),
+                    WriteOp::Clear => format!("{}&~{}", read, value),
+                };
+                let statement = format!("Data.Set A:{:#010X} %{} {}", write.address, size, value);
+                (statement, Some(write.address))
+            }
+            Command::Check(check) if check.count == Some(0) => {
+                ("; check with count=0, i.e. nop".to_owned(), None)
+            }
+            Command::Check(check) => {
+                let (size, digits) = ops(check.width);
+                let mask = format!("{:#0digits$X}", check.mask & check.width.mask());
+                let masked = format!("(Data.{}(A:{:#010X})&{})", size, check.address, mask);
+                let unsatisfied = unsatisfied(check.cond, &masked, &mask).replace(' ', "");
+                let statement = match check.count {
+                    Some(count) => format!(
+                        "&polls=0.\nWHILE {}\n(\n    &polls=&polls+1.\n    IF &polls>={}.\n    \
+                         (\n        PRINT %ERROR \"DCD check #{} not satisfied after {} polls\"\n        \
+                         ENDDO FALSE()\n    )\n)",
+                        unsatisfied, count, index, count
+                    ),
+                    None => format!("WHILE {}\n(\n)", unsatisfied),
+                };
+                (statement, Some(check.address))
+            }
+        };
+        push_statement(&mut out, "", &statement, address, ";");
+    }
+    out += "ENDDO TRUE()\n";
+    out
+}
+
+/// Appends a statement (possibly multi-line, with `indent` before the first line) to a script, with
+/// the symbolized address as a comment on its first line.
+fn push_statement(
+    out: &mut String,
+    indent: &str,
+    statement: &str,
+    address: Option<u32>,
+    comment: &str,
+) {
+    let mut lines = statement.lines();
+    write!(out, "{}{}", indent, lines.next().unwrap()).unwrap();
+    match address {
+        Some(address) if symbols::peripheral(address).is_some() => {
+            write!(out, " {} {}", comment, symbols::symbolize(address)).unwrap()
+        }
+        _ => {}
+    }
+    out.push('\n');
+    for line in lines {
+        writeln!(out, "{}", line).unwrap();
+    }
+}
+
+/// Returns the condition of polling again for a check, given the expression of the masked value
+/// and the mask, in the C-like expression syntax of the debugger scripts.
+fn unsatisfied(cond: CheckCond, masked: &str, mask: &str) -> String {
+    match cond {
+        CheckCond::AllClear => format!("{} != 0", masked),
+        CheckCond::AnyClear => format!("{} == {}", masked, mask),
+        CheckCond::AllSet => format!("{} != {}", masked, mask),
+        CheckCond::AnySet => format!("{} == 0", masked),
+    }
+}
+
+#[cfg(all(test, feature = "imxrt1062"))]
+mod tests {
+    use super::*;
+    use crate::Write;
+
+    #[test]
+    fn openocd_widths() {
+        let commands = [
+            Command::Nop,
+            Command::Write(Write::new(Width::B1, WriteOp::Clear, 0x401F_8014, 0x1_07)),
+            Command::Write(Write::new(Width::B2, WriteOp::Write, 0x2000_0000, 0xABCD)),
+            Command::check32(CheckCond::AnyClear, 0x2000_0000, 3),
+            Command::check32(CheckCond::AllClear, 0x2000_0000, 3).with_count(0),
+        ];
+        assert_eq!(
+            openocd(&commands, "init_board"),
+            "\
+# Generated by imxrt-dcd; do not edit.
+
+proc init_board {} {
+    # nop
+    mwb 0x401F8014 [expr {[mrb 0x401F8014] & ~0x07}] ;# IOMUXC+0x014
+    mwh 0x20000000 0xABCD
+    while {([mrw 0x20000000] & 0x00000003) == 0x00000003} {}
+    # check with count=0, i.e. nop
+}
+"
+        );
+    }
+
+    #[test]
+    fn jlink_widths() {
+        let commands = [
+            Command::Write(Write::new(Width::B2, WriteOp::Clear, 0x401F_8014, 0x1_0007)),
+            Command::check32(CheckCond::AnySet, 0x2000_0000, 3),
+        ];
+        assert_eq!(
+            jlink(&commands, "AfterResetTarget"),
+            "\
+// Generated by imxrt-dcd; do not edit.
+
+int AfterResetTarget(void) {
+    JLINK_MEM_WriteU16(0x401F8014, JLINK_MEM_ReadU16(0x401F8014) & ~0x0007); // IOMUXC+0x014
+    while ((JLINK_MEM_ReadU32(0x20000000) & 0x00000003) == 0) {}
+    return 0;
+}
+"
+        );
+    }
+
+    #[test]
+    fn lauterbach_widths() {
+        let commands = [
+            Command::Write(Write::new(Width::B1, WriteOp::Clear, 0x401F_8014, 0x07)),
+            Command::check32(CheckCond::AllClear, 0x2000_0000, 3),
+            Command::Nop,
+        ];
+        assert_eq!(
+            lauterbach(&commands),
+            "\
+; Generated by imxrt-dcd; do not edit.
+LOCAL &polls
+
+Data.Set A:0x401F8014 %Byte Data.Byte(A:0x401F8014)&~0x07 ; IOMUXC+0x014
+WHILE (Data.Long(A:0x20000000)&0x00000003)!=0
+(
+)
+; nop
+ENDDO TRUE()
+"
+        );
+    }
+}
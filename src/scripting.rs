@@ -0,0 +1,280 @@
+//! Generating commands from [Rhai](https://rhai.rs) scripts, for configurations with loops,
+//! arithmetic on timing parameters or conditionals, without writing Rust.
+//!
+//! A script emits commands by calling functions named after the [text format](crate::text),
+//! taking the width in bits:
+//!
+//! | function                                 | command                                     |
+//! |------------------------------------------|---------------------------------------------|
+//! | `write(bits, address, value)`            | [`WriteOp::Write`]                          |
+//! | `set(bits, address, value)`              | [`WriteOp::Set`]                            |
+//! | `clear(bits, address, value)`            | [`WriteOp::Clear`]                          |
+//! | `write32(address, value)`, `set32`, ...  | the same with 32 bits                       |
+//! | `check(bits, cond, address, mask)`       | [`Check`], polling indefinitely             |
+//! | `check(bits, cond, address, mask, count)`| [`Check`] with a poll count                 |
+//! | `nop()`                                  | [`Command::Nop`]                            |
+//!
+//! Conditions are the [names](CheckCond::name) of [`CheckCond`], e.g. `"all_set"`. Parameters
+//! (e.g. from a build script) are passed in as constants:
+//!
+//! ```
+//! # use imxrt_dcd::{scripting::Script, CheckCond, Command};
+//! let commands = Script::new()
+//!     .constant("SEMC_CLOCK_MHZ", 166)
+//!     .eval(r#"
+//!         write32(0x400F_C068, 0xFFFF_FFFF);
+//!         // one register per chip select
+//!         for cs in 0..2 {
+//!             write32(0x402F_0010 + 4 * cs, 0x8000_001B + cs * 0x0100_0000);
+//!         }
+//!         // precharge time of 18 ns, in clock cycles
+//!         let cycles = (18 * SEMC_CLOCK_MHZ + 999) / 1000;
+//!         if cycles > 2 {
+//!             set32(0x402F_0044, cycles - 1);
+//!         }
+//!         check(32, "all_set", 0x402F_003C, 1, 100);
+//!     "#)
+//!     .unwrap();
+//! assert_eq!(commands.len(), 5);
+//! assert_eq!(commands[3], Command::set32(0x402F_0044, 2));
+//! assert_eq!(
+//!     commands[4],
+//!     Command::check32(CheckCond::AllSet, 0x402F_003C, 1).with_count(100),
+//! );
+//! ```
+//!
+//! Scripts run with limits on the number of operations and the depth of function calls (see
+//! [`Script::max_operations`]), so that a script that does not terminate fails instead of hanging
+//! the build.
+
+use std::cell::RefCell;
+use std::io;
+use std::rc::Rc;
+
+use rhai::{Dynamic, Engine, EvalAltResult, ImmutableString, Position, Scope, INT};
+
+use crate::{Check, CheckCond, Command, Width, Write, WriteOp};
+
+type FnResult = Result<(), Box<EvalAltResult>>;
+
+/// A script configuration: the constants passed to the script, and limits on its run time; see
+/// the [module-level docs](self).
+#[derive(Debug, Clone)]
+pub struct Script {
+    constants: Vec<(String, INT)>,
+    max_operations: u64,
+    max_call_levels: usize,
+}
+
+impl Default for Script {
+    fn default() -> Self {
+        Self {
+            constants: vec![],
+            max_operations: Self::DEFAULT_MAX_OPERATIONS,
+            max_call_levels: Self::DEFAULT_MAX_CALL_LEVELS,
+        }
+    }
+}
+
+impl Script {
+    /// Default of [`Script::max_operations`], plenty for configurations of a few thousand
+    /// commands.
+    pub const DEFAULT_MAX_OPERATIONS: u64 = 1_000_000;
+
+    /// Default of [`Script::max_call_levels`].
+    pub const DEFAULT_MAX_CALL_LEVELS: usize = 64;
+
+    /// Creates a configuration without constants, with the default limits.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Limits the number of operations the script may run, so that e.g. an endless loop fails
+    /// instead of hanging the build; see [`Script::DEFAULT_MAX_OPERATIONS`].
+    pub fn max_operations(mut self, max_operations: u64) -> Self {
+        self.max_operations = max_operations;
+        self
+    }
+
+    /// Limits the depth of function calls, e.g. of a runaway recursion; see
+    /// [`Script::DEFAULT_MAX_CALL_LEVELS`].
+    pub fn max_call_levels(mut self, max_call_levels: usize) -> Self {
+        self.max_call_levels = max_call_levels;
+        self
+    }
+
+    /// Defines a constant for the script, e.g. a timing parameter.
+    pub fn constant(mut self, name: &str, value: INT) -> Self {
+        self.constants.push((name.into(), value));
+        self
+    }
+
+    /// Runs the script and returns the commands it emits, in order.
+    ///
+    /// Returns [`io::ErrorKind::InvalidData`] with the position in the script if the script does
+    /// not parse, fails, exceeds a limit, or passes invalid arguments, e.g. an address that does
+    /// not fit in 32 bits or an unknown condition.
+    pub fn eval(&self, script: &str) -> io::Result<Vec<Command>> {
+        let commands = Rc::new(RefCell::new(vec![]));
+        let mut engine = Engine::new();
+        engine.set_max_operations(self.max_operations);
+        engine.set_max_call_levels(self.max_call_levels);
+        for (name, op) in [
+            ("write", WriteOp::Write),
+            ("set", WriteOp::Set),
+            ("clear", WriteOp::Clear),
+        ] {
+            let emit = commands.clone();
+            engine.register_fn(
+                name,
+                move |bits: INT, address: INT, value: INT| -> FnResult {
+                    let write = Write::new(width(bits)?, op, word(address)?, word(value)?);
+                    emit.borrow_mut().push(Command::Write(write));
+                    Ok(())
+                },
+            );
+            let emit = commands.clone();
+            engine.register_fn(
+                format!("{}32", name),
+                move |address: INT, value: INT| -> FnResult {
+                    let write = Write::new(Width::B4, op, word(address)?, word(value)?);
+                    emit.borrow_mut().push(Command::Write(write));
+                    Ok(())
+                },
+            );
+        }
+        let emit = commands.clone();
+        engine.register_fn(
+            "check",
+            move |bits: INT, cond: ImmutableString, address: INT, mask: INT| -> FnResult {
+                let check = Check::new(width(bits)?, cond_of(&cond)?, word(address)?, word(mask)?);
+                emit.borrow_mut().push(Command::Check(check));
+                Ok(())
+            },
+        );
+        let emit = commands.clone();
+        engine.register_fn(
+            "check",
+            move |bits: INT,
+                  cond: ImmutableString,
+                  address: INT,
+                  mask: INT,
+                  count: INT|
+                  -> FnResult {
+                let check = Check::new(width(bits)?, cond_of(&cond)?, word(address)?, word(mask)?)
+                    .with_count(word(count)?);
+                emit.borrow_mut().push(Command::Check(check));
+                Ok(())
+            },
+        );
+        let emit = commands.clone();
+        engine.register_fn("nop", move || emit.borrow_mut().push(Command::Nop));
+
+        let mut scope = Scope::new();
+        for (name, value) in &self.constants {
+            scope.push_constant(name.as_str(), *value);
+        }
+        engine
+            .run_with_scope(&mut scope, script)
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err.to_string()))?;
+        Ok(commands.take())
+    }
+}
+
+/// Same as `Script::new().eval(script)`; see [`Script::eval`].
+pub fn eval(script: &str) -> io::Result<Vec<Command>> {
+    Script::new().eval(script)
+}
+
+fn width(bits: INT) -> Result<Width, Box<EvalAltResult>> {
+    match bits {
+        8 => Ok(Width::B1),
+        16 => Ok(Width::B2),
+        32 => Ok(Width::B4),
+        _ => Err(error(format!(
+            "invalid width of {} bits, expected 8, 16 or 32",
+            bits
+        ))),
+    }
+}
+
+fn word(value: INT) -> Result<u32, Box<EvalAltResult>> {
+    u32::try_from(value).map_err(|_| error(format!("{} does not fit in 32 bits", value)))
+}
+
+fn cond_of(name: &str) -> Result<CheckCond, Box<EvalAltResult>> {
+    name.parse().map_err(|err| error(format!("{}", err)))
+}
+
+fn error(message: String) -> Box<EvalAltResult> {
+    EvalAltResult::ErrorRuntime(Dynamic::from(message), Position::NONE).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn commands() {
+        let commands = eval(
+            r#"
+            write(8, 0x401F_8014, 5);
+            set(16, 0x2000_0000, 1 << 15);
+            clear32(0x400F_C068, 0xC000_0000);
+            check(16, "any_clear", 0x2000_0000, 3);
+            nop();
+            "#,
+        )
+        .unwrap();
+        assert_eq!(
+            commands,
+            [
+                Command::Write(Write::new(Width::B1, WriteOp::Write, 0x401F_8014, 5)),
+                Command::Write(Write::new(Width::B2, WriteOp::Set, 0x2000_0000, 1 << 15)),
+                Command::clear32(0x400F_C068, 0xC000_0000),
+                Command::Check(Check::new(Width::B2, CheckCond::AnyClear, 0x2000_0000, 3)),
+                Command::Nop,
+            ]
+        );
+        assert!(eval("").unwrap().is_empty());
+    }
+
+    #[test]
+    fn errors() {
+        let error = |script: &str| {
+            let err = eval(script).unwrap_err();
+            assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+            err.to_string()
+        };
+        assert!(error("write(12, 0, 0);").contains("invalid width of 12 bits"));
+        assert!(error("write32(-1, 0);").contains("-1 does not fit in 32 bits"));
+        assert!(error("write32(0x1_0000_0000, 0);").contains("4294967296 does not fit"));
+        assert!(error(r#"check(32, "all", 0, 1);"#).contains("invalid check condition `all`"));
+        assert!(error("write32(0, 0)\nwrite32(0, 0);").contains("line 2"));
+        assert!(error("write64(0, 0);").contains("write64"));
+
+        let script = Script::new().constant("CLOCK", 1 << 40);
+        assert!(script.eval("write32(0, CLOCK);").is_err());
+        assert!(Script::new().eval("CLOCK = 1;").is_err());
+        let script = Script::new().constant("CLOCK", 1);
+        assert!(
+            script.eval("CLOCK = 2;").is_err(),
+            "constants are read-only"
+        );
+    }
+
+    #[test]
+    fn limits() {
+        let err = eval("loop {}").unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        assert!(err.to_string().contains("Too many operations"), "{}", err);
+        assert!(eval("fn f(n) { f(n + 1) } f(0);").is_err());
+
+        let script = "for i in 0..100 { nop(); }";
+        assert_eq!(eval(script).unwrap().len(), 100);
+        assert!(Script::new().max_operations(100).eval(script).is_err());
+        let recursion = "fn f(n) { if n > 0 { f(n - 1) } } f(10);";
+        assert!(Script::new().eval(recursion).is_ok());
+        assert!(Script::new().max_call_levels(5).eval(recursion).is_err());
+    }
+}
@@ -0,0 +1,502 @@
+//! Importing DCDs from the C sources of the NXP MCUXpresso SDK, and comparing them with commands.
+//!
+//! Board support packages of the SDK define their DCD as a byte array in `dcd.c`:
+//!
+//! ```c
+//! const uint8_t dcd_data[] = {
+//!     /* HEADER */
+//!     DCD_TAG_HEADER, 0x00, 0x0C, DCD_VERSION,
+//!     /* #1.1, command: write_value, address: CCM_CCGR0, value: 0xFFFFFFFF, size: 4 */
+//!     0xCC, 0x00, 0x0C, 0x04, 0x40, 0x0F, 0xC0, 0x68, 0xFF, 0xFF, 0xFF, 0xFF,
+//! };
+//! ```
+//!
+//! [`parse_c`] extracts the bytes of such an array, and [`compare_with_sdk`] checks whether a
+//! command list (e.g. a port of the SDK DCD to Rust) has the same effect as the SDK `dcd.c`.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+use std::io;
+use std::path::Path;
+
+use crate::effects::{self, ValueKnowledge};
+use crate::{deserialize, dump, golden, serialize, symbols, text, Check, Command, Write};
+
+/// Macros used by SDK `dcd.c` files (defined in `dcd.h`).
+const SDK_DEFINES: &[(&str, u32)] = &[("DCD_TAG_HEADER", 0xD2), ("DCD_VERSION", 0x41)];
+
+/// Extracts the bytes of the DCD byte array defined in C source code.
+///
+/// The array is the initializer of `dcd_data[]` if present, otherwise the first brace
+/// initializer. Elements may be integer literals (hex, decimal or octal, with `u` / `l` suffixes),
+/// optionally parenthesized, or names of object-like macros `#define`d in the source or by the
+/// SDK's `dcd.h`.
+///
+/// ```
+/// # use imxrt_dcd::sdk::parse_c;
+/// let source = "
+///     #define NOP_TAG (0xC0U)
+///     const uint8_t dcd_data[] = {
+///         DCD_TAG_HEADER, 0, 8, DCD_VERSION, // header
+///         NOP_TAG, 0x00, 0x04, 0x00,         /* nop */
+///     };
+/// ";
+/// assert_eq!(parse_c(source).unwrap(), [0xD2, 0, 8, 0x41, 0xC0, 0, 4, 0]);
+/// ```
+pub fn parse_c(source: &str) -> io::Result<Vec<u8>> {
+    let invalid = |message: String| io::Error::new(io::ErrorKind::InvalidData, message);
+    let source = strip_comments(source);
+
+    let mut defines: BTreeMap<&str, &str> = BTreeMap::new();
+    for line in source.lines() {
+        let mut tokens = line.trim().splitn(3, char::is_whitespace);
+        if let (Some("#define"), Some(name), Some(value)) =
+            (tokens.next(), tokens.next(), tokens.next())
+        {
+            defines.insert(name, value.trim());
+        }
+    }
+
+    let start = source.find("dcd_data[").unwrap_or(0);
+    let open = source[start..]
+        .find('{')
+        .map(|index| start + index + 1)
+        .ok_or_else(|| invalid("no array initializer found".into()))?;
+    let close = source[open..]
+        .find('}')
+        .map(|index| open + index)
+        .ok_or_else(|| invalid("unterminated array initializer".into()))?;
+
+    let mut bytes = vec![];
+    for element in source[open..close].split(',').map(str::trim) {
+        if element.is_empty() {
+            continue;
+        }
+        let value = evaluate(element, &defines, 0)
+            .ok_or_else(|| invalid(format!("unsupported array element `{}`", element)))?;
+        let byte = u8::try_from(value)
+            .map_err(|_| invalid(format!("array element `{}` is not a byte", element)))?;
+        bytes.push(byte);
+    }
+    Ok(bytes)
+}
+
+/// Replaces `/* */` and `//` comments with whitespace.
+fn strip_comments(source: &str) -> String {
+    let mut out = String::with_capacity(source.len());
+    let mut rest = source;
+    while let Some(index) = rest.find("/*").into_iter().chain(rest.find("//")).min() {
+        out.push_str(&rest[..index]);
+        let end = if rest[index..].starts_with("/*") {
+            rest[index..]
+                .find("*/")
+                .map_or(rest.len(), |end| index + end + 2)
+        } else {
+            rest[index..]
+                .find('\n')
+                .map_or(rest.len(), |end| index + end)
+        };
+        out.push(' ');
+        rest = &rest[end..];
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Evaluates an integer literal or macro name.
+fn evaluate(element: &str, defines: &BTreeMap<&str, &str>, depth: usize) -> Option<u32> {
+    let element = element.trim();
+    if let Some(inner) = element.strip_prefix('(').and_then(|e| e.strip_suffix(')')) {
+        return evaluate(inner, defines, depth);
+    }
+    if element.starts_with(|c: char| c.is_ascii_digit()) {
+        let digits = element.trim_end_matches(['u', 'U', 'l', 'L']);
+        return if let Some(hex) = digits.strip_prefix("0x").or(digits.strip_prefix("0X")) {
+            u32::from_str_radix(hex, 16).ok()
+        } else if digits.len() > 1 && digits.starts_with('0') {
+            u32::from_str_radix(&digits[1..], 8).ok()
+        } else {
+            digits.parse().ok()
+        };
+    }
+    // guard against recursive macros
+    if depth > 8 {
+        return None;
+    }
+    match defines.get(element) {
+        Some(value) => evaluate(value, defines, depth + 1),
+        None => SDK_DEFINES
+            .iter()
+            .find(|&&(name, _)| name == element)
+            .map(|&(_, value)| value),
+    }
+}
+
+/// Outcome of [`compare_with_sdk`].
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Comparison {
+    /// The commands serialize to exactly the SDK DCD.
+    Identical,
+    /// The commands differ, e.g. in order or merging, but perform the same checks in the same
+    /// order, and the writes between each pair of checks leave the same register values. Writes
+    /// to registers with side effects, e.g. triggering a SEMC IP command, must match one by one.
+    /// Contains a command-level diff.
+    SameEffect(String),
+    /// The commands have a different effect.
+    Different {
+        /// Human-readable differences in register values, by segment between checks, and in checks.
+        differences: Vec<String>,
+        /// Command-level diff.
+        diff: String,
+    },
+}
+
+impl fmt::Display for Comparison {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Comparison::Identical => writeln!(f, "identical to the SDK DCD"),
+            Comparison::SameEffect(diff) => {
+                writeln!(f, "same effect as the SDK DCD, but different commands")?;
+                f.write_str(diff)
+            }
+            Comparison::Different { differences, diff } => {
+                writeln!(f, "different effect than the SDK DCD:")?;
+                for difference in differences {
+                    writeln!(f, "- {}", difference)?;
+                }
+                f.write_str(diff)
+            }
+        }
+    }
+}
+
+/// The writes up to a check, or after the last check.
+#[derive(Default)]
+struct Segment<'a> {
+    /// Register values left by the writes, except for registers with side effects.
+    registers: BTreeMap<u32, ValueKnowledge>,
+    /// Writes to registers with side effects (see [`dump::diff`]), e.g. triggering a SEMC IP
+    /// command, by address in order.
+    side_effects: BTreeMap<u32, Vec<Write>>,
+    /// Check ending the segment, or `None` for the writes after the last check.
+    check: Option<&'a Check>,
+}
+
+/// Splits the commands into segments at the checks, such that a write is compared with the
+/// writes between the same checks, and repeated writes to registers with side effects count.
+fn segments(commands: &[Command]) -> Vec<Segment<'_>> {
+    let mut segments = vec![Segment::default()];
+    for (index, command) in commands.iter().enumerate() {
+        let segment = segments.last_mut().unwrap();
+        match command {
+            Command::Write(_) | Command::WriteGroup(_) => {
+                for write in command.writes() {
+                    let side_effect = symbols::peripheral(write.address)
+                        .and_then(|(name, offset)| dump::write_sensitive(name, offset));
+                    if side_effect.is_some() {
+                        segment
+                            .side_effects
+                            .entry(write.address)
+                            .or_default()
+                            .push(write);
+                    } else {
+                        effects::apply(&mut segment.registers, index, &Command::Write(write));
+                    }
+                }
+            }
+            Command::Check(check) => {
+                segment.check = Some(check);
+                segments.push(Segment::default());
+            }
+            Command::Nop | Command::Unlock(_) => {}
+        }
+    }
+    segments
+}
+
+/// Describes the final value of a register, e.g. `0x00000030 (bits 0x00000030)`.
+fn describe(register: Option<&ValueKnowledge>) -> String {
+    match register {
+        Some(knowledge) => match knowledge.value() {
+            Some(value) => format!("{:#010X}", value),
+            None => format!(
+                "{:#010X} (bits {:#010X}, {}-byte)",
+                knowledge.value, knowledge.known, knowledge.width as u8
+            ),
+        },
+        None => "not written".to_owned(),
+    }
+}
+
+/// Describes the writes to a register with side effects, e.g. `write 0xA55A000F, set 0x00000001`.
+fn describe_writes(writes: Option<&Vec<Write>>) -> String {
+    match writes {
+        Some(writes) => {
+            let writes: Vec<String> = writes
+                .iter()
+                .map(|write| format!("{} {:#010X}", write.op.name(), write.value))
+                .collect();
+            writes.join(", ")
+        }
+        None => "not written".to_owned(),
+    }
+}
+
+/// Compares commands against the DCD defined in an SDK `dcd.c` file (see [`parse_c`]).
+///
+/// Returns an error if the file cannot be read, or does not contain a valid DCD.
+///
+/// ```no_run
+/// # use imxrt_dcd::{sdk::{compare_with_sdk, Comparison}, Command};
+/// # let commands: Vec<Command> = vec![];
+/// match compare_with_sdk("evkmimxrt1060/xip/evkmimxrt1060_sdram_ini_dcd.c", &commands).unwrap() {
+///     Comparison::Identical | Comparison::SameEffect(_) => {}
+///     different => panic!("{}", different),
+/// }
+/// ```
+pub fn compare_with_sdk(path: impl AsRef<Path>, commands: &[Command]) -> io::Result<Comparison> {
+    let path = path.as_ref();
+    let source = std::fs::read_to_string(path)?;
+    let bytes = parse_c(&source)
+        .map_err(|err| io::Error::new(err.kind(), format!("{}: {}", path.display(), err)))?;
+    compare_bytes(&bytes, commands)
+}
+
+fn compare_bytes(sdk_bytes: &[u8], commands: &[Command]) -> io::Result<Comparison> {
+    let sdk = deserialize(sdk_bytes)?;
+    let mut ours = vec![];
+    serialize(&mut ours, commands)?;
+    if ours == sdk_bytes {
+        return Ok(Comparison::Identical);
+    }
+
+    let mut diff = String::new();
+    golden::render_diff(&mut diff, ("sdk", &sdk), ("ours", commands));
+
+    let (sdk_segments, our_segments) = (segments(&sdk), segments(commands));
+    let checks = sdk_segments.len().max(our_segments.len()) - 1;
+    let empty = Segment::default();
+    let mut differences = vec![];
+    for index in 0..=checks {
+        let sdk = sdk_segments.get(index).unwrap_or(&empty);
+        let ours = our_segments.get(index).unwrap_or(&empty);
+        let segment = if index < checks {
+            format!("before check #{}: ", index)
+        } else {
+            String::new()
+        };
+        let addresses: BTreeSet<u32> = sdk
+            .registers
+            .keys()
+            .chain(ours.registers.keys())
+            .copied()
+            .collect();
+        for address in addresses {
+            let (sdk_register, our_register) =
+                (sdk.registers.get(&address), ours.registers.get(&address));
+            let state = |register: Option<&ValueKnowledge>| {
+                register.map(|knowledge| (knowledge.width, knowledge.known, knowledge.value))
+            };
+            if state(sdk_register) != state(our_register) {
+                differences.push(format!(
+                    "{}{}: SDK {}, ours {}",
+                    segment,
+                    symbols::symbolize(address),
+                    describe(sdk_register),
+                    describe(our_register)
+                ));
+            }
+        }
+        let addresses: BTreeSet<u32> = sdk
+            .side_effects
+            .keys()
+            .chain(ours.side_effects.keys())
+            .copied()
+            .collect();
+        for address in addresses {
+            let (sdk_writes, our_writes) = (
+                sdk.side_effects.get(&address),
+                ours.side_effects.get(&address),
+            );
+            if sdk_writes != our_writes {
+                differences.push(format!(
+                    "{}{}: SDK {}, ours {}",
+                    segment,
+                    symbols::symbolize(address),
+                    describe_writes(sdk_writes),
+                    describe_writes(our_writes)
+                ));
+            }
+        }
+    }
+
+    let sdk_checks: Vec<&Check> = sdk_segments.iter().filter_map(|s| s.check).collect();
+    let our_checks: Vec<&Check> = our_segments.iter().filter_map(|s| s.check).collect();
+    if sdk_checks != our_checks {
+        let index = sdk_checks
+            .iter()
+            .zip(&our_checks)
+            .take_while(|(sdk, ours)| sdk == ours)
+            .count();
+        let render = |check: Option<&&Check>| {
+            check.map_or("no check".to_owned(), |check| {
+                text::render_command(&Command::Check((*check).clone()))
+            })
+        };
+        differences.push(format!(
+            "check #{} (of SDK {}, ours {}): SDK `{}`, ours `{}`",
+            index,
+            sdk_checks.len(),
+            our_checks.len(),
+            render(sdk_checks.get(index)),
+            render(our_checks.get(index))
+        ));
+    }
+
+    Ok(if differences.is_empty() {
+        Comparison::SameEffect(diff)
+    } else {
+        Comparison::Different { differences, diff }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::CheckCond;
+
+    /// Excerpt of an SDK `dcd.c` (with the header length adjusted).
+    const DCD_C: &str = r#"
+#include "dcd.h"
+
+#if defined(XIP_BOOT_HEADER_DCD_ENABLE) && (XIP_BOOT_HEADER_DCD_ENABLE == 1)
+__attribute__((section(".boot_hdr.dcd_data"), used))
+const uint8_t dcd_data[] = {
+    /* HEADER */
+    /* Tag */
+    DCD_TAG_HEADER,
+    /* Image Length */
+    0x00, 0x30,
+    /* Version */
+    DCD_VERSION,
+
+    /* COMMANDS */
+
+    /* group: 'Imported Commands' */
+    /* #1.1-2, command header bytes for merged 'Write - value' command */
+    0xCC, 0x00, 0x14, 0x04,
+    /* #1.1, command: write_value, address: CCM_CCGR0, value: 0xFFFFFFFF, size: 4 */
+    0x40, 0x0F, 0xC0, 0x68, 0xFF, 0xFF, 0xFF, 0xFF,
+    /* #1.2, command: write_value, address: CCM_CBCDR, value: 0x000D8340, size: 4 */
+    0x40, 0x0F, 0xC0, 0x14, 0x00, 0x0D, 0x83, 0x40,
+    /* #2, command: check_any_bit_set, address: SEMC_INTR, value: 0x01, size: 4 */
+    0xCF, 0x00, 0x0C, 0x1C, 0x40, 0x2F, 0x00, 0x3C, 0x00, 0x00, 0x00, 0x01,
+    /* #3, command: write_value, address: SEMC_IPCMD, value: 0xA55A000F, size: 4 */
+    0xCC, 0x00, 0x0C, 0x04, 0x40, 0x2F, 0x00, 0x9C, 0xA5, 0x5A, 0x00, 0x0F,
+};
+#else
+const uint8_t dcd_data[] = {0x00};
+#endif
+"#;
+
+    fn port() -> Vec<Command> {
+        vec![
+            Command::write32(0x400F_C068, 0xFFFF_FFFF),
+            Command::write32(0x400F_C014, 0x000D_8340),
+            Command::check32(CheckCond::AnySet, 0x402F_003C, 1),
+            Command::write32(0x402F_009C, 0xA55A_000F),
+        ]
+    }
+
+    #[test]
+    fn parse() {
+        let bytes = parse_c(DCD_C).unwrap();
+        assert_eq!(deserialize(&bytes).unwrap(), port());
+        assert!(parse_c("const uint8_t dcd_data[] = { 0x100 };")
+            .unwrap_err()
+            .to_string()
+            .contains("not a byte"));
+        assert!(parse_c("const uint8_t dcd_data[] = { 1 + 1 };")
+            .unwrap_err()
+            .to_string()
+            .contains("unsupported array element `1 + 1`"));
+        assert_eq!(parse_c("{ 010, 0XAu, 7UL }").unwrap(), [8, 10, 7]);
+    }
+
+    #[cfg(feature = "imxrt1062")]
+    #[test]
+    fn compare() {
+        let sdk = parse_c(DCD_C).unwrap();
+        assert_eq!(compare_bytes(&sdk, &port()).unwrap(), Comparison::Identical);
+
+        // the same final values, but split differently
+        let mut commands = port();
+        commands.splice(
+            0..1,
+            [
+                Command::write32(0x400F_C068, 0),
+                Command::set32(0x400F_C068, 0xFFFF_FFFF),
+            ],
+        );
+        assert!(matches!(
+            compare_bytes(&sdk, &commands).unwrap(),
+            Comparison::SameEffect(_)
+        ));
+
+        let mut commands = port();
+        commands[1] = Command::set32(0x400F_C014, 0x0001_0000);
+        commands[2] = Command::check32(CheckCond::AnySet, 0x402F_003C, 1).with_count(100);
+        commands.push(Command::write32(0x402F_0040, 0x3F));
+        let Comparison::Different { differences, diff } = compare_bytes(&sdk, &commands).unwrap()
+        else {
+            panic!("expected a different effect");
+        };
+        assert_eq!(
+            differences,
+            [
+                "before check #0: CCM+0x014: \
+                 SDK 0x000D8340, ours 0x00010000 (bits 0x00010000, 4-byte)",
+                "SEMC+0x040: SDK not written, ours 0x0000003F",
+                "check #0 (of SDK 1, ours 1): \
+                 SDK `check.32 any_set 0x402F003C 0x00000001  # SEMC+0x03C`, \
+                 ours `check.32 any_set 0x402F003C 0x00000001 count=100  # SEMC+0x03C`",
+            ]
+        );
+        assert!(diff.starts_with("--- sdk\n+++ ours\n"));
+    }
+
+    #[cfg(feature = "imxrt1062")]
+    #[test]
+    fn compare_order() {
+        let sdk = parse_c(DCD_C).unwrap();
+        let differences = |commands: &[Command]| match compare_bytes(&sdk, commands).unwrap() {
+            Comparison::Different { differences, .. } => differences,
+            comparison => panic!("expected a different effect, got {:?}", comparison),
+        };
+
+        // the same final values, but a write moved behind the check
+        let mut commands = port();
+        commands.swap(1, 2);
+        assert_eq!(
+            differences(&commands),
+            [
+                "before check #0: CCM+0x014: SDK 0x000D8340, ours not written",
+                "CCM+0x014: SDK not written, ours 0x000D8340",
+            ]
+        );
+
+        // a repeated IP command
+        let mut commands = port();
+        commands.push(Command::write32(0x402F_009C, 0xA55A_000F));
+        assert_eq!(
+            differences(&commands),
+            ["SEMC+0x09C: SDK write 0xA55A000F, ours write 0xA55A000F, write 0xA55A000F"]
+        );
+
+        // a truncated init
+        assert_eq!(
+            differences(&port()[..3]),
+            ["SEMC+0x09C: SDK write 0xA55A000F, ours not written"]
+        );
+    }
+}
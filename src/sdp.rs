@@ -0,0 +1,231 @@
+//! Host side of the Serial Download Protocol (SDP) of the boot ROM, to send a freshly built DCD to
+//! a board in serial downloader mode for live experimentation, without reflashing the boot image.
+//!
+//! [`write_dcd`] sends a `DCD_WRITE` command, which makes the boot ROM apply the DCD right away.
+//! The protocol runs over a [`Transport`]: [`Uart`] wraps any serial port implementing
+//! [`io::Read`] and [`io::Write`] (e.g. of the `serialport` crate), and with the `"sdp-hid"`
+//! feature, [`Hid`] wraps a USB HID device of `hidapi`:
+//!
+//! ```no_run
+//! # #[cfg(feature = "sdp-hid")]
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! # let commands = vec![];
+//! use imxrt_dcd::sdp::{write_dcd, Hid};
+//!
+//! // VID / PID of the RT1060 boot ROM
+//! let device = hidapi::HidApi::new()?.open(0x1FC9, 0x0135)?;
+//! write_dcd(&mut Hid(device), 0x2000_0000, &commands)?;
+//! # Ok(())
+//! # }
+//! # #[cfg(not(feature = "sdp-hid"))]
+//! # fn main() {}
+//! ```
+
+use std::io;
+
+use crate::{serialize, Command};
+
+/// `COMMAND_TYPE` of `DCD_WRITE`.
+const DCD_WRITE: u16 = 0x0A0A;
+/// Security configuration reported by the boot ROM before the status of a command.
+const HAB_CLOSED: u32 = 0x1234_3412;
+const HAB_OPEN: u32 = 0x5678_7856;
+/// Status of a successful `DCD_WRITE`.
+const WRITE_COMPLETE: u32 = 0x128A_8A12;
+/// Maximum length of a data report over HID.
+#[cfg(feature = "sdp-hid")]
+const HID_DATA_LEN: usize = 1024;
+
+/// A connection to the boot ROM carrying SDP packets.
+pub trait Transport {
+    /// Sends a 16-byte command packet.
+    fn send_command(&mut self, command: &[u8; 16]) -> io::Result<()>;
+    /// Sends the data following a command.
+    fn send_data(&mut self, data: &[u8]) -> io::Result<()>;
+    /// Receives a 4-byte response (security configuration or status).
+    fn read_response(&mut self) -> io::Result<u32>;
+}
+
+/// SDP over a UART: packets are sent and received as plain bytes.
+#[derive(Debug)]
+pub struct Uart<T>(pub T);
+
+impl<T: io::Read + io::Write> Transport for Uart<T> {
+    fn send_command(&mut self, command: &[u8; 16]) -> io::Result<()> {
+        self.0.write_all(command)?;
+        self.0.flush()
+    }
+
+    fn send_data(&mut self, data: &[u8]) -> io::Result<()> {
+        self.0.write_all(data)?;
+        self.0.flush()
+    }
+
+    fn read_response(&mut self) -> io::Result<u32> {
+        let mut response = [0; 4];
+        self.0.read_exact(&mut response)?;
+        Ok(u32::from_be_bytes(response))
+    }
+}
+
+/// SDP over USB HID: commands are sent as report 1, data as reports 2 of up to 1024 bytes, and
+/// responses are received as reports 3 and 4.
+#[cfg(feature = "sdp-hid")]
+pub struct Hid(pub hidapi::HidDevice);
+
+#[cfg(feature = "sdp-hid")]
+impl Transport for Hid {
+    fn send_command(&mut self, command: &[u8; 16]) -> io::Result<()> {
+        let mut report = vec![1];
+        report.extend_from_slice(command);
+        self.0.write(&report).map_err(io::Error::other)?;
+        Ok(())
+    }
+
+    fn send_data(&mut self, data: &[u8]) -> io::Result<()> {
+        for chunk in data.chunks(HID_DATA_LEN) {
+            let mut report = vec![2];
+            report.extend_from_slice(chunk);
+            self.0.write(&report).map_err(io::Error::other)?;
+        }
+        Ok(())
+    }
+
+    fn read_response(&mut self) -> io::Result<u32> {
+        let mut report = [0; 65];
+        let len = self
+            .0
+            .read_timeout(&mut report, 5_000)
+            .map_err(io::Error::other)?;
+        if len < 5 || !matches!(report[0], 3 | 4) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("unexpected HID report {:02X?}", &report[..len]),
+            ));
+        }
+        Ok(u32::from_be_bytes(report[1..5].try_into().unwrap()))
+    }
+}
+
+/// Sends `commands` as a DCD with `DCD_WRITE`, which the boot ROM copies to `address` and applies.
+///
+/// `address` must be RAM that the boot ROM does not use itself. Returns
+/// [`io::ErrorKind::InvalidData`] if the boot ROM responds with anything but success, and any
+/// error of the transport as is.
+///
+/// ```
+/// # use imxrt_dcd::{sdp::{write_dcd, Uart}, Command};
+/// # use std::io::{self, Read, Write};
+/// /// A serial port replaying the responses of the boot ROM.
+/// struct Port { sent: Vec<u8>, responses: io::Cursor<Vec<u8>> }
+/// impl Read for Port {
+///     fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> { self.responses.read(buf) }
+/// }
+/// impl Write for Port {
+///     fn write(&mut self, buf: &[u8]) -> io::Result<usize> { self.sent.write(buf) }
+///     fn flush(&mut self) -> io::Result<()> { Ok(()) }
+/// }
+///
+/// // HAB open, then write complete
+/// let responses = vec![0x56, 0x78, 0x78, 0x56, 0x12, 0x8A, 0x8A, 0x12];
+/// let mut port = Uart(Port { sent: vec![], responses: io::Cursor::new(responses) });
+/// write_dcd(&mut port, 0x2000_0000, &[Command::write32(0x400F_C068, 0xFFFF_FFFF)]).unwrap();
+/// assert_eq!(
+///     port.0.sent[..16],
+///     [0x0A, 0x0A, 0x20, 0, 0, 0, 0, 0, 0, 0, 0x10, 0, 0, 0, 0, 0],
+/// );
+/// assert_eq!(port.0.sent.len(), 16 + 16);
+/// ```
+pub fn write_dcd(
+    transport: &mut impl Transport,
+    address: u32,
+    commands: &[Command],
+) -> io::Result<()> {
+    let mut dcd = vec![];
+    serialize(&mut dcd, commands)?;
+    transport.send_command(&command(DCD_WRITE, address, dcd.len() as u32))?;
+    transport.send_data(&dcd)?;
+    let invalid = |message: String| io::Error::new(io::ErrorKind::InvalidData, message);
+    match transport.read_response()? {
+        HAB_OPEN | HAB_CLOSED => {}
+        response => {
+            return Err(invalid(format!(
+                "unexpected security configuration {:#010X}",
+                response
+            )))
+        }
+    }
+    match transport.read_response()? {
+        WRITE_COMPLETE => Ok(()),
+        status => Err(invalid(format!(
+            "DCD_WRITE failed with status {:#010X}",
+            status
+        ))),
+    }
+}
+
+/// Returns a command packet: type, address, format (unused), data count, data and a reserved byte,
+/// big-endian.
+fn command(kind: u16, address: u32, count: u32) -> [u8; 16] {
+    let mut packet = [0; 16];
+    packet[0..2].copy_from_slice(&kind.to_be_bytes());
+    packet[2..6].copy_from_slice(&address.to_be_bytes());
+    packet[7..11].copy_from_slice(&count.to_be_bytes());
+    packet
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Records what is sent, and replays responses.
+    struct Mock {
+        sent: Vec<Vec<u8>>,
+        responses: Vec<u32>,
+    }
+
+    impl Transport for Mock {
+        fn send_command(&mut self, command: &[u8; 16]) -> io::Result<()> {
+            self.sent.push(command.to_vec());
+            Ok(())
+        }
+
+        fn send_data(&mut self, data: &[u8]) -> io::Result<()> {
+            self.sent.push(data.to_vec());
+            Ok(())
+        }
+
+        fn read_response(&mut self) -> io::Result<u32> {
+            Ok(self.responses.remove(0))
+        }
+    }
+
+    #[test]
+    fn dcd_write() {
+        let commands = [
+            Command::write32(0x400F_C068, 0xFFFF_FFFF),
+            Command::write32(0x400F_C06C, 0xFFFF_FFFF),
+        ];
+        let mut mock = Mock {
+            sent: vec![],
+            responses: vec![HAB_CLOSED, WRITE_COMPLETE],
+        };
+        write_dcd(&mut mock, 0x2020_0000, &commands).unwrap();
+        let mut dcd = vec![];
+        serialize(&mut dcd, &commands).unwrap();
+        assert_eq!(
+            mock.sent,
+            [
+                vec![0x0A, 0x0A, 0x20, 0x20, 0, 0, 0, 0, 0, 0, 0x18, 0, 0, 0, 0, 0],
+                dcd
+            ]
+        );
+
+        let mut mock = Mock {
+            sent: vec![],
+            responses: vec![HAB_OPEN, 0x3355_5533],
+        };
+        let err = write_dcd(&mut mock, 0x2020_0000, &commands).unwrap_err();
+        assert_eq!(err.to_string(), "DCD_WRITE failed with status 0x33555533");
+    }
+}
@@ -0,0 +1,423 @@
+//! SDRAM setup through the Smart External Memory Controller (SEMC), from the parameters of the
+//! SDRAM datasheet instead of hand-computed register values.
+//!
+//! [`SdramConfig`] follows the `SEMC_ConfigureSDRAM` sequence of the MCUXpresso SDK: controller
+//! and chip select 0 setup, then the JEDEC initialization of the SDRAM through IP commands
+//! (precharge all, two auto refreshes, mode register set), and finally enabling the periodic
+//! refresh. Timings are given in ns, and converted to cycles of the SEMC clock:
+//!
+//! ```
+//! # use imxrt_dcd::{semc::SdramConfig, Command};
+//! let config = SdramConfig {
+//!     clock_hz: 133_000_000,
+//!     ..SdramConfig::default()
+//! };
+//! let commands = config.commands().unwrap();
+//! // SEMC_SDRAMCR0: 16 bit, burst length 8, 9 column bits, CAS latency 3
+//! assert!(commands.contains(&Command::write32(0x402F_0040, 0x0000_0F31)));
+//! assert_eq!(commands.last(), Some(&Command::set32(0x402F_004C, 1)));
+//! ```
+//!
+//! The SEMC clock itself (`CCM_CBCDR`) and its clock gate are not configured here. The register
+//! layout is that of the RT1050 / RT1060 / RT1064.
+//!
+//! NOTE: Each IP command is followed by a write of 1s clearing its done flag in `SEMC_INTR`, so
+//! that the check of the next command waits for that command. The [lints](crate::lint) do not
+//! know about write-1-to-clear bits, and report these checks as
+//! [`dead_check`](crate::lint::Lint::DeadCheck).
+
+use std::io;
+
+use crate::board::DcdFragment;
+use crate::{CheckCond, Command};
+
+const MCR_ADDRESS: u32 = 0x402F_0000;
+const BMCR0_ADDRESS: u32 = 0x402F_0008;
+const BMCR1_ADDRESS: u32 = 0x402F_000C;
+const BR0_ADDRESS: u32 = 0x402F_0010;
+const INTR_ADDRESS: u32 = 0x402F_003C;
+const SDRAMCR0_ADDRESS: u32 = 0x402F_0040;
+const SDRAMCR1_ADDRESS: u32 = 0x402F_0044;
+const SDRAMCR2_ADDRESS: u32 = 0x402F_0048;
+const SDRAMCR3_ADDRESS: u32 = 0x402F_004C;
+const IPCR0_ADDRESS: u32 = 0x402F_0090;
+const IPCR1_ADDRESS: u32 = 0x402F_0094;
+const IPCR2_ADDRESS: u32 = 0x402F_0098;
+const IPCMD_ADDRESS: u32 = 0x402F_009C;
+const IPTXDAT_ADDRESS: u32 = 0x402F_00A0;
+
+/// `SEMC_MCR`: bus timeout `BTO` of 0x10, and `DQSMD` (read strobe looped back from the DQS pad).
+const MCR_VALUE: u32 = 0x1000_0004;
+/// `SEMC_BMCR0` / `SEMC_BMCR1`: arbitration weights of the SDK defaults.
+const BMCR0_VALUE: u32 = 0x0003_0524;
+const BMCR1_VALUE: u32 = 0x0603_0524;
+/// `SEMC_INTR[IPCMDDONE]` and `SEMC_INTR[IPCMDERR]` (write 1 to clear).
+const INTR_IPCMDDONE: u32 = 1 << 0;
+const INTR_IPCMDERR: u32 = 1 << 1;
+/// `SEMC_SDRAMCR3[REN]`: enables the periodic refresh.
+const SDRAMCR3_REN: u32 = 1 << 0;
+/// `SEMC_SDRAMCR3[PRESCALE]`, setting the prescaler period to 160 clock cycles.
+const PRESCALE: u32 = 10;
+/// `SEMC_IPCMD[KEY]`, required for the command to be accepted.
+const IPCMD_KEY: u32 = 0xA55A << 16;
+/// SDRAM commands of `SEMC_IPCMD[CMD]`.
+const CMD_MODESET: u32 = 0xA;
+const CMD_AUTO_REFRESH: u32 = 0xC;
+const CMD_PRECHARGE_ALL: u32 = 0xF;
+
+/// Addresses of `IOMUXC_SW_MUX_CTL_PAD_GPIO_EMC_00` and `IOMUXC_SW_PAD_CTL_PAD_GPIO_EMC_00`; the
+/// registers of `GPIO_EMC_01` to `GPIO_EMC_41` follow.
+const EMC_MUX_ADDRESS: u32 = 0x401F_8014;
+const EMC_PAD_ADDRESS: u32 = 0x401F_8204;
+const EMC_PADS: u32 = 42;
+/// `GPIO_EMC_39` is `SEMC_DQS`, whose input must be forced on (`SION`) for the loopback.
+const EMC_DQS_PAD: u32 = 39;
+const MUX_SION: u32 = 1 << 4;
+/// Pad settings of the MIMXRT1060-EVK: hysteresis, keeper, 200 MHz, R0/7, fast slew rate.
+const EMC_PAD_VALUE: u32 = 0x0001_10F9;
+
+/// Timings of the SDRAM in ns, from its datasheet; see [`SdramConfig::timings`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct SdramTimings {
+    /// tRP: precharge to activate or refresh.
+    pub precharge_to_act_ns: u32,
+    /// tRCD: activate to read or write.
+    pub act_to_rw_ns: u32,
+    /// tRFC: refresh recovery.
+    pub refresh_recovery_ns: u32,
+    /// tWR: write recovery.
+    pub write_recovery_ns: u32,
+    /// Minimum time CKE is held low.
+    pub cke_off_ns: u32,
+    /// tRAS: activate to precharge.
+    pub act_to_precharge_ns: u32,
+    /// tXSR: self refresh recovery.
+    pub self_refresh_recovery_ns: u32,
+    /// tRC: refresh to refresh.
+    pub refresh_to_refresh_ns: u32,
+    /// tRRD: activate to activate of different banks.
+    pub act_to_act_ns: u32,
+}
+
+/// An SDRAM on chip select 0 of the SEMC; see the [module-level docs](self).
+///
+/// The default is the 32 MiB IS42S16160J-6 of the MIMXRT1060-EVK at a SEMC clock of 166 MHz.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct SdramConfig {
+    /// Frequency of the SEMC clock in Hz, which the timings are converted to cycles of.
+    pub clock_hz: u32,
+    /// Address the SDRAM is mapped to, aligned to its size.
+    pub base: u32,
+    /// Size in bytes, a power of two from 4 KiB to 2 GiB.
+    pub size: u32,
+    /// Width of the data bus in bits, 8 or 16.
+    pub port_bits: u8,
+    /// Burst length: 1, 2, 4 or 8.
+    pub burst_length: u8,
+    /// Number of column address bits, 8 to 12.
+    pub column_bits: u8,
+    /// CAS latency in cycles, 1 to 3.
+    pub cas_latency: u8,
+    pub timings: SdramTimings,
+    /// Maximum time between refreshes of a row in ns, i.e. the refresh period of the SDRAM
+    /// divided by its number of rows (e.g. 64 ms / 8192).
+    pub refresh_period_ns: u32,
+    /// Number of rows refreshed at a time, 1 to 8.
+    pub refresh_burst: u8,
+    /// Muxes the pads `GPIO_EMC_00` to `GPIO_EMC_41` to the SEMC, with the pad settings of the
+    /// MIMXRT1060-EVK.
+    pub pads: bool,
+    /// Poll count of the checks waiting for the IP commands; `None` polls indefinitely.
+    pub poll_count: Option<u32>,
+}
+
+impl Default for SdramConfig {
+    fn default() -> Self {
+        Self {
+            clock_hz: 166_000_000,
+            base: 0x8000_0000,
+            size: 32 << 20,
+            port_bits: 16,
+            burst_length: 8,
+            column_bits: 9,
+            cas_latency: 3,
+            timings: SdramTimings {
+                precharge_to_act_ns: 18,
+                act_to_rw_ns: 18,
+                refresh_recovery_ns: 67,
+                write_recovery_ns: 12,
+                cke_off_ns: 42,
+                act_to_precharge_ns: 42,
+                self_refresh_recovery_ns: 67,
+                refresh_to_refresh_ns: 60,
+                act_to_act_ns: 60,
+            },
+            refresh_period_ns: 64_000_000 / 8192,
+            refresh_burst: 1,
+            pads: true,
+            poll_count: None,
+        }
+    }
+}
+
+impl SdramConfig {
+    /// Returns the commands setting up the SEMC and initializing the SDRAM.
+    ///
+    /// Returns [`io::ErrorKind::InvalidInput`] if a parameter is out of range, or a timing does
+    /// not fit its register field at the given clock.
+    pub fn commands(&self) -> io::Result<Vec<Command>> {
+        let mut commands = vec![];
+        if self.pads {
+            for pad in 0..EMC_PADS {
+                let mux = if pad == EMC_DQS_PAD { MUX_SION } else { 0 };
+                commands.push(Command::write32(EMC_MUX_ADDRESS + 4 * pad, mux));
+            }
+            for pad in 0..EMC_PADS {
+                commands.push(Command::write32(EMC_PAD_ADDRESS + 4 * pad, EMC_PAD_VALUE));
+            }
+        }
+        commands.extend([
+            Command::write32(MCR_ADDRESS, MCR_VALUE),
+            Command::write32(BMCR0_ADDRESS, BMCR0_VALUE),
+            Command::write32(BMCR1_ADDRESS, BMCR1_VALUE),
+            Command::write32(BR0_ADDRESS, self.br0()?),
+            Command::write32(SDRAMCR0_ADDRESS, self.sdramcr0()?),
+            Command::write32(SDRAMCR1_ADDRESS, self.sdramcr1()?),
+            Command::write32(SDRAMCR2_ADDRESS, self.sdramcr2()?),
+            Command::write32(SDRAMCR3_ADDRESS, self.sdramcr3()?),
+            Command::write32(IPCR1_ADDRESS, self.port_bits as u32 / 8),
+            Command::write32(IPCR2_ADDRESS, 0),
+        ]);
+        let mode = burst_length_code(self.burst_length)? | (self.cas_latency as u32) << 4;
+        for (command, data) in [
+            (CMD_PRECHARGE_ALL, None),
+            (CMD_AUTO_REFRESH, None),
+            (CMD_AUTO_REFRESH, None),
+            (CMD_MODESET, Some(mode)),
+        ] {
+            commands.push(Command::write32(IPCR0_ADDRESS, self.base));
+            if let Some(data) = data {
+                commands.push(Command::write32(IPTXDAT_ADDRESS, data));
+            }
+            commands.push(Command::write32(IPCMD_ADDRESS, IPCMD_KEY | command));
+            let check = Command::check32(CheckCond::AnySet, INTR_ADDRESS, INTR_IPCMDDONE);
+            commands.push(match self.poll_count {
+                Some(count) => check.with_count(count),
+                None => check,
+            });
+            commands.push(Command::write32(
+                INTR_ADDRESS,
+                INTR_IPCMDDONE | INTR_IPCMDERR,
+            ));
+        }
+        commands.push(Command::set32(SDRAMCR3_ADDRESS, SDRAMCR3_REN));
+        Ok(commands)
+    }
+
+    /// `SEMC_BR0`: base address, memory size and valid.
+    // `u32::is_multiple_of` requires Rust 1.87
+    #[allow(clippy::manual_is_multiple_of)]
+    fn br0(&self) -> io::Result<u32> {
+        if !self.size.is_power_of_two() || self.size < 4 << 10 {
+            return Err(invalid(format!(
+                "SDRAM size {:#X} is not a power of two of at least 4 KiB",
+                self.size
+            )));
+        }
+        if self.base % self.size != 0 {
+            return Err(invalid(format!(
+                "SDRAM base address {:#010X} is not aligned to its size {:#X}",
+                self.base, self.size
+            )));
+        }
+        let ms = self.size.trailing_zeros() - 12;
+        Ok(self.base | ms << 1 | 1)
+    }
+
+    /// `SEMC_SDRAMCR0`: port size, burst length, column address bits and CAS latency.
+    fn sdramcr0(&self) -> io::Result<u32> {
+        let ps = match self.port_bits {
+            8 => 0,
+            16 => 1,
+            bits => return Err(invalid(format!("SDRAM port size of {} bits", bits))),
+        };
+        let col = match self.column_bits {
+            // COL8
+            8 => 1 << 7,
+            9..=12 => (12 - self.column_bits as u32) << 8,
+            bits => return Err(invalid(format!("SDRAM with {} column address bits", bits))),
+        };
+        if !(1..=3).contains(&self.cas_latency) {
+            return Err(invalid(format!(
+                "SDRAM CAS latency of {} cycles",
+                self.cas_latency
+            )));
+        }
+        let bl = burst_length_code(self.burst_length)?;
+        Ok(ps | bl << 4 | col | (self.cas_latency as u32) << 10)
+    }
+
+    /// `SEMC_SDRAMCR1`.
+    fn sdramcr1(&self) -> io::Result<u32> {
+        let t = &self.timings;
+        Ok(self.cycles(t.precharge_to_act_ns, "PRE2ACT", 4)?
+            | self.cycles(t.act_to_rw_ns, "ACT2RW", 4)? << 4
+            | self.cycles(t.refresh_recovery_ns, "RFRC", 5)? << 8
+            | self.cycles(t.write_recovery_ns, "WRC", 3)? << 13
+            | self.cycles(t.cke_off_ns, "CKEOFF", 4)? << 16
+            | self.cycles(t.act_to_precharge_ns, "ACT2PRE", 4)? << 20)
+    }
+
+    /// `SEMC_SDRAMCR2`, without idle timeout.
+    fn sdramcr2(&self) -> io::Result<u32> {
+        let t = &self.timings;
+        Ok(self.cycles(t.self_refresh_recovery_ns, "SRRC", 8)?
+            | self.cycles(t.refresh_to_refresh_ns, "REF2REF", 8)? << 8
+            | self.cycles(t.act_to_act_ns, "ACT2ACT", 8)? << 16)
+    }
+
+    /// `SEMC_SDRAMCR3` with the refresh disabled: refresh burst, prescaler, refresh timer, and the
+    /// urgent threshold at one prescaler period after the refresh timer.
+    fn sdramcr3(&self) -> io::Result<u32> {
+        if !(1..=8).contains(&self.refresh_burst) {
+            return Err(invalid(format!(
+                "SDRAM refresh burst of {} rows",
+                self.refresh_burst
+            )));
+        }
+        let period_cycles =
+            self.refresh_period_ns as u64 * self.refresh_burst as u64 * self.clock_hz as u64
+                / 1_000_000_000;
+        // RT + 1 and UT, in prescaler periods of 16 * PRESCALE cycles
+        let periods = period_cycles / (16 * PRESCALE as u64);
+        if !(1..=0xFF).contains(&periods) {
+            return Err(invalid(format!(
+                "SDRAM refresh period of {} ns does not fit SEMC_SDRAMCR3[RT]",
+                self.refresh_period_ns
+            )));
+        }
+        let periods = periods as u32;
+        Ok((self.refresh_burst as u32 - 1) << 1
+            | PRESCALE << 8
+            | (periods - 1) << 16
+            | periods << 24)
+    }
+
+    /// Converts a timing to the value of a field holding the number of cycles minus 1.
+    fn cycles(&self, ns: u32, field: &str, width: u32) -> io::Result<u32> {
+        let cycles = (ns as u64 * self.clock_hz as u64)
+            .div_ceil(1_000_000_000)
+            .max(1);
+        if cycles > 1 << width {
+            return Err(invalid(format!(
+                "SDRAM timing of {} ns is {} cycles at {} Hz, more than the {} of {}",
+                ns,
+                cycles,
+                self.clock_hz,
+                1 << width,
+                field
+            )));
+        }
+        Ok(cycles as u32 - 1)
+    }
+}
+
+impl DcdFragment for SdramConfig {
+    fn name(&self) -> &str {
+        "sdram"
+    }
+
+    /// # Panics
+    ///
+    /// Panics if the configuration is invalid (see [`SdramConfig::commands`]).
+    fn commands(&self) -> Vec<Command> {
+        SdramConfig::commands(self).unwrap()
+    }
+}
+
+/// Returns the burst length as encoded in `SEMC_SDRAMCR0[BL]` and the SDRAM mode register.
+fn burst_length_code(burst_length: u8) -> io::Result<u32> {
+    match burst_length {
+        1 | 2 | 4 | 8 => Ok(burst_length.trailing_zeros()),
+        _ => Err(invalid(format!("SDRAM burst length of {}", burst_length))),
+    }
+}
+
+fn invalid(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidInput, message)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn registers() {
+        let commands = SdramConfig {
+            pads: false,
+            poll_count: Some(1000),
+            ..SdramConfig::default()
+        }
+        .commands()
+        .unwrap();
+        let done = Command::check32(CheckCond::AnySet, 0x402F_003C, 1).with_count(1000);
+        let clear = Command::write32(0x402F_003C, 3);
+        assert_eq!(
+            commands,
+            [
+                Command::write32(0x402F_0000, 0x1000_0004),
+                Command::write32(0x402F_0008, 0x0003_0524),
+                Command::write32(0x402F_000C, 0x0603_0524),
+                Command::write32(0x402F_0010, 0x8000_001B),
+                Command::write32(0x402F_0040, 0x0000_0F31),
+                Command::write32(0x402F_0044, 0x0066_2B22),
+                Command::write32(0x402F_0048, 0x0009_090B),
+                Command::write32(0x402F_004C, 0x0807_0A00),
+                Command::write32(0x402F_0094, 2),
+                Command::write32(0x402F_0098, 0),
+                Command::write32(0x402F_0090, 0x8000_0000),
+                Command::write32(0x402F_009C, 0xA55A_000F),
+                done.clone(),
+                clear.clone(),
+                Command::write32(0x402F_0090, 0x8000_0000),
+                Command::write32(0x402F_009C, 0xA55A_000C),
+                done.clone(),
+                clear.clone(),
+                Command::write32(0x402F_0090, 0x8000_0000),
+                Command::write32(0x402F_009C, 0xA55A_000C),
+                done.clone(),
+                clear.clone(),
+                Command::write32(0x402F_0090, 0x8000_0000),
+                Command::write32(0x402F_00A0, 0x33),
+                Command::write32(0x402F_009C, 0xA55A_000A),
+                done,
+                clear,
+                Command::set32(0x402F_004C, 1),
+            ]
+        );
+        let pads = SdramConfig::default().commands().unwrap();
+        assert_eq!(pads.len(), commands.len() + 84);
+        assert_eq!(pads[39], Command::write32(0x401F_80B0, 0x10));
+        assert_eq!(pads[83], Command::write32(0x401F_82A8, 0x0001_10F9));
+
+        let message = |config: SdramConfig| config.commands().unwrap_err().to_string();
+        assert_eq!(
+            message(SdramConfig {
+                base: 0x8100_0000,
+                ..SdramConfig::default()
+            }),
+            "SDRAM base address 0x81000000 is not aligned to its size 0x2000000"
+        );
+        assert_eq!(
+            message(SdramConfig {
+                clock_hz: 200_000_000,
+                timings: SdramTimings {
+                    write_recovery_ns: 45,
+                    ..SdramConfig::default().timings
+                },
+                ..SdramConfig::default()
+            }),
+            "SDRAM timing of 45 ns is 9 cycles at 200000000 Hz, more than the 8 of WRC"
+        );
+    }
+}
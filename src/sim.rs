@@ -0,0 +1,470 @@
+//! Simulating how the boot ROM applies a DCD to memory, e.g. to test which checks pass for a given
+//! initial register state.
+//!
+//! Memory that was neither preloaded nor written reads as zero. Since real registers rarely reset
+//! to zero, preload the reset values of the registers the DCD modifies or checks (from the
+//! reference manual) for Set / Clear writes and checks to behave as on the device. The simulated
+//! memory does not change by itself, so a check that is not satisfied right away never is.
+//!
+//! ```
+//! # use imxrt_dcd::{sim::{Outcome, Simulator}, CheckCond, Command, Width};
+//! // CCM_CCGR0, reset value 0xC0C00FFF
+//! let mut sim = Simulator::with_reset_values([(0x400F_C068, 0xC0C0_0FFF)]);
+//! let commands = [
+//!     Command::clear32(0x400F_C068, 0xC000_0000),
+//!     Command::check32(CheckCond::AllSet, 0x400F_C068, 0x00C0_0000).with_count(10),
+//!     Command::check32(CheckCond::AllSet, 0x400F_C068, 0xC000_0000).with_count(10),
+//!     Command::write32(0x400F_C06C, 0xFFFF_FFFF),
+//! ];
+//! assert_eq!(sim.run(&commands), Outcome::Aborted { index: 2 });
+//! assert_eq!(sim.read(0x400F_C068, Width::B4), 0x00C0_0FFF);
+//! assert_eq!(sim.read(0x400F_C06C, Width::B4), 0);
+//! ```
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write as _;
+use std::io;
+
+use crate::{symbols, Check, CheckCond, Command, Width, WriteOp};
+
+/// How applying a DCD ended.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Outcome {
+    /// All commands were applied.
+    Completed,
+    /// The check at this index was not satisfied within its poll count, so the boot ROM abandoned
+    /// the rest of the DCD.
+    Aborted {
+        /// Index of the check in the command list.
+        index: usize,
+    },
+    /// The check at this index polls indefinitely and is not satisfied, so the boot ROM hangs.
+    Hung {
+        /// Index of the check in the command list.
+        index: usize,
+    },
+}
+
+/// Byte-addressed (little-endian) memory the commands are applied to.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Simulator {
+    memory: BTreeMap<u32, u8>,
+}
+
+impl Simulator {
+    /// Creates a simulator with all memory reading as zero.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a simulator with 32-bit registers preloaded with the given `(address, value)`
+    /// pairs, e.g. reset values.
+    pub fn with_reset_values(values: impl IntoIterator<Item = (u32, u32)>) -> Self {
+        let mut sim = Self::new();
+        for (address, value) in values {
+            sim.write(address, Width::B4, value);
+        }
+        sim
+    }
+
+    /// Reads `width` bytes at `address`.
+    pub fn read(&self, address: u32, width: Width) -> u32 {
+        (0..width as u32).rev().fold(0, |value, offset| {
+            let byte = self.memory.get(&address.wrapping_add(offset));
+            value << 8 | byte.copied().unwrap_or(0) as u32
+        })
+    }
+
+    /// Writes the low `width` bytes of `value` at `address`, e.g. to preload a register.
+    pub fn write(&mut self, address: u32, width: Width, value: u32) {
+        for (offset, byte) in value.to_le_bytes()[..width as usize].iter().enumerate() {
+            self.memory
+                .insert(address.wrapping_add(offset as u32), *byte);
+        }
+    }
+
+    /// Applies commands in order, like the boot ROM.
+    pub fn run(&mut self, commands: &[Command]) -> Outcome {
+        self.trace(commands).0
+    }
+
+    /// Applies commands in order like [`Simulator::run`], and additionally returns the writes in
+    /// order, e.g. for [`write_vcd`].
+    pub fn trace(&mut self, commands: &[Command]) -> (Outcome, Vec<Change>) {
+        let mut changes = vec![];
+        for (index, command) in commands.iter().enumerate() {
+            match command {
+                Command::Nop | Command::Unlock(_) => {}
+                Command::Write(_) | Command::WriteGroup(_) => {
+                    for write in command.writes() {
+                        let old = self.read(write.address, write.width);
+                        let new = match write.op {
+                            WriteOp::Write => write.value,
+                            WriteOp::Set => old | write.value,
+                            WriteOp::Clear => old & !write.value,
+                        };
+                        self.write(write.address, write.width, new);
+                        changes.push(Change {
+                            index,
+                            address: write.address,
+                            width: write.width,
+                            old,
+                            new: self.read(write.address, write.width),
+                        });
+                    }
+                }
+                Command::Check(check) => match check.count {
+                    Some(0) => {}
+                    _ if self.satisfies(check) => {}
+                    Some(_) => return (Outcome::Aborted { index }, changes),
+                    None => return (Outcome::Hung { index }, changes),
+                },
+            }
+        }
+        (Outcome::Completed, changes)
+    }
+
+    /// Returns whether the check is satisfied by the current memory contents.
+    pub fn satisfies(&self, check: &Check) -> bool {
+        satisfied(check, self.read(check.address, check.width))
+    }
+
+    /// Runs the commands `trials` times, each time with random initial values for the memory the
+    /// commands access and that was not preloaded, to find out whether the outcome secretly depends
+    /// on it reading as zero. Does not change `self`.
+    ///
+    /// Returns the outcome if every trial ends like a [run](Simulator::run) without randomization,
+    /// with the same final values of the registers written. Otherwise, returns the first trial that
+    /// did not, e.g. because a check tests bits that no command writes, or a Set / Clear write
+    /// keeps bits whose reset value should be preloaded. The same `seed` gives the same trials.
+    ///
+    /// ```
+    /// # use imxrt_dcd::{sim::{Outcome, Simulator}, CheckCond, Command};
+    /// let commands = [
+    ///     Command::write32(0x400D_8100, 0x2000),
+    ///     Command::check32(CheckCond::AllSet, 0x400D_8100, 0x2000).with_count(10),
+    /// ];
+    /// assert_eq!(Simulator::new().run_randomized(&commands, 100, 0), Ok(Outcome::Completed));
+    ///
+    /// // relies on bit 0 of the register reading as zero
+    /// let commands = [Command::check32(CheckCond::AllClear, 0x400D_8100, 1).with_count(10)];
+    /// let divergence = Simulator::new().run_randomized(&commands, 100, 0).unwrap_err();
+    /// assert_eq!(divergence.outcome, Outcome::Aborted { index: 0 });
+    /// assert_eq!(divergence.initial.read(0x400D_8100, imxrt_dcd::Width::B1) & 1, 1);
+    /// ```
+    pub fn run_randomized(
+        &self,
+        commands: &[Command],
+        trials: usize,
+        seed: u64,
+    ) -> Result<Outcome, Divergence> {
+        let mut expected_sim = self.clone();
+        let (expected, changes) = expected_sim.trace(commands);
+        let written: BTreeSet<(u32, u8)> = changes
+            .iter()
+            .map(|change| (change.address, change.width as u8))
+            .collect();
+        let unknown: BTreeSet<u32> = commands
+            .iter()
+            .flat_map(|command| command.accessed())
+            .flat_map(|(address, width)| {
+                (0..width as u32).map(move |offset| address.wrapping_add(offset))
+            })
+            .filter(|address| !self.memory.contains_key(address))
+            .collect();
+        let mut state = seed;
+        for trial in 0..trials {
+            let mut initial = self.clone();
+            for &address in &unknown {
+                initial.memory.insert(address, splitmix64(&mut state) as u8);
+            }
+            let mut sim = initial.clone();
+            let outcome = sim.run(commands);
+            let mut addresses = vec![];
+            if outcome == expected {
+                for &(address, bytes) in &written {
+                    let width = Width::from_num_bytes(bytes as usize);
+                    if sim.read(address, width) != expected_sim.read(address, width) {
+                        addresses.push(address);
+                    }
+                }
+                addresses.dedup();
+                if addresses.is_empty() {
+                    continue;
+                }
+            }
+            return Err(Divergence {
+                trial,
+                initial,
+                expected,
+                outcome,
+                addresses,
+            });
+        }
+        Ok(expected)
+    }
+}
+
+/// A trial of [`Simulator::run_randomized`] that ended differently from the run without
+/// randomization.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Divergence {
+    /// Number of the trial, from 0.
+    pub trial: usize,
+    /// Memory at the start of the trial; running the commands on a clone of it repeats the trial.
+    pub initial: Simulator,
+    /// Outcome without randomization.
+    pub expected: Outcome,
+    /// Outcome of the trial.
+    pub outcome: Outcome,
+    /// Addresses of the registers whose final value differs from the run without randomization,
+    /// if the outcomes are the same.
+    pub addresses: Vec<u32>,
+}
+
+/// Returns the next number of the SplitMix64 sequence, which is good enough for picking register
+/// values and needs no dependency.
+fn splitmix64(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+/// Returns whether the check is satisfied by the value read from its address.
+pub(crate) fn satisfied(check: &Check, value: u32) -> bool {
+    let masked = value & check.mask;
+    match check.cond {
+        CheckCond::AllClear => masked == 0,
+        CheckCond::AnyClear => masked != check.mask,
+        CheckCond::AllSet => masked == check.mask,
+        CheckCond::AnySet => masked != 0,
+    }
+}
+
+/// A write applied by [`Simulator::trace`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Change {
+    /// Index of the write command in the command list.
+    pub index: usize,
+    /// Address written to.
+    pub address: u32,
+    /// Width of the write.
+    pub width: Width,
+    /// Value before the write.
+    pub old: u32,
+    /// Value after the write.
+    pub new: u32,
+}
+
+/// Writes the register changes of [`Simulator::trace`] as a Value Change Dump, e.g. to view the
+/// boot configuration in a waveform viewer such as GTKWave, next to logic analyzer captures.
+///
+/// Each register written is a signal named after its address (e.g. `CCM+0x068`), with its value
+/// before its first write dumped at time 0; a write by command `#i` shows at time `i + 1`. Writes
+/// of different widths to the same address are separate signals, which do not reflect each
+/// other's writes.
+///
+/// ```
+/// # use imxrt_dcd::{sim::{write_vcd, Simulator}, Command};
+/// let mut sim = Simulator::with_reset_values([(0x400F_C068, 0xC0C0_0FFF)]);
+/// let (_, changes) = sim.trace(&[Command::Nop, Command::set32(0x400F_C068, 0xC000_0000)]);
+/// let mut vcd = vec![];
+/// write_vcd(&mut vcd, &changes).unwrap();
+/// let vcd = String::from_utf8(vcd).unwrap();
+/// assert!(vcd.contains("$var reg 32 ! CCM+0x068 $end"));
+/// assert!(vcd.ends_with("#2\nb11000000110000000000111111111111 !\n"));
+/// ```
+pub fn write_vcd(mut w: impl io::Write, changes: &[Change]) -> io::Result<()> {
+    // (address, width) => (identifier code, initial value), ordered by address
+    let mut signals = BTreeMap::new();
+    for change in changes {
+        let count = signals.len();
+        signals
+            .entry((change.address, change.width as u8))
+            .or_insert_with(|| (identifier(count), change.old));
+    }
+    let mut vcd = String::new();
+    vcd += "$comment imxrt-dcd simulation; one time unit per command $end\n";
+    vcd += "$timescale 1 us $end\n$scope module dcd $end\n";
+    for (&(address, bytes), (id, _)) in &signals {
+        let name = symbols::symbolize(address);
+        writeln!(vcd, "$var reg {} {} {} $end", 8 * bytes, id, name).unwrap();
+    }
+    vcd += "$upscope $end\n$enddefinitions $end\n#0\n$dumpvars\n";
+    for (id, initial) in signals.values() {
+        writeln!(vcd, "b{:b} {}", initial, id).unwrap();
+    }
+    vcd += "$end\n";
+    let mut time = 0;
+    for change in changes {
+        if change.index + 1 != time {
+            time = change.index + 1;
+            writeln!(vcd, "#{}", time).unwrap();
+        }
+        let (id, _) = &signals[&(change.address, change.width as u8)];
+        writeln!(vcd, "b{:b} {}", change.new, id).unwrap();
+    }
+    w.write_all(vcd.as_bytes())
+}
+
+/// Returns the `n`th VCD identifier code, made of printable ASCII characters.
+fn identifier(mut n: usize) -> String {
+    let mut id = String::new();
+    loop {
+        id.push((b'!' + (n % 94) as u8) as char);
+        n /= 94;
+        if n == 0 {
+            return id;
+        }
+        n -= 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Write;
+
+    #[test]
+    fn memory() {
+        let mut sim = Simulator::new();
+        assert_eq!(sim.read(0x2000_0000, Width::B4), 0);
+        sim.write(0x2000_0000, Width::B4, 0x1234_5678);
+        assert_eq!(sim.read(0x2000_0000, Width::B1), 0x78);
+        assert_eq!(sim.read(0x2000_0002, Width::B2), 0x1234);
+        sim.write(0x2000_0001, Width::B1, 0xAB);
+        assert_eq!(sim.read(0x2000_0000, Width::B4), 0x1234_AB78);
+        assert_eq!(sim.read(0x2000_0002, Width::B4), 0x1234);
+    }
+
+    #[test]
+    fn run() {
+        let mut sim = Simulator::with_reset_values([(0x2000_0000, 0xFF00)]);
+        let commands = [
+            Command::Write(Write::new(Width::B1, WriteOp::Set, 0x2000_0000, 0x0F)),
+            Command::Write(Write::new(Width::B2, WriteOp::Clear, 0x2000_0000, 0xF00F)),
+            Command::check32(CheckCond::AllSet, 0x2000_0000, 0x0F00),
+            Command::check32(CheckCond::AnyClear, 0x2000_0000, 0xF000),
+            Command::check32(CheckCond::AllClear, 0x2000_0000, 0x0F00).with_count(0),
+        ];
+        assert_eq!(sim.run(&commands), Outcome::Completed);
+        assert_eq!(sim.read(0x2000_0000, Width::B4), 0x0F00);
+        assert!(sim.satisfies(&Check::new(
+            Width::B1,
+            CheckCond::AllClear,
+            0x2000_0000,
+            0xFF
+        )));
+        assert!(!sim.satisfies(&Check::new(Width::B4, CheckCond::AnySet, 0x2000_0000, 0xFF)));
+
+        let commands = [
+            Command::write32(0x2000_0004, 1),
+            Command::check32(CheckCond::AnySet, 0x2000_0004, 2),
+            Command::write32(0x2000_0008, 1),
+        ];
+        assert_eq!(sim.run(&commands), Outcome::Hung { index: 1 });
+        assert_eq!(sim.read(0x2000_0008, Width::B4), 0);
+    }
+
+    #[test]
+    fn randomized() {
+        assert_eq!(splitmix64(&mut 0), 0xE220_A839_7B1D_CDAF);
+
+        let commands = [
+            Command::write32(0x2000_0000, 0x0F),
+            Command::Write(Write::new(Width::B1, WriteOp::Set, 0x2000_0000, 0x30)),
+            Command::check32(CheckCond::AllSet, 0x2000_0000, 0x3F),
+            Command::check32(CheckCond::AnyClear, 0x2000_0000, 0xC0).with_count(0),
+        ];
+        let sim = Simulator::new();
+        assert_eq!(
+            sim.run_randomized(&commands, 100, 1),
+            Ok(Outcome::Completed)
+        );
+        assert_eq!(sim, Simulator::new());
+
+        // a Set write keeps the other bits, which are only known if preloaded
+        let commands = [Command::set32(0x2000_0000, 1)];
+        let divergence = sim.run_randomized(&commands, 100, 1).unwrap_err();
+        assert_eq!(divergence.outcome, Outcome::Completed);
+        assert_eq!(divergence.addresses, [0x2000_0000]);
+        let mut again = divergence.initial.clone();
+        assert_eq!(again.run(&commands), Outcome::Completed);
+        assert_ne!(again.read(0x2000_0000, Width::B4), 1);
+        let sim = Simulator::with_reset_values([(0x2000_0000, 0)]);
+        assert_eq!(
+            sim.run_randomized(&commands, 100, 1),
+            Ok(Outcome::Completed)
+        );
+
+        let commands = [Command::check32(CheckCond::AnySet, 0x2000_0004, 1 << 31)];
+        let divergence = sim.run_randomized(&commands, 100, 1).unwrap_err();
+        assert_eq!(divergence.expected, Outcome::Hung { index: 0 });
+        assert_eq!(divergence.outcome, Outcome::Completed);
+        assert!(divergence.addresses.is_empty());
+        assert_eq!(
+            sim.run_randomized(&commands, 100, 1),
+            Err(divergence),
+            "same seed, same trials"
+        );
+    }
+
+    #[cfg(feature = "imxrt1062")]
+    #[test]
+    fn vcd() {
+        assert_eq!(identifier(0), "!");
+        assert_eq!(identifier(93), "~");
+        assert_eq!(identifier(94), "!!");
+        assert_eq!(identifier(94 + 94 * 94), "!!!");
+
+        let mut sim = Simulator::with_reset_values([(0x2000_0000, 0xFF)]);
+        let (outcome, changes) = sim.trace(&[
+            Command::write32(0x400F_C068, 1),
+            Command::Nop,
+            Command::clear32(0x2000_0000, 0xF0),
+            Command::Write(Write::new(Width::B1, WriteOp::Write, 0x2000_0000, 0x0A)),
+            Command::write32(0x400F_C068, 3),
+        ]);
+        assert_eq!(outcome, Outcome::Completed);
+        assert_eq!(
+            changes[3],
+            Change {
+                index: 4,
+                address: 0x400F_C068,
+                width: Width::B4,
+                old: 1,
+                new: 3
+            }
+        );
+        let mut vcd = vec![];
+        write_vcd(&mut vcd, &changes).unwrap();
+        assert_eq!(
+            String::from_utf8(vcd).unwrap(),
+            r#"$comment imxrt-dcd simulation; one time unit per command $end
+$timescale 1 us $end
+$scope module dcd $end
+$var reg 8 # 0x20000000 $end
+$var reg 32 " 0x20000000 $end
+$var reg 32 ! CCM+0x068 $end
+$upscope $end
+$enddefinitions $end
+#0
+$dumpvars
+b1111 #
+b11111111 "
+b0 !
+$end
+#1
+b1 !
+#3
+b1111 "
+#4
+b1010 #
+#5
+b11 !
+"#
+        );
+    }
+}
@@ -0,0 +1,201 @@
+//! Summary numbers of a DCD, e.g. for release notes or to track the DCD size over time.
+//!
+//! ```
+//! # use imxrt_dcd::*;
+//! let dcd = Dcd::new(vec![
+//!     Command::write32(0x400F_C068, 0xFFFF_FFFF),
+//!     Command::set32(0x400F_C06C, 0b11 << 4),
+//!     Command::check32(CheckCond::AllSet, 0x400D_8000, 1 << 31).with_count(100),
+//!     Command::Nop,
+//! ]);
+//! let stats = dcd.statistics();
+//! assert_eq!(stats.writes, 2);
+//! assert_eq!(stats.by_peripheral, [("CCM", 2), ("CCM_ANALOG", 1)]);
+//! assert_eq!(stats.max_poll_count, Some(100));
+//! assert_eq!(
+//!     stats.to_string(),
+//!     "4 commands, 48 bytes
+//! writes: 2 (write 1, set 1, clear 0)
+//! checks: 1 (1 bounded, at most 100 polls in total, 100 at most per check; 0 unbounded)
+//! nops: 1
+//! by peripheral: CCM 2, CCM_ANALOG 1
+//! "
+//! );
+//! ```
+
+use std::fmt;
+
+use crate::{command_byte_lens, peripheral_name, Command, WriteOp};
+
+/// Counts of commands, bytes and polls of a DCD; see [`crate::Dcd::statistics`].
+///
+/// The [`fmt::Display`] implementation lists them in a few lines of text.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Statistics {
+    /// Number of commands, counting a [`Command::WriteGroup`] once (like
+    /// [`Statistics::by_peripheral`]).
+    pub commands: usize,
+    /// Number of [`Command::Nop`]s.
+    pub nops: usize,
+    /// Number of [`Command::Unlock`]s; listed by the [`fmt::Display`] implementation only if there
+    /// are any.
+    pub unlocks: usize,
+    /// Number of [`Command::Write`]s, counting each address / value pair of a
+    /// [`Command::WriteGroup`].
+    pub writes: usize,
+    /// Number of writes for each [`WriteOp`], in the order `Write`, `Set`, `Clear`.
+    pub writes_by_op: [(WriteOp, usize); 3],
+    /// Number of [`Command::Check`]s.
+    pub checks: usize,
+    /// Number of commands accessing each peripheral, in order of first access. Addresses outside
+    /// of known peripherals count as `(unknown)`; NOPs and Unlock commands are not counted.
+    pub by_peripheral: Vec<(&'static str, usize)>,
+    /// Serialized byte length, including the DCD header (0 for no commands, like
+    /// [`crate::ensure_fits`]).
+    pub byte_len: usize,
+    /// Largest poll count of the checks with a poll count.
+    pub max_poll_count: Option<u32>,
+    /// Sum of the poll counts of the checks with a poll count, i.e. the number of polls if each of
+    /// them is satisfied only on its last poll.
+    pub total_poll_count: u64,
+    /// Number of checks that poll indefinitely.
+    pub unbounded_checks: usize,
+}
+
+impl Statistics {
+    /// Counts the given commands.
+    pub fn of(commands: &[Command]) -> Self {
+        let lens = command_byte_lens(commands);
+        let mut stats = Statistics {
+            writes_by_op: [(WriteOp::Write, 0), (WriteOp::Set, 0), (WriteOp::Clear, 0)],
+            byte_len: if commands.is_empty() {
+                0
+            } else {
+                4 + lens.iter().sum::<usize>()
+            },
+            commands: commands.len(),
+            ..Statistics::default()
+        };
+        for command in commands {
+            match command {
+                Command::Nop => {
+                    stats.nops += 1;
+                    continue;
+                }
+                Command::Unlock(_) => {
+                    stats.unlocks += 1;
+                    continue;
+                }
+                Command::Write(_) | Command::WriteGroup(_) => {
+                    for write in command.writes() {
+                        stats.writes += 1;
+                        let (_, count) = stats
+                            .writes_by_op
+                            .iter_mut()
+                            .find(|(op, _)| *op == write.op)
+                            .unwrap();
+                        *count += 1;
+                    }
+                }
+                Command::Check(check) => {
+                    stats.checks += 1;
+                    match check.count {
+                        Some(count) => {
+                            stats.max_poll_count = stats.max_poll_count.max(Some(count));
+                            stats.total_poll_count += count as u64;
+                        }
+                        None => stats.unbounded_checks += 1,
+                    }
+                }
+            }
+            let name = peripheral_name(command);
+            match stats.by_peripheral.iter_mut().find(|(n, _)| *n == name) {
+                Some((_, count)) => *count += 1,
+                None => stats.by_peripheral.push((name, 1)),
+            }
+        }
+        stats
+    }
+}
+
+impl fmt::Display for Statistics {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{} commands, {} bytes", self.commands, self.byte_len)?;
+        let [(_, write), (_, set), (_, clear)] = self.writes_by_op;
+        writeln!(
+            f,
+            "writes: {} (write {}, set {}, clear {})",
+            self.writes, write, set, clear
+        )?;
+        write!(f, "checks: {} (", self.checks)?;
+        if let Some(max) = self.max_poll_count {
+            write!(
+                f,
+                "{} bounded, at most {} polls in total, {} at most per check; ",
+                self.checks - self.unbounded_checks,
+                self.total_poll_count,
+                max
+            )?;
+        }
+        writeln!(f, "{} unbounded)", self.unbounded_checks)?;
+        writeln!(f, "nops: {}", self.nops)?;
+        if self.unlocks > 0 {
+            writeln!(f, "unlocks: {}", self.unlocks)?;
+        }
+        write!(f, "by peripheral:")?;
+        for (i, (name, count)) in self.by_peripheral.iter().enumerate() {
+            let separator = if i == 0 { "" } else { "," };
+            write!(f, "{} {} {}", separator, name, count)?;
+        }
+        writeln!(f)
+    }
+}
+
+#[cfg(all(test, feature = "imxrt1062"))]
+mod tests {
+    use super::*;
+    use crate::{CheckCond, Dcd, Width, WriteGroup};
+
+    #[test]
+    fn counts() {
+        let stats = Dcd::default().statistics();
+        assert_eq!(stats.byte_len, 0);
+        assert_eq!(
+            stats.to_string(),
+            "0 commands, 0 bytes\nwrites: 0 (write 0, set 0, clear 0)\nchecks: 0 (0 unbounded)\nnops: 0\nby peripheral:\n"
+        );
+
+        let dcd = Dcd::new(vec![
+            Command::write32(0x402F_0000, 1),
+            Command::clear32(0x2000_0000, 1),
+            Command::WriteGroup(WriteGroup::new(
+                Width::B4,
+                WriteOp::Clear,
+                vec![(0x2000_0004, 1), (0x2000_0008, 1)],
+            )),
+            Command::check32(CheckCond::AllSet, 0x402F_0004, 1).with_count(10),
+            Command::check32(CheckCond::AllSet, 0x402F_0004, 1),
+            Command::check32(CheckCond::AllSet, 0x402F_0004, 1).with_count(u32::MAX),
+        ]);
+        let stats = dcd.statistics();
+        assert_eq!(
+            stats,
+            Statistics {
+                commands: 6,
+                nops: 0,
+                unlocks: 0,
+                writes: 4,
+                writes_by_op: [(WriteOp::Write, 1), (WriteOp::Set, 0), (WriteOp::Clear, 3)],
+                checks: 3,
+                by_peripheral: vec![("SEMC", 4), ("(unknown)", 2)],
+                byte_len: 4 + 12 + 12 + 20 + 16 + 12 + 16,
+                max_poll_count: Some(u32::MAX),
+                total_poll_count: u32::MAX as u64 + 10,
+                unbounded_checks: 1,
+            }
+        );
+        let mut buf = vec![];
+        assert_eq!(dcd.serialize(&mut buf).unwrap(), stats.byte_len);
+        assert!(stats.to_string().starts_with("6 commands, "));
+    }
+}
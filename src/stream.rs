@@ -0,0 +1,211 @@
+//! Serializing commands one at a time, for command lists too large to collect first.
+//!
+//! [`crate::serialize`] takes all commands as a slice, since the DCD header and the header of
+//! each merged write start with their byte length. [`Encoder`] instead writes each command as it
+//! is pushed, and goes back to patch the lengths, so it needs a seekable writer (e.g. a file or an
+//! [`io::Cursor`]) but no memory proportional to the number of commands. The output is the same
+//! as of [`crate::serialize`]:
+//!
+//! ```
+//! # use imxrt_dcd::{serialize, stream::Encoder, Command};
+//! # use std::io::Cursor;
+//! let commands = (0..100).map(|i| Command::write32(0x2000_0000 + 4 * i, i));
+//!
+//! let mut encoder = Encoder::new(Cursor::new(vec![]));
+//! for command in commands.clone() {
+//!     encoder.push(&command).unwrap();
+//! }
+//! let (cursor, byte_len) = encoder.finish().unwrap();
+//!
+//! let mut bytes = vec![];
+//! assert_eq!(serialize(&mut bytes, &commands.collect::<Vec<_>>()).unwrap(), byte_len);
+//! assert_eq!(cursor.into_inner(), bytes);
+//! ```
+
+use std::io::{self, Seek, SeekFrom};
+
+use crate::{dcd_header, too_large, Command, Width, Write, WriteOp, MAX_BYTE_LEN, NOP_HEADER};
+
+/// Serializes commands pushed one at a time into a complete DCD block; see the
+/// [module-level docs](self).
+#[derive(Debug)]
+pub struct Encoder<W> {
+    w: W,
+    /// Stream position of the DCD header, once the first command is pushed.
+    start: Option<u64>,
+    /// Bytes written so far, including the DCD header.
+    byte_len: usize,
+    /// Stream position of the header, width, op and size of the group of writes being merged.
+    group: Option<(u64, Width, WriteOp, usize)>,
+}
+
+impl<W: io::Write + Seek> Encoder<W> {
+    /// Creates an encoder writing the DCD from the current position of `w` on.
+    pub fn new(w: W) -> Self {
+        Self {
+            w,
+            start: None,
+            byte_len: 0,
+            group: None,
+        }
+    }
+
+    /// Writes a command, merging it with the previous write like [`crate::serialize`].
+    ///
+    /// Returns [`io::ErrorKind::InvalidInput`] if the DCD would exceed the maximum byte length of
+    /// 65535 bytes, or for an empty [`crate::WriteGroup`]; the command is not written then.
+    pub fn push(&mut self, command: &Command) -> io::Result<()> {
+        if matches!(command, Command::WriteGroup(group) if group.pairs.is_empty()) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "write group without address / value pairs",
+            ));
+        }
+        if self.start.is_none() {
+            self.start = Some(self.w.stream_position()?);
+            self.w.write_all(&dcd_header(0))?;
+            self.byte_len = 4;
+        }
+        match command {
+            Command::Write(write) => {
+                if let Some((position, width, op, size)) = self.group {
+                    if (width, op) == (write.width, write.op) {
+                        self.reserve(8)?;
+                        self.w.write_all(&write.payload())?;
+                        self.group = Some((position, width, op, size + 1));
+                        return Ok(());
+                    }
+                }
+                self.reserve(12)?;
+                self.end_group()?;
+                let position = self.w.stream_position()?;
+                self.w.write_all(&write.header(1)?)?;
+                self.w.write_all(&write.payload())?;
+                self.group = Some((position, write.width, write.op, 1));
+            }
+            Command::WriteGroup(group) => {
+                self.reserve(4 + 8 * group.pairs.len())?;
+                self.end_group()?;
+                let mut writes = group.writes();
+                if let Some(first) = writes.next() {
+                    self.w.write_all(&first.header(group.pairs.len())?)?;
+                    self.w.write_all(&first.payload())?;
+                }
+                for write in writes {
+                    self.w.write_all(&write.payload())?;
+                }
+            }
+            Command::Nop => {
+                self.reserve(Command::NOP_BYTES)?;
+                self.end_group()?;
+                self.w.write_all(&NOP_HEADER)?;
+            }
+            Command::Unlock(unlock) => {
+                self.reserve(unlock.byte_len() as usize)?;
+                self.end_group()?;
+                self.w.write_all(&unlock.bytes())?;
+            }
+            Command::Check(check) => {
+                self.reserve(check.byte_len() as usize)?;
+                self.end_group()?;
+                self.w.write_all(&check.header())?;
+                match check.count {
+                    Some(count) => self.w.write_all(&check.payload_with_count(count))?,
+                    None => self.w.write_all(&check.payload())?,
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Patches the byte lengths, and returns the writer (positioned after the DCD) and the number
+    /// of bytes written, which is 0 if no commands were pushed.
+    pub fn finish(mut self) -> io::Result<(W, usize)> {
+        self.end_group()?;
+        if let Some(start) = self.start {
+            let end = self.w.stream_position()?;
+            self.w.seek(SeekFrom::Start(start))?;
+            // `reserve` keeps the byte length within the range of the header
+            self.w.write_all(&dcd_header(self.byte_len as u16))?;
+            self.w.seek(SeekFrom::Start(end))?;
+        }
+        Ok((self.w, self.byte_len))
+    }
+
+    /// Accounts for `bytes` more bytes, if the DCD stays within its maximum byte length.
+    fn reserve(&mut self, bytes: usize) -> io::Result<()> {
+        if self.byte_len + bytes > MAX_BYTE_LEN {
+            return Err(too_large(self.byte_len + bytes));
+        }
+        self.byte_len += bytes;
+        Ok(())
+    }
+
+    /// Patches the header of the group of writes being merged, if it has more than one write.
+    fn end_group(&mut self) -> io::Result<()> {
+        if let Some((position, width, op, size)) = self.group.take() {
+            if size > 1 {
+                let end = self.w.stream_position()?;
+                self.w.seek(SeekFrom::Start(position))?;
+                let write = Write::new(width, op, 0, 0);
+                self.w.write_all(&write.header(size)?)?;
+                self.w.seek(SeekFrom::Start(end))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{serialize, CheckCond};
+    use std::io::Cursor;
+
+    fn encode(commands: &[Command]) -> io::Result<Vec<u8>> {
+        let mut encoder = Encoder::new(Cursor::new(vec![0xAA]));
+        encoder.w.set_position(1);
+        for command in commands {
+            encoder.push(command)?;
+        }
+        let (cursor, byte_len) = encoder.finish()?;
+        let bytes = cursor.into_inner();
+        assert_eq!(bytes[0], 0xAA);
+        assert_eq!(bytes.len(), 1 + byte_len);
+        Ok(bytes[1..].to_vec())
+    }
+
+    #[test]
+    fn same_as_serialize() {
+        let commands = [
+            Command::Nop,
+            Command::write32(0x400F_C068, 1),
+            Command::write32(0x400F_C06C, 2),
+            Command::set32(0x400F_C070, 3),
+            Command::check32(CheckCond::AllSet, 0x400D_8000, 1 << 31).with_count(10),
+            Command::set32(0x400F_C074, 4),
+            Command::Write(Write::new(Width::B1, WriteOp::Set, 0x401F_8014, 5)),
+            Command::Write(Write::new(Width::B1, WriteOp::Set, 0x401F_8018, 6)),
+            Command::check32(CheckCond::AnyClear, 0x400D_8000, 1),
+        ];
+        for len in 0..=commands.len() {
+            let mut bytes = vec![];
+            serialize(&mut bytes, &commands[..len]).unwrap();
+            assert_eq!(encode(&commands[..len]).unwrap(), bytes);
+        }
+    }
+
+    #[test]
+    fn too_large() {
+        let mut encoder = Encoder::new(Cursor::new(vec![]));
+        // 4-byte DCD header, 4-byte write header, 8 bytes per write
+        for i in 0..(u16::MAX as u32 - 8) / 8 {
+            encoder.push(&Command::write32(0x2000_0000, i)).unwrap();
+        }
+        let check = Command::check32(CheckCond::AllSet, 0x2000_0000, 1).with_count(1);
+        let err = encoder.push(&check).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+        let (_, byte_len) = encoder.finish().unwrap();
+        assert_eq!(byte_len, 8 + 8 * 8190);
+    }
+}
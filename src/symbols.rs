@@ -0,0 +1,86 @@
+//! Address symbolization for human-readable output.
+
+/// Base addresses of RT1050/1060/1064 peripheral instances, sorted by address.
+///
+/// Taken from the `imxrt1062` instances of `imxrt-ral`. Where several instances share a base
+/// address (e.g. `CCM_ANALOG` / `PMU` / `XTALOSC24M`), only the first one is listed. Empty unless
+/// one of the `imxrt1052`, `imxrt1062` or `imxrt1064` features is enabled.
+#[cfg(any(feature = "imxrt1052", feature = "imxrt1062", feature = "imxrt1064"))]
+#[rustfmt::skip]
+const PERIPHERALS: &[(u32, &str)] = &[
+    (0x4007_C000, "AIPSTZ1"), (0x4008_0000, "DCDC"), (0x4008_4000, "PIT"),
+    (0x4009_4000, "CMP1"), (0x4009_4008, "CMP2"), (0x4009_4010, "CMP3"), (0x4009_4018, "CMP4"),
+    (0x400A_4000, "IOMUXC_SNVS_GPR"), (0x400A_8000, "IOMUXC_SNVS"), (0x400A_C000, "IOMUXC_GPR"),
+    (0x400B_0000, "FLEXRAM"), (0x400B_4000, "EWM"), (0x400B_8000, "WDOG1"), (0x400B_C000, "RTWDOG"),
+    (0x400C_0000, "GPIO5"), (0x400C_4000, "ADC1"), (0x400C_8000, "ADC2"), (0x400C_C000, "TRNG"),
+    (0x400D_0000, "WDOG2"), (0x400D_4000, "SNVS"), (0x400D_8000, "CCM_ANALOG"),
+    (0x400D_9000, "USBPHY1"), (0x400D_A000, "USBPHY2"), (0x400D_C000, "CSU"), (0x400E_0000, "TSC"),
+    (0x400E_8000, "DMA"), (0x400E_C000, "DMAMUX"), (0x400F_4000, "GPC"), (0x400F_8000, "SRC"),
+    (0x400F_C000, "CCM"),
+    (0x4017_C000, "AIPSTZ2"), (0x4018_0000, "ROMC"),
+    (0x4018_4000, "LPUART1"), (0x4018_8000, "LPUART2"), (0x4018_C000, "LPUART3"),
+    (0x4019_0000, "LPUART4"), (0x4019_4000, "LPUART5"), (0x4019_8000, "LPUART6"),
+    (0x4019_C000, "LPUART7"), (0x401A_0000, "LPUART8"), (0x401A_C000, "FLEXIO1"),
+    (0x401B_0000, "FLEXIO2"), (0x401B_8000, "GPIO1"), (0x401B_C000, "GPIO2"), (0x401C_0000, "GPIO3"),
+    (0x401C_4000, "GPIO4"), (0x401D_0000, "CAN1"), (0x401D_4000, "CAN2"), (0x401D_8000, "CAN3"),
+    (0x401D_C000, "TMR1"), (0x401E_0000, "TMR2"), (0x401E_4000, "TMR3"), (0x401E_8000, "TMR4"),
+    (0x401E_C000, "GPT1"), (0x401F_0000, "GPT2"), (0x401F_4000, "OCOTP"), (0x401F_8000, "IOMUXC"),
+    (0x401F_C000, "KPP"),
+    (0x4027_C000, "AIPSTZ3"), (0x402A_4000, "FLEXSPI2"), (0x402A_8000, "FLEXSPI1"),
+    (0x402B_4000, "PXP"), (0x402B_8000, "LCDIF"), (0x402B_C000, "CSI"), (0x402C_0000, "USDHC1"),
+    (0x402C_4000, "USDHC2"), (0x402D_4000, "ENET2"), (0x402D_8000, "ENET1"), (0x402E_0000, "USB1"),
+    (0x402E_0200, "USB2"), (0x402F_0000, "SEMC"), (0x402F_C000, "DCP"),
+    (0x4037_C000, "AIPSTZ4"), (0x4038_0000, "SPDIF"), (0x4038_4000, "SAI1"), (0x4038_8000, "SAI2"),
+    (0x4038_C000, "SAI3"), (0x4039_4000, "LPSPI1"), (0x4039_8000, "LPSPI2"), (0x4039_C000, "LPSPI3"),
+    (0x403A_0000, "LPSPI4"), (0x403B_0000, "ADC_ETC"), (0x403B_4000, "AOI1"), (0x403B_8000, "AOI2"),
+    (0x403B_C000, "XBARA1"), (0x403C_0000, "XBARB2"), (0x403C_4000, "XBARB3"), (0x403C_8000, "ENC1"),
+    (0x403C_C000, "ENC2"), (0x403D_0000, "ENC3"), (0x403D_4000, "ENC4"), (0x403D_C000, "PWM1"),
+    (0x403E_0000, "PWM2"), (0x403E_4000, "PWM3"), (0x403E_8000, "PWM4"), (0x403E_C000, "BEE"),
+    (0x403F_0000, "LPI2C1"), (0x403F_4000, "LPI2C2"), (0x403F_8000, "LPI2C3"), (0x403F_C000, "LPI2C4"),
+    (0x4200_0000, "GPIO6"), (0x4200_4000, "GPIO7"), (0x4200_8000, "GPIO8"), (0x4200_C000, "GPIO9"),
+    (0x4202_0000, "FLEXIO3"),
+];
+#[cfg(not(any(feature = "imxrt1052", feature = "imxrt1062", feature = "imxrt1064")))]
+const PERIPHERALS: &[(u32, &str)] = &[];
+
+/// Size of the address space assumed for each peripheral instance.
+const PERIPHERAL_SIZE: u32 = 0x4000;
+
+/// Returns the name of the peripheral instance containing `address`, and the offset of `address`
+/// from its base.
+pub(crate) fn peripheral(address: u32) -> Option<(&'static str, u32)> {
+    let index = PERIPHERALS
+        .partition_point(|&(base, _)| base <= address)
+        .checked_sub(1)?;
+    let (base, name) = PERIPHERALS[index];
+    let offset = address - base;
+    (offset < PERIPHERAL_SIZE).then_some((name, offset))
+}
+
+/// Formats `address` as `NAME+0xOFFSET`, or as a plain hexadecimal address if it is not known.
+pub(crate) fn symbolize(address: u32) -> String {
+    match peripheral(address) {
+        Some((name, offset)) => format!("{}+{:#05X}", name, offset),
+        None => format!("{:#010X}", address),
+    }
+}
+
+#[cfg(all(
+    test,
+    any(feature = "imxrt1052", feature = "imxrt1062", feature = "imxrt1064")
+))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lookup() {
+        assert!(PERIPHERALS.windows(2).all(|w| w[0].0 < w[1].0));
+        assert_eq!(peripheral(0x400F_C068), Some(("CCM", 0x68)));
+        assert_eq!(peripheral(0x402E_0204), Some(("USB2", 0x4)));
+        assert_eq!(peripheral(0x4009_4000), Some(("CMP1", 0)));
+        assert_eq!(peripheral(0x4000_0000), None);
+        assert_eq!(peripheral(0x4202_4000), None);
+        assert_eq!(symbolize(0x400F_C068), "CCM+0x068");
+        assert_eq!(symbolize(0x2000_0000), "0x20000000");
+    }
+}
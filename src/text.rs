@@ -0,0 +1,692 @@
+//! Line-based text format for DCD commands, intended for snapshot tests and code review.
+//!
+//! Each command is rendered on its own line, with a fixed field order and fixed-width hexadecimal
+//! numbers, so that the rendering of a command list is deterministic and changes show up as
+//! minimal line diffs:
+//!
+//! ```text
+//! write.32 0x400FC068 0xFFFFFFFF  # CCM+0x068
+//! clear.8 0x401F8014 0x00000007  # IOMUXC+0x014
+//! check.32 all_set 0x400D8000 0x80000000 count=100  # CCM_ANALOG+0x000
+//! nop
+//! ```
+//!
+//! Write mnemonics are `write`, `set` and `clear` (see [`WriteOp`]), check conditions are
+//! `all_clear`, `any_clear`, `all_set` and `any_set` (see [`CheckCond`]); both are suffixed with
+//! the width in bits. [`parse`] ignores blank lines and `#` comments, which [`render`] uses to
+//! annotate addresses with their peripheral, and [`render_annotated`] with their register and
+//! fields.
+//!
+//! A [`WriteGroup`] is rendered as `group` followed by the write mnemonic and
+//! its address / value pairs, e.g. `group write.32 0x400FC068 0xFFFFFFFF 0x400FC06C 0xFFFFFFFF`.
+//!
+//! HAB Unlock commands are rendered as `unlock <engine> <features>`, optionally followed by
+//! `uid=<uid>`; see [`Unlock`].
+//!
+//! A comment may suppress [lints](crate::lint) for its command with `allow(<lint>, ...)`, e.g.
+//! `# allow(poll_count)`; see [`parse_with_allows`].
+//!
+//! For commands that only apply to some board variants, [`parse_when`] additionally accepts a
+//! `when <flag>` prefix, and filters the commands according to the given flags.
+//!
+//! ```
+//! # use imxrt_dcd::{text, Command, CheckCond};
+//! let commands = [
+//!     Command::write32(0x400F_C068, 0xFFFF_FFFF),
+//!     Command::check32(CheckCond::AllSet, 0x400D_8000, 1 << 31).with_count(100),
+//! ];
+//! let rendered = text::render(&commands);
+//! assert_eq!(
+//!     rendered,
+//!     "write.32 0x400FC068 0xFFFFFFFF  # CCM+0x068\n\
+//!      check.32 all_set 0x400D8000 0x80000000 count=100  # CCM_ANALOG+0x000\n",
+//! );
+//! assert_eq!(text::parse(&rendered).unwrap(), commands);
+//! ```
+
+use std::fmt;
+
+use crate::lint::Lint;
+use crate::{
+    command_byte_lens, peripheral_name, symbols, Check, CheckCond, Command, Unlock, Width, Write,
+    WriteGroup, WriteOp,
+};
+
+/// Renders commands in the text format, one line per command.
+pub fn render(commands: &[Command]) -> String {
+    commands
+        .iter()
+        .map(|command| render_command(command) + "\n")
+        .collect()
+}
+
+/// Renders commands grouped by the peripheral they access, with subtotals, e.g. for reviewing a
+/// bring-up sequence one peripheral at a time.
+///
+/// Groups are in order of the first access to each peripheral, and each line starts with the
+/// index of the command in `commands`. Bytes are counted as serialized, so that the subtotals add
+/// up to the DCD byte length (except for the DCD header); a merged write counts 8 bytes plus the
+/// 4-byte write header if it starts a group of writes. Unlike [`render`], the output is a report
+/// and cannot be parsed back.
+///
+/// ```
+/// # use imxrt_dcd::{text, Command, CheckCond};
+/// let commands = [
+///     Command::write32(0x400F_C068, 0xFFFF_FFFF),
+///     Command::write32(0x402F_0000, 0x1000_0004),
+///     Command::write32(0x400F_C06C, 0xFFFF_FFFF),
+/// ];
+/// assert_eq!(
+///     text::render_by_peripheral(&commands),
+///     "CCM: 2 commands, 20 bytes\n\
+///      \x20    0  write.32 0x400FC068 0xFFFFFFFF  # CCM+0x068\n\
+///      \x20    2  write.32 0x400FC06C 0xFFFFFFFF  # CCM+0x06C\n\
+///      SEMC: 1 command, 8 bytes\n\
+///      \x20    1  write.32 0x402F0000 0x10000004  # SEMC+0x000\n\
+///      total: 3 commands, 32 bytes (including the 4-byte DCD header)\n",
+/// );
+/// ```
+pub fn render_by_peripheral(commands: &[Command]) -> String {
+    let lens = command_byte_lens(commands);
+    // (peripheral, bytes, indices), in order of first appearance
+    let mut groups: Vec<(&str, usize, Vec<usize>)> = vec![];
+    for (index, (command, len)) in commands.iter().zip(&lens).enumerate() {
+        let name = peripheral_name(command);
+        match groups.iter_mut().find(|(n, _, _)| *n == name) {
+            Some((_, bytes, indices)) => {
+                *bytes += len;
+                indices.push(index);
+            }
+            None => groups.push((name, *len, vec![index])),
+        }
+    }
+    let plural = |count: usize| if count == 1 { "" } else { "s" };
+    let mut out = String::new();
+    for (name, bytes, indices) in groups {
+        out += &format!(
+            "{}: {} command{}, {} bytes\n",
+            name,
+            indices.len(),
+            plural(indices.len()),
+            bytes
+        );
+        for index in indices {
+            out += &format!("{:>6}  {}\n", index, render_command(&commands[index]));
+        }
+    }
+    let byte_len = if commands.is_empty() {
+        0
+    } else {
+        4 + lens.iter().sum::<usize>()
+    };
+    out += &format!(
+        "total: {} command{}, {} bytes (including the 4-byte DCD header)\n",
+        commands.len(),
+        plural(commands.len()),
+        byte_len
+    );
+    out
+}
+
+/// Renders commands like [`render`], but annotates each command with the name of the register it
+/// accesses and the values of the fields it writes or checks (see [`explain`]), e.g. to make the
+/// disassembly of a binary DCD self-documenting. Without the `"ral"` feature, the annotations are
+/// the same as of [`render`].
+///
+/// The annotations are comments, so [`parse`] reads the output back. For the annotations
+/// themselves, attached to the commands, see [`explain::annotate`](crate::explain::annotate).
+///
+/// ```
+/// # use imxrt_dcd::{deserialize, serialize, text, Command};
+/// # let mut binary = vec![];
+/// # serialize(&mut binary, &[Command::write32(0x400D_8000, 0x0000_2064)]).unwrap();
+/// let commands = deserialize(&binary).unwrap();
+/// let rendered = text::render_annotated(&commands);
+/// # #[cfg(feature = "ral")]
+/// assert_eq!(
+///     rendered,
+///     "write.32 0x400D8000 0x00002064  # CCM_ANALOG_PLL_ARM: DIV_SELECT = 100, POWERDOWN = 0, \
+///      ENABLE = 1, BYPASS_CLK_SRC = 0, BYPASS = 0, PLL_SEL = 0, LOCK = 0\n",
+/// );
+/// assert_eq!(text::parse(&rendered).unwrap(), commands);
+/// ```
+///
+/// [`explain`]: crate::explain::explain
+pub fn render_annotated(commands: &[Command]) -> String {
+    crate::explain::annotate(commands)
+        .into_iter()
+        .map(|annotated| {
+            let (line, _) = render_instruction(&annotated.command);
+            match annotated.annotation {
+                Some(annotation) => format!("{}  # {}\n", line, annotation),
+                None => line + "\n",
+            }
+        })
+        .collect()
+}
+
+/// Renders a single command without a trailing newline.
+pub(crate) fn render_command(command: &Command) -> String {
+    match render_instruction(command) {
+        (line, Some(address)) if symbols::peripheral(address).is_some() => {
+            format!("{}  # {}", line, symbols::symbolize(address))
+        }
+        (line, _) => line,
+    }
+}
+
+/// Renders a single command without comment, and returns the address to annotate it with.
+fn render_instruction(command: &Command) -> (String, Option<u32>) {
+    let (line, address) = match command {
+        Command::Nop => return ("nop".into(), None),
+        Command::Unlock(unlock) => {
+            let mut line = format!("unlock {:#04X} {:#010X}", unlock.engine, unlock.features);
+            if let Some(uid) = unlock.uid {
+                line += &format!(" uid={:#018X}", uid);
+            }
+            return (line, None);
+        }
+        Command::Write(write) => (
+            format!(
+                "{}.{} {:#010X} {:#010X}",
+                write.op,
+                8 * write.width as u8,
+                write.address,
+                write.value
+            ),
+            write.address,
+        ),
+        Command::WriteGroup(group) => {
+            let mut line = format!("group {}.{}", group.op, 8 * group.width as u8);
+            for (address, value) in &group.pairs {
+                line += &format!(" {:#010X} {:#010X}", address, value);
+            }
+            match group.pairs.first() {
+                Some(&(address, _)) => (line, address),
+                None => return (line, None),
+            }
+        }
+        Command::Check(check) => {
+            let mut line = format!(
+                "check.{} {} {:#010X} {:#010X}",
+                8 * check.width as u8,
+                check.cond,
+                check.address,
+                check.mask
+            );
+            if let Some(count) = check.count {
+                line += &format!(" count={}", count);
+            }
+            (line, check.address)
+        }
+    };
+    (line, Some(address))
+}
+
+/// Error returned by [`parse`].
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ParseError {
+    /// 1-based line number.
+    pub line: usize,
+    /// Description of the error.
+    pub message: String,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Parses commands from the text format; see [module-level docs](self).
+///
+/// Numbers may be given in decimal or in hexadecimal with a `0x` prefix, and may contain `_`
+/// separators.
+///
+/// Conditional commands are rejected; see [`parse_when`].
+pub fn parse(text: &str) -> Result<Vec<Command>, ParseError> {
+    parse_lines(text, None)
+}
+
+/// Parses commands from the text format like [`parse`], additionally returning the lints that
+/// comments suppress for their command, by command index.
+///
+/// A comment suppresses lints with `allow(<lint>, ...)`, using the [names](Lint::name) of the
+/// lints, anywhere in the comment; [`Validator::allow_at`](crate::lint::Validator::allow_at)
+/// applies them:
+///
+/// ```
+/// # use imxrt_dcd::{lint::{Lint, Validator}, text};
+/// let text = "\
+///     write.32 0x400D8000 0x00002064  # PLL_ARM
+///     check.32 all_set 0x400D8000 0x80000000 count=20000000  # slow on some boards, allow(poll_count)
+/// ";
+/// let (commands, allows) = text::parse_with_allows(text).unwrap();
+/// assert_eq!(allows, [(1, Lint::PollCount)]);
+/// let validator = allows
+///     .into_iter()
+///     .fold(Validator::new(), |validator, (index, lint)| validator.allow_at(index, lint));
+/// assert!(validator.validate(&commands).is_empty());
+/// ```
+pub fn parse_with_allows(text: &str) -> Result<(Vec<Command>, Allows), ParseError> {
+    let mut commands = vec![];
+    let mut allows = vec![];
+    for (index, line) in text.lines().enumerate() {
+        let error = |message| ParseError {
+            line: index + 1,
+            message,
+        };
+        let (line, comment) = line.split_once('#').unwrap_or((line, ""));
+        let lints = parse_allow(comment).map_err(error)?;
+        if line.trim().is_empty() {
+            if !lints.is_empty() {
+                return Err(error("`allow` without a command".into()));
+            }
+            continue;
+        }
+        commands.extend(parse_lines(line, None).map_err(|err| error(err.message))?);
+        allows.extend(lints.into_iter().map(|lint| (commands.len() - 1, lint)));
+    }
+    Ok((commands, allows))
+}
+
+/// Lints suppressed for single commands, by command index.
+type Allows = Vec<(usize, Lint)>;
+
+/// Parses the lints in an `allow(...)` annotation of a comment, if any.
+fn parse_allow(comment: &str) -> Result<Vec<Lint>, String> {
+    let Some((_, rest)) = comment.split_once("allow(") else {
+        return Ok(vec![]);
+    };
+    let (names, _) = rest.split_once(')').ok_or("missing `)` after `allow(`")?;
+    names
+        .split(',')
+        .map(|name| {
+            let name = name.trim();
+            Lint::from_name(name).ok_or_else(|| format!("unknown lint `{}`", name))
+        })
+        .collect()
+}
+
+/// Parses commands from the text format like [`parse`], additionally accepting commands prefixed
+/// with a condition, `when <flag>` or `when !<flag>`.
+///
+/// A conditional command is only included if `enabled(flag)` returns `true` (or `false` for
+/// `!<flag>`), so that the commands for several board variants can be kept in one file. Excluded
+/// commands must still be valid.
+///
+/// In a build script, flags can be mapped to cargo features of the firmware crate:
+///
+/// ```
+/// # use imxrt_dcd::{text, Command};
+/// let text = "\
+///     write.32 0x400FC068 0xFFFFFFFF
+///     when sdram write.32 0x402F0000 0x10000004  # SEMC_MCR
+///     when !sdram nop
+/// ";
+/// let feature = |name: &str| {
+///     let name = name.to_uppercase().replace('-', "_");
+///     std::env::var_os(format!("CARGO_FEATURE_{}", name)).is_some()
+/// };
+/// # let feature = |name: &str| name == "sdram";
+/// assert_eq!(
+///     text::parse_when(text, feature).unwrap(),
+///     [Command::write32(0x400F_C068, 0xFFFF_FFFF), Command::write32(0x402F_0000, 0x1000_0004)],
+/// );
+/// ```
+pub fn parse_when(
+    text: &str,
+    mut enabled: impl FnMut(&str) -> bool,
+) -> Result<Vec<Command>, ParseError> {
+    parse_lines(text, Some(&mut enabled))
+}
+
+fn parse_lines(
+    text: &str,
+    mut enabled: Option<&mut dyn FnMut(&str) -> bool>,
+) -> Result<Vec<Command>, ParseError> {
+    let mut commands = vec![];
+    for (index, line) in text.lines().enumerate() {
+        let line = line.split('#').next().unwrap().trim();
+        if line.is_empty() {
+            continue;
+        }
+        let error = |message| ParseError {
+            line: index + 1,
+            message,
+        };
+        let (include, line) = match line.strip_prefix("when ") {
+            None => (true, line),
+            Some(rest) => {
+                let Some(enabled) = enabled.as_mut() else {
+                    return Err(error("conditional command outside of `parse_when`".into()));
+                };
+                let rest = rest.trim_start();
+                let (condition, line) = rest.split_once(char::is_whitespace).unwrap_or((rest, ""));
+                let (negated, flag) = match condition.strip_prefix('!') {
+                    Some(flag) => (true, flag),
+                    None => (false, condition),
+                };
+                let valid_flag = !flag.is_empty()
+                    && flag
+                        .chars()
+                        .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-');
+                if !valid_flag {
+                    return Err(error(format!("invalid condition `{}`", condition)));
+                }
+                (enabled(flag) != negated, line.trim_start())
+            }
+        };
+        let command = parse_command(line).map_err(error)?;
+        if include {
+            commands.push(command);
+        }
+    }
+    Ok(commands)
+}
+
+/// Parses a single command, without comments.
+pub(crate) fn parse_command(line: &str) -> Result<Command, String> {
+    let mut tokens = line.split_whitespace();
+    let mnemonic = tokens.next().ok_or("missing command")?;
+    if mnemonic == "nop" {
+        expect_end(tokens)?;
+        return Ok(Command::Nop);
+    }
+    if mnemonic == "unlock" {
+        let engine = next_number(&mut tokens, "engine")?;
+        let engine = u8::try_from(engine).map_err(|_| format!("invalid engine `{}`", engine))?;
+        let unlock = Unlock::new(engine, next_number(&mut tokens, "features")?);
+        let unlock = match tokens.next() {
+            None => unlock,
+            Some(token) => {
+                let uid = token
+                    .strip_prefix("uid=")
+                    .and_then(parse_u64)
+                    .ok_or_else(|| format!("invalid uid `{}`", token))?;
+                unlock.with_uid(uid)
+            }
+        };
+        expect_end(tokens)?;
+        return Ok(Command::Unlock(unlock));
+    }
+    let (grouped, mnemonic) = match mnemonic {
+        "group" => (true, tokens.next().ok_or("missing write after `group`")?),
+        mnemonic => (false, mnemonic),
+    };
+    let (name, bits) = mnemonic
+        .split_once('.')
+        .ok_or_else(|| format!("missing width in `{}`", mnemonic))?;
+    let width = match bits {
+        "8" => Width::B1,
+        "16" => Width::B2,
+        "32" => Width::B4,
+        _ => return Err(format!("invalid width `{}`", bits)),
+    };
+    let command = match name {
+        "check" if grouped => return Err("only writes can be grouped".into()),
+        "check" => {
+            let cond = tokens.next().ok_or("missing condition")?;
+            let cond: CheckCond = cond
+                .parse()
+                .map_err(|_| format!("invalid condition `{}`", cond))?;
+            let address = next_number(&mut tokens, "address")?;
+            let mask = next_number(&mut tokens, "mask")?;
+            let check = Check::new(width, cond, address, mask);
+            match tokens.next() {
+                None => Command::Check(check),
+                Some(token) => {
+                    let count = token
+                        .strip_prefix("count=")
+                        .and_then(parse_number)
+                        .ok_or_else(|| format!("invalid count `{}`", token))?;
+                    Command::Check(check.with_count(count))
+                }
+            }
+        }
+        _ => {
+            let op: WriteOp = name
+                .parse()
+                .map_err(|_| format!("unknown command `{}`", name))?;
+            let address = next_number(&mut tokens, "address")?;
+            let value = next_number(&mut tokens, "value")?;
+            if grouped {
+                let mut pairs = vec![(address, value)];
+                while let Some(token) = tokens.next() {
+                    let address = parse_number(token)
+                        .ok_or_else(|| format!("invalid address `{}`", token))?;
+                    pairs.push((address, next_number(&mut tokens, "value")?));
+                }
+                Command::WriteGroup(WriteGroup::new(width, op, pairs))
+            } else {
+                Command::Write(Write::new(width, op, address, value))
+            }
+        }
+    };
+    expect_end(tokens)?;
+    Ok(command)
+}
+
+fn expect_end<'a>(mut tokens: impl Iterator<Item = &'a str>) -> Result<(), String> {
+    match tokens.next() {
+        None => Ok(()),
+        Some(token) => Err(format!("unexpected `{}`", token)),
+    }
+}
+
+fn next_number<'a>(tokens: &mut impl Iterator<Item = &'a str>, what: &str) -> Result<u32, String> {
+    let token = tokens.next().ok_or_else(|| format!("missing {}", what))?;
+    parse_number(token).ok_or_else(|| format!("invalid {} `{}`", what, token))
+}
+
+fn parse_number(token: &str) -> Option<u32> {
+    parse_u64(token).and_then(|number| u32::try_from(number).ok())
+}
+
+fn parse_u64(token: &str) -> Option<u64> {
+    let token = token.replace('_', "");
+    match token
+        .strip_prefix("0x")
+        .or_else(|| token.strip_prefix("0X"))
+    {
+        Some(hex) => u64::from_str_radix(hex, 16).ok(),
+        None => token.parse().ok(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "imxrt1062")]
+    #[test]
+    fn roundtrip() {
+        let commands = [
+            Command::Nop,
+            Command::Write(Write::new(Width::B1, WriteOp::Clear, 0x401F_8014, 0x07)),
+            Command::Write(Write::new(Width::B2, WriteOp::Set, 0x2000_0000, 0x8000)),
+            Command::check32(CheckCond::AnyClear, 0x400D_8000, 1 << 31),
+            Command::Check(
+                Check::new(Width::B2, CheckCond::AllClear, 0x400D_8000, 3).with_count(0),
+            ),
+            Command::WriteGroup(WriteGroup::new(
+                Width::B4,
+                WriteOp::Write,
+                vec![(0x2000_0000, 1), (0x2000_0004, 2)],
+            )),
+            Command::Unlock(Unlock::new(Unlock::ENGINE_OCOTP, 1).with_uid(0x0123_4567_89AB_CDEF)),
+        ];
+        let text = render(&commands);
+        assert_eq!(
+            text,
+            "nop\n\
+             clear.8 0x401F8014 0x00000007  # IOMUXC+0x014\n\
+             set.16 0x20000000 0x00008000\n\
+             check.32 any_clear 0x400D8000 0x80000000  # CCM_ANALOG+0x000\n\
+             check.16 all_clear 0x400D8000 0x00000003 count=0  # CCM_ANALOG+0x000\n\
+             group write.32 0x20000000 0x00000001 0x20000004 0x00000002\n\
+             unlock 0x21 0x00000001 uid=0x0123456789ABCDEF\n",
+        );
+        assert_eq!(parse(&text).unwrap(), commands);
+    }
+
+    #[test]
+    fn lenient_input() {
+        assert_eq!(
+            parse("# comment\n\n  write.32  0x400F_C068   4294967295 # trailing\n").unwrap(),
+            [Command::write32(0x400F_C068, 0xFFFF_FFFF)],
+        );
+    }
+
+    #[test]
+    fn errors() {
+        let error = |text: &str| parse(text).unwrap_err();
+        assert_eq!(
+            error("nop\nwrite.24 0 0"),
+            ParseError {
+                line: 2,
+                message: "invalid width `24`".into()
+            },
+        );
+        assert_eq!(error("write.32 0").message, "missing value");
+        assert_eq!(
+            error("set.32 0 0x1_0000_0000").message,
+            "invalid value `0x1_0000_0000`"
+        );
+        assert_eq!(error("check.32 all 0 0").message, "invalid condition `all`");
+        assert_eq!(
+            error("check.32 all_set 0 0 100").message,
+            "invalid count `100`"
+        );
+        assert_eq!(error("nop nop").message, "unexpected `nop`");
+        assert_eq!(error("poke.32 0 0").message, "unknown command `poke`");
+        assert_eq!(
+            error("nop\n\nfoo").to_string(),
+            "line 3: missing width in `foo`"
+        );
+    }
+
+    #[test]
+    fn conditions() {
+        let text = "\
+            when a write.32 0 1\n\
+            when !a write.32 0 2\n\
+            when  b-c   nop  # comment\n\
+            write.32 0 3\n";
+        let parse_with = |flags: &[&str]| parse_when(text, |flag| flags.contains(&flag)).unwrap();
+        assert_eq!(
+            parse_with(&[]),
+            [Command::write32(0, 2), Command::write32(0, 3)]
+        );
+        assert_eq!(
+            parse_with(&["a", "b-c"]),
+            [Command::write32(0, 1), Command::Nop, Command::write32(0, 3)]
+        );
+
+        assert_eq!(
+            parse(text).unwrap_err(),
+            ParseError {
+                line: 1,
+                message: "conditional command outside of `parse_when`".into()
+            }
+        );
+        let error = |text: &str| parse_when(text, |_| false).unwrap_err().message;
+        assert_eq!(error("when ! nop"), "invalid condition `!`");
+        assert_eq!(error("when a.b nop"), "invalid condition `a.b`");
+        assert_eq!(error("when a"), "missing command");
+        // excluded commands are validated, too
+        assert_eq!(error("when a write.32 0"), "missing value");
+    }
+
+    #[test]
+    fn allows() {
+        let text = "\
+            nop  # allow(dead_check ,poll_count)\n\
+            write.32 0 1  # SEMC+0x000, allow(semc_before_clock)\n\
+            \n\
+            nop  # allowed\n";
+        let (commands, allows) = parse_with_allows(text).unwrap();
+        assert_eq!(commands.len(), 3);
+        assert_eq!(
+            allows,
+            [
+                (0, Lint::DeadCheck),
+                (0, Lint::PollCount),
+                (1, Lint::SemcBeforeClock)
+            ]
+        );
+        let error = |text: &str| parse_with_allows(text).unwrap_err().to_string();
+        assert_eq!(
+            error("nop\n# allow(poll_count)"),
+            "line 2: `allow` without a command"
+        );
+        assert_eq!(error("nop # allow(poll)"), "line 1: unknown lint `poll`");
+        assert_eq!(
+            error("nop # allow(poll_count"),
+            "line 1: missing `)` after `allow(`"
+        );
+        assert_eq!(
+            error("when a nop"),
+            "line 1: conditional command outside of `parse_when`"
+        );
+    }
+
+    #[cfg(feature = "imxrt1062")]
+    #[test]
+    fn by_peripheral() {
+        let commands = [
+            Command::Nop,
+            Command::set32(0x2000_0000, 1),
+            Command::set32(0x400F_C068, 1),
+            Command::check32(CheckCond::AllSet, 0x400F_C068, 1).with_count(1),
+        ];
+        assert_eq!(
+            render_by_peripheral(&commands),
+            "(nop): 1 command, 4 bytes
+     0  nop
+(unknown): 1 command, 12 bytes
+     1  set.32 0x20000000 0x00000001
+CCM: 2 commands, 24 bytes
+     2  set.32 0x400FC068 0x00000001  # CCM+0x068
+     3  check.32 all_set 0x400FC068 0x00000001 count=1  # CCM+0x068
+total: 4 commands, 44 bytes (including the 4-byte DCD header)
+"
+        );
+        assert_eq!(
+            render_by_peripheral(&[]),
+            "total: 0 commands, 0 bytes (including the 4-byte DCD header)\n"
+        );
+    }
+
+    #[test]
+    fn annotated() {
+        let commands = [
+            Command::Nop,
+            Command::set32(0x2000_0000, 1),
+            Command::Write(Write::new(Width::B1, WriteOp::Write, 0x400F_C068, 0xFF)),
+            Command::check32(CheckCond::AnySet, 0x400D_8000, 1 << 31 | 1 << 13),
+            Command::WriteGroup(WriteGroup::new(
+                Width::B4,
+                WriteOp::Write,
+                vec![(0x400F_C068, 0xFFFF_FFFF), (0x400F_C06C, 0xFFFF_FFFF)],
+            )),
+        ];
+        let rendered = render_annotated(&commands);
+        assert_eq!(parse(&rendered).unwrap(), commands);
+        #[cfg(not(feature = "ral"))]
+        assert_eq!(rendered, render(&commands));
+        #[cfg(feature = "ral")]
+        assert_eq!(
+            rendered,
+            "nop
+set.32 0x20000000 0x00000001
+write.8 0x400FC068 0x000000FF  # CCM+0x068
+check.32 any_set 0x400D8000 0x80002000  # CCM_ANALOG_PLL_ARM: ENABLE (bits 0x00002000), \
+LOCK (bits 0x80000000)
+group write.32 0x400FC068 0xFFFFFFFF 0x400FC06C 0xFFFFFFFF  # CCM+0x068
+"
+        );
+    }
+}
@@ -0,0 +1,218 @@
+//! Recording a DCD from a log of register writes captured with a debugger, e.g. while an SDK
+//! example configures SDRAM at runtime, to bootstrap a DCD doing the same at boot.
+//!
+//! [`Recorder::record`] recognizes these lines, and ignores all others:
+//!
+//! - OpenOCD commands `mww`, `mwh` and `mwb` (e.g. `mww 0x400fc068 0xffffffff`), possibly after a
+//!   `>` prompt;
+//! - OpenOCD debug output (`-d3`) of `target_write_u32`, `target_write_u16` and
+//!   `target_write_u8` (e.g. `Debug: 42 8 target.c:2614 target_write_u32(): address:
+//!   0x400fc068, value: 0xffffffff`);
+//! - names of the `probe_rs::MemoryInterface` methods `write_word_32`, `write_word_16` and
+//!   `write_word_8` followed by address and value (e.g. `write_word_32 0x400fc068 0xffffffff`),
+//!   as printed by a logging wrapper around a `probe_rs::Core`.
+//!
+//! The writes are filtered to the address ranges accepted by the boot ROM, which drops e.g.
+//! memory tests of the freshly configured SDRAM, and validated with the [lints](crate::lint) of
+//! the target. A runtime initialization waits for hardware by reading registers, which a trace of
+//! writes does not show: add the corresponding checks (e.g. for PLL locks or SEMC IP command
+//! completion) before using the DCD.
+//!
+//! ```
+//! # use imxrt_dcd::{trace::Recorder, Command, Target};
+//! let log = "
+//!     Open On-Chip Debugger 0.12.0
+//!     > mww 0x400fc068 0xffffffff
+//!     > mwb 0x80000000 0x55
+//! ";
+//! let recording = Recorder::new(Target::Imxrt1060).record(log).unwrap();
+//! assert_eq!(recording.commands, [Command::write32(0x400F_C068, 0xFFFF_FFFF)]);
+//! assert_eq!(recording.dropped[0].line, 4); // SDRAM, not accepted by the boot ROM
+//! assert!(recording.diagnostics.is_empty());
+//! ```
+
+use crate::lint::{Diagnostic, Validator};
+use crate::text::ParseError;
+use crate::{AllowedRange, Command, Target, Width, Write, WriteOp};
+
+/// Converts debugger write logs into command lists for a target.
+#[derive(Debug, Clone)]
+pub struct Recorder {
+    target: Target,
+    extra_ranges: Vec<AllowedRange>,
+}
+
+/// Result of [`Recorder::record`].
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct Recording {
+    /// Writes accepted by the boot ROM, in order.
+    pub commands: Vec<Command>,
+    /// Writes outside the address ranges accepted by the boot ROM, in order.
+    pub dropped: Vec<Dropped>,
+    /// Issues found by the lints of the target in `commands`.
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+/// A write of the log left out of a [`Recording`].
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Dropped {
+    /// 1-based line number in the log.
+    pub line: usize,
+    /// The write.
+    pub write: Write,
+}
+
+impl Recorder {
+    /// Creates a recorder keeping the writes accepted by the boot ROM of `target`.
+    pub fn new(target: Target) -> Self {
+        Self {
+            target,
+            extra_ranges: vec![],
+        }
+    }
+
+    /// Keeps writes in `range` too, like [`Validator::allow_range`].
+    pub fn allow_range(mut self, range: AllowedRange) -> Self {
+        self.extra_ranges.push(range);
+        self
+    }
+
+    /// Extracts the writes of `log`, filters and validates them.
+    ///
+    /// Returns an error for a recognized line with a missing or malformed address or value.
+    pub fn record(&self, log: &str) -> Result<Recording, ParseError> {
+        let mut recording = Recording::default();
+        for (index, line) in log.lines().enumerate() {
+            let line_number = index + 1;
+            let write = parse_line(line).map_err(|message| ParseError {
+                line: line_number,
+                message,
+            })?;
+            let Some(write) = write else { continue };
+            let accepted = (self.target.allowed_ranges().iter())
+                .chain(&self.extra_ranges)
+                .any(|range| range.contains(write.address));
+            if accepted {
+                recording.commands.push(Command::Write(write));
+            } else {
+                recording.dropped.push(Dropped {
+                    line: line_number,
+                    write,
+                });
+            }
+        }
+        let validator = (self.extra_ranges.iter())
+            .fold(Validator::new().target(self.target), |validator, range| {
+                validator.allow_range(*range)
+            });
+        recording.diagnostics = validator.validate(&recording.commands);
+        Ok(recording)
+    }
+}
+
+/// Returns the write of a line, `None` if the line is not recognized.
+fn parse_line(line: &str) -> Result<Option<Write>, String> {
+    let line = line.trim().trim_start_matches('>').trim();
+    let mut words = line.split_whitespace();
+    let first = words.next().unwrap_or_default();
+    let width = match first {
+        "mww" | "write_word_32" => Width::B4,
+        "mwh" | "write_word_16" => Width::B2,
+        "mwb" | "write_word_8" => Width::B1,
+        _ => return parse_openocd_debug(line),
+    };
+    let address = words.next().ok_or("missing address")?;
+    let value = words.next().ok_or("missing value")?;
+    write(width, address, value).map(Some)
+}
+
+/// Parses `target_write_uN(): address: A, value: V` of OpenOCD debug output.
+fn parse_openocd_debug(line: &str) -> Result<Option<Write>, String> {
+    let widths = [
+        ("target_write_u32():", Width::B4),
+        ("target_write_u16():", Width::B2),
+        ("target_write_u8():", Width::B1),
+    ];
+    for (function, width) in widths {
+        if let Some(start) = line.find(function) {
+            let rest = &line[start + function.len()..];
+            let field = |name: &str| {
+                let start = rest.find(name).ok_or(format!("missing {}", name))? + name.len();
+                let value = rest[start..]
+                    .split([',', ' '])
+                    .find(|word| !word.is_empty());
+                value.ok_or(format!("missing {}", name))
+            };
+            return write(width, field("address:")?, field("value:")?).map(Some);
+        }
+    }
+    Ok(None)
+}
+
+fn write(width: Width, address: &str, value: &str) -> Result<Write, String> {
+    let number = |word: &str| {
+        let parsed = match word.strip_prefix("0x").or_else(|| word.strip_prefix("0X")) {
+            Some(hex) => u64::from_str_radix(&hex.replace('_', ""), 16),
+            None => word.parse(),
+        };
+        parsed.map_err(|_| format!("invalid number `{}`", word))
+    };
+    let address = u32::try_from(number(address)?)
+        .map_err(|_| format!("address `{}` out of range", address))?;
+    let parsed = number(value)?;
+    if parsed > u64::from(width.mask()) {
+        return Err(format!(
+            "value `{}` too wide for {} bits",
+            value,
+            8 * width as u32
+        ));
+    }
+    Ok(Write::new(width, WriteOp::Write, address, parsed as u32))
+}
+
+#[cfg(all(test, feature = "imxrt1062"))]
+mod tests {
+    use super::*;
+    use crate::lint::Lint;
+
+    #[test]
+    fn formats() {
+        let log = "\
+            Debug: 101 8 target.c:2614 target_write_u32(): address: 0x400d8030, value: 0x00002001\n\
+            Debug: 102 8 target.c:2650 target_write_u16(): address: 0x401f8014, value: 0x0010\n\
+            Debug: 103 8 target.c:2614 target_read_u32(): address: 0x400d8030\n\
+            write_word_8 0x401F8200 0x12\n\
+            write_word_32 0x401F4000 1\n\
+            mww 0x20200000 0\n";
+        let recording = Recorder::new(Target::Imxrt1060).record(log).unwrap();
+        assert_eq!(
+            recording.commands,
+            [
+                Command::write32(0x400D_8030, 0x2001),
+                Command::Write(Write::new(Width::B2, WriteOp::Write, 0x401F_8014, 0x10)),
+                Command::Write(Write::new(Width::B1, WriteOp::Write, 0x401F_8200, 0x12)),
+            ]
+        );
+        let dropped: Vec<_> = recording.dropped.iter().map(|d| d.line).collect();
+        assert_eq!(dropped, [5, 6]);
+        assert!(recording.diagnostics.is_empty());
+
+        let ocotp = AllowedRange {
+            begin: 0x401F_4000,
+            end: 0x401F_7FFF,
+            name: "OCOTP",
+        };
+        let recording = (Recorder::new(Target::Imxrt1060).allow_range(ocotp))
+            .record(log)
+            .unwrap();
+        assert_eq!(recording.commands.len(), 4);
+        assert_eq!(recording.diagnostics[0].lint, Lint::DangerousWrite);
+
+        let err = Recorder::new(Target::Imxrt1060)
+            .record("mww 0x400fc068\nmwb 0x401f8014 0x100")
+            .unwrap_err();
+        assert_eq!(err.to_string(), "line 1: missing value");
+        let err = (Recorder::new(Target::Imxrt1060).record("mwb 0x401f8014 0x100")).unwrap_err();
+        assert_eq!(err.to_string(), "line 1: value `0x100` too wide for 8 bits");
+    }
+}
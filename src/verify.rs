@@ -0,0 +1,245 @@
+//! Hardware-in-the-loop verification: reading back the registers a DCD wrote after the target
+//! booted, and comparing them against the values expected from the DCD, e.g. on bring-up test
+//! racks.
+//!
+//! [`verify`] reads each register written by the DCD through [`ReadMemory`] and compares the bits
+//! whose final value is known from [`effects`]. With the `"probe"` feature, `ReadMemory` is
+//! implemented for a `probe_rs::Core`, so a host program can attach to the halted target after
+//! boot and print the [`Report`]:
+//!
+//! ```no_run
+//! # #[cfg(feature = "probe")]
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! # let commands = vec![];
+//! use imxrt_dcd::verify::{default_mask, verify};
+//! use probe_rs::{probe::list::Lister, Permissions};
+//!
+//! let probe = Lister::new().list_all()[0].open()?;
+//! let mut session = probe.attach("MIMXRT1062xxxxA", Permissions::default())?;
+//! let mut core = session.core(0)?;
+//! let report = verify(&commands, &mut core, default_mask)?;
+//! print!("{}", report);
+//! assert!(report.passed());
+//! # Ok(())
+//! # }
+//! # #[cfg(not(feature = "probe"))]
+//! # fn main() {}
+//! ```
+//!
+//! Registers that do not read back as written must be excluded through the mask, e.g. status bits
+//! that change by themselves, or write-1-to-clear flags; see [`default_mask`].
+
+use std::fmt;
+use std::io;
+
+use crate::effects::{effects, ValueKnowledge};
+use crate::readback::readback_mask;
+use crate::sim::Simulator;
+use crate::{symbols, Command, Width};
+
+/// Memory of a target that can be read, e.g. through a debug probe.
+pub trait ReadMemory {
+    /// Reads `width` bytes at `address`.
+    fn read(&mut self, address: u32, width: Width) -> io::Result<u32>;
+}
+
+impl ReadMemory for Simulator {
+    fn read(&mut self, address: u32, width: Width) -> io::Result<u32> {
+        Ok(Simulator::read(self, address, width))
+    }
+}
+
+#[cfg(feature = "probe")]
+impl ReadMemory for probe_rs::Core<'_> {
+    fn read(&mut self, address: u32, width: Width) -> io::Result<u32> {
+        use probe_rs::MemoryInterface;
+        let address = address as u64;
+        match width {
+            Width::B1 => self.read_word_8(address).map(u32::from),
+            Width::B2 => self.read_word_16(address).map(u32::from),
+            Width::B4 => self.read_word_32(address),
+        }
+        .map_err(io::Error::other)
+    }
+}
+
+/// Result of reading back one register; see [`verify`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct RegisterResult {
+    pub address: u32,
+    pub width: Width,
+    /// Bits compared.
+    pub mask: u32,
+    /// Expected value of the compared bits; other bits are 0.
+    pub expected: u32,
+    /// Value read back, all bits.
+    pub actual: u32,
+}
+
+impl RegisterResult {
+    /// Returns whether the compared bits read back as expected.
+    pub fn passed(&self) -> bool {
+        self.actual & self.mask == self.expected
+    }
+}
+
+/// Pass / fail report of [`verify`], one line per register when displayed.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct Report {
+    /// Compared registers, by address.
+    pub results: Vec<RegisterResult>,
+}
+
+impl Report {
+    /// Returns whether all registers passed.
+    pub fn passed(&self) -> bool {
+        self.results.iter().all(RegisterResult::passed)
+    }
+
+    /// Returns the registers that failed.
+    pub fn failures(&self) -> impl Iterator<Item = &RegisterResult> {
+        self.results.iter().filter(|result| !result.passed())
+    }
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for result in &self.results {
+            let name = symbols::symbolize(result.address).replace("0X", "0x");
+            if result.passed() {
+                writeln!(f, "PASS {}", name)?;
+            } else {
+                writeln!(
+                    f,
+                    "FAIL {}: expected {:#010x}, read {:#010x} (mask {:#010x})",
+                    name,
+                    result.expected,
+                    result.actual & result.mask,
+                    result.mask
+                )?;
+            }
+        }
+        let failed = self.failures().count();
+        write!(
+            f,
+            "{} of {} registers passed",
+            self.results.len() - failed,
+            self.results.len()
+        )
+    }
+}
+
+/// Default mask for [`verify`]: the known bits that read back as written, as for
+/// [`readback_all`](crate::readback::readback_all).
+///
+/// Registers where writing has side effects are skipped. With the `"ral"` feature, write-only
+/// registers are skipped, and only the bits of writable fields are compared, excluding reserved
+/// bits and read-only bits (e.g. `LOCK` of the PLLs); see [`readback_mask`].
+pub fn default_mask(address: u32, knowledge: &ValueKnowledge) -> u32 {
+    knowledge.known & readback_mask(address, knowledge.width)
+}
+
+/// Reads back every register written by `commands`, and compares the bits selected by `mask`
+/// against their expected final value.
+///
+/// `mask` is called for each register with its address and what is known about its value (see
+/// [`effects`]); it returns the bits to compare, which are intersected with the known bits.
+/// Registers with an empty mask are not read. Errors of `memory` are returned as is.
+///
+/// ```
+/// # use imxrt_dcd::{sim::Simulator, verify::*, Command};
+/// let commands = [
+///     Command::write32(0x2020_0000, 0x1234_5678),
+///     Command::set32(0x2020_0004, 0x0000_0100),
+/// ];
+/// let mut target = Simulator::new();
+/// target.run(&commands[..1]);
+/// let report = verify(&commands, &mut target, |_, knowledge| knowledge.known).unwrap();
+/// assert!(!report.passed());
+/// assert_eq!(
+///     report.to_string(),
+///     "\
+/// PASS 0x20200000
+/// FAIL 0x20200004: expected 0x00000100, read 0x00000000 (mask 0x00000100)
+/// 1 of 2 registers passed",
+/// );
+/// ```
+pub fn verify(
+    commands: &[Command],
+    memory: &mut impl ReadMemory,
+    mut mask: impl FnMut(u32, &ValueKnowledge) -> u32,
+) -> io::Result<Report> {
+    let mut report = Report::default();
+    for (address, knowledge) in effects(commands) {
+        let mask = mask(address, &knowledge) & knowledge.known;
+        if mask == 0 {
+            continue;
+        }
+        report.results.push(RegisterResult {
+            address,
+            width: knowledge.width,
+            mask,
+            expected: knowledge.value & mask,
+            actual: memory.read(address, knowledge.width)?,
+        });
+    }
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Write, WriteOp};
+
+    #[test]
+    fn simulator() {
+        let commands = [
+            Command::write32(0x400F_C068, 0xFFFF_FFFF), // CCM_CCGR0
+            Command::Write(Write::new(Width::B1, WriteOp::Write, 0x2020_0010, 0xAB)),
+            Command::clear32(0x2020_0020, 0xF0),
+        ];
+        let mut target = Simulator::with_reset_values([(0x2020_0020, 0xFF)]);
+        target.run(&commands);
+        let report = verify(&commands, &mut target, default_mask).unwrap();
+        assert!(report.passed());
+        assert_eq!(report.results.len(), 3);
+        // by address
+        assert_eq!(report.results[0].width, Width::B1);
+        assert_eq!(report.results[0].expected, 0xAB);
+        assert_eq!(report.results[1].actual, 0x0F);
+
+        target.write(0x400F_C068, Width::B4, 0xFFFF_FF0F);
+        let report = verify(&commands, &mut target, default_mask).unwrap();
+        assert_eq!(report.failures().count(), 1);
+        assert_eq!(report.failures().next().unwrap().address, 0x400F_C068);
+
+        // excluded registers are not read
+        let skip_ccgr0 = |address, _: &ValueKnowledge| match address {
+            0x400F_C068 => 0,
+            _ => u32::MAX,
+        };
+        let report = verify(&commands, &mut target, skip_ccgr0).unwrap();
+        assert!(report.passed());
+        assert_eq!(report.results.len(), 2);
+    }
+
+    #[cfg(feature = "imxrt1062")]
+    #[test]
+    fn default_mask_excludes() {
+        let commands = [
+            Command::write32(0x400D_8000, 0x0000_2064), // CCM_ANALOG_PLL_ARM
+            Command::set32(0x402F_003C, 0b11),          // SEMC_INTR, write 1 to clear
+        ];
+        let mut target = Simulator::new();
+        target.run(&commands);
+        // the PLL locked, and the flags were cleared
+        target.write(0x400D_8000, Width::B4, 0x8000_2064);
+        target.write(0x402F_003C, Width::B4, 0);
+        let report = verify(&commands, &mut target, default_mask).unwrap();
+        assert_eq!(report.results.len(), 1);
+        // without the register table, the read-only bits are compared, too
+        assert_eq!(report.passed(), cfg!(feature = "ral"));
+        #[cfg(feature = "ral")]
+        assert_eq!(report.results[0].mask, 0x0009_F07F);
+    }
+}
@@ -0,0 +1,155 @@
+//! Versioned serde representation of command lists, for long-lived description files.
+//!
+//! [`VersionedCommands`] serializes as `{ "version": N, "commands": [...] }` with the current
+//! [`FORMAT_VERSION`], and deserializes any supported version, migrating it to the current model.
+//! Format-agnostic, i.e. works with JSON / YAML / TOML alike.
+//!
+//! | version | representation                                                      |
+//! |---------|---------------------------------------------------------------------|
+//! | 0       | bare list of commands, i.e. `Vec<Command>` with the `serde` feature |
+//! | 1       | `{ "version": 1, "commands": [...] }`                               |
+//!
+//! ```
+//! # use imxrt_dcd::{versioned::VersionedCommands, Command};
+//! let VersionedCommands(commands) = serde_json::from_str(
+//!     r#"{ "version": 1, "commands": [{ "type": "nop" }] }"#,
+//! ).unwrap();
+//! assert_eq!(commands, [Command::Nop]);
+//!
+//! // version 0 files are migrated
+//! let VersionedCommands(commands) = serde_json::from_str(r#"[{ "type": "nop" }]"#).unwrap();
+//! assert_eq!(commands, [Command::Nop]);
+//!
+//! assert_eq!(
+//!     serde_json::to_string(&VersionedCommands(commands)).unwrap(),
+//!     r#"{"version":1,"commands":[{"type":"nop"}]}"#,
+//! );
+//! ```
+
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::Command;
+
+/// Format version written by [`VersionedCommands`].
+pub const FORMAT_VERSION: u32 = 1;
+
+/// A command list that (de)serializes with a format version; see [module-level docs](self).
+#[derive(Default, Clone, Debug, Eq, PartialEq)]
+pub struct VersionedCommands(pub Vec<Command>);
+
+#[derive(Serialize)]
+struct Envelope<'a> {
+    version: u32,
+    commands: &'a [Command],
+}
+
+impl Serialize for VersionedCommands {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        Envelope {
+            version: FORMAT_VERSION,
+            commands: &self.0,
+        }
+        .serialize(serializer)
+    }
+}
+
+/// Deserializes only from the number `N`.
+struct Version<const N: u32>;
+
+impl<'de, const N: u32> Deserialize<'de> for Version<N> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let version = u32::deserialize(deserializer)?;
+        if version != N {
+            return Err(de::Error::custom(format_args!(
+                "expected version {}, found {}",
+                N, version
+            )));
+        }
+        Ok(Version)
+    }
+}
+
+#[derive(Deserialize)]
+struct V1 {
+    #[serde(rename = "version")]
+    _version: Version<1>,
+    commands: Vec<Command>,
+}
+
+/// Only deserializes the version field, to report unsupported versions.
+#[derive(Deserialize)]
+struct Unsupported {
+    version: u32,
+}
+
+/// All supported versions; each is tried in order.
+///
+/// When the model changes, freeze the old model in a submodule, add a variant for the new version
+/// at the front, and migrate the older variants in [`AnyVersion::migrate`].
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum AnyVersion {
+    V1(V1),
+    V0(Vec<Command>),
+    Unsupported(Unsupported),
+}
+
+impl AnyVersion {
+    fn migrate<E: de::Error>(self) -> Result<Vec<Command>, E> {
+        match self {
+            AnyVersion::V1(v1) => Ok(v1.commands),
+            AnyVersion::V0(commands) => Ok(commands),
+            // a supported version that did not match its variant
+            AnyVersion::Unsupported(Unsupported { version }) if version <= FORMAT_VERSION => {
+                Err(E::custom(format_args!(
+                    "invalid commands for format version {}",
+                    version
+                )))
+            }
+            AnyVersion::Unsupported(Unsupported { version }) => Err(E::custom(format_args!(
+                "unsupported format version {} (supported: 0 to {})",
+                version, FORMAT_VERSION
+            ))),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for VersionedCommands {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        AnyVersion::deserialize(deserializer)?
+            .migrate()
+            .map(VersionedCommands)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unsupported_version() {
+        let err = serde_json::from_str::<VersionedCommands>(r#"{ "version": 7, "commands": [] }"#)
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "unsupported format version 7 (supported: 0 to 1)"
+        );
+        let err = serde_json::from_str::<VersionedCommands>(r#"{ "version": 1, "commands": 0 }"#)
+            .unwrap_err();
+        assert_eq!(err.to_string(), "invalid commands for format version 1");
+        assert!(serde_json::from_str::<VersionedCommands>(r#"{ "commands": [] }"#).is_err());
+    }
+
+    #[test]
+    fn roundtrip() {
+        let commands = VersionedCommands(vec![
+            Command::write32(0x400F_C068, 0xFFFF_FFFF),
+            Command::Nop,
+        ]);
+        let json = serde_json::to_string(&commands).unwrap();
+        assert_eq!(
+            serde_json::from_str::<VersionedCommands>(&json).unwrap(),
+            commands
+        );
+    }
+}
@@ -0,0 +1,36 @@
+//! JavaScript bindings for use in the browser, e.g. for a web-based DCD inspector.
+//!
+//! Build with the `wasm` feature for `wasm32-unknown-unknown`, then run `wasm-bindgen` (or
+//! `wasm-pack`) on the output. Commands cross the boundary in the [`text`] format.
+//!
+//! Everything except [`golden`](crate::golden) (which needs a filesystem) is usable on
+//! `wasm32-unknown-unknown` without this feature, too.
+
+use wasm_bindgen::prelude::*;
+
+use crate::{deserialize, lint, serialize, text};
+
+/// Serializes commands given in the text format into a DCD binary.
+#[wasm_bindgen(js_name = textToDcd)]
+pub fn text_to_dcd(text: &str) -> Result<Vec<u8>, JsError> {
+    let commands = text::parse(text)?;
+    let mut bytes = vec![];
+    serialize(&mut bytes, &commands)?;
+    Ok(bytes)
+}
+
+/// Renders a DCD binary in the text format.
+#[wasm_bindgen(js_name = dcdToText)]
+pub fn dcd_to_text(bytes: &[u8]) -> Result<String, JsError> {
+    Ok(text::render(&deserialize(bytes)?))
+}
+
+/// Runs all lints at their default levels over a DCD binary, returning one message per
+/// diagnostic.
+#[wasm_bindgen(js_name = lintDcd)]
+pub fn lint_dcd(bytes: &[u8]) -> Result<Vec<String>, JsError> {
+    Ok(lint::validate(&deserialize(bytes)?)
+        .iter()
+        .map(ToString::to_string)
+        .collect())
+}
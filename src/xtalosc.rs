@@ -0,0 +1,245 @@
+//! Helpers for the 24 MHz oscillators (`XTALOSC24M`): the crystal oscillator and the internal RC
+//! oscillator, which feed the PLLs.
+//!
+//! The boot ROM leaves both oscillators at their defaults, which suits most boards. [`OscConfig`]
+//! emits the writes for boards that need otherwise, e.g. a lower crystal drive current, or running
+//! from the trimmed RC oscillator, before the PLLs are configured:
+//!
+//! ```
+//! # use imxrt_dcd::{xtalosc::*, CheckCond, Command, Target};
+//! let config = OscConfig {
+//!     crystal: true,
+//!     crystal_current: Some(CrystalCurrent::Minus25),
+//!     poll_count: Some(100_000),
+//!     ..OscConfig::new(Target::Imxrt1060)
+//! };
+//! assert_eq!(
+//!     config.commands().unwrap(),
+//!     [
+//!         // CCM_ANALOG_MISC0: OSC_I
+//!         Command::clear32(0x400D_8150, 3 << 13),
+//!         Command::set32(0x400D_8150, 2 << 13),
+//!         // power up and wait for the oscillator to be stable
+//!         Command::clear32(0x400D_8150, 1 << 30),
+//!         Command::check32(CheckCond::AllSet, 0x400D_8270, 1 << 16).with_count(100_000),
+//!         Command::set32(0x400D_8150, 1 << 16),
+//!         Command::check32(CheckCond::AllSet, 0x400D_8150, 1 << 15).with_count(100_000),
+//!         Command::clear32(0x400D_8150, 1 << 16),
+//!     ],
+//! );
+//! ```
+//!
+//! The register layout is that of the RT1050 / RT1060 / RT1064; other targets are rejected.
+
+use std::io;
+
+use crate::board::DcdFragment;
+use crate::{registers, CheckCond, Command, Target, Width};
+
+/// Address of `CCM_ANALOG_MISC0`.
+const MISC0_ADDRESS: u32 = 0x400D_8150;
+/// `CCM_ANALOG_MISC0[OSC_I]`: crystal oscillator bias current.
+const MISC0_OSC_I: (u32, u32) = (13, 0b11);
+/// `CCM_ANALOG_MISC0[OSC_XTALOK]`: crystal oscillator frequency is stable.
+const MISC0_OSC_XTALOK: u32 = 1 << 15;
+/// `CCM_ANALOG_MISC0[OSC_XTALOK_EN]`: enables the detection of `OSC_XTALOK`.
+const MISC0_OSC_XTALOK_EN: u32 = 1 << 16;
+/// `CCM_ANALOG_MISC0[XTAL_24M_PWD]`: powers down the crystal oscillator.
+const MISC0_XTAL_24M_PWD: u32 = 1 << 30;
+/// Address of `XTALOSC24M_LOWPWR_CTRL`.
+const LOWPWR_CTRL_ADDRESS: u32 = 0x400D_8270;
+/// `XTALOSC24M_LOWPWR_CTRL[RC_OSC_EN]`: enables the RC oscillator.
+const LOWPWR_CTRL_RC_OSC_EN: u32 = 1 << 0;
+/// `XTALOSC24M_LOWPWR_CTRL[OSC_SEL]`: selects the RC oscillator instead of the crystal.
+const LOWPWR_CTRL_OSC_SEL: u32 = 1 << 4;
+/// `XTALOSC24M_LOWPWR_CTRL[XTALOSC_PWRUP_STAT]`: crystal oscillator is powered up.
+const LOWPWR_CTRL_XTALOSC_PWRUP_STAT: u32 = 1 << 16;
+/// Address of `XTALOSC24M_OSC_CONFIG0`.
+const OSC_CONFIG0_ADDRESS: u32 = 0x400D_82A0;
+/// `XTALOSC24M_OSC_CONFIG0[RC_OSC_PROG]`: RC oscillator frequency trim.
+const OSC_CONFIG0_RC_OSC_PROG: (u32, u32) = (4, 0xFF);
+
+/// Oscillator providing the 24 MHz reference clock (`XTALOSC24M_LOWPWR_CTRL[OSC_SEL]`).
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Source {
+    /// The crystal oscillator (reset default).
+    Crystal,
+    /// The internal RC oscillator.
+    Rc,
+}
+
+/// Bias current of the crystal oscillator (`CCM_ANALOG_MISC0[OSC_I]`), relative to nominal.
+///
+/// A lower current reduces power consumption and drive level, for crystals specified for less
+/// drive than the default.
+#[derive(Default, Debug, Copy, Clone, Eq, PartialEq)]
+#[repr(u32)]
+pub enum CrystalCurrent {
+    #[default]
+    Nominal = 0,
+    /// -12.5 %
+    Minus12_5 = 1,
+    /// -25 %
+    Minus25 = 2,
+    /// -37.5 %
+    Minus37_5 = 3,
+}
+
+/// Configuration of the 24 MHz oscillators. Fields left at their default (see
+/// [`OscConfig::new`]) are not changed.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct OscConfig {
+    /// Chip family, which determines the register layout.
+    pub target: Target,
+    /// Powers up the crystal oscillator and waits until it is stable, like
+    /// `CLOCK_InitExternalClk` of the MCUXpresso SDK.
+    pub crystal: bool,
+    /// Changes the bias current of the crystal oscillator.
+    pub crystal_current: Option<CrystalCurrent>,
+    /// Enables the RC oscillator, like `CLOCK_InitRcOsc24M` of the MCUXpresso SDK.
+    pub rc: bool,
+    /// Trims the frequency of the RC oscillator (`XTALOSC24M_OSC_CONFIG0[RC_OSC_PROG]`), e.g.
+    /// with a value measured per device against the crystal.
+    pub rc_trim: Option<u8>,
+    /// Switches the 24 MHz reference clock to the given oscillator, like `CLOCK_SwitchOsc` of the
+    /// MCUXpresso SDK. The selected oscillator is powered up first, as with [`OscConfig::crystal`]
+    /// / [`OscConfig::rc`].
+    pub source: Option<Source>,
+    /// Poll count of the stability checks; `None` polls indefinitely, which hangs the boot if the
+    /// crystal does not start.
+    pub poll_count: Option<u32>,
+}
+
+impl OscConfig {
+    /// Creates a configuration changing nothing.
+    pub fn new(target: Target) -> Self {
+        Self {
+            target,
+            crystal: false,
+            crystal_current: None,
+            rc: false,
+            rc_trim: None,
+            source: None,
+            poll_count: None,
+        }
+    }
+
+    /// Returns the commands applying the configuration: crystal current, crystal power-up with
+    /// stability checks, RC oscillator enable and trim, then source selection.
+    ///
+    /// Returns [`io::ErrorKind::Unsupported`] if the register layout of the target is not known.
+    pub fn commands(&self) -> io::Result<Vec<Command>> {
+        if !matches!(
+            self.target,
+            Target::Imxrt1050 | Target::Imxrt1060 | Target::Imxrt1064
+        ) {
+            return Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                format!("the XTALOSC24M layout of {:?} is not known", self.target),
+            ));
+        }
+        let check = |address, mask| {
+            let check = Command::check32(CheckCond::AllSet, address, mask);
+            match self.poll_count {
+                Some(count) => check.with_count(count),
+                None => check,
+            }
+        };
+        let mut commands = vec![];
+        if let Some(current) = self.crystal_current {
+            commands.extend(modify_field(MISC0_ADDRESS, MISC0_OSC_I, current as u32));
+        }
+        if self.crystal || self.source == Some(Source::Crystal) {
+            commands.extend([
+                Command::clear32(MISC0_ADDRESS, MISC0_XTAL_24M_PWD),
+                check(LOWPWR_CTRL_ADDRESS, LOWPWR_CTRL_XTALOSC_PWRUP_STAT),
+                Command::set32(MISC0_ADDRESS, MISC0_OSC_XTALOK_EN),
+                check(MISC0_ADDRESS, MISC0_OSC_XTALOK),
+                Command::clear32(MISC0_ADDRESS, MISC0_OSC_XTALOK_EN),
+            ]);
+        }
+        if self.rc || self.source == Some(Source::Rc) {
+            commands.push(Command::set32(LOWPWR_CTRL_ADDRESS, LOWPWR_CTRL_RC_OSC_EN));
+        }
+        if let Some(trim) = self.rc_trim {
+            commands.extend(modify_field(
+                OSC_CONFIG0_ADDRESS,
+                OSC_CONFIG0_RC_OSC_PROG,
+                trim as u32,
+            ));
+        }
+        match self.source {
+            Some(Source::Crystal) => {
+                commands.push(Command::clear32(LOWPWR_CTRL_ADDRESS, LOWPWR_CTRL_OSC_SEL))
+            }
+            Some(Source::Rc) => {
+                commands.push(Command::set32(LOWPWR_CTRL_ADDRESS, LOWPWR_CTRL_OSC_SEL))
+            }
+            None => {}
+        }
+        Ok(commands)
+    }
+}
+
+impl DcdFragment for OscConfig {
+    fn name(&self) -> &str {
+        "oscillators"
+    }
+
+    /// # Panics
+    ///
+    /// Panics if the register layout of the target is not known (see [`OscConfig::commands`]).
+    fn commands(&self) -> Vec<Command> {
+        OscConfig::commands(self).unwrap()
+    }
+}
+
+/// Returns the commands changing the field `(offset, mask)` of a register to `value`; see
+/// [`registers::modify`].
+fn modify_field(address: u32, (offset, mask): (u32, u32), value: u32) -> Vec<Command> {
+    registers::modify(Width::B4, address, mask << offset, value << offset)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rc_source() {
+        let config = OscConfig {
+            rc_trim: Some(0xA7),
+            source: Some(Source::Rc),
+            ..OscConfig::new(Target::Imxrt1060)
+        };
+        assert_eq!(
+            config.commands().unwrap(),
+            [
+                Command::set32(0x400D_8270, 1),
+                Command::clear32(0x400D_82A0, 0xFF << 4),
+                Command::set32(0x400D_82A0, 0xA7 << 4),
+                Command::set32(0x400D_8270, 1 << 4),
+            ]
+        );
+        let unchanged = OscConfig::new(Target::Imxrt1050).commands().unwrap();
+        assert!(unchanged.is_empty());
+
+        let config = OscConfig {
+            source: Some(Source::Crystal),
+            ..OscConfig::new(Target::Imxrt1064)
+        };
+        let commands = config.commands().unwrap();
+        assert_eq!(commands.len(), 6);
+        assert_eq!(
+            commands[1],
+            Command::check32(CheckCond::AllSet, 0x400D_8270, 1 << 16)
+        );
+        assert_eq!(commands[5], Command::clear32(0x400D_8270, 1 << 4));
+
+        let err = OscConfig::new(Target::Imxrt1010).commands().unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::Unsupported);
+        assert_eq!(
+            err.to_string(),
+            "the XTALOSC24M layout of Imxrt1010 is not known"
+        );
+    }
+}
@@ -0,0 +1,650 @@
+//! Importing the SDRAM of a Zephyr board from its devicetree source (`.dts`), so that boards
+//! already described for Zephyr don't need their SDRAM parameters transcribed by hand.
+//!
+//! Zephyr boards with an SDRAM on the SEMC choose it as `zephyr,sram`, with its address and size
+//! in `reg`:
+//!
+//! ```dts
+//! / {
+//!     chosen {
+//!         zephyr,sram = &sdram0;
+//!     };
+//!
+//!     sdram0: memory@80000000 {
+//!         device_type = "memory";
+//!         reg = <0x80000000 DT_SIZE_M(32)>;
+//!     };
+//! };
+//! ```
+//!
+//! Zephyr has no binding for the geometry and timings of the SDRAM (its DCD is in the C sources
+//! of the board), so [`sdram_config`] reads them from the optional properties below, e.g. added to
+//! the board in an overlay. They are looked up on the chosen node, then on the node labeled
+//! `semc`; parameters not given keep the value of [`SdramConfig::default`].
+//!
+//! - `clock-frequency`: [`SdramConfig::clock_hz`]
+//! - `port-width`: [`SdramConfig::port_bits`]
+//! - `burst-length`, `column-bits`, `cas-latency`, `refresh-period-ns`, `refresh-burst`: the
+//!   fields of the same name
+//! - `t-rp-ns`, `t-rcd-ns`, `t-rfc-ns`, `t-wr-ns`, `t-cke-off-ns`, `t-ras-ns`, `t-xsr-ns`,
+//!   `t-rc-ns`, `t-rrd-ns`: [`SdramConfig::timings`], by the names of the timings in SDRAM
+//!   datasheets
+//!
+//! The parser covers the devicetree syntax of board files: nodes with labels, `&label { ... }`
+//! references merged into the labeled node, and properties with strings, references and cells.
+//! Preprocessor lines (`#include`, ...) are skipped, so macros defined in included headers are
+//! not known; cells may use integers, parenthesized arithmetic and the `DT_SIZE_K` / `DT_SIZE_M`
+//! / `DT_FREQ_K` / `DT_FREQ_M` macros of Zephyr.
+
+use std::io;
+
+use crate::semc::SdramConfig;
+use crate::text::ParseError;
+
+/// Reads the configuration of the SDRAM chosen as `zephyr,sram` from devicetree source; see the
+/// [module-level docs](self).
+///
+/// Returns [`io::ErrorKind::InvalidData`] for syntax errors, if no SDRAM is chosen, or if a
+/// property does not hold a single 32-bit cell.
+///
+/// ```
+/// # use imxrt_dcd::{semc::SdramConfig, zephyr};
+/// let dts = r#"
+///     /dts-v1/;
+///     #include <nxp/nxp_rt1060.dtsi>
+///
+///     / {
+///         chosen {
+///             zephyr,sram = &sdram0;
+///         };
+///         sdram0: memory@80000000 {
+///             device_type = "memory";
+///             reg = <0x80000000 DT_SIZE_M(16)>;
+///         };
+///     };
+///
+///     &sdram0 {
+///         clock-frequency = <DT_FREQ_M(133)>;
+///         cas-latency = <2>;
+///     };
+/// "#;
+/// let config = zephyr::sdram_config(dts).unwrap();
+/// assert_eq!((config.base, config.size), (0x8000_0000, 16 << 20));
+/// assert_eq!((config.clock_hz, config.cas_latency), (133_000_000, 2));
+/// assert_eq!(config.timings, SdramConfig::default().timings);
+/// ```
+pub fn sdram_config(dts: &str) -> io::Result<SdramConfig> {
+    let root = parse(dts).map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+    let invalid = |message: String| io::Error::new(io::ErrorKind::InvalidData, message);
+    let chosen = root
+        .children
+        .iter()
+        .find(|node| node.name == "chosen")
+        .and_then(|chosen| chosen.property("zephyr,sram"))
+        .ok_or_else(|| invalid("no `zephyr,sram` in `/chosen`".into()))?;
+    let sdram = match chosen.values.as_slice() {
+        [Value::Reference(label)] => root.find_label(label),
+        [Value::String(path)] => root.find_path(path),
+        _ => None,
+    }
+    .ok_or_else(|| invalid(format!("line {}: unknown node", chosen.line)))?;
+
+    let nodes: Vec<&Node> = [Some(sdram), root.find_label("semc")]
+        .into_iter()
+        .flatten()
+        .collect();
+    let property = |name: &str| -> io::Result<Option<u32>> {
+        let Some(property) = nodes.iter().find_map(|node| node.property(name)) else {
+            return Ok(None);
+        };
+        match property.values.as_slice() {
+            [Value::Cells(cells)] if cells.len() == 1 && cells[0] <= u32::MAX as u64 => {
+                Ok(Some(cells[0] as u32))
+            }
+            _ => Err(invalid(format!(
+                "line {}: `{}` is not a single 32-bit cell",
+                property.line, name
+            ))),
+        }
+    };
+
+    let mut config = SdramConfig::default();
+    let reg = sdram
+        .property("reg")
+        .ok_or_else(|| invalid(format!("node `{}` has no `reg`", sdram.name)))?;
+    match reg.values.as_slice() {
+        [Value::Cells(cells)]
+            if cells.len() == 2 && cells.iter().all(|&c| c <= u32::MAX as u64) =>
+        {
+            (config.base, config.size) = (cells[0] as u32, cells[1] as u32);
+        }
+        _ => {
+            return Err(invalid(format!(
+                "line {}: `reg` is not a 32-bit address and size",
+                reg.line
+            )))
+        }
+    }
+    let narrow = |value: u32, name: &str| {
+        u8::try_from(value).map_err(|_| invalid(format!("`{}` of {} is out of range", name, value)))
+    };
+    if let Some(value) = property("clock-frequency")? {
+        config.clock_hz = value;
+    }
+    for (name, field) in [
+        ("port-width", &mut config.port_bits),
+        ("burst-length", &mut config.burst_length),
+        ("column-bits", &mut config.column_bits),
+        ("cas-latency", &mut config.cas_latency),
+        ("refresh-burst", &mut config.refresh_burst),
+    ] {
+        if let Some(value) = property(name)? {
+            *field = narrow(value, name)?;
+        }
+    }
+    let timings = &mut config.timings;
+    for (name, field) in [
+        ("refresh-period-ns", &mut config.refresh_period_ns),
+        ("t-rp-ns", &mut timings.precharge_to_act_ns),
+        ("t-rcd-ns", &mut timings.act_to_rw_ns),
+        ("t-rfc-ns", &mut timings.refresh_recovery_ns),
+        ("t-wr-ns", &mut timings.write_recovery_ns),
+        ("t-cke-off-ns", &mut timings.cke_off_ns),
+        ("t-ras-ns", &mut timings.act_to_precharge_ns),
+        ("t-xsr-ns", &mut timings.self_refresh_recovery_ns),
+        ("t-rc-ns", &mut timings.refresh_to_refresh_ns),
+        ("t-rrd-ns", &mut timings.act_to_act_ns),
+    ] {
+        if let Some(value) = property(name)? {
+            *field = value;
+        }
+    }
+    Ok(config)
+}
+
+/// A devicetree node.
+#[derive(Debug, Default)]
+struct Node {
+    labels: Vec<String>,
+    name: String,
+    properties: Vec<Property>,
+    children: Vec<Node>,
+}
+
+#[derive(Debug)]
+struct Property {
+    name: String,
+    values: Vec<Value>,
+    /// Line of the definition, for error messages.
+    line: usize,
+}
+
+/// A comma-separated part of a property value.
+#[derive(Debug)]
+enum Value {
+    String(String),
+    /// `<...>`, with references to other nodes (phandles) as 0.
+    Cells(Vec<u64>),
+    /// `&label`.
+    Reference(String),
+    /// `[...]`, whose contents are not kept.
+    Bytes,
+}
+
+impl Node {
+    fn property(&self, name: &str) -> Option<&Property> {
+        self.properties
+            .iter()
+            .find(|property| property.name == name)
+    }
+
+    fn find_label(&self, label: &str) -> Option<&Node> {
+        if self.labels.iter().any(|l| l == label) {
+            return Some(self);
+        }
+        self.children
+            .iter()
+            .find_map(|child| child.find_label(label))
+    }
+
+    fn find_label_mut(&mut self, label: &str) -> Option<&mut Node> {
+        if self.labels.iter().any(|l| l == label) {
+            return Some(self);
+        }
+        self.children
+            .iter_mut()
+            .find_map(|child| child.find_label_mut(label))
+    }
+
+    fn find_path(&self, path: &str) -> Option<&Node> {
+        path.split('/')
+            .filter(|name| !name.is_empty())
+            .try_fold(self, |node, name| {
+                node.children.iter().find(|child| child.name == name)
+            })
+    }
+
+    /// Merges the properties and children of `other` into this node, like a later definition of
+    /// the same node in the source.
+    fn merge(&mut self, other: Node) {
+        self.labels.extend(other.labels);
+        for property in other.properties {
+            self.properties.retain(|p| p.name != property.name);
+            self.properties.push(property);
+        }
+        for child in other.children {
+            match self.children.iter_mut().find(|c| c.name == child.name) {
+                Some(existing) => existing.merge(child),
+                None => self.children.push(child),
+            }
+        }
+    }
+}
+
+/// Parses devicetree source into the root node, with `&label { ... }` blocks merged in.
+fn parse(source: &str) -> Result<Node, ParseError> {
+    let mut parser = Parser { source, pos: 0 };
+    let mut root = Node::default();
+    loop {
+        parser.skip_whitespace();
+        if parser.pos == source.len() {
+            return Ok(root);
+        }
+        let rest = parser.rest();
+        if rest.starts_with("/dts-v1/")
+            || rest.starts_with("/plugin/")
+            || rest.starts_with("/memreserve/")
+            || rest.starts_with("/delete-node/")
+        {
+            parser.skip_statement();
+        } else if let Some(rest) = rest.strip_prefix('/') {
+            if !rest.trim_start().starts_with('{') {
+                return Err(parser.error("expected `{` after `/`"));
+            }
+            parser.pos += 1;
+            let node = parser.node_body(Node::default())?;
+            root.merge(node);
+        } else if rest.starts_with('&') {
+            parser.pos += 1;
+            let line = parser.line();
+            let label = parser.word()?;
+            let node = parser.node_body(Node::default())?;
+            match root.find_label_mut(&label) {
+                Some(target) => target.merge(node),
+                None => return Err(parser.error_at(line, format!("unknown label `{}`", label))),
+            }
+        } else {
+            return Err(parser.error("expected a root node or `&label`"));
+        }
+    }
+}
+
+struct Parser<'a> {
+    source: &'a str,
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn rest(&self) -> &'a str {
+        &self.source[self.pos..]
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.source.as_bytes().get(self.pos).copied()
+    }
+
+    fn line(&self) -> usize {
+        self.source[..self.pos].matches('\n').count() + 1
+    }
+
+    fn error(&self, message: &str) -> ParseError {
+        self.error_at(self.line(), message.into())
+    }
+
+    fn error_at(&self, line: usize, message: String) -> ParseError {
+        ParseError { line, message }
+    }
+
+    /// Skips whitespace, comments and preprocessor lines.
+    fn skip_whitespace(&mut self) {
+        loop {
+            let rest = self.rest();
+            let trimmed = rest.trim_start();
+            self.pos += rest.len() - trimmed.len();
+            if trimmed.starts_with("//") {
+                self.pos += trimmed.find('\n').unwrap_or(trimmed.len());
+            } else if let Some(comment) = trimmed.strip_prefix("/*") {
+                self.pos += comment.find("*/").map_or(trimmed.len(), |end| end + 4);
+            } else if let Some(directive) = trimmed.strip_prefix('#') {
+                // preprocessor directives, but not properties such as `#size-cells`
+                let directive = directive.trim_start();
+                let word_len = directive
+                    .find(|c: char| !c.is_ascii_alphabetic())
+                    .unwrap_or(directive.len());
+                if !matches!(
+                    &directive[..word_len],
+                    "include"
+                        | "define"
+                        | "undef"
+                        | "if"
+                        | "ifdef"
+                        | "ifndef"
+                        | "elif"
+                        | "else"
+                        | "endif"
+                        | "pragma"
+                        | "error"
+                ) {
+                    return;
+                }
+                self.pos += trimmed.find('\n').unwrap_or(trimmed.len());
+            } else {
+                return;
+            }
+        }
+    }
+
+    /// Skips up to and including the next `;`.
+    fn skip_statement(&mut self) {
+        self.pos += self
+            .rest()
+            .find(';')
+            .map_or(self.rest().len(), |end| end + 1);
+    }
+
+    fn expect(&mut self, c: u8) -> Result<(), ParseError> {
+        self.skip_whitespace();
+        if self.peek() != Some(c) {
+            return Err(self.error(&format!("expected `{}`", c as char)));
+        }
+        self.pos += 1;
+        Ok(())
+    }
+
+    /// Reads a node or property name, label, or integer literal.
+    fn word(&mut self) -> Result<String, ParseError> {
+        self.skip_whitespace();
+        let len = self
+            .rest()
+            .find(|c: char| !(c.is_ascii_alphanumeric() || ",._+-@#?".contains(c)))
+            .unwrap_or(self.rest().len());
+        if len == 0 {
+            return Err(self.error("expected a name"));
+        }
+        let word = self.rest()[..len].to_owned();
+        self.pos += len;
+        Ok(word)
+    }
+
+    /// Parses `{ ... };` into `node`.
+    fn node_body(&mut self, mut node: Node) -> Result<Node, ParseError> {
+        self.expect(b'{')?;
+        loop {
+            self.skip_whitespace();
+            match self.peek() {
+                None => return Err(self.error("unterminated node")),
+                Some(b'}') => {
+                    self.pos += 1;
+                    self.expect(b';')?;
+                    return Ok(node);
+                }
+                _ => {}
+            }
+            if self.rest().starts_with("/delete-") {
+                self.skip_statement();
+                continue;
+            }
+            let line = self.line();
+            let mut labels = vec![];
+            let mut name = self.word()?;
+            loop {
+                self.skip_whitespace();
+                if self.peek() != Some(b':') {
+                    break;
+                }
+                self.pos += 1;
+                labels.push(name);
+                name = self.word()?;
+            }
+            match self.peek() {
+                Some(b'{') => {
+                    let child = self.node_body(Node {
+                        labels,
+                        name,
+                        ..Node::default()
+                    })?;
+                    match node.children.iter_mut().find(|c| c.name == child.name) {
+                        Some(existing) => existing.merge(child),
+                        None => node.children.push(child),
+                    }
+                }
+                Some(b'=' | b';') => {
+                    let values = if self.peek() == Some(b'=') {
+                        self.pos += 1;
+                        self.values()?
+                    } else {
+                        self.pos += 1;
+                        vec![]
+                    };
+                    node.properties.retain(|p| p.name != name);
+                    node.properties.push(Property { name, values, line });
+                }
+                _ => return Err(self.error("expected `{`, `=` or `;`")),
+            }
+        }
+    }
+
+    /// Parses the comma-separated values of a property, and the terminating `;`.
+    fn values(&mut self) -> Result<Vec<Value>, ParseError> {
+        let mut values = vec![];
+        loop {
+            self.skip_whitespace();
+            values.push(match self.peek() {
+                Some(b'"') => Value::String(self.string()?),
+                Some(b'<') => {
+                    self.pos += 1;
+                    let mut cells = vec![];
+                    loop {
+                        self.skip_whitespace();
+                        match self.peek() {
+                            Some(b'>') => break,
+                            Some(b'&') => {
+                                self.pos += 1;
+                                self.word()?;
+                                cells.push(0);
+                            }
+                            _ => cells.push(self.primary()?),
+                        }
+                    }
+                    self.pos += 1;
+                    Value::Cells(cells)
+                }
+                Some(b'&') => {
+                    self.pos += 1;
+                    Value::Reference(self.word()?)
+                }
+                Some(b'[') => {
+                    let end = self
+                        .rest()
+                        .find(']')
+                        .ok_or_else(|| self.error("expected `]`"))?;
+                    self.pos += end + 1;
+                    Value::Bytes
+                }
+                _ => return Err(self.error("expected a property value")),
+            });
+            self.skip_whitespace();
+            if self.peek() == Some(b',') {
+                self.pos += 1;
+            } else {
+                self.expect(b';')?;
+                return Ok(values);
+            }
+        }
+    }
+
+    fn string(&mut self) -> Result<String, ParseError> {
+        self.pos += 1;
+        let mut string = String::new();
+        let mut chars = self.rest().char_indices();
+        while let Some((i, c)) = chars.next() {
+            match c {
+                '"' => {
+                    self.pos += i + 1;
+                    return Ok(string);
+                }
+                '\\' => string.extend(chars.next().map(|(_, c)| c)),
+                c => string.push(c),
+            }
+        }
+        Err(self.error("unterminated string"))
+    }
+
+    /// Parses a cell: an integer, a macro call or a parenthesized expression.
+    fn primary(&mut self) -> Result<u64, ParseError> {
+        self.skip_whitespace();
+        if self.peek() == Some(b'(') {
+            self.pos += 1;
+            let value = self.expression(0)?;
+            self.expect(b')')?;
+            return Ok(value);
+        }
+        let len = self
+            .rest()
+            .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+            .unwrap_or(self.rest().len());
+        if len == 0 {
+            return Err(self.error("expected a cell"));
+        }
+        let word = self.rest()[..len].to_owned();
+        self.pos += len;
+        let scale = match word.as_str() {
+            "DT_SIZE_K" => Some(1 << 10),
+            "DT_SIZE_M" => Some(1 << 20),
+            "DT_FREQ_K" => Some(1_000),
+            "DT_FREQ_M" => Some(1_000_000),
+            _ => None,
+        };
+        if let Some(scale) = scale {
+            return Ok(self.primary()?.wrapping_mul(scale));
+        }
+        let digits = word.trim_end_matches(['U', 'L', 'u', 'l']);
+        let value = match digits.strip_prefix("0x").or(digits.strip_prefix("0X")) {
+            Some(hex) => u64::from_str_radix(hex, 16),
+            None => digits.parse(),
+        };
+        value.map_err(|_| self.error(&format!("unsupported cell `{}`", word)))
+    }
+
+    /// Parses binary operators of at least the given precedence, by precedence climbing.
+    fn expression(&mut self, min_precedence: u8) -> Result<u64, ParseError> {
+        let mut value = self.primary()?;
+        loop {
+            self.skip_whitespace();
+            let rest = self.rest();
+            let Some((op, precedence)) = [
+                ("<<", 3),
+                (">>", 3),
+                ("*", 5),
+                ("/", 5),
+                ("+", 4),
+                ("-", 4),
+                ("&", 2),
+                ("|", 1),
+            ]
+            .into_iter()
+            .find(|(op, _)| rest.starts_with(op)) else {
+                return Ok(value);
+            };
+            if precedence < min_precedence {
+                return Ok(value);
+            }
+            self.pos += op.len();
+            let rhs = self.expression(precedence + 1)?;
+            value = match op {
+                "<<" => value.wrapping_shl(rhs as u32),
+                ">>" => value.wrapping_shr(rhs as u32),
+                "*" => value.wrapping_mul(rhs),
+                "/" => value
+                    .checked_div(rhs)
+                    .ok_or_else(|| self.error("division by zero"))?,
+                "+" => value.wrapping_add(rhs),
+                "-" => value.wrapping_sub(rhs),
+                "&" => value & rhs,
+                _ => value | rhs,
+            };
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn board() {
+        let dts = r#"
+            /dts-v1/;
+            /* Excerpt in the style of a Zephyr board */
+            #include <nxp/nxp_rt1060.dtsi>
+
+            / {
+                model = "Test board";
+                #address-cells = <1>;
+                chosen {
+                    zephyr,sram = "/soc/memory@80000000";
+                    zephyr,console = &lpuart1;
+                };
+                soc {
+                    sdram0: memory@80000000 {
+                        device_type = "memory";
+                        reg = <0x80000000 (DT_SIZE_M(8) * 4)>;
+                        t-rfc-ns = <0x3C>;
+                    };
+                    semc: semc@402f0000 {
+                        reg = <0x402f0000 0x4000>;
+                        clock-frequency = <166000000>;
+                        port-width = <8>;
+                    };
+                    lpuart1: uart@40184000 {
+                        status = "disabled";
+                        pinctrl-0 = <&pinmux_lpuart1>;
+                        data = [00 0a];
+                    };
+                };
+            };
+
+            &lpuart1 {
+                status = "okay";
+            };
+
+            &semc {
+                clock-frequency = <(133 * 1000 * 1000)>;
+            };
+        "#;
+        let config = sdram_config(dts).unwrap();
+        assert_eq!((config.base, config.size), (0x8000_0000, 32 << 20));
+        assert_eq!((config.clock_hz, config.port_bits), (133_000_000, 8));
+        assert_eq!(config.timings.refresh_recovery_ns, 60);
+        assert_eq!(config.cas_latency, SdramConfig::default().cas_latency);
+
+        let root = parse(dts).unwrap();
+        let uart = root.find_label("lpuart1").unwrap();
+        assert!(matches!(
+            uart.property("status").unwrap().values.as_slice(),
+            [Value::String(status)] if status == "okay"
+        ));
+
+        let message = |dts: &str| sdram_config(dts).unwrap_err().to_string();
+        assert_eq!(message("/ { };"), "no `zephyr,sram` in `/chosen`");
+        assert_eq!(
+            message("/ {\n chosen { zephyr,sram = &ocram; };\n};\n&ocram { };"),
+            "line 4: unknown label `ocram`"
+        );
+        assert_eq!(
+            message("/ {\n chosen { zephyr,sram = &m; };\n m: memory { reg = <0 1 2>; };\n};"),
+            "line 3: `reg` is not a 32-bit address and size"
+        );
+        assert_eq!(message("/ {\n node {\n};"), "line 3: unterminated node");
+    }
+}